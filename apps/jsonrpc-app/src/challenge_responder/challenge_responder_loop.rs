@@ -0,0 +1,241 @@
+use std::result::Result;
+
+use common_sentinel::{
+    call_core,
+    get_utc_timestamp,
+    ChallengeInfo,
+    ChallengeResponderConfig,
+    ChallengeResponse,
+    ChallengeState,
+    Challenges,
+    EthRpcMessages,
+    EthRpcSenders,
+    PendingTxs,
+    SentinelConfig,
+    SentinelError,
+    TxManagerMessages,
+    WebSocketMessagesEncodable,
+};
+use ethereum_types::H256 as EthHash;
+use tokio::time::{interval, Duration, MissedTickBehavior};
+
+use crate::{
+    health::HEALTH,
+    type_aliases::{TxManagerTx, WebSocketTx},
+};
+
+async fn get_challenges(config: &SentinelConfig, websocket_tx: WebSocketTx) -> Result<Challenges, SentinelError> {
+    let msg = WebSocketMessagesEncodable::GetChallenges;
+    Ok(Challenges::try_from(
+        call_core(*config.core().timeout(), websocket_tx, msg).await?,
+    )?)
+}
+
+async fn set_challenge_state(
+    config: &SentinelConfig,
+    websocket_tx: WebSocketTx,
+    id: EthHash,
+    state: ChallengeState,
+) -> Result<(), SentinelError> {
+    let msg = WebSocketMessagesEncodable::SetChallengeState(id, state);
+    call_core(*config.core().timeout(), websocket_tx, msg).await?;
+    Ok(())
+}
+
+// NOTE: The response is signed inside the core, and includes its signed status, which is why the
+// core must be asked for it rather than it being built here.
+async fn get_challenge_response(
+    config: &SentinelConfig,
+    websocket_tx: WebSocketTx,
+    eth_rpc_senders: &EthRpcSenders,
+    id: EthHash,
+) -> Result<ChallengeResponse, SentinelError> {
+    let mut network_ids = eth_rpc_senders.network_ids();
+    network_ids.sort_by_key(|id| id.to_string());
+    let msg = WebSocketMessagesEncodable::GetChallengeResponse(id, network_ids);
+    let r = call_core(*config.core().timeout(), websocket_tx, msg).await?;
+    Ok(ChallengeResponse::try_from(r)?)
+}
+
+// NOTE: Responses are submitted via the tx manager, which pushes them via the eth rpc for the
+// challenge's network, and replaces any which get stuck with ones paying higher fees, lest a stuck
+// response see the challenge expire.
+async fn respond_to_challenge(
+    config: &SentinelConfig,
+    responder_config: &ChallengeResponderConfig,
+    websocket_tx: WebSocketTx,
+    eth_rpc_senders: &EthRpcSenders,
+    tx_manager_tx: &TxManagerTx,
+    info: &ChallengeInfo,
+) -> Result<EthHash, SentinelError> {
+    let challenge = info.challenge();
+    let network_id = *challenge.network_id();
+    let hub = config.pnetwork_hub(&network_id)?;
+    let response = get_challenge_response(config, websocket_tx, eth_rpc_senders, *info.id()).await?;
+    let data = response.to_call_data(challenge)?;
+    let (msg, rx) = TxManagerMessages::submit_tx_msg(network_id, hub, data, *responder_config.gas_limit());
+    tx_manager_tx.send(msg).await?;
+    rx.await?
+}
+
+// NOTE: Returns the state of a challenge which is being answered. Whilst the tx manager has the tx
+// answering it pending, that's any replacements it has made of that tx, else it's whether any of
+// those hashes was mined successfully. A challenge whose answer failed goes back to pending, so
+// that it may be answered again before it expires.
+async fn check_answer(
+    config: &SentinelConfig,
+    websocket_tx: WebSocketTx,
+    eth_rpc_senders: &EthRpcSenders,
+    info: &ChallengeInfo,
+    tx_hashes: &[EthHash],
+    answered_at: u64,
+    now: u64,
+) -> Result<ChallengeState, SentinelError> {
+    let id = *info.id();
+    let network_id = *info.challenge().network_id();
+    let msg = WebSocketMessagesEncodable::GetPendingTxs;
+    let pending_txs = PendingTxs::try_from(call_core(*config.core().timeout(), websocket_tx, msg).await?)?;
+    if let Some(tx) = pending_txs
+        .txs()
+        .iter()
+        .find(|tx| tx.tx_hashes().iter().any(|h| tx_hashes.contains(h)))
+    {
+        debug!("answer to challenge 0x{id:x} still pending");
+        return Ok(ChallengeState::Answering {
+            tx_hashes: tx.tx_hashes().clone(),
+            answered_at,
+        });
+    };
+
+    let sender = eth_rpc_senders.sender(&network_id)?;
+    for tx_hash in tx_hashes.iter().rev() {
+        let (msg, rx) = EthRpcMessages::get_tx_receipt_msg(network_id, *tx_hash);
+        sender.send(msg).await?;
+        if let Some(receipt) = rx.await?? {
+            if receipt.status {
+                info!("challenge 0x{id:x} answered with tx 0x{tx_hash:x}");
+                return Ok(ChallengeState::Responded {
+                    tx_hash: *tx_hash,
+                    responded_at: now,
+                });
+            };
+            warn!("answer to challenge 0x{id:x} reverted with tx 0x{tx_hash:x}");
+            return Ok(ChallengeState::Pending);
+        };
+    }
+
+    warn!("answer to challenge 0x{id:x} was not mined");
+    Ok(ChallengeState::Pending)
+}
+
+async fn handle_challenges(
+    config: &SentinelConfig,
+    responder_config: &ChallengeResponderConfig,
+    websocket_tx: WebSocketTx,
+    eth_rpc_senders: &EthRpcSenders,
+    tx_manager_tx: &TxManagerTx,
+) -> Result<(), SentinelError> {
+    if !HEALTH.core_connected() {
+        return Err(SentinelError::NoCore);
+    };
+
+    let challenges = get_challenges(config, websocket_tx.clone()).await?;
+    for info in challenges.unresolved() {
+        let id = *info.id();
+        let expires_at = info.challenge().expires_at(*responder_config.challenge_duration());
+        let now = get_utc_timestamp()?;
+
+        let state = match info.state() {
+            ChallengeState::Answering { tx_hashes, answered_at } => {
+                let ws_tx = websocket_tx.clone();
+                match check_answer(config, ws_tx, eth_rpc_senders, info, tx_hashes, *answered_at, now).await {
+                    Ok(state) => state,
+                    Err(e) => {
+                        warn!("could not check answer to challenge 0x{id:x}: {e}");
+                        continue;
+                    },
+                }
+            },
+            state => state.clone(),
+        };
+
+        let state = if !state.is_resolved() && now >= expires_at {
+            error!("challenge 0x{id:x} expired at {expires_at} without being answered - the sentinel may be slashed");
+            ChallengeState::Expired
+        } else {
+            state
+        };
+
+        if &state != info.state() {
+            set_challenge_state(config, websocket_tx.clone(), id, state.clone()).await?;
+        };
+
+        if state != ChallengeState::Pending {
+            continue;
+        };
+
+        warn!("answering challenge 0x{id:x}, which expires in {}s", expires_at - now);
+        match respond_to_challenge(
+            config,
+            responder_config,
+            websocket_tx.clone(),
+            eth_rpc_senders,
+            tx_manager_tx,
+            info,
+        )
+        .await
+        {
+            // NOTE: The challenge is only responded to once the tx answering it has been mined.
+            Ok(tx_hash) => {
+                info!("challenge 0x{id:x} being answered with tx 0x{tx_hash:x}");
+                let state = ChallengeState::Answering {
+                    tx_hashes: vec![tx_hash],
+                    answered_at: now,
+                };
+                set_challenge_state(config, websocket_tx.clone(), id, state).await?;
+            },
+            // NOTE: The challenge remains pending, and so will be retried next time around.
+            Err(e) => error!("could not answer challenge 0x{id:x}: {e}"),
+        };
+    }
+
+    Ok(())
+}
+
+pub async fn challenge_responder_loop(
+    config: SentinelConfig,
+    websocket_tx: WebSocketTx,
+    eth_rpc_senders: EthRpcSenders,
+    tx_manager_tx: TxManagerTx,
+) -> Result<(), SentinelError> {
+    let name = "challenge responder";
+    let responder_config = config.challenge_responder().clone();
+    let responder_is_enabled = *responder_config.enabled();
+    let mut poll_interval = interval(Duration::from_secs(*responder_config.poll_interval()));
+    poll_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    if !responder_is_enabled {
+        warn!("{name} is disabled - any challenges to this sentinel will go unanswered");
+    };
+
+    'challenge_responder_loop: loop {
+        tokio::select! {
+            _ = poll_interval.tick(), if responder_is_enabled => {
+                if let Err(e) = handle_challenges(
+                    &config,
+                    &responder_config,
+                    websocket_tx.clone(),
+                    &eth_rpc_senders,
+                    &tx_manager_tx,
+                ).await {
+                    warn!("could not handle challenges in {name}: {e}");
+                };
+                continue 'challenge_responder_loop
+            },
+            _ = tokio::signal::ctrl_c() => {
+                warn!("{name} shutting down...");
+                break 'challenge_responder_loop Err(SentinelError::SigInt(name.into()))
+            },
+        }
+    }
+}
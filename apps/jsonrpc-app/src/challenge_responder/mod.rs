@@ -0,0 +1,3 @@
+mod challenge_responder_loop;
+
+pub(crate) use self::challenge_responder_loop::challenge_responder_loop;
@@ -1,5 +1,9 @@
 use clap::Subcommand;
 
+// NOTE: Each block replayed is a sub mat fetched one at a time, so this is capped to keep a dry run
+// from hammering an endpoint or running for hours.
+const MAX_DRY_RUN_BLOCKS: u64 = 1000;
+
 #[derive(Debug, Subcommand)]
 #[command(rename_all = "camelCase")]
 pub enum Commands {
@@ -8,4 +12,12 @@ pub enum Commands {
 
     /// Get submission materail for given block number from given endpoint
     GetSubMat { block_num: u64, endpoint: String },
+
+    /// Validate the config, checking each network's endpoints and contracts, printing a report
+    ValidateConfig {
+        /// Replay this many of the latest blocks (max 1000) to show which configured events would
+        /// have matched
+        #[arg(long, value_parser = clap::value_parser!(u64).range(1..=MAX_DRY_RUN_BLOCKS as i64))]
+        dry_run_blocks: Option<u64>,
+    },
 }
@@ -4,7 +4,7 @@ use std::{
 };
 
 use common_network_ids::NetworkId;
-use common_sentinel::{get_sub_mat, Endpoints, SentinelError};
+use common_sentinel::{get_raw_txs, get_sub_mat, Endpoints, SentinelError};
 use tokio::time::{sleep, Duration};
 
 pub async fn handle_get_sub_mat(block_num: u64, endpoint: String) -> Result<String, SentinelError> {
@@ -15,6 +15,13 @@ pub async fn handle_get_sub_mat(block_num: u64, endpoint: String) -> Result<Stri
     let client = endpoint.get_first_ws_client().await?;
     let sleep_time = 30;
     let sub_mat = get_sub_mat(&client, block_num, sleep_time, &network_id, endpoint.use_quicknode()).await?;
+    // NOTE: So that the written sub mat's receipts can be validated by a core.
+    let sub_mat = if sub_mat.receipts.is_empty() {
+        sub_mat
+    } else {
+        let raw_txs = get_raw_txs(&client, block_num, sleep_time, &network_id).await?;
+        sub_mat.add_raw_transactions(raw_txs)?
+    };
     let path = format!("./block-{block_num}.json");
     let file = File::create(&path)?;
     let mut writer = BufWriter::new(file);
@@ -0,0 +1,298 @@
+use common_chain_ids::EthChainId;
+use common_network_ids::NetworkId;
+use common_sentinel::{
+    get_chain_id,
+    get_code,
+    get_latest_block_num,
+    get_sub_mat,
+    Endpoints,
+    NetworkConfig,
+    SentinelConfig,
+    SentinelError,
+};
+use ethereum_types::{Address as EthAddress, H256 as EthHash};
+use jsonrpsee::ws_client::WsClient;
+use serde::Serialize;
+
+// NOTE: Kept short so that validating a config with unreachable endpoints doesn't take an age.
+const SLEEP_TIME: u64 = 5;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EndpointReport {
+    // NOTE: Endpoints are identified by their index in the config, since their urls may contain
+    // api keys.
+    index: usize,
+    chain_id: Option<u64>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ContractReport {
+    name: String,
+    address: EthAddress,
+    has_code: Option<bool>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EventMatch {
+    address: EthAddress,
+    topic: EthHash,
+    block_num: u64,
+    tx_hash: EthHash,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DryRunReport {
+    from_block: u64,
+    to_block: u64,
+    matches: Vec<EventMatch>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct NetworkReport {
+    network_id: NetworkId,
+    expected_chain_id: Option<u64>,
+    endpoints: Vec<EndpointReport>,
+    contracts: Vec<ContractReport>,
+    dry_run: Option<DryRunReport>,
+    errors: Vec<String>,
+}
+
+impl NetworkReport {
+    fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+            && !self.endpoints.is_empty()
+            && self
+                .endpoints
+                .iter()
+                .all(|e| e.error.is_none() && e.chain_id.is_some() && e.chain_id == self.expected_chain_id)
+            && self.contracts.iter().all(|c| c.has_code == Some(true))
+            && self.dry_run.as_ref().map_or(true, |d| d.error.is_none())
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigReport {
+    path: String,
+    valid: bool,
+    error: Option<String>,
+    networks: Vec<NetworkReport>,
+}
+
+impl ConfigReport {
+    pub fn is_valid(&self) -> bool {
+        self.valid
+    }
+}
+
+async fn check_endpoint(
+    network_id: NetworkId,
+    index: usize,
+    url: &str,
+) -> (EndpointReport, Option<(Endpoints, WsClient)>) {
+    let endpoints = Endpoints::new(SLEEP_TIME, network_id, vec![url.to_string()]);
+    let client = match endpoints.get_first_ws_client().await {
+        Ok(c) => c,
+        Err(e) => {
+            let report = EndpointReport {
+                index,
+                chain_id: None,
+                error: Some(e.to_string()),
+            };
+            return (report, None);
+        },
+    };
+    match get_chain_id(&client, SLEEP_TIME, network_id).await {
+        Ok(chain_id) => {
+            let report = EndpointReport {
+                index,
+                chain_id: Some(chain_id),
+                error: None,
+            };
+            (report, Some((endpoints, client)))
+        },
+        Err(e) => {
+            let report = EndpointReport {
+                index,
+                chain_id: None,
+                error: Some(e.to_string()),
+            };
+            (report, None)
+        },
+    }
+}
+
+async fn check_contract(client: &WsClient, network_id: NetworkId, name: &str, address: EthAddress) -> ContractReport {
+    let r = get_code(client, &address, SLEEP_TIME, network_id).await;
+    ContractReport {
+        address,
+        name: name.to_string(),
+        has_code: r.as_ref().ok().map(|code| !code.is_empty()),
+        error: r.err().map(|e| e.to_string()),
+    }
+}
+
+// NOTE: This replays the most recent blocks through the same event matching the core uses when
+// signing events, without the need for a core, and thus without any signing or chain validation.
+async fn dry_run(
+    client: &WsClient,
+    endpoints: &Endpoints,
+    network_config: &NetworkConfig,
+    num_blocks: u64,
+) -> Result<DryRunReport, SentinelError> {
+    let network_id = network_config.network_id();
+    let to_block = get_latest_block_num(client, SLEEP_TIME, &network_id).await?;
+    let from_block = to_block.saturating_sub(num_blocks.saturating_sub(1));
+
+    let mut matches = vec![];
+    for block_num in from_block..=to_block {
+        let sub_mat = get_sub_mat(client, block_num, SLEEP_TIME, &network_id, endpoints.use_quicknode()).await?;
+        for event in network_config.events().iter() {
+            for receipt in sub_mat.receipts.iter() {
+                receipt.logs.iter().filter(|log| event.matches(log)).for_each(|_| {
+                    matches.push(EventMatch {
+                        block_num,
+                        address: event.address,
+                        topic: event.topic,
+                        tx_hash: receipt.transaction_hash,
+                    })
+                });
+            }
+        }
+    }
+
+    Ok(DryRunReport {
+        from_block,
+        to_block,
+        matches,
+        error: None,
+    })
+}
+
+async fn validate_network(network_config: &NetworkConfig, dry_run_blocks: Option<u64>) -> NetworkReport {
+    let network_id = network_config.network_id();
+    let mut errors = vec![];
+
+    let expected_chain_id = match EthChainId::try_from(&network_id) {
+        Ok(id) => Some(id.to_u64()),
+        Err(e) => {
+            errors.push(e.to_string());
+            None
+        },
+    };
+
+    let mut endpoint_reports = vec![];
+    let mut working_endpoint = None;
+    for (i, url) in network_config.endpoints().endpoints().iter().enumerate() {
+        let (report, endpoint) = check_endpoint(network_id, i, url).await;
+        if working_endpoint.is_none() && report.chain_id.is_some() && report.chain_id == expected_chain_id {
+            working_endpoint = endpoint;
+        };
+        endpoint_reports.push(report);
+    }
+
+    if endpoint_reports.is_empty() {
+        errors.push(format!("no endpoints configured for {network_id}"));
+    };
+
+    let mut contracts = vec![];
+    let mut dry_run_report = None;
+    match working_endpoint {
+        None => errors.push(format!("no working endpoint for {network_id} to check contracts with")),
+        Some((endpoints, client)) => {
+            contracts.push(check_contract(&client, network_id, "pnetworkHub", *network_config.pnetwork_hub()).await);
+            for event in network_config.events().iter() {
+                contracts.push(check_contract(&client, network_id, "event", event.address).await);
+            }
+
+            if let Some(n) = dry_run_blocks {
+                let r = dry_run(&client, &endpoints, network_config, n).await;
+                dry_run_report = Some(r.unwrap_or_else(|e| DryRunReport {
+                    error: Some(e.to_string()),
+                    ..Default::default()
+                }));
+            };
+        },
+    };
+
+    NetworkReport {
+        errors,
+        contracts,
+        network_id,
+        expected_chain_id,
+        dry_run: dry_run_report,
+        endpoints: endpoint_reports,
+    }
+}
+
+pub async fn handle_validate_config(path: &str, dry_run_blocks: Option<u64>) -> ConfigReport {
+    debug!("validating config at path '{path}'...");
+
+    // NOTE: Parsing the config also checks its sanity bounds, such as those of the `LogConfig`.
+    let config = match SentinelConfig::new(path) {
+        Ok(c) => c,
+        Err(e) => {
+            return ConfigReport {
+                path: path.to_string(),
+                valid: false,
+                error: Some(e.to_string()),
+                networks: vec![],
+            }
+        },
+    };
+
+    let mut network_ids = config.network_ids();
+    network_ids.sort_by_key(|id| id.to_string());
+
+    let mut networks = vec![];
+    for network_id in network_ids.iter() {
+        if let Some(network_config) = config.networks().get(network_id) {
+            networks.push(validate_network(network_config, dry_run_blocks).await);
+        };
+    }
+
+    ConfigReport {
+        path: path.to_string(),
+        valid: networks.iter().all(|n| n.is_valid()),
+        error: None,
+        networks,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn should_report_config_which_cannot_be_parsed() {
+        let report = handle_validate_config("not/a/config/path", None).await;
+        assert!(!report.is_valid());
+        assert!(report.error.is_some());
+        assert!(report.networks.is_empty());
+    }
+
+    #[test]
+    fn network_with_endpoint_on_wrong_chain_should_be_invalid() {
+        let report = NetworkReport {
+            network_id: NetworkId::default(),
+            expected_chain_id: Some(1),
+            endpoints: vec![EndpointReport {
+                index: 0,
+                chain_id: Some(56),
+                error: None,
+            }],
+            contracts: vec![],
+            dry_run: None,
+            errors: vec![],
+        };
+        assert!(!report.is_valid());
+    }
+}
@@ -1,4 +1,9 @@
 mod handle_get_sub_mat;
 mod handle_test_endpoint;
+mod handle_validate_config;
 
-pub(super) use self::{handle_get_sub_mat::handle_get_sub_mat, handle_test_endpoint::handle_test_endpoint};
+pub(super) use self::{
+    handle_get_sub_mat::handle_get_sub_mat,
+    handle_test_endpoint::handle_test_endpoint,
+    handle_validate_config::handle_validate_config,
+};
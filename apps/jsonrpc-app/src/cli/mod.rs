@@ -1,15 +1,24 @@
 mod commands;
 mod handle_commands;
 use common_sentinel::SentinelError;
-use serde_json::json;
+use serde_json::{json, Value as Json};
 
 pub(crate) use self::commands::Commands;
-use self::handle_commands::{handle_get_sub_mat, handle_test_endpoint};
+use self::handle_commands::{handle_get_sub_mat, handle_test_endpoint, handle_validate_config};
 
-pub async fn handle_cli(cmds: Commands) -> Result<String, SentinelError> {
+pub async fn handle_cli(cmds: Commands, config_path: &str) -> Result<String, SentinelError> {
     let result = match cmds {
-        Commands::TestEndpoint { endpoint } => handle_test_endpoint(endpoint).await,
-        Commands::GetSubMat { block_num, endpoint } => handle_get_sub_mat(block_num, endpoint).await,
+        Commands::TestEndpoint { endpoint } => handle_test_endpoint(endpoint).await.map(Json::from),
+        Commands::GetSubMat { block_num, endpoint } => handle_get_sub_mat(block_num, endpoint).await.map(Json::from),
+        Commands::ValidateConfig { dry_run_blocks } => {
+            let report = handle_validate_config(config_path, dry_run_blocks).await;
+            // NOTE: An invalid config is returned as an error so that the exit code reflects it.
+            return if report.is_valid() {
+                Ok(json!({"jsonrpc": "2.0", "success": report}).to_string())
+            } else {
+                Err(SentinelError::Json(json!({"jsonrpc": "2.0", "error": report})))
+            };
+        },
     };
 
     result
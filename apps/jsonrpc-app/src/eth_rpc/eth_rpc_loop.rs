@@ -2,15 +2,21 @@ use std::result::Result;
 
 use common_network_ids::NetworkId;
 use common_sentinel::{
+    estimate_gas,
     eth_call,
+    get_eip_1559_fees,
     get_eth_balance,
     get_gas_price,
     get_latest_block_num,
     get_nonce,
+    get_raw_txs,
     get_sub_mat,
+    get_tx_receipt,
     push_tx,
     BroadcastChannelMessages,
     Endpoints,
+    EthFees,
+    EthRpcBroadcastChannelMessages,
     EthRpcMessages,
     SentinelConfig,
     SentinelError,
@@ -18,12 +24,14 @@ use common_sentinel::{
 use jsonrpsee::ws_client::WsClient;
 use tokio::{
     sync::{
-        broadcast::{Receiver as MpMcRx, Sender as MpMcTx},
+        broadcast::{error::RecvError, Receiver as MpMcRx, Sender as MpMcTx},
         mpsc::Receiver as MpscRx,
     },
-    time::{sleep, Duration},
+    time::{interval, sleep, Duration, Instant, Interval, MissedTickBehavior},
 };
 
+use crate::{health::HEALTH, metrics::METRICS};
+
 // NOTE: The underlying RPC calls have both retry & timeout logic, however in the event of a websocket disconnect, they
 // immediately return with an error. That error is handled in each of the arms below, via rotating the endpoint to get a
 // new socket.
@@ -37,23 +45,38 @@ async fn rotate_endpoint(
     use_quicknode: &mut bool,
 ) -> Result<(), SentinelError> {
     warn!("sleeping for {ENDPOINT_ROTATION_SLEEP_TIME} then rotating {network_id} endpoint");
+    HEALTH.set_endpoint_reachable(network_id, false);
     sleep(Duration::from_secs(ENDPOINT_ROTATION_SLEEP_TIME)).await;
     *ws_client = endpoints.rotate().await?;
+    HEALTH.set_endpoint_reachable(network_id, true);
     *use_quicknode = endpoints.use_quicknode();
+    METRICS.set_endpoint_rotations(network_id, *endpoints.rotations());
     Ok(())
 }
 
+fn get_node_poll_interval(config: &SentinelConfig) -> Interval {
+    let mut i = interval(Duration::from_secs(
+        (*config.core().health().node_poll_interval()).max(1),
+    ));
+    i.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    i
+}
+
 pub async fn eth_rpc_loop(
     mut eth_rpc_rx: MpscRx<EthRpcMessages>,
     config: SentinelConfig,
     network_id: NetworkId,
     _broadcast_channel_tx: MpMcTx<BroadcastChannelMessages>,
-    _broadcast_channel_rx: MpMcRx<BroadcastChannelMessages>,
+    mut broadcast_channel_rx: MpMcRx<BroadcastChannelMessages>,
 ) -> Result<(), SentinelError> {
     let mut endpoints = config.endpoints(&network_id)?;
     let mut use_quicknode = endpoints.use_quicknode();
-    let sleep_duration = *endpoints.sleep_time();
+    let mut sleep_duration = *endpoints.sleep_time();
     let mut ws_client = endpoints.get_first_ws_client().await?;
+    HEALTH.set_endpoint_reachable(&network_id, true);
+    // NOTE: The node's latest block is polled for independently of any requests for it, so that
+    // the health & metrics sync deltas stay current even when nothing else is asking.
+    let mut node_poll_interval = get_node_poll_interval(&config);
 
     'eth_rpc_loop: loop {
         tokio::select! {
@@ -62,13 +85,17 @@ pub async fn eth_rpc_loop(
                     match msg {
                         EthRpcMessages::GetLatestBlockNum((network_id, responder)) => {
                             'inner: loop {
+                                let t = Instant::now();
                                 let r = get_latest_block_num(
                                     &ws_client,
                                     sleep_duration,
                                     &network_id,
                                 ).await;
+                                METRICS.observe_eth_rpc_call(&network_id, &endpoints.current_endpoint_redacted(), "getLatestBlockNum", t.elapsed(), r.is_ok());
                                 match r {
                                     Ok(r) => {
+                                        METRICS.set_node_latest_block_num(&network_id, r);
+                                        HEALTH.set_node_latest_block_num(&network_id, r);
                                         let _ = responder.send(Ok(r));
                                         continue 'eth_rpc_loop
                                     },
@@ -82,11 +109,36 @@ pub async fn eth_rpc_loop(
                         },
                         EthRpcMessages::GetGasPrice((network_id, responder)) => {
                             'inner: loop {
+                                let t = Instant::now();
                                 let r = get_gas_price(
                                     &ws_client,
                                     sleep_duration,
                                     network_id,
                                 ).await;
+                                METRICS.observe_eth_rpc_call(&network_id, &endpoints.current_endpoint_redacted(), "getGasPrice", t.elapsed(), r.is_ok());
+                                match r {
+                                    Ok(r) => {
+                                        let _ = responder.send(Ok(r));
+                                        continue 'eth_rpc_loop
+                                    },
+                                    Err(e) => {
+                                        error!("{network_id} eth rpc error: {e}");
+                                        rotate_endpoint(&network_id, &mut endpoints, &mut ws_client, &mut use_quicknode).await?;
+                                        continue 'inner
+                                    },
+                                }
+                            }
+                        },
+                        EthRpcMessages::GetFees((network_id, responder)) => {
+                            let use_eip_1559 = config.eip_1559(&network_id).unwrap_or_default();
+                            'inner: loop {
+                                let t = Instant::now();
+                                let (name, r) = if use_eip_1559 {
+                                    ("getFeeHistory", get_eip_1559_fees(&ws_client, sleep_duration, &network_id).await)
+                                } else {
+                                    ("getGasPrice", get_gas_price(&ws_client, sleep_duration, network_id).await.map(EthFees::legacy))
+                                };
+                                METRICS.observe_eth_rpc_call(&network_id, &endpoints.current_endpoint_redacted(), name, t.elapsed(), r.is_ok());
                                 match r {
                                     Ok(r) => {
                                         let _ = responder.send(Ok(r));
@@ -102,17 +154,25 @@ pub async fn eth_rpc_loop(
                         },
                         EthRpcMessages::PushTx((tx, network_id, responder)) => {
                             'inner: loop {
+                                let t = Instant::now();
                                 let r = push_tx(
                                     &tx,
                                     &ws_client,
                                     sleep_duration,
                                     &network_id,
                                 ).await;
+                                METRICS.observe_eth_rpc_call(&network_id, &endpoints.current_endpoint_redacted(), "pushTx", t.elapsed(), r.is_ok());
                                 match r {
                                     Ok(r) => {
                                         let _ = responder.send(Ok(r));
                                         continue 'eth_rpc_loop
                                     },
+                                    // NOTE: No other endpoint would accept a tx this one has rejected.
+                                    Err(SentinelError::Endpoint(e)) if e.is_tx_rejection() => {
+                                        error!("{network_id} tx rejected: {e}");
+                                        let _ = responder.send(Err(e.into()));
+                                        continue 'eth_rpc_loop
+                                    },
                                     Err(e) => {
                                         error!("{network_id} eth rpc error: {e}");
                                         rotate_endpoint(&network_id, &mut endpoints, &mut ws_client, &mut use_quicknode).await?;
@@ -123,12 +183,14 @@ pub async fn eth_rpc_loop(
                         },
                         EthRpcMessages::GetNonce((network_id, address, responder)) => {
                             'inner: loop {
+                                let t = Instant::now();
                                 let r = get_nonce(
                                     &ws_client,
                                     &address,
                                     sleep_duration,
                                     network_id,
                                 ).await;
+                                METRICS.observe_eth_rpc_call(&network_id, &endpoints.current_endpoint_redacted(), "getNonce", t.elapsed(), r.is_ok());
                                 match r {
                                     Ok(r) => {
                                         let _ = responder.send(Ok(r));
@@ -144,6 +206,7 @@ pub async fn eth_rpc_loop(
                         },
                         EthRpcMessages::EthCall((data, network_id, address, default_block_parameter, responder)) => {
                             'inner: loop {
+                                let t = Instant::now();
                                 let r = eth_call(
                                     &address,
                                     &data,
@@ -152,6 +215,7 @@ pub async fn eth_rpc_loop(
                                     sleep_duration,
                                     network_id,
                                 ).await;
+                                METRICS.observe_eth_rpc_call(&network_id, &endpoints.current_endpoint_redacted(), "ethCall", t.elapsed(), r.is_ok());
                                 match r {
                                     Ok(r) => {
                                         let _ = responder.send(Ok(r));
@@ -165,8 +229,40 @@ pub async fn eth_rpc_loop(
                                 }
                             }
                         },
+                        EthRpcMessages::EstimateGas((network_id, from, to, data, responder)) => {
+                            'inner: loop {
+                                let t = Instant::now();
+                                let r = estimate_gas(
+                                    &from,
+                                    &to,
+                                    &data,
+                                    &ws_client,
+                                    sleep_duration,
+                                    network_id,
+                                ).await;
+                                METRICS.observe_eth_rpc_call(&network_id, &endpoints.current_endpoint_redacted(), "estimateGas", t.elapsed(), r.is_ok());
+                                match r {
+                                    Ok(r) => {
+                                        let _ = responder.send(Ok(r));
+                                        continue 'eth_rpc_loop
+                                    },
+                                    // NOTE: No other endpoint could estimate a tx this one can't, eg due to it reverting.
+                                    Err(SentinelError::Endpoint(e)) if e.is_tx_rejection() => {
+                                        error!("{network_id} gas estimate rejected: {e}");
+                                        let _ = responder.send(Err(e.into()));
+                                        continue 'eth_rpc_loop
+                                    },
+                                    Err(e) => {
+                                        error!("{network_id} eth rpc error: {e}");
+                                        rotate_endpoint(&network_id, &mut endpoints, &mut ws_client, &mut use_quicknode).await?;
+                                        continue 'inner
+                                    },
+                                }
+                            }
+                        },
                         EthRpcMessages::GetSubMat((network_id, block_num, responder)) => {
                             'inner: loop {
+                                let t = Instant::now();
                                 let r = get_sub_mat(
                                     &ws_client,
                                     block_num,
@@ -174,6 +270,32 @@ pub async fn eth_rpc_loop(
                                     &network_id,
                                     use_quicknode,
                                 ).await;
+                                METRICS.observe_eth_rpc_call(&network_id, &endpoints.current_endpoint_redacted(), "getSubMat", t.elapsed(), r.is_ok());
+                                match r {
+                                    Ok(r) => {
+                                        METRICS.set_node_latest_block_num(&network_id, block_num);
+                                        HEALTH.set_node_latest_block_num(&network_id, block_num);
+                                        let _ = responder.send(Ok(r));
+                                        continue 'eth_rpc_loop
+                                    }
+                                    Err(e) => {
+                                        error!("{network_id} eth rpc error: {e}");
+                                        rotate_endpoint(&network_id, &mut endpoints, &mut ws_client, &mut use_quicknode).await?;
+                                        continue 'inner
+                                    },
+                                }
+                            }
+                        },
+                        EthRpcMessages::GetRawTxs((network_id, block_num, responder)) => {
+                            'inner: loop {
+                                let t = Instant::now();
+                                let r = get_raw_txs(
+                                    &ws_client,
+                                    block_num,
+                                    sleep_duration,
+                                    &network_id,
+                                ).await;
+                                METRICS.observe_eth_rpc_call(&network_id, &endpoints.current_endpoint_redacted(), "getRawTxs", t.elapsed(), r.is_ok());
                                 match r {
                                     Ok(r) => {
                                         let _ = responder.send(Ok(r));
@@ -189,12 +311,14 @@ pub async fn eth_rpc_loop(
                         },
                         EthRpcMessages::GetEthBalance((network_id, address, responder)) => {
                             'inner: loop {
+                                let t = Instant::now();
                                 let r = get_eth_balance(
                                     &ws_client,
                                     &address,
                                     sleep_duration,
                                     network_id,
                                 ).await;
+                                METRICS.observe_eth_rpc_call(&network_id, &endpoints.current_endpoint_redacted(), "getEthBalance", t.elapsed(), r.is_ok());
                                 match r {
                                     Ok(r) => {
                                         let _ = responder.send(Ok(r));
@@ -208,6 +332,29 @@ pub async fn eth_rpc_loop(
                                 }
                             }
                         },
+                        EthRpcMessages::GetTxReceipt((network_id, tx_hash, responder)) => {
+                            'inner: loop {
+                                let t = Instant::now();
+                                let r = get_tx_receipt(
+                                    &ws_client,
+                                    &tx_hash,
+                                    sleep_duration,
+                                    &network_id,
+                                ).await;
+                                METRICS.observe_eth_rpc_call(&network_id, &endpoints.current_endpoint_redacted(), "getTxReceipt", t.elapsed(), r.is_ok());
+                                match r {
+                                    Ok(r) => {
+                                        let _ = responder.send(Ok(r));
+                                        continue 'eth_rpc_loop
+                                    },
+                                    Err(e) => {
+                                        error!("{network_id} eth rpc error: {e}");
+                                        rotate_endpoint(&network_id, &mut endpoints, &mut ws_client, &mut use_quicknode).await?;
+                                        continue 'inner
+                                    },
+                                }
+                            }
+                        },
                     }
                 },
                 None => {
@@ -216,6 +363,52 @@ pub async fn eth_rpc_loop(
                     break 'eth_rpc_loop Err(SentinelError::Custom(m))
                 },
             },
+            r = broadcast_channel_rx.recv() => match r {
+                Ok(BroadcastChannelMessages::EthRpc(nid, EthRpcBroadcastChannelMessages::UpdateConfig(c))) if nid == network_id => {
+                    info!("updating config for eth rpc for network {network_id}");
+                    // NOTE: The new endpoints are only swapped in once a client for them is had, so
+                    // that a bad config update can't leave this network without a working client.
+                    let r: Result<(Endpoints, WsClient), SentinelError> = async {
+                        let new_endpoints = c.endpoints(&network_id)?;
+                        let new_ws_client = new_endpoints.get_first_ws_client().await?;
+                        Ok((new_endpoints, new_ws_client))
+                    }.await;
+                    match r {
+                        Ok((new_endpoints, new_ws_client)) => {
+                            endpoints = new_endpoints;
+                            use_quicknode = endpoints.use_quicknode();
+                            sleep_duration = *endpoints.sleep_time();
+                            ws_client = new_ws_client;
+                            node_poll_interval = get_node_poll_interval(&c);
+                            HEALTH.set_endpoint_reachable(&network_id, true);
+                        },
+                        Err(e) => error!("could not update eth rpc config for network {network_id}, keeping previous endpoints: {e}"),
+                    };
+                    continue 'eth_rpc_loop
+                },
+                Ok(_) => continue 'eth_rpc_loop, // NOTE: The message wasn't for this eth rpc loop
+                Err(RecvError::Lagged(n)) => {
+                    warn!("eth rpc for network {network_id} missed {n} broadcast channel messages");
+                    continue 'eth_rpc_loop
+                },
+                Err(e) => break 'eth_rpc_loop Err(e.into()),
+            },
+            _ = node_poll_interval.tick() => {
+                let t = Instant::now();
+                let r = get_latest_block_num(&ws_client, sleep_duration, &network_id).await;
+                METRICS.observe_eth_rpc_call(&network_id, &endpoints.current_endpoint_redacted(), "getLatestBlockNum", t.elapsed(), r.is_ok());
+                match r {
+                    Ok(n) => {
+                        METRICS.set_node_latest_block_num(&network_id, n);
+                        HEALTH.set_node_latest_block_num(&network_id, n);
+                    },
+                    Err(e) => {
+                        error!("{network_id} eth rpc error polling latest block num: {e}");
+                        rotate_endpoint(&network_id, &mut endpoints, &mut ws_client, &mut use_quicknode).await?;
+                    },
+                };
+                continue 'eth_rpc_loop
+            },
             _ = tokio::signal::ctrl_c() => {
                 warn!("eth rpc for network {network_id} shutting down...");
                 break 'eth_rpc_loop Err(SentinelError::SigInt("eth rpc".into()))
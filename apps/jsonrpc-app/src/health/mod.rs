@@ -0,0 +1,3 @@
+mod sentinel_health;
+
+pub(crate) use self::sentinel_health::{HealthReport, HEALTH};
@@ -0,0 +1,247 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+        MutexGuard,
+    },
+    time::SystemTime,
+};
+
+use common_network_ids::NetworkId;
+use common_sentinel::{Batch, HealthConfig, SyncState};
+use lazy_static::lazy_static;
+use serde::Serialize;
+
+lazy_static! {
+    pub(crate) static ref HEALTH: SentinelHealth = SentinelHealth::default();
+}
+
+#[derive(Debug, Clone, Default)]
+struct NetworkHealth {
+    tasks_failed: bool,
+    syncer_enabled: bool,
+    endpoint_reachable: Option<bool>,
+    time_of_last_submission: Option<SystemTime>,
+    core_latest_block_num: Option<u64>,
+    node_latest_block_num: Option<u64>,
+}
+
+impl NetworkHealth {
+    fn seconds_since_last_submission(&self) -> Option<u64> {
+        self.time_of_last_submission
+            .map(|t| t.elapsed().map(|d| d.as_secs()).unwrap_or_default())
+    }
+
+    fn sync_delta(&self) -> Option<u64> {
+        match (self.core_latest_block_num, self.node_latest_block_num) {
+            (Some(core), Some(node)) => Some(node.saturating_sub(core)),
+            _ => None,
+        }
+    }
+
+    // NOTE: A stalled syncer is one which is enabled & able to submit to a core, yet hasn't done so
+    // successfully for too long.
+    fn is_stalled(&self, core_connected: bool, config: &HealthConfig) -> bool {
+        self.syncer_enabled
+            && core_connected
+            && self
+                .seconds_since_last_submission()
+                .map_or(false, |s| s > *config.max_seconds_since_last_submission())
+    }
+
+    fn is_ready(&self, config: &HealthConfig) -> bool {
+        !self.tasks_failed
+            && (!self.syncer_enabled
+                || (self.endpoint_reachable != Some(false)
+                    && self.sync_delta().map_or(true, |d| d <= *config.max_sync_delta())))
+    }
+
+    fn to_report(&self, network_id: NetworkId) -> NetworkHealthReport {
+        NetworkHealthReport {
+            network_id,
+            tasks_failed: self.tasks_failed,
+            sync_delta: self.sync_delta(),
+            syncer_enabled: self.syncer_enabled,
+            endpoint_reachable: self.endpoint_reachable,
+            seconds_since_last_submission: self.seconds_since_last_submission(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct NetworkHealthReport {
+    network_id: NetworkId,
+    tasks_failed: bool,
+    syncer_enabled: bool,
+    endpoint_reachable: Option<bool>,
+    seconds_since_last_submission: Option<u64>,
+    sync_delta: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct HealthReport {
+    ok: bool,
+    core_connected: bool,
+    networks: Vec<NetworkHealthReport>,
+}
+
+impl HealthReport {
+    pub(crate) fn is_ok(&self) -> bool {
+        self.ok
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct SentinelHealth {
+    core_connected: AtomicBool,
+    networks: Mutex<HashMap<NetworkId, NetworkHealth>>,
+}
+
+impl SentinelHealth {
+    fn lock(&self) -> MutexGuard<HashMap<NetworkId, NetworkHealth>> {
+        self.networks.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn update<F: FnOnce(&mut NetworkHealth)>(&self, network_id: &NetworkId, f: F) {
+        f(self.lock().entry(*network_id).or_default())
+    }
+
+    pub(crate) fn set_core_connected(&self, b: bool) {
+        self.core_connected.store(b, Ordering::Relaxed)
+    }
+
+    pub(crate) fn core_connected(&self) -> bool {
+        self.core_connected.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set_tasks_failed(&self, network_id: &NetworkId, b: bool) {
+        self.update(network_id, |h| h.tasks_failed = b)
+    }
+
+    pub(crate) fn set_syncer_enabled(&self, network_id: &NetworkId, b: bool) {
+        self.update(network_id, |h| h.syncer_enabled = b)
+    }
+
+    pub(crate) fn set_endpoint_reachable(&self, network_id: &NetworkId, b: bool) {
+        self.update(network_id, |h| h.endpoint_reachable = Some(b))
+    }
+
+    pub(crate) fn set_batch(&self, batch: &Batch) {
+        self.update(batch.network_id(), |h| {
+            h.time_of_last_submission = Some(batch.get_time_of_last_submission())
+        })
+    }
+
+    pub(crate) fn set_core_latest_block_num(&self, network_id: &NetworkId, n: u64) {
+        self.update(network_id, |h| h.core_latest_block_num = Some(n))
+    }
+
+    // NOTE: As with the metrics, a node is known to be at _least_ at whatever block it last returned.
+    pub(crate) fn set_node_latest_block_num(&self, network_id: &NetworkId, n: u64) {
+        self.update(network_id, |h| {
+            h.node_latest_block_num = Some(h.node_latest_block_num.map_or(n, |x| x.max(n)))
+        })
+    }
+
+    pub(crate) fn set_sync_state(&self, sync_state: &SyncState) {
+        sync_state.iter().for_each(|s| {
+            self.set_core_latest_block_num(s.network_id(), *s.core_latest_block_num());
+            self.set_node_latest_block_num(s.network_id(), *s.node_latest_block_num());
+        })
+    }
+
+    pub(crate) fn remove_network(&self, network_id: &NetworkId) {
+        self.lock().remove(network_id);
+    }
+
+    fn to_report<F: Fn(&NetworkHealth) -> bool>(&self, core_is_ok: bool, f: F) -> HealthReport {
+        let networks = self.lock();
+        let mut network_ids = networks.keys().copied().collect::<Vec<_>>();
+        network_ids.sort_by_key(|id| id.to_string());
+        HealthReport {
+            core_connected: self.core_connected(),
+            ok: core_is_ok && networks.values().all(f),
+            networks: network_ids.iter().map(|id| networks[id].to_report(*id)).collect(),
+        }
+    }
+
+    // NOTE: Liveness only fails if a syncer has stalled, since restarting is unlikely to fix a missing
+    // core connection or an unreachable endpoint.
+    pub(crate) fn liveness(&self, config: &HealthConfig) -> HealthReport {
+        let core_connected = self.core_connected();
+        self.to_report(true, |h| !h.is_stalled(core_connected, config))
+    }
+
+    // NOTE: Readiness requires a connected core, plus reachable endpoints & a sync delta within bounds
+    // for every enabled syncer.
+    pub(crate) fn readiness(&self, config: &HealthConfig) -> HealthReport {
+        self.to_report(self.core_connected(), |h| h.is_ready(config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_not_be_ready_without_core_connection() {
+        let health = SentinelHealth::default();
+        assert!(!health.readiness(&HealthConfig::default()).is_ok());
+        health.set_core_connected(true);
+        assert!(health.readiness(&HealthConfig::default()).is_ok());
+    }
+
+    #[test]
+    fn should_not_be_ready_if_enabled_syncer_is_too_far_behind() {
+        let health = SentinelHealth::default();
+        let network_id = NetworkId::default();
+        let config = HealthConfig::default();
+        health.set_core_connected(true);
+        health.set_syncer_enabled(&network_id, true);
+        health.set_core_latest_block_num(&network_id, 1);
+        health.set_node_latest_block_num(&network_id, 2 + config.max_sync_delta());
+        assert!(!health.readiness(&config).is_ok());
+        health.set_syncer_enabled(&network_id, false);
+        assert!(health.readiness(&config).is_ok());
+    }
+
+    #[test]
+    fn should_not_be_ready_if_enabled_syncer_endpoint_is_unreachable() {
+        let health = SentinelHealth::default();
+        let network_id = NetworkId::default();
+        health.set_core_connected(true);
+        health.set_syncer_enabled(&network_id, true);
+        health.set_endpoint_reachable(&network_id, false);
+        assert!(!health.readiness(&HealthConfig::default()).is_ok());
+    }
+
+    #[test]
+    fn should_not_be_ready_if_network_tasks_have_failed() {
+        let health = SentinelHealth::default();
+        let network_id = NetworkId::default();
+        health.set_core_connected(true);
+        health.set_tasks_failed(&network_id, true);
+        assert!(!health.readiness(&HealthConfig::default()).is_ok());
+        health.set_tasks_failed(&network_id, false);
+        assert!(health.readiness(&HealthConfig::default()).is_ok());
+    }
+
+    #[test]
+    fn should_be_live_unless_syncer_has_stalled() {
+        let health = SentinelHealth::default();
+        let network_id = NetworkId::default();
+        let config = HealthConfig::default();
+        health.set_core_connected(true);
+        health.set_syncer_enabled(&network_id, true);
+        assert!(health.liveness(&config).is_ok());
+        health.update(&network_id, |h| {
+            h.time_of_last_submission = SystemTime::now().checked_sub(std::time::Duration::from_secs(
+                config.max_seconds_since_last_submission() + 1,
+            ))
+        });
+        assert!(!health.liveness(&config).is_ok());
+    }
+}
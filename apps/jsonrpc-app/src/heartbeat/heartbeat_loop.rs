@@ -0,0 +1,221 @@
+use std::result::Result;
+
+use common_sentinel::{
+    call_core,
+    get_heartbeat_gas_limit,
+    get_heartbeat_tx_data,
+    EthRpcMessages,
+    EthRpcSenders,
+    HeartbeatTarget,
+    SentinelConfig,
+    SentinelError,
+    SentinelStatus,
+    TxManagerMessages,
+    WebSocketMessagesEncodable,
+};
+use futures::future::join_all;
+use tokio::time::{sleep, Duration};
+
+use crate::{
+    health::HEALTH,
+    type_aliases::{TxManagerTx, WebSocketTx},
+};
+
+const BASE_BACKOFF_SECONDS: u64 = 1;
+const MAX_BACKOFF_SECONDS: u64 = 300;
+
+fn get_backoff_seconds(attempt: u64) -> u64 {
+    u32::try_from(attempt)
+        .ok()
+        .and_then(|a| 1u64.checked_shl(a))
+        .map_or(MAX_BACKOFF_SECONDS, |m| BASE_BACKOFF_SECONDS.saturating_mul(m))
+        .min(MAX_BACKOFF_SECONDS)
+}
+
+async fn get_status(
+    config: &SentinelConfig,
+    websocket_tx: WebSocketTx,
+    eth_rpc_senders: &EthRpcSenders,
+) -> Result<SentinelStatus, SentinelError> {
+    let mut network_ids = eth_rpc_senders.network_ids();
+    network_ids.sort_by_key(|id| id.to_string());
+    let msg = WebSocketMessagesEncodable::GetStatus(network_ids);
+    let r = call_core(*config.core().timeout(), websocket_tx, msg).await?;
+    Ok(SentinelStatus::try_from(r)?)
+}
+
+async fn publish_to_http(config: &SentinelConfig, url: &str, status: &SentinelStatus) -> Result<(), SentinelError> {
+    reqwest::Client::new()
+        .post(url)
+        .timeout(Duration::from_secs(*config.core().timeout()))
+        .json(status)
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
+
+// NOTE: The status is written to a temporary file first, and then moved into place, so that readers
+// never see a partially written status.
+async fn publish_to_file(path: &str, status: &SentinelStatus) -> Result<(), SentinelError> {
+    let tmp_path = format!("{path}.tmp");
+    tokio::fs::write(&tmp_path, serde_json::to_vec(status)?).await?;
+    tokio::fs::rename(&tmp_path, path).await?;
+    Ok(())
+}
+
+async fn publish_on_chain(
+    config: &SentinelConfig,
+    eth_rpc_senders: &EthRpcSenders,
+    tx_manager_tx: &TxManagerTx,
+    target: &HeartbeatTarget,
+    status: &SentinelStatus,
+) -> Result<(), SentinelError> {
+    let (network_id, address, gas_limit) = match target {
+        HeartbeatTarget::OnChain {
+            network_id,
+            address,
+            gas_limit,
+        } => (*network_id, *address, *gas_limit),
+        _ => return Err(SentinelError::Custom(format!("{target} is not an on chain target"))),
+    };
+
+    let data = get_heartbeat_tx_data(status)?;
+    let gas_limit = match gas_limit {
+        Some(gas_limit) => gas_limit,
+        None => {
+            let from = config.private_key()?.to_address();
+            let (msg, rx) = EthRpcMessages::get_estimate_gas_msg(network_id, from, address, data.clone());
+            eth_rpc_senders.sender(&network_id)?.send(msg).await?;
+            get_heartbeat_gas_limit(rx.await??, &data)
+        },
+    };
+    let (msg, rx) = TxManagerMessages::submit_tx_msg(network_id, address, data, gas_limit);
+    tx_manager_tx.send(msg).await?;
+    let tx_hash = rx.await??;
+    info!(
+        "heartbeat tx submitted to {network_id} with hash 0x{}",
+        hex::encode(tx_hash)
+    );
+    Ok(())
+}
+
+async fn publish(
+    config: &SentinelConfig,
+    eth_rpc_senders: &EthRpcSenders,
+    tx_manager_tx: &TxManagerTx,
+    target: &HeartbeatTarget,
+    status: &SentinelStatus,
+) -> Result<(), SentinelError> {
+    match target {
+        HeartbeatTarget::Http { url } => publish_to_http(config, url, status).await,
+        HeartbeatTarget::File { path } => publish_to_file(path, status).await,
+        HeartbeatTarget::OnChain { .. } => {
+            publish_on_chain(config, eth_rpc_senders, tx_manager_tx, target, status).await
+        },
+    }
+}
+
+// NOTE: Retries back off exponentially, up to a cap, and should they all fail the target is given up
+// on until the next heartbeat.
+async fn publish_with_retries(
+    config: &SentinelConfig,
+    eth_rpc_senders: &EthRpcSenders,
+    tx_manager_tx: &TxManagerTx,
+    target: &HeartbeatTarget,
+    status: &SentinelStatus,
+) -> Result<(), SentinelError> {
+    let max_retries = *config.heartbeat().max_retries();
+    let mut attempt = 0;
+    loop {
+        match publish(config, eth_rpc_senders, tx_manager_tx, target, status).await {
+            Ok(_) => {
+                debug!("heartbeat published to {target}");
+                break Ok(());
+            },
+            Err(e) if attempt < max_retries => {
+                let backoff = get_backoff_seconds(attempt);
+                warn!("could not publish heartbeat to {target}: {e} - retrying in {backoff}s");
+                sleep(Duration::from_secs(backoff)).await;
+                attempt += 1;
+            },
+            Err(e) => break Err(e),
+        }
+    }
+}
+
+async fn publish_heartbeat(
+    config: &SentinelConfig,
+    websocket_tx: WebSocketTx,
+    eth_rpc_senders: &EthRpcSenders,
+    tx_manager_tx: &TxManagerTx,
+) -> Result<(), SentinelError> {
+    if !HEALTH.core_connected() {
+        return Err(SentinelError::NoCore);
+    };
+
+    let status = &get_status(config, websocket_tx, eth_rpc_senders).await?;
+
+    join_all(config.heartbeat().targets().iter().map(|target| async move {
+        if let Err(e) = publish_with_retries(config, eth_rpc_senders, tx_manager_tx, target, status).await {
+            error!("could not publish heartbeat to {target}: {e}");
+        }
+    }))
+    .await;
+
+    Ok(())
+}
+
+pub async fn heartbeat_loop(
+    config: SentinelConfig,
+    websocket_tx: WebSocketTx,
+    eth_rpc_senders: EthRpcSenders,
+    tx_manager_tx: TxManagerTx,
+) -> Result<(), SentinelError> {
+    let name = "heartbeat";
+    let heartbeat_is_enabled = *config.heartbeat().enabled() && !config.heartbeat().targets().is_empty();
+    let interval = *config.heartbeat().interval();
+
+    if !heartbeat_is_enabled {
+        info!("{name} is disabled");
+    };
+
+    'heartbeat_loop: loop {
+        tokio::select! {
+            _ = sleep(Duration::from_secs(interval)), if heartbeat_is_enabled => {
+                if let Err(e) = publish_heartbeat(&config, websocket_tx.clone(), &eth_rpc_senders, &tx_manager_tx).await {
+                    warn!("could not publish {name}: {e}");
+                };
+                continue 'heartbeat_loop
+            },
+            _ = tokio::signal::ctrl_c() => {
+                warn!("{name} shutting down...");
+                break 'heartbeat_loop Err(SentinelError::SigInt(name.into()))
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn should_publish_status_to_file() {
+        let path = std::env::temp_dir().join("sentinel-heartbeat-test-status.json");
+        let path = path.to_str().unwrap();
+        let status = SentinelStatus::default();
+        publish_to_file(path, &status).await.unwrap();
+        let result: SentinelStatus = serde_json::from_slice(&std::fs::read(path).unwrap()).unwrap();
+        assert_eq!(result, status);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn should_cap_backoff() {
+        assert_eq!(get_backoff_seconds(0), BASE_BACKOFF_SECONDS);
+        assert_eq!(get_backoff_seconds(3), BASE_BACKOFF_SECONDS * 8);
+        assert_eq!(get_backoff_seconds(64), MAX_BACKOFF_SECONDS);
+        assert_eq!(get_backoff_seconds(u64::MAX), MAX_BACKOFF_SECONDS);
+    }
+}
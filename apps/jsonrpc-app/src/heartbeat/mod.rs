@@ -0,0 +1,3 @@
+mod heartbeat_loop;
+
+pub(crate) use self::heartbeat_loop::heartbeat_loop;
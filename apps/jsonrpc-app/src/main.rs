@@ -1,8 +1,15 @@
+mod challenge_responder;
 mod cli;
 mod eth_rpc;
+mod health;
+mod heartbeat;
+mod metrics;
+mod network_manager;
+mod registration;
 mod rpc_server;
 mod start_sentinel;
 mod syncer;
+mod tx_manager;
 mod type_aliases;
 mod ws_server;
 
@@ -52,6 +59,12 @@ async fn start() -> Result<String, SentinelError> {
     } else {
         "sentinel-config".to_string()
     };
+
+    // NOTE: Validating the config mustn't require it to be loadable in the first place.
+    if let Some(cmds @ Commands::ValidateConfig { .. }) = cli_args.commands {
+        return handle_cli(cmds, &config_path).await;
+    };
+
     let config = SentinelConfig::new(&config_path)?;
 
     if config.log().is_enabled() {
@@ -59,7 +72,7 @@ async fn start() -> Result<String, SentinelError> {
     };
 
     if let Some(commands) = cli_args.commands {
-        handle_cli(commands).await
+        handle_cli(commands, &config_path).await
     } else {
         start_sentinel::start_sentinel(&config, cli_args.disable)
             .await
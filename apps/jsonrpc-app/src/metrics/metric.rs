@@ -0,0 +1,237 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    sync::{Mutex, MutexGuard},
+};
+
+pub(super) type Labels = Vec<(&'static str, String)>;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum MetricKind {
+    Gauge,
+    Counter,
+}
+
+impl MetricKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Gauge => "gauge",
+            Self::Counter => "counter",
+        }
+    }
+}
+
+// NOTE: A single named metric, holding one value per distinct set of labels, which can be written
+// out in the prometheus text exposition format.
+#[derive(Debug)]
+pub(super) struct Metric {
+    name: &'static str,
+    help: &'static str,
+    kind: MetricKind,
+    values: Mutex<BTreeMap<Labels, f64>>,
+}
+
+fn escape_label_value(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+fn format_labels(labels: &[(&'static str, String)]) -> String {
+    if labels.is_empty() {
+        return String::new();
+    };
+    let labels = labels
+        .iter()
+        .map(|(k, v)| format!("{k}=\"{}\"", escape_label_value(v)))
+        .collect::<Vec<String>>()
+        .join(",");
+    format!("{{{labels}}}")
+}
+
+impl Metric {
+    pub(super) fn new(name: &'static str, help: &'static str, kind: MetricKind) -> Self {
+        Self {
+            name,
+            help,
+            kind,
+            values: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    pub(super) fn gauge(name: &'static str, help: &'static str) -> Self {
+        Self::new(name, help, MetricKind::Gauge)
+    }
+
+    pub(super) fn counter(name: &'static str, help: &'static str) -> Self {
+        Self::new(name, help, MetricKind::Counter)
+    }
+
+    // NOTE: A panic whilst holding the lock can't leave a metric in an inconsistent state, so we
+    // needn't propagate poisoning.
+    fn lock(&self) -> MutexGuard<BTreeMap<Labels, f64>> {
+        self.values.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub(super) fn set(&self, labels: Labels, v: f64) {
+        self.lock().insert(labels, v);
+    }
+
+    pub(super) fn set_max(&self, labels: Labels, v: f64) {
+        let mut values = self.lock();
+        let entry = values.entry(labels).or_insert(v);
+        if v > *entry {
+            *entry = v
+        };
+    }
+
+    pub(super) fn inc_by(&self, labels: Labels, v: f64) {
+        *self.lock().entry(labels).or_insert(0.0) += v;
+    }
+
+    pub(super) fn inc(&self, labels: Labels) {
+        self.inc_by(labels, 1.0)
+    }
+
+    #[cfg(test)]
+    pub(super) fn get(&self, labels: &Labels) -> Option<f64> {
+        self.lock().get(labels).copied()
+    }
+
+    pub(super) fn write_to(&self, s: &mut String) {
+        let values = self.lock();
+        if values.is_empty() {
+            return;
+        };
+
+        let _ = writeln!(s, "# HELP {} {}", self.name, self.help);
+        let _ = writeln!(s, "# TYPE {} {}", self.name, self.kind.as_str());
+        for (labels, v) in values.iter() {
+            let _ = writeln!(s, "{}{} {v}", self.name, format_labels(labels));
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct HistogramValues {
+    // NOTE: Per bucket, rather than cumulative, counts. They're summed when written out.
+    bucket_counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+// NOTE: A named histogram, holding one set of bucket counts per distinct set of labels, which can be
+// written out in the prometheus text exposition format.
+#[derive(Debug)]
+pub(super) struct Histogram {
+    name: &'static str,
+    help: &'static str,
+    buckets: &'static [f64],
+    values: Mutex<BTreeMap<Labels, HistogramValues>>,
+}
+
+impl Histogram {
+    pub(super) fn new(name: &'static str, help: &'static str, buckets: &'static [f64]) -> Self {
+        Self {
+            name,
+            help,
+            buckets,
+            values: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<BTreeMap<Labels, HistogramValues>> {
+        self.values.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub(super) fn observe(&self, labels: Labels, v: f64) {
+        let mut values = self.lock();
+        let entry = values.entry(labels).or_insert_with(|| HistogramValues {
+            bucket_counts: vec![0; self.buckets.len()],
+            ..Default::default()
+        });
+        if let Some(i) = self.buckets.iter().position(|upper_bound| v <= *upper_bound) {
+            entry.bucket_counts[i] += 1;
+        };
+        entry.sum += v;
+        entry.count += 1;
+    }
+
+    #[cfg(test)]
+    pub(super) fn get_count(&self, labels: &Labels) -> Option<u64> {
+        self.lock().get(labels).map(|v| v.count)
+    }
+
+    pub(super) fn write_to(&self, s: &mut String) {
+        let values = self.lock();
+        if values.is_empty() {
+            return;
+        };
+
+        let _ = writeln!(s, "# HELP {} {}", self.name, self.help);
+        let _ = writeln!(s, "# TYPE {} histogram", self.name);
+        for (labels, v) in values.iter() {
+            let mut cumulative_count = 0;
+            for (upper_bound, n) in self.buckets.iter().zip(v.bucket_counts.iter()) {
+                cumulative_count += n;
+                let bucket_labels = [labels.clone(), vec![("le", upper_bound.to_string())]].concat();
+                let _ = writeln!(
+                    s,
+                    "{}_bucket{} {cumulative_count}",
+                    self.name,
+                    format_labels(&bucket_labels)
+                );
+            }
+            let bucket_labels = [labels.clone(), vec![("le", "+Inf".to_string())]].concat();
+            let _ = writeln!(s, "{}_bucket{} {}", self.name, format_labels(&bucket_labels), v.count);
+            let _ = writeln!(s, "{}_sum{} {}", self.name, format_labels(labels), v.sum);
+            let _ = writeln!(s, "{}_count{} {}", self.name, format_labels(labels), v.count);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_write_metric_in_prometheus_text_format() {
+        let m = Metric::counter("test_total", "a test counter");
+        m.inc(vec![("network", "EthereumMainnet".into())]);
+        m.inc_by(vec![("network", "EthereumMainnet".into())], 2.0);
+        m.inc(vec![("network", "Bsc\"Mainnet".into())]);
+        let mut s = String::new();
+        m.write_to(&mut s);
+        let expected = "# HELP test_total a test counter\n# TYPE test_total counter\ntest_total{network=\"Bsc\\\"Mainnet\"} 1\ntest_total{network=\"EthereumMainnet\"} 3\n";
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn should_not_write_metric_with_no_values() {
+        let m = Metric::gauge("test", "a test gauge");
+        let mut s = String::new();
+        m.write_to(&mut s);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn should_write_histogram_in_prometheus_text_format() {
+        let h = Histogram::new("test_seconds", "a test histogram", &[0.5, 1.0]);
+        let labels = vec![("network", "EthereumMainnet".to_string())];
+        h.observe(labels.clone(), 0.25);
+        h.observe(labels.clone(), 0.75);
+        h.observe(labels, 2.0);
+        let mut s = String::new();
+        h.write_to(&mut s);
+        let expected = "# HELP test_seconds a test histogram\n# TYPE test_seconds histogram\ntest_seconds_bucket{network=\"EthereumMainnet\",le=\"0.5\"} 1\ntest_seconds_bucket{network=\"EthereumMainnet\",le=\"1\"} 2\ntest_seconds_bucket{network=\"EthereumMainnet\",le=\"+Inf\"} 3\ntest_seconds_sum{network=\"EthereumMainnet\"} 3\ntest_seconds_count{network=\"EthereumMainnet\"} 3\n";
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn set_max_should_only_ever_increase_value() {
+        let m = Metric::gauge("test", "a test gauge");
+        m.set_max(vec![], 10.0);
+        m.set_max(vec![], 5.0);
+        assert_eq!(m.get(&vec![]), Some(10.0));
+        m.set_max(vec![], 11.0);
+        assert_eq!(m.get(&vec![]), Some(11.0));
+    }
+}
@@ -0,0 +1,4 @@
+mod metric;
+mod sentinel_metrics;
+
+pub(crate) use self::sentinel_metrics::METRICS;
@@ -0,0 +1,219 @@
+use std::time::Duration;
+
+use common_network_ids::NetworkId;
+use common_sentinel::{Batch, SyncState};
+use lazy_static::lazy_static;
+
+use super::metric::{Histogram, Labels, Metric};
+
+// NOTE: The rpc calls have their own retry & timeout logic, so the upper buckets are generous.
+const ETH_RPC_CALL_SECONDS_BUCKETS: &[f64] = &[0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0];
+
+lazy_static! {
+    pub(crate) static ref METRICS: SentinelMetrics = SentinelMetrics::default();
+}
+
+fn network_labels(network_id: &NetworkId) -> Labels {
+    vec![("network", network_id.to_string())]
+}
+
+#[derive(Debug)]
+pub(crate) struct SentinelMetrics {
+    core_latest_block_num: Metric,
+    node_latest_block_num: Metric,
+    blocks_per_minute: Metric,
+    batch_size: Metric,
+    batch_age: Metric,
+    signed_events: Metric,
+    eth_rpc_calls: Metric,
+    eth_rpc_errors: Metric,
+    eth_rpc_call_seconds: Histogram,
+    endpoint_rotations: Metric,
+    core_calls: Metric,
+    core_call_seconds: Metric,
+    core_timeouts: Metric,
+    db_integrity_failures: Metric,
+}
+
+impl Default for SentinelMetrics {
+    fn default() -> Self {
+        Self {
+            core_latest_block_num: Metric::gauge(
+                "sentinel_core_latest_block_number",
+                "Latest block number processed by the core.",
+            ),
+            node_latest_block_num: Metric::gauge(
+                "sentinel_node_latest_block_number",
+                "Latest block number seen from the network's node.",
+            ),
+            blocks_per_minute: Metric::gauge(
+                "sentinel_blocks_per_minute",
+                "Moving average of blocks processed per minute.",
+            ),
+            batch_size: Metric::gauge("sentinel_batch_size_blocks", "Number of blocks in the current batch."),
+            batch_age: Metric::gauge(
+                "sentinel_batch_age_seconds",
+                "Seconds since the last batch was submitted to the core.",
+            ),
+            signed_events: Metric::counter("sentinel_signed_events_total", "Number of signed events emitted."),
+            eth_rpc_calls: Metric::counter("sentinel_eth_rpc_calls_total", "Number of calls to eth rpc endpoints."),
+            eth_rpc_errors: Metric::counter(
+                "sentinel_eth_rpc_errors_total",
+                "Number of failed calls to eth rpc endpoints.",
+            ),
+            eth_rpc_call_seconds: Histogram::new(
+                "sentinel_eth_rpc_call_duration_seconds",
+                "Latency of calls to eth rpc endpoints.",
+                ETH_RPC_CALL_SECONDS_BUCKETS,
+            ),
+            endpoint_rotations: Metric::gauge(
+                "sentinel_endpoint_rotations",
+                "Number of full rotations through the network's configured endpoints.",
+            ),
+            core_calls: Metric::counter(
+                "sentinel_core_calls_total",
+                "Number of messages sent to the core over the websocket.",
+            ),
+            core_call_seconds: Metric::counter(
+                "sentinel_core_call_seconds_total",
+                "Total round trip time of messages sent to the core over the websocket.",
+            ),
+            core_timeouts: Metric::counter(
+                "sentinel_core_timeouts_total",
+                "Number of messages sent to the core which timed out.",
+            ),
+            db_integrity_failures: Metric::counter(
+                "sentinel_db_integrity_failures_total",
+                "Number of db integrity failures reported by the core.",
+            ),
+        }
+    }
+}
+
+impl SentinelMetrics {
+    pub(crate) fn set_sync_state(&self, sync_state: &SyncState) {
+        sync_state.iter().for_each(|s| {
+            self.set_core_latest_block_num(s.network_id(), *s.core_latest_block_num());
+            self.set_node_latest_block_num(s.network_id(), *s.node_latest_block_num());
+        })
+    }
+
+    pub(crate) fn set_core_latest_block_num(&self, network_id: &NetworkId, n: u64) {
+        self.core_latest_block_num.set(network_labels(network_id), n as f64)
+    }
+
+    // NOTE: Nodes can be seen to be at _least_ at whatever block they last returned, and so this only
+    // ever increases, lest a slower endpoint after a rotation make a syncer look caught up.
+    pub(crate) fn set_node_latest_block_num(&self, network_id: &NetworkId, n: u64) {
+        self.node_latest_block_num.set_max(network_labels(network_id), n as f64)
+    }
+
+    pub(crate) fn set_batch(&self, batch: &Batch) {
+        let labels = network_labels(batch.network_id());
+        self.batch_size.set(labels.clone(), batch.size_in_blocks() as f64);
+        self.batch_age
+            .set(labels.clone(), batch.get_seconds_since_last_submission() as f64);
+        self.blocks_per_minute.set(labels, batch.bpm().calc_bpm());
+    }
+
+    pub(crate) fn inc_signed_events(&self, network_id: &NetworkId, n: usize) {
+        self.signed_events.inc_by(network_labels(network_id), n as f64)
+    }
+
+    // NOTE: The endpoint is expected to have been redacted, since urls may contain api keys.
+    pub(crate) fn observe_eth_rpc_call(
+        &self,
+        network_id: &NetworkId,
+        endpoint: &str,
+        method: &str,
+        d: Duration,
+        is_ok: bool,
+    ) {
+        let labels = vec![
+            ("network", network_id.to_string()),
+            ("endpoint", endpoint.to_string()),
+            ("method", method.to_string()),
+        ];
+        self.eth_rpc_calls.inc(labels.clone());
+        self.eth_rpc_call_seconds.observe(labels.clone(), d.as_secs_f64());
+        if !is_ok {
+            self.eth_rpc_errors.inc(labels)
+        };
+    }
+
+    pub(crate) fn set_endpoint_rotations(&self, network_id: &NetworkId, n: usize) {
+        self.endpoint_rotations.set(network_labels(network_id), n as f64)
+    }
+
+    pub(crate) fn observe_core_call(&self, d: Duration) {
+        self.core_calls.inc(vec![]);
+        self.core_call_seconds.inc_by(vec![], d.as_secs_f64());
+    }
+
+    pub(crate) fn inc_core_timeouts(&self) {
+        self.core_timeouts.inc(vec![])
+    }
+
+    pub(crate) fn inc_db_integrity_failures(&self) {
+        self.db_integrity_failures.inc(vec![])
+    }
+
+    pub(crate) fn to_prometheus_string(&self) -> String {
+        let mut s = String::new();
+        [
+            &self.core_latest_block_num,
+            &self.node_latest_block_num,
+            &self.blocks_per_minute,
+            &self.batch_size,
+            &self.batch_age,
+            &self.signed_events,
+            &self.eth_rpc_calls,
+            &self.eth_rpc_errors,
+            &self.endpoint_rotations,
+            &self.core_calls,
+            &self.core_call_seconds,
+            &self.core_timeouts,
+            &self.db_integrity_failures,
+        ]
+        .iter()
+        .for_each(|m| m.write_to(&mut s));
+        self.eth_rpc_call_seconds.write_to(&mut s);
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_export_sync_state_metrics() {
+        let metrics = SentinelMetrics::default();
+        let sync_state = SyncState::from((vec![NetworkId::default()], vec![10], vec![15]));
+        metrics.set_sync_state(&sync_state);
+        let s = metrics.to_prometheus_string();
+        let labels = format!("{{network=\"{}\"}}", NetworkId::default());
+        assert!(s.contains(&format!("sentinel_core_latest_block_number{labels} 10\n")));
+        assert!(s.contains(&format!("sentinel_node_latest_block_number{labels} 15\n")));
+    }
+
+    #[test]
+    fn should_count_eth_rpc_errors() {
+        let metrics = SentinelMetrics::default();
+        let network_id = NetworkId::default();
+        let endpoint = "wss://host/<redacted>";
+        metrics.observe_eth_rpc_call(&network_id, endpoint, "getSubMat", Duration::from_millis(500), true);
+        metrics.observe_eth_rpc_call(&network_id, endpoint, "getSubMat", Duration::from_millis(1500), false);
+        let labels = vec![
+            ("network", network_id.to_string()),
+            ("endpoint", endpoint.to_string()),
+            ("method", "getSubMat".to_string()),
+        ];
+        assert_eq!(metrics.eth_rpc_calls.get(&labels), Some(2.0));
+        assert_eq!(metrics.eth_rpc_errors.get(&labels), Some(1.0));
+        assert_eq!(metrics.eth_rpc_call_seconds.get_count(&labels), Some(2));
+        assert!(metrics
+            .to_prometheus_string()
+            .contains("sentinel_eth_rpc_call_duration_seconds_bucket{"));
+    }
+}
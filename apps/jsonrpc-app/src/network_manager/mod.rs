@@ -0,0 +1,3 @@
+mod network_manager_loop;
+
+pub(crate) use self::network_manager_loop::network_manager_loop;
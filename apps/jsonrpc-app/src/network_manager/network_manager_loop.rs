@@ -0,0 +1,182 @@
+use std::{collections::HashMap, future::Future, panic::AssertUnwindSafe, result::Result};
+
+use common_network_ids::NetworkId;
+use common_sentinel::{
+    Batch,
+    EthRpcSenders,
+    NetworkManagerMessages,
+    SentinelConfig,
+    SentinelConfigError,
+    SentinelError,
+    MAX_CHANNEL_CAPACITY,
+};
+use futures::FutureExt;
+use tokio::{
+    sync::mpsc,
+    task::{AbortHandle, JoinSet},
+};
+
+use crate::{
+    eth_rpc::eth_rpc_loop,
+    health::HEALTH,
+    syncer::syncer,
+    type_aliases::{BroadcastChannelTx, NetworkManagerRx, WebSocketTx},
+};
+
+// NOTE: Owns the tasks which sync & make eth rpc calls for each network, so that networks can be
+// added to or removed from a running sentinel without restarting it.
+struct NetworkTasks {
+    eth_rpc_senders: EthRpcSenders,
+    websocket_tx: WebSocketTx,
+    broadcast_channel_tx: BroadcastChannelTx,
+    disable_syncers: bool,
+    tasks: JoinSet<(NetworkId, Result<(), SentinelError>)>,
+    abort_handles: HashMap<NetworkId, Vec<AbortHandle>>,
+}
+
+impl NetworkTasks {
+    // NOTE: Tasks return the network they belong to, and any panic is caught & returned as an error,
+    // so that a failed task can always be attributed to its network.
+    fn spawn<F>(&mut self, network_id: NetworkId, f: F) -> AbortHandle
+    where
+        F: Future<Output = Result<(), SentinelError>> + Send + 'static,
+    {
+        self.tasks.spawn(async move {
+            let r = AssertUnwindSafe(f)
+                .catch_unwind()
+                .await
+                .unwrap_or_else(|_| Err(SentinelError::Custom(format!("{network_id} task panicked"))));
+            (network_id, r)
+        })
+    }
+
+    fn add(&mut self, network_id: NetworkId, config: &SentinelConfig, core_cxn: bool) -> Result<(), SentinelError> {
+        if self.abort_handles.contains_key(&network_id) {
+            return Err(SentinelConfigError::NetworkExists(network_id).into());
+        };
+
+        let batch = Batch::new_from_config(network_id, config)?;
+
+        // NOTE: The sender must be registered before the syncer is spawned, since the syncer gets its
+        // eth rpc sender from the registry upon starting.
+        let (eth_rpc_tx, eth_rpc_rx) = mpsc::channel(MAX_CHANNEL_CAPACITY);
+        self.eth_rpc_senders.insert(network_id, eth_rpc_tx);
+
+        let eth_rpc_handle = self.spawn(
+            network_id,
+            eth_rpc_loop(
+                eth_rpc_rx,
+                config.clone(),
+                network_id,
+                self.broadcast_channel_tx.clone(),
+                self.broadcast_channel_tx.subscribe(),
+            ),
+        );
+        let syncer_handle = self.spawn(
+            network_id,
+            syncer(
+                batch,
+                config.clone(),
+                self.eth_rpc_senders.clone(),
+                self.websocket_tx.clone(),
+                self.broadcast_channel_tx.clone(),
+                self.disable_syncers,
+                core_cxn,
+            ),
+        );
+
+        self.abort_handles
+            .insert(network_id, vec![eth_rpc_handle, syncer_handle]);
+        HEALTH.set_tasks_failed(&network_id, false);
+        info!("{network_id} network added");
+        Ok(())
+    }
+
+    fn remove(&mut self, network_id: &NetworkId) -> Result<(), SentinelError> {
+        let handles = self
+            .abort_handles
+            .remove(network_id)
+            .ok_or_else(|| SentinelConfigError::NoConfig(*network_id))?;
+        handles.iter().for_each(|h| h.abort());
+        self.eth_rpc_senders.remove(network_id);
+        HEALTH.remove_network(network_id);
+        info!("{network_id} network removed");
+        Ok(())
+    }
+
+    // NOTE: A network whose syncer or eth rpc task has failed is dropped, leaving the other networks
+    // running. It's reported as failed by the health checks until it's added again.
+    fn drop_failed(&mut self, network_id: &NetworkId) {
+        if let Some(handles) = self.abort_handles.remove(network_id) {
+            handles.iter().for_each(|h| h.abort());
+            self.eth_rpc_senders.remove(network_id);
+            HEALTH.set_tasks_failed(network_id, true);
+            warn!("{network_id} network dropped - it may be added again via the `addNetwork` rpc call");
+        };
+    }
+}
+
+pub async fn network_manager_loop(
+    mut network_manager_rx: NetworkManagerRx,
+    config: SentinelConfig,
+    eth_rpc_senders: EthRpcSenders,
+    websocket_tx: WebSocketTx,
+    broadcast_channel_tx: BroadcastChannelTx,
+    disable_syncers: bool,
+) -> Result<(), SentinelError> {
+    let name = "network manager";
+
+    let mut network_tasks = NetworkTasks {
+        eth_rpc_senders,
+        websocket_tx,
+        broadcast_channel_tx,
+        disable_syncers,
+        tasks: JoinSet::new(),
+        abort_handles: HashMap::new(),
+    };
+
+    // NOTE: For each network defined in the config, a syncer & an eth rpc task are spawned...
+    for network_id in config.network_ids() {
+        network_tasks.add(network_id, &config, false)?;
+    }
+
+    'network_manager_loop: loop {
+        tokio::select! {
+            r = network_manager_rx.recv() => match r {
+                Some(NetworkManagerMessages::AddNetwork((network_id, config, core_cxn, responder))) => {
+                    let _ = responder.send(network_tasks.add(network_id, &config, core_cxn));
+                    continue 'network_manager_loop
+                },
+                Some(NetworkManagerMessages::RemoveNetwork((network_id, responder))) => {
+                    let _ = responder.send(network_tasks.remove(&network_id));
+                    continue 'network_manager_loop
+                },
+                None => {
+                    let m = format!("all {name} senders dropped");
+                    warn!("{m}");
+                    break 'network_manager_loop Err(SentinelError::Custom(m))
+                },
+            },
+            Some(r) = network_tasks.tasks.join_next() => match r {
+                // NOTE: Tasks are only cancelled when their network is removed or dropped.
+                Err(e) if e.is_cancelled() => continue 'network_manager_loop,
+                Err(e) => {
+                    error!("{name} could not join network task: {e}");
+                    continue 'network_manager_loop
+                },
+                Ok((_, Ok(()))) => continue 'network_manager_loop,
+                // NOTE: Shutting down is handled by the arm below.
+                Ok((_, Err(SentinelError::SigInt(_)))) => continue 'network_manager_loop,
+                Ok((network_id, Err(e))) => {
+                    error!("{network_id} network task failed: {e}");
+                    network_tasks.drop_failed(&network_id);
+                    continue 'network_manager_loop
+                },
+            },
+            _ = tokio::signal::ctrl_c() => {
+                warn!("{name} shutting down...");
+                break 'network_manager_loop Err(SentinelError::SigInt(name.into()))
+            },
+        }
+    }
+}
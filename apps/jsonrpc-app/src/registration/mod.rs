@@ -0,0 +1,3 @@
+mod registration_loop;
+
+pub(crate) use self::registration_loop::registration_loop;
@@ -0,0 +1,207 @@
+use std::result::Result;
+
+use common::Bytes;
+use common_eth::DefaultBlockParameter;
+use common_network_ids::NetworkId;
+use common_sentinel::{
+    call_core,
+    decode_address_output,
+    decode_u64_output,
+    get_epoch_duration_call_data,
+    get_epochs_manager_call_data,
+    get_registration_extension_tx_data,
+    get_sentinel_registration_call_data,
+    get_start_first_epoch_timestamp_call_data,
+    get_utc_timestamp,
+    EthRpcMessages,
+    EthRpcSenders,
+    PendingTxs,
+    RegistrationConfig,
+    SentinelConfig,
+    SentinelError,
+    SentinelRegistration,
+    TxManagerMessages,
+    WebSocketMessagesEncodable,
+};
+use ethereum_types::{Address as EthAddress, H256 as EthHash};
+use tokio::time::{interval, Duration, MissedTickBehavior};
+
+use crate::{
+    health::HEALTH,
+    type_aliases::{EthRpcTx, TxManagerTx, WebSocketTx},
+};
+
+// NOTE: A renewal which has been submitted, along with the expiry it was submitted against, so that
+// it can be told whether the renewal has taken effect.
+#[derive(Clone, Copy, Debug)]
+struct PendingRenewal {
+    tx_hash: EthHash,
+    expiry: u64,
+}
+
+async fn get_sentinel_address(config: &SentinelConfig, websocket_tx: WebSocketTx) -> Result<EthAddress, SentinelError> {
+    let msg = WebSocketMessagesEncodable::GetAddress;
+    match call_core(*config.core().timeout(), websocket_tx, msg).await? {
+        WebSocketMessagesEncodable::Success(json) => Ok(serde_json::from_value(json["address"].clone())?),
+        r => Err(SentinelError::Custom(format!(
+            "unexpected response getting sentinel address: {r}"
+        ))),
+    }
+}
+
+async fn eth_call(
+    sender: &EthRpcTx,
+    network_id: NetworkId,
+    address: EthAddress,
+    data: Bytes,
+) -> Result<Bytes, SentinelError> {
+    let (msg, rx) = EthRpcMessages::get_eth_call_msg(data, address, network_id, DefaultBlockParameter::Latest);
+    sender.send(msg).await?;
+    rx.await?
+}
+
+async fn get_registration_expiry(
+    config: &SentinelConfig,
+    registration_config: &RegistrationConfig,
+    websocket_tx: WebSocketTx,
+    eth_rpc_senders: &EthRpcSenders,
+) -> Result<u64, SentinelError> {
+    let network_id = *registration_config.network_id();
+    let registration_manager = *registration_config.registration_manager();
+    let sender = eth_rpc_senders.sender(&network_id)?;
+
+    let sentinel = get_sentinel_address(config, websocket_tx).await?;
+    let data = get_sentinel_registration_call_data(&sentinel)?;
+    let output = eth_call(&sender, network_id, registration_manager, data).await?;
+    let registration = SentinelRegistration::from_eth_call_output(&sentinel, &output)?;
+
+    let data = get_epochs_manager_call_data()?;
+    let epochs_manager = decode_address_output(&eth_call(&sender, network_id, registration_manager, data).await?)?;
+
+    let data = get_epoch_duration_call_data()?;
+    let epoch_duration = decode_u64_output(&eth_call(&sender, network_id, epochs_manager, data).await?)?;
+
+    let data = get_start_first_epoch_timestamp_call_data()?;
+    let start = decode_u64_output(&eth_call(&sender, network_id, epochs_manager, data).await?)?;
+
+    Ok(registration.expiry(start, epoch_duration))
+}
+
+async fn renewal_is_pending(
+    config: &SentinelConfig,
+    websocket_tx: WebSocketTx,
+    tx_hash: &EthHash,
+) -> Result<bool, SentinelError> {
+    let msg = WebSocketMessagesEncodable::GetPendingTxs;
+    let pending_txs = PendingTxs::try_from(call_core(*config.core().timeout(), websocket_tx, msg).await?)?;
+    // NOTE: A stuck renewal may have been replaced by one paying higher fees, but its hash remains in
+    // the list of those it may have been mined with.
+    Ok(pending_txs.txs().iter().any(|tx| tx.tx_hashes().contains(tx_hash)))
+}
+
+async fn renew_registration(
+    registration_config: &RegistrationConfig,
+    tx_manager_tx: &TxManagerTx,
+) -> Result<EthHash, SentinelError> {
+    let network_id = *registration_config.network_id();
+    let (data, gas_limit) = get_registration_extension_tx_data(*registration_config.extension_duration())?;
+    let (msg, rx) =
+        TxManagerMessages::submit_tx_msg(network_id, *registration_config.registration_manager(), data, gas_limit);
+    tx_manager_tx.send(msg).await?;
+    rx.await?
+}
+
+async fn check_registration(
+    config: &SentinelConfig,
+    registration_config: &RegistrationConfig,
+    websocket_tx: WebSocketTx,
+    eth_rpc_senders: &EthRpcSenders,
+    tx_manager_tx: &TxManagerTx,
+    pending_renewal: &mut Option<PendingRenewal>,
+) -> Result<(), SentinelError> {
+    if !HEALTH.core_connected() {
+        return Err(SentinelError::NoCore);
+    };
+
+    let expiry = get_registration_expiry(config, registration_config, websocket_tx.clone(), eth_rpc_senders).await?;
+    let now = get_utc_timestamp()?;
+
+    if let Some(p) = *pending_renewal {
+        if expiry > p.expiry {
+            info!(
+                "registration renewal 0x{:x} confirmed, now expires at {expiry}",
+                p.tx_hash
+            );
+            *pending_renewal = None;
+        } else if renewal_is_pending(config, websocket_tx, &p.tx_hash).await? {
+            debug!("registration renewal 0x{:x} still pending", p.tx_hash);
+            return Ok(());
+        } else {
+            warn!("registration renewal 0x{:x} did not extend the registration", p.tx_hash);
+            *pending_renewal = None;
+        };
+    };
+
+    if now >= expiry {
+        error!("registration expired at {expiry} - the sentinel must be registered again");
+        return Ok(());
+    };
+
+    let remaining = expiry - now;
+    if remaining <= *registration_config.warn_before() {
+        warn!("registration expires in {remaining}s at {expiry}");
+    } else {
+        debug!("registration expires in {remaining}s at {expiry}");
+    };
+
+    if *registration_config.auto_renew() && remaining <= *registration_config.renew_before() {
+        let tx_hash = renew_registration(registration_config, tx_manager_tx).await?;
+        info!("registration renewal submitted with hash 0x{tx_hash:x}");
+        *pending_renewal = Some(PendingRenewal { tx_hash, expiry });
+    };
+
+    Ok(())
+}
+
+pub async fn registration_loop(
+    config: SentinelConfig,
+    websocket_tx: WebSocketTx,
+    eth_rpc_senders: EthRpcSenders,
+    tx_manager_tx: TxManagerTx,
+) -> Result<(), SentinelError> {
+    let name = "registration";
+    let registration_config = config.registration().clone();
+    let registration_is_enabled = registration_config.is_some();
+    let check_interval = registration_config.as_ref().map_or(1, |c| *c.check_interval());
+    let mut check_interval = interval(Duration::from_secs(check_interval));
+    check_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let mut pending_renewal = None;
+
+    if !registration_is_enabled {
+        info!("{name} checks are disabled");
+    };
+
+    'registration_loop: loop {
+        tokio::select! {
+            _ = check_interval.tick(), if registration_is_enabled => {
+                if let Some(ref c) = registration_config {
+                    if let Err(e) = check_registration(
+                        &config,
+                        c,
+                        websocket_tx.clone(),
+                        &eth_rpc_senders,
+                        &tx_manager_tx,
+                        &mut pending_renewal,
+                    ).await {
+                        warn!("could not check {name}: {e}");
+                    };
+                };
+                continue 'registration_loop
+            },
+            _ = tokio::signal::ctrl_c() => {
+                warn!("{name} shutting down...");
+                break 'registration_loop Err(SentinelError::SigInt(name.into()))
+            },
+        }
+    }
+}
@@ -0,0 +1,14 @@
+mod rate_limiter;
+mod rpc_auth;
+
+pub(super) use self::{
+    rate_limiter::RateLimiter,
+    rpc_auth::{
+        AuthenticatedClient,
+        ClientCertFingerprint,
+        RpcAuth,
+        AUTHORIZATION_HEADER,
+        SIGNATURE_HEADER,
+        SIGNATURE_TIMESTAMP_HEADER,
+    },
+};
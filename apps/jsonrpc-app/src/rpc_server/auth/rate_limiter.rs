@@ -0,0 +1,61 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(60);
+
+// NOTE: A simple fixed window rate limiter, counting each client's requests per minute.
+#[derive(Debug, Default)]
+pub(crate) struct RateLimiter(Mutex<HashMap<String, (Instant, u64)>>);
+
+impl RateLimiter {
+    pub(crate) fn check(&self, client: &str, limit: u64) -> bool {
+        self.check_at(client, limit, Instant::now())
+    }
+
+    fn check_at(&self, client: &str, limit: u64, now: Instant) -> bool {
+        if limit == 0 {
+            return true;
+        };
+        let mut windows = match self.0.lock() {
+            Ok(w) => w,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let (window_start, count) = windows.entry(client.to_string()).or_insert((now, 0));
+        if now.duration_since(*window_start) >= RATE_LIMIT_WINDOW {
+            *window_start = now;
+            *count = 0;
+        };
+        if *count >= limit {
+            false
+        } else {
+            *count += 1;
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_rate_limit_per_client_per_window() {
+        let limiter = RateLimiter::default();
+        let now = Instant::now();
+        assert!(limiter.check_at("a", 2, now));
+        assert!(limiter.check_at("a", 2, now));
+        assert!(!limiter.check_at("a", 2, now));
+        assert!(limiter.check_at("b", 2, now));
+        assert!(limiter.check_at("a", 2, now + RATE_LIMIT_WINDOW));
+    }
+
+    #[test]
+    fn zero_limit_should_mean_unlimited() {
+        let limiter = RateLimiter::default();
+        let now = Instant::now();
+        assert!((0..1000).all(|_| limiter.check_at("a", 0, now)));
+    }
+}
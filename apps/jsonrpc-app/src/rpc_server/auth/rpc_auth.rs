@@ -0,0 +1,364 @@
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::{Mutex, RwLock},
+};
+
+use common::crypto_utils::keccak_hash_bytes;
+use common_eth::EthSignature;
+use common_sentinel::{get_utc_timestamp, JsonRpcError, JsonRpcErrorCode, RpcAuthConfig, RpcClientConfig, RpcRole};
+use derive_getters::Getters;
+use ethereum_types::{Address as EthAddress, H256 as EthHash};
+
+use super::RateLimiter;
+
+pub(crate) const AUTHORIZATION_HEADER: &str = "authorization";
+pub(crate) const SIGNATURE_HEADER: &str = "x-signature";
+pub(crate) const SIGNATURE_TIMESTAMP_HEADER: &str = "x-signature-timestamp";
+
+const BEARER_PREFIX: &str = "Bearer ";
+
+// NOTE: The hex encoded sha256 of the DER encoded certificate a client presented, which the rpc
+// server's TLS layer adds to each of that connection's requests when client certs are required.
+#[derive(Debug, Clone)]
+pub(crate) struct ClientCertFingerprint(pub(crate) String);
+
+#[derive(Debug, Clone, Getters)]
+pub(crate) struct AuthenticatedClient {
+    name: String,
+    role: RpcRole,
+    rate_limit: u64,
+}
+
+impl AuthenticatedClient {
+    fn from_config(config: &RpcAuthConfig, client: &RpcClientConfig) -> Self {
+        Self {
+            name: client.name().clone(),
+            role: *client.role(),
+            rate_limit: config.rate_limit_for(client),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct RpcAuth {
+    // NOTE: Behind a lock so that the config may be hot swapped without restarting the rpc server.
+    config: RwLock<RpcAuthConfig>,
+    rate_limiter: RateLimiter,
+    // NOTE: The signed msgs seen within the signature freshness window, keyed by signer & msg hash,
+    // so that no signed request may be replayed within it either.
+    used_signatures: Mutex<HashMap<(EthAddress, EthHash), u64>>,
+}
+
+// NOTE: So that the time taken to compare tokens leaks nothing about how much of one matched.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+// NOTE: As per EIP-191, the same scheme used when signing messages via `eth_sign`. The signed msg is
+// the request's timestamp & body, separated by a colon.
+fn get_eip_191_msg_hash(timestamp: u64, body: &[u8]) -> EthHash {
+    let prefix = b"\x19Ethereum Signed Message:\n";
+    let signed = [format!("{timestamp}:").as_bytes(), body].concat();
+    let msg = [prefix, format!("{}", signed.len()).as_bytes(), &signed[..]].concat();
+    keccak_hash_bytes(&msg)
+}
+
+fn recover_eip_191_signer(signature: &str, msg_hash: &EthHash) -> Option<EthAddress> {
+    EthSignature::from_str(signature)
+        .and_then(|sig| sig.recover_signer_address(msg_hash))
+        .ok()
+}
+
+impl RpcAuth {
+    pub(crate) fn new(config: RpcAuthConfig) -> Self {
+        Self {
+            config: RwLock::new(config),
+            rate_limiter: RateLimiter::default(),
+            used_signatures: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) fn update_config(&self, config: RpcAuthConfig) {
+        *self.config.write().unwrap_or_else(|e| e.into_inner()) = config;
+    }
+
+    fn unauthorized(msg: &str) -> JsonRpcError {
+        JsonRpcError::new(JsonRpcErrorCode::Unauthorized, msg, None)
+    }
+
+    fn check_signature_is_unused(
+        &self,
+        signer: EthAddress,
+        msg_hash: EthHash,
+        timestamp: u64,
+        now: u64,
+        max_age: u64,
+    ) -> Result<(), JsonRpcError> {
+        let mut used = self.used_signatures.lock().unwrap_or_else(|e| e.into_inner());
+        used.retain(|_, t| now.abs_diff(*t) <= max_age);
+        if used.insert((signer, msg_hash), timestamp).is_some() {
+            Err(Self::unauthorized("signature has already been used"))
+        } else {
+            Ok(())
+        }
+    }
+
+    pub(crate) fn authenticate(
+        &self,
+        authorization: Option<&str>,
+        signature: Option<&str>,
+        signature_timestamp: Option<&str>,
+        cert_fingerprint: Option<&ClientCertFingerprint>,
+        body: &[u8],
+    ) -> Result<AuthenticatedClient, JsonRpcError> {
+        let now = get_utc_timestamp().map_err(|e| Self::unauthorized(&e.to_string()))?;
+        self.authenticate_at(
+            authorization,
+            signature,
+            signature_timestamp,
+            cert_fingerprint,
+            body,
+            now,
+        )
+    }
+
+    // NOTE: Signatures commit to a timestamp as well as to the entire request body, including its id.
+    // Those whose timestamp is too far from now are rejected, as is any signed msg seen before whilst
+    // it's fresh, so that no signed request may be replayed.
+    fn authenticate_at(
+        &self,
+        authorization: Option<&str>,
+        signature: Option<&str>,
+        signature_timestamp: Option<&str>,
+        cert_fingerprint: Option<&ClientCertFingerprint>,
+        body: &[u8],
+        now: u64,
+    ) -> Result<AuthenticatedClient, JsonRpcError> {
+        let config = self.config.read().unwrap_or_else(|e| e.into_inner());
+
+        if !config.enabled() {
+            return Ok(AuthenticatedClient {
+                name: "anonymous".to_string(),
+                role: RpcRole::Admin,
+                rate_limit: 0,
+            });
+        };
+
+        if let Some(header) = authorization {
+            let token = header
+                .strip_prefix(BEARER_PREFIX)
+                .ok_or_else(|| Self::unauthorized("malformed authorization header"))?;
+            return config
+                .clients()
+                .iter()
+                .find(|c| {
+                    c.bearer_token()
+                        .as_ref()
+                        .map(|t| constant_time_eq(t.as_bytes(), token.as_bytes()))
+                        .unwrap_or_default()
+                })
+                .map(|c| AuthenticatedClient::from_config(&config, c))
+                .ok_or_else(|| Self::unauthorized("invalid bearer token"));
+        };
+
+        if let Some(sig) = signature {
+            let timestamp = signature_timestamp
+                .and_then(|t| t.parse::<u64>().ok())
+                .ok_or_else(|| Self::unauthorized("signed requests require a valid signature timestamp"))?;
+            let max_age = *config.signature_max_age();
+            if now.abs_diff(timestamp) > max_age {
+                return Err(Self::unauthorized(&format!(
+                    "signature timestamp {timestamp} is more than {max_age}s from now"
+                )));
+            };
+            let msg_hash = get_eip_191_msg_hash(timestamp, body);
+            let signer =
+                recover_eip_191_signer(sig, &msg_hash).ok_or_else(|| Self::unauthorized("invalid signature"))?;
+            let client = config
+                .clients()
+                .iter()
+                .find(|c| c.eth_address() == &Some(signer))
+                .map(|c| AuthenticatedClient::from_config(&config, c))
+                .ok_or_else(|| Self::unauthorized(&format!("unknown signer {signer}")))?;
+            self.check_signature_is_unused(signer, msg_hash, timestamp, now, max_age)?;
+            return Ok(client);
+        };
+
+        match cert_fingerprint {
+            // NOTE: The TLS layer has already rejected any connection without a valid client cert.
+            Some(ClientCertFingerprint(fingerprint)) if config.uses_client_certs() => config
+                .clients()
+                .iter()
+                .find(|c| c.has_cert_fingerprint(fingerprint))
+                .map(|c| AuthenticatedClient::from_config(&config, c))
+                .ok_or_else(|| Self::unauthorized(&format!("unknown client cert {fingerprint}"))),
+            _ => Err(Self::unauthorized("no credentials provided")),
+        }
+    }
+
+    pub(crate) fn authorize(&self, client: &AuthenticatedClient, required_role: RpcRole) -> Result<(), JsonRpcError> {
+        if client.role >= required_role {
+            Ok(())
+        } else {
+            Err(JsonRpcError::new(
+                JsonRpcErrorCode::Forbidden,
+                &format!(
+                    "client '{}' with role '{}' cannot call a method requiring role '{required_role}'",
+                    client.name, client.role
+                ),
+                None,
+            ))
+        }
+    }
+
+    pub(crate) fn check_rate_limit(&self, client: &AuthenticatedClient) -> Result<(), JsonRpcError> {
+        if self.rate_limiter.check(&client.name, client.rate_limit) {
+            Ok(())
+        } else {
+            Err(JsonRpcError::new(
+                JsonRpcErrorCode::RateLimited,
+                &format!(
+                    "client '{}' exceeded its rate limit of {} requests per minute",
+                    client.name, client.rate_limit
+                ),
+                None,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_eth::{EthPrivateKey, EthSigningCapabilities};
+    use serde_json::json;
+
+    use super::*;
+
+    const NOW: u64 = 1_700_000_000;
+    const CERT_FINGERPRINT: &str = "c0ffee";
+
+    fn get_sample_rpc_auth(eth_address: EthAddress) -> RpcAuth {
+        let config: RpcAuthConfig = serde_json::from_value(json!({
+            "enabled": true,
+            "rate_limit": 1,
+            "signature_max_age": 30,
+            "tls": { "cert_path": "cert.pem", "key_path": "key.pem", "client_ca_path": "ca.pem" },
+            "clients": [
+                { "name": "monitoring", "role": "read_only", "bearer_token": "token", "eth_address": null, "rate_limit": null },
+                { "name": "operator", "role": "admin", "bearer_token": null, "eth_address": eth_address, "rate_limit": 0 },
+                { "name": "dashboard", "role": "operator", "bearer_token": null, "eth_address": null, "cert_fingerprint": CERT_FINGERPRINT, "rate_limit": null },
+            ],
+        }))
+        .unwrap();
+        RpcAuth::new(config)
+    }
+
+    fn sign(pk: &EthPrivateKey, timestamp: u64, body: &[u8]) -> String {
+        let msg = [format!("{timestamp}:").as_bytes(), body].concat();
+        pk.hash_and_sign_msg_with_eth_prefix(&msg).unwrap().to_0x_string()
+    }
+
+    #[test]
+    fn should_authenticate_via_bearer_token() {
+        let auth = get_sample_rpc_auth(EthAddress::random());
+        let result = auth.authenticate(Some("Bearer token"), None, None, None, b"").unwrap();
+        assert_eq!(result.name(), "monitoring");
+        assert_eq!(*result.role(), RpcRole::ReadOnly);
+        assert!(auth.authenticate(Some("Bearer wrong"), None, None, None, b"").is_err());
+    }
+
+    #[test]
+    fn should_authenticate_via_eip_191_signature() {
+        let pk = EthPrivateKey::generate_random().unwrap();
+        let auth = get_sample_rpc_auth(pk.to_address());
+        let body = br#"{"id":1,"method":"hardReset","params":[]}"#;
+        let sig = sign(&pk, NOW, body);
+        let ts = NOW.to_string();
+        let result = auth
+            .authenticate_at(None, Some(&sig), Some(&ts), None, body, NOW)
+            .unwrap();
+        assert_eq!(result.name(), "operator");
+        assert_eq!(*result.role(), RpcRole::Admin);
+        let tampered_body = br#"{"id":2,"method":"hardReset","params":[]}"#;
+        assert!(auth
+            .authenticate_at(None, Some(&sig), Some(&ts), None, tampered_body, NOW)
+            .is_err());
+        let tampered_ts = (NOW + 1).to_string();
+        assert!(auth
+            .authenticate_at(None, Some(&sig), Some(&tampered_ts), None, body, NOW)
+            .is_err());
+    }
+
+    #[test]
+    fn should_not_authenticate_stale_or_untimestamped_signature() {
+        let pk = EthPrivateKey::generate_random().unwrap();
+        let auth = get_sample_rpc_auth(pk.to_address());
+        let body = br#"{"id":1,"method":"hardReset","params":[]}"#;
+        let timestamp = NOW - 31;
+        let sig = sign(&pk, timestamp, body);
+        let ts = timestamp.to_string();
+        assert!(auth
+            .authenticate_at(None, Some(&sig), Some(&ts), None, body, NOW)
+            .is_err());
+        assert!(auth.authenticate_at(None, Some(&sig), None, None, body, NOW).is_err());
+    }
+
+    #[test]
+    fn should_not_authenticate_replayed_signature() {
+        let pk = EthPrivateKey::generate_random().unwrap();
+        let auth = get_sample_rpc_auth(pk.to_address());
+        let body = br#"{"id":1,"method":"hardReset","params":[]}"#;
+        let sig = sign(&pk, NOW, body);
+        let ts = NOW.to_string();
+        assert!(auth
+            .authenticate_at(None, Some(&sig), Some(&ts), None, body, NOW)
+            .is_ok());
+        assert!(auth
+            .authenticate_at(None, Some(&sig), Some(&ts), None, body, NOW + 1)
+            .is_err());
+    }
+
+    #[test]
+    fn should_authenticate_via_client_cert_fingerprint() {
+        let auth = get_sample_rpc_auth(EthAddress::random());
+        let known = ClientCertFingerprint(CERT_FINGERPRINT.to_string());
+        let result = auth.authenticate(None, None, None, Some(&known), b"").unwrap();
+        assert_eq!(result.name(), "dashboard");
+        assert_eq!(*result.role(), RpcRole::Operator);
+        let unknown = ClientCertFingerprint("decaf".to_string());
+        assert!(auth.authenticate(None, None, None, Some(&unknown), b"").is_err());
+    }
+
+    #[test]
+    fn should_not_authenticate_without_credentials_when_enabled() {
+        let auth = get_sample_rpc_auth(EthAddress::random());
+        assert!(auth.authenticate(None, None, None, None, b"").is_err());
+    }
+
+    #[test]
+    fn should_allow_everything_when_disabled() {
+        let auth = RpcAuth::default();
+        let client = auth.authenticate(None, None, None, None, b"").unwrap();
+        assert!(auth.authorize(&client, RpcRole::Admin).is_ok());
+    }
+
+    #[test]
+    fn should_authorize_by_role_and_rate_limit() {
+        let auth = get_sample_rpc_auth(EthAddress::random());
+        let client = auth.authenticate(Some("Bearer token"), None, None, None, b"").unwrap();
+        assert!(auth.authorize(&client, RpcRole::ReadOnly).is_ok());
+        assert!(auth.authorize(&client, RpcRole::Operator).is_err());
+        assert!(auth.check_rate_limit(&client).is_ok());
+        assert!(auth.check_rate_limit(&client).is_err());
+    }
+
+    #[test]
+    fn should_authenticate_with_updated_config() {
+        let auth = RpcAuth::default();
+        assert!(auth.authenticate(None, None, None, None, b"").is_ok());
+        auth.update_config(get_sample_rpc_auth(EthAddress::random()).config.into_inner().unwrap());
+        assert!(auth.authenticate(None, None, None, None, b"").is_err());
+        assert!(auth.authenticate(Some("Bearer token"), None, None, None, b"").is_ok());
+    }
+}
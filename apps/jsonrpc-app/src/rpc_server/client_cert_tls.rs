@@ -0,0 +1,102 @@
+use std::{convert::Infallible, fs::File, io::BufReader, net::SocketAddr, sync::Arc};
+
+use common_sentinel::SentinelError;
+use sha2::{Digest, Sha256};
+use tokio::net::TcpListener;
+use tokio_rustls::{
+    rustls::{server::AllowAnyAuthenticatedClient, Certificate, PrivateKey, RootCertStore, ServerConfig},
+    TlsAcceptor,
+};
+use warp::hyper::{
+    server::conn::Http,
+    service::{service_fn, Service},
+    Body,
+    Request,
+    Response,
+};
+
+use super::auth::ClientCertFingerprint;
+
+fn read_certs(path: &str) -> Result<Vec<Certificate>, SentinelError> {
+    let certs = rustls_pemfile::certs(&mut BufReader::new(File::open(path)?))?;
+    if certs.is_empty() {
+        return Err(SentinelError::Custom(format!("no certificates found in {path}")));
+    };
+    Ok(certs.into_iter().map(Certificate).collect())
+}
+
+fn read_key(path: &str) -> Result<PrivateKey, SentinelError> {
+    for item in rustls_pemfile::read_all(&mut BufReader::new(File::open(path)?))? {
+        match item {
+            rustls_pemfile::Item::PKCS8Key(k) | rustls_pemfile::Item::RSAKey(k) | rustls_pemfile::Item::ECKey(k) => {
+                return Ok(PrivateKey(k))
+            },
+            _ => continue,
+        }
+    }
+    Err(SentinelError::Custom(format!("no private key found in {path}")))
+}
+
+// NOTE: Warp's own TLS server can require client certs, but has no means of telling a request which
+// cert its connection presented. So when client certs are required, we terminate TLS ourselves in
+// order to attribute each request to the client configured with that cert's fingerprint.
+pub(super) fn get_client_cert_tls_acceptor(
+    cert_path: &str,
+    key_path: &str,
+    client_ca_path: &str,
+) -> Result<TlsAcceptor, SentinelError> {
+    let mut roots = RootCertStore::empty();
+    for ca in read_certs(client_ca_path)? {
+        roots
+            .add(&ca)
+            .map_err(|e| SentinelError::Custom(format!("invalid client ca cert in {client_ca_path}: {e}")))?;
+    }
+    let mut config = ServerConfig::builder()
+        .with_safe_defaults()
+        .with_client_cert_verifier(AllowAnyAuthenticatedClient::new(roots).boxed())
+        .with_single_cert(read_certs(cert_path)?, read_key(key_path)?)
+        .map_err(|e| SentinelError::Custom(format!("invalid rpc server tls config: {e}")))?;
+    config.alpn_protocols = vec![b"http/1.1".to_vec()];
+    Ok(TlsAcceptor::from(Arc::new(config)))
+}
+
+pub(super) async fn serve_with_client_certs<S>(
+    service: S,
+    acceptor: TlsAcceptor,
+    address: SocketAddr,
+) -> Result<(), SentinelError>
+where
+    S: Service<Request<Body>, Response = Response<Body>, Error = Infallible> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    let listener = TcpListener::bind(address).await?;
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        let acceptor = acceptor.clone();
+        let service = service.clone();
+        tokio::spawn(async move {
+            let stream = match acceptor.accept(stream).await {
+                Ok(s) => s,
+                Err(e) => {
+                    warn!("rpc server tls handshake with {peer} failed: {e}");
+                    return;
+                },
+            };
+            let fingerprint = stream
+                .get_ref()
+                .1
+                .peer_certificates()
+                .and_then(|certs| certs.first())
+                .map(|cert| ClientCertFingerprint(hex::encode(Sha256::digest(&cert.0))));
+            let service = service_fn(move |mut request: Request<Body>| {
+                if let Some(ref f) = fingerprint {
+                    request.extensions_mut().insert(f.clone());
+                };
+                service.clone().call(request)
+            });
+            if let Err(e) = Http::new().http1_only(true).serve_connection(stream, service).await {
+                warn!("rpc server connection with {peer} failed: {e}");
+            };
+        });
+    }
+}
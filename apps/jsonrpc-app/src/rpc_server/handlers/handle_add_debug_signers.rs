@@ -16,21 +16,23 @@ impl RpcCalls {
         core_cxn: bool,
     ) -> Result<WebSocketMessagesEncodable, SentinelError> {
         Self::check_core_is_connected(core_cxn)?;
-        let checked_params = Self::check_params(params, 2)?;
+        let names_and_addresses = params.strings("namesAndAddresses")?;
+        let n = names_and_addresses.len();
         let mut signers = vec![];
 
         // NOTE: We expect the input to be an array of strings that are zero or more names and addresses,
         // possibly followed by a debug signature.
-        for chunk in checked_params[..checked_params.len() - (checked_params.len() % 2)].chunks(2) {
+        for chunk in names_and_addresses[..n - (n % 2)].chunks(2) {
             signers.push((chunk[0].clone(), EthAddress::from_str(&chunk[1])?));
         }
 
         // NOTE: If no debug signers are present the signature check for the first addition(s) is
-        // omitted.
-        let sig = if checked_params.len() % 2 == 0 {
+        // omitted. Where the core's debug signature policies require more than one signature, they
+        // are passed in comma separated as that final param.
+        let sig = if n % 2 == 0 {
             DebugSignature::new(None)
         } else {
-            DebugSignature::new(checked_params.last().cloned())
+            DebugSignature::new(names_and_addresses.last().cloned())
         };
 
         let msg = WebSocketMessagesEncodable::AddDebugSigners(signers, sig);
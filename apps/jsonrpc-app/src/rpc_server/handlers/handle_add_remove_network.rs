@@ -0,0 +1,120 @@
+use common_sentinel::{
+    call_core,
+    BroadcastChannelMessages,
+    EthRpcSenders,
+    NetworkManagerMessages,
+    RpcServerBroadcastChannelMessages,
+    SentinelConfig,
+    SentinelError,
+    WebSocketMessagesEncodable,
+    WebSocketMessagesError,
+    WebSocketMessagesInitArgs,
+};
+use serde_json::{json, Value as Json};
+
+use crate::{
+    rpc_server::{RpcCalls, RpcParams, STRONGBOX_TIMEOUT},
+    type_aliases::{BroadcastChannelTx, NetworkManagerTx, WebSocketTx},
+};
+
+impl RpcCalls {
+    // NOTE: Adds a network to the running sentinel, taking its config from the passed in path, else
+    // from wherever the config was originally loaded from. If a core is connected, it's checked for
+    // whether it's initialized for the network, and initialized if not & a tail length and number of
+    // confirmations are passed in.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn handle_add_network(
+        config: SentinelConfig,
+        eth_rpc_senders: EthRpcSenders,
+        websocket_tx: WebSocketTx,
+        network_manager_tx: NetworkManagerTx,
+        broadcast_channel_tx: BroadcastChannelTx,
+        params: RpcParams,
+        core_cxn: bool,
+    ) -> Result<Json, SentinelError> {
+        let network_id = params.network_id("networkId")?;
+        let init_args = match (
+            params.optional_u64("tailLength")?,
+            params.optional_u64("confirmations")?,
+        ) {
+            (Some(t), Some(c)) => Some((t, c)),
+            _ => None,
+        };
+        let path = params
+            .optional_string("path")?
+            .unwrap_or_else(|| config.path().to_string());
+        debug!("adding network {network_id} using config from path '{path}'...");
+
+        let updated_config = config.with_network_from(&network_id, &SentinelConfig::new(&path)?)?;
+
+        let (msg, rx) = NetworkManagerMessages::add_network_msg(network_id, updated_config.clone(), core_cxn);
+        network_manager_tx.send(msg).await?;
+        rx.await??;
+
+        broadcast_channel_tx.send(BroadcastChannelMessages::RpcServer(
+            RpcServerBroadcastChannelMessages::UpdateConfig(Box::new(updated_config.clone())),
+        ))?;
+
+        if !core_cxn {
+            warn!("{network_id} added whilst no core is connected - it may need initializing");
+            return Ok(json!({ "networkId": network_id, "added": true, "coreInitialized": null }));
+        };
+
+        // NOTE: The network remains added should anything below fail, & it can then be initialized
+        // via the `init` rpc call.
+        let core_initialized = match call_core(
+            STRONGBOX_TIMEOUT,
+            websocket_tx.clone(),
+            WebSocketMessagesEncodable::CheckInit(network_id),
+        )
+        .await?
+        {
+            WebSocketMessagesEncodable::Success(_) => true,
+            WebSocketMessagesEncodable::Error(WebSocketMessagesError::NotInitialized(_)) => match init_args {
+                None => false,
+                Some((tail_length, confirmations)) => {
+                    let args = WebSocketMessagesInitArgs::new(
+                        updated_config.validate(&network_id)?,
+                        updated_config.pnetwork_hub(&network_id)?,
+                        tail_length,
+                        confirmations,
+                        network_id,
+                    );
+                    match Self::init_core(websocket_tx, eth_rpc_senders, args).await? {
+                        WebSocketMessagesEncodable::Success(_) => true,
+                        WebSocketMessagesEncodable::Error(e) => return Err(e.into()),
+                        r => return Err(WebSocketMessagesError::UnexpectedResponse(r.to_string()).into()),
+                    }
+                },
+            },
+            WebSocketMessagesEncodable::Error(e) => return Err(e.into()),
+            r => return Err(WebSocketMessagesError::UnexpectedResponse(r.to_string()).into()),
+        };
+
+        Ok(json!({ "networkId": network_id, "added": true, "coreInitialized": core_initialized }))
+    }
+
+    // NOTE: Stops the syncer & eth rpc tasks for the network. The core's state for that network is
+    // left untouched, so that it may be added back again later.
+    pub(crate) async fn handle_remove_network(
+        config: SentinelConfig,
+        network_manager_tx: NetworkManagerTx,
+        broadcast_channel_tx: BroadcastChannelTx,
+        params: RpcParams,
+    ) -> Result<Json, SentinelError> {
+        let network_id = params.network_id("networkId")?;
+        debug!("removing network {network_id}...");
+
+        let updated_config = config.without_network(&network_id)?;
+
+        let (msg, rx) = NetworkManagerMessages::remove_network_msg(network_id);
+        network_manager_tx.send(msg).await?;
+        rx.await??;
+
+        broadcast_channel_tx.send(BroadcastChannelMessages::RpcServer(
+            RpcServerBroadcastChannelMessages::UpdateConfig(Box::new(updated_config)),
+        ))?;
+
+        Ok(json!({ "networkId": network_id, "removed": true }))
+    }
+}
@@ -0,0 +1,26 @@
+use common_sentinel::{call_core, SentinelError, WebSocketMessagesEncodable};
+
+use crate::{
+    rpc_server::{RpcCalls, RpcParams, STRONGBOX_TIMEOUT},
+    type_aliases::WebSocketTx,
+};
+
+// NOTE: Params are any number of debug signatures, which a core with no debug signatories yet, ie a
+// fresh one, does not require.
+impl RpcCalls {
+    pub(crate) async fn handle_create_key(
+        params: RpcParams,
+        websocket_tx: WebSocketTx,
+        core_cxn: bool,
+    ) -> Result<WebSocketMessagesEncodable, SentinelError> {
+        debug!("handling create key rpc call...");
+        Self::check_core_is_connected(core_cxn)?;
+        let sig = params.debug_signature("signatures")?;
+        call_core(
+            STRONGBOX_TIMEOUT,
+            websocket_tx.clone(),
+            WebSocketMessagesEncodable::CreateKey(sig),
+        )
+        .await
+    }
+}
@@ -1,4 +1,4 @@
-use common_sentinel::{call_core, SentinelError, WebSocketMessagesEncodable};
+use common_sentinel::{call_core, SentinelError, WebSocketMessagesEncodable, WebSocketMessagesEncodableDbOps};
 
 use crate::{
     rpc_server::{RpcCalls, RpcParams, STRONGBOX_TIMEOUT},
@@ -13,7 +13,8 @@ impl RpcCalls {
     ) -> Result<WebSocketMessagesEncodable, SentinelError> {
         debug!("handling db delete...");
         Self::check_core_is_connected(core_cxn)?;
-        let msg = WebSocketMessagesEncodable::try_from(Self::create_args("delete", params))?;
+        let op = WebSocketMessagesEncodableDbOps::Delete(params.hex("key")?, params.debug_signature("signatures")?);
+        let msg = WebSocketMessagesEncodable::DbOps(op);
         call_core(STRONGBOX_TIMEOUT, websocket_tx.clone(), msg).await
     }
 
@@ -24,7 +25,8 @@ impl RpcCalls {
     ) -> Result<WebSocketMessagesEncodable, SentinelError> {
         debug!("handling db get...");
         Self::check_core_is_connected(core_cxn)?;
-        let msg = WebSocketMessagesEncodable::try_from(Self::create_args("get", params))?;
+        let op = WebSocketMessagesEncodableDbOps::Get(params.hex("key")?, params.debug_signature("signatures")?);
+        let msg = WebSocketMessagesEncodable::DbOps(op);
         call_core(STRONGBOX_TIMEOUT, websocket_tx.clone(), msg).await
     }
 
@@ -35,7 +37,12 @@ impl RpcCalls {
     ) -> Result<WebSocketMessagesEncodable, SentinelError> {
         debug!("handling db put...");
         Self::check_core_is_connected(core_cxn)?;
-        let msg = WebSocketMessagesEncodable::try_from(Self::create_args("put", params))?;
+        let op = WebSocketMessagesEncodableDbOps::Put(
+            params.hex("key")?,
+            params.hex("value")?,
+            params.debug_signature("signatures")?,
+        );
+        let msg = WebSocketMessagesEncodable::DbOps(op);
         call_core(STRONGBOX_TIMEOUT, websocket_tx.clone(), msg).await
     }
 }
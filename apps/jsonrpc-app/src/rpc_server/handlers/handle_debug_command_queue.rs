@@ -0,0 +1,49 @@
+use common_sentinel::{call_core, SentinelError, WebSocketMessagesEncodable};
+
+use crate::{
+    rpc_server::{RpcCalls, RpcParams, STRONGBOX_TIMEOUT},
+    type_aliases::WebSocketTx,
+};
+
+impl RpcCalls {
+    // NOTE: Params are the delay in seconds, followed by any debug signatures. A delay of zero
+    // disables the queue, such that time lockable debug commands once again run immediately.
+    pub(crate) async fn handle_set_debug_command_delay(
+        params: RpcParams,
+        websocket_tx: WebSocketTx,
+        core_cxn: bool,
+    ) -> Result<WebSocketMessagesEncodable, SentinelError> {
+        Self::check_core_is_connected(core_cxn)?;
+        let delay = params.u64("delay")?;
+        let sig = params.debug_signature("signatures")?;
+        let msg = WebSocketMessagesEncodable::SetDebugCommandDelay(delay, sig);
+        call_core(STRONGBOX_TIMEOUT, websocket_tx.clone(), msg).await
+    }
+
+    // NOTE: Params are the id of the queued command, followed by a debug signature from any one
+    // debug signatory.
+    pub(crate) async fn handle_cancel_queued(
+        params: RpcParams,
+        websocket_tx: WebSocketTx,
+        core_cxn: bool,
+    ) -> Result<WebSocketMessagesEncodable, SentinelError> {
+        Self::check_core_is_connected(core_cxn)?;
+        let id = params.u64("id")?;
+        let sig = params.debug_signature("signature")?;
+        let msg = WebSocketMessagesEncodable::CancelQueued(id, sig);
+        call_core(STRONGBOX_TIMEOUT, websocket_tx.clone(), msg).await
+    }
+
+    // NOTE: The queued command's signatures were validated when it was queued, so executing it once
+    // its delay has passed requires only its id.
+    pub(crate) async fn handle_execute_queued(
+        params: RpcParams,
+        websocket_tx: WebSocketTx,
+        core_cxn: bool,
+    ) -> Result<WebSocketMessagesEncodable, SentinelError> {
+        Self::check_core_is_connected(core_cxn)?;
+        let id = params.u64("id")?;
+        let msg = WebSocketMessagesEncodable::ExecuteQueued(id);
+        call_core(STRONGBOX_TIMEOUT, websocket_tx.clone(), msg).await
+    }
+}
@@ -0,0 +1,28 @@
+use common_sentinel::{call_core, SentinelError, WebSocketMessagesEncodable};
+
+use crate::{
+    rpc_server::{RpcCalls, RpcParams, STRONGBOX_TIMEOUT},
+    type_aliases::WebSocketTx,
+};
+
+// NOTE: Exports the core's state (chains, sub mats, debug signers etc) as an archive encrypted to the
+// passed in public key (IE that of the core being migrated to, via `createKey`), and signed by
+// this core. Params are the recipient's public key, a comma separated list of network ids, and
+// finally any number of debug signatures.
+impl RpcCalls {
+    pub(crate) async fn handle_export_core_state(
+        params: RpcParams,
+        websocket_tx: WebSocketTx,
+        core_cxn: bool,
+    ) -> Result<WebSocketMessagesEncodable, SentinelError> {
+        Self::check_core_is_connected(core_cxn)?;
+
+        let recipient = params.hex("recipient")?;
+        let network_ids = params.network_ids("networkIds")?;
+        let sig = params.debug_signature("signatures")?;
+
+        let msg = WebSocketMessagesEncodable::ExportCoreState(network_ids, recipient, sig);
+
+        call_core(STRONGBOX_TIMEOUT, websocket_tx.clone(), msg).await
+    }
+}
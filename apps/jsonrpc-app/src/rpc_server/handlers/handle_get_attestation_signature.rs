@@ -1,4 +1,3 @@
-use common::strip_hex_prefix;
 use common_sentinel::{call_core, SentinelError, WebSocketMessagesEncodable};
 
 use crate::{
@@ -14,12 +13,7 @@ impl RpcCalls {
     ) -> Result<WebSocketMessagesEncodable, SentinelError> {
         debug!("handling get attestation rpc call...");
         Self::check_core_is_connected(core_cxn)?;
-        let checked_params = Self::check_params(params, 1)?;
-
-        let bytes = hex::decode(strip_hex_prefix(&checked_params[0])).map_err(|e| {
-            error!("{e}");
-            SentinelError::Custom(format!("invalid hex: '{}'", checked_params[0]))
-        })?;
+        let bytes = params.hex("bytes")?;
 
         call_core(
             STRONGBOX_TIMEOUT,
@@ -0,0 +1,22 @@
+use common_sentinel::{call_core, SentinelError, WebSocketMessagesEncodable, MAX_AUDIT_LOG_PAGE_SIZE};
+
+use crate::{
+    rpc_server::{RpcCalls, RpcParams, STRONGBOX_TIMEOUT},
+    type_aliases::WebSocketTx,
+};
+
+impl RpcCalls {
+    // NOTE: Params are an optional offset and an optional page size, defaulting to the first page
+    // of the maximum size.
+    pub(crate) async fn handle_get_audit_log(
+        params: RpcParams,
+        websocket_tx: WebSocketTx,
+        core_cxn: bool,
+    ) -> Result<WebSocketMessagesEncodable, SentinelError> {
+        Self::check_core_is_connected(core_cxn)?;
+        let offset = params.optional_u64("offset")?.unwrap_or_default();
+        let limit = params.optional_u64("limit")?.unwrap_or(MAX_AUDIT_LOG_PAGE_SIZE);
+        let msg = WebSocketMessagesEncodable::GetAuditLog(offset, limit);
+        call_core(STRONGBOX_TIMEOUT, websocket_tx.clone(), msg).await
+    }
+}
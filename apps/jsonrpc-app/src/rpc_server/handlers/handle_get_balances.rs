@@ -1,7 +1,4 @@
-use std::str::FromStr;
-
 use common_sentinel::{Balance, Balances, EthRpcMessages, EthRpcSenders, SentinelConfig, SentinelError};
-use ethereum_types::Address as EthAddress;
 use serde_json::{json, Value as Json};
 
 use crate::rpc_server::{RpcCalls, RpcParams};
@@ -14,8 +11,7 @@ impl RpcCalls {
     ) -> Result<Json, SentinelError> {
         debug!("handling get balances...");
 
-        let checked_params = Self::check_params(params, 1)?;
-        let address = EthAddress::from_str(&checked_params[0])?;
+        let address = params.eth_address("address")?;
         let network_ids = config.network_ids();
 
         let mut balances: Balances = Balances::new(vec![]);
@@ -1,5 +1,4 @@
-use common_network_ids::NetworkId;
-use common_sentinel::{call_core, SentinelError, WebSocketMessagesEncodable, WebSocketMessagesError};
+use common_sentinel::{call_core, SentinelError, WebSocketMessagesEncodable};
 
 use crate::{
     rpc_server::{RpcCalls, RpcParams, STRONGBOX_TIMEOUT},
@@ -14,21 +13,7 @@ impl RpcCalls {
     ) -> Result<WebSocketMessagesEncodable, SentinelError> {
         Self::check_core_is_connected(core_cxn)?;
 
-        let n = 1;
-        let l = params.len();
-        if l < n {
-            return Err(WebSocketMessagesError::NotEnoughArgs {
-                got: l,
-                expected: n,
-                args: params,
-            }
-            .into());
-        }
-
-        let network_ids = params
-            .iter()
-            .map(|s| NetworkId::try_from(s).map_err(|_| WebSocketMessagesError::ParseNetworkId(s.into())))
-            .collect::<Result<Vec<NetworkId>, WebSocketMessagesError>>()?;
+        let network_ids = params.network_ids("networkIds")?;
 
         call_core(
             STRONGBOX_TIMEOUT,
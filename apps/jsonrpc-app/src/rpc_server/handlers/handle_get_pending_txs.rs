@@ -0,0 +1,25 @@
+use common_sentinel::{call_core, PendingTxs, SentinelError, WebSocketMessagesEncodable};
+
+use crate::{
+    rpc_server::{RpcCalls, STRONGBOX_TIMEOUT},
+    type_aliases::WebSocketTx,
+};
+
+impl RpcCalls {
+    // NOTE: The core persists the pending txs as they're stored, and so they're converted here into
+    // a more readable form.
+    pub(crate) async fn handle_get_pending_txs(
+        websocket_tx: WebSocketTx,
+        core_cxn: bool,
+    ) -> Result<WebSocketMessagesEncodable, SentinelError> {
+        debug!("handling get pending txs rpc call...");
+        Self::check_core_is_connected(core_cxn)?;
+        let r = call_core(
+            STRONGBOX_TIMEOUT,
+            websocket_tx.clone(),
+            WebSocketMessagesEncodable::GetPendingTxs,
+        )
+        .await?;
+        Ok(WebSocketMessagesEncodable::Success(PendingTxs::try_from(r)?.to_json()))
+    }
+}
@@ -1,8 +1,12 @@
-use std::str::FromStr;
-
 use common_eth::EthPrivateKey;
-use common_network_ids::NetworkId;
-use common_sentinel::{get_registration_extension_tx, EthRpcMessages, EthRpcSenders, SentinelConfig, SentinelError};
+use common_sentinel::{
+    get_registration_extension_tx,
+    EthFees,
+    EthRpcMessages,
+    EthRpcSenders,
+    SentinelConfig,
+    SentinelError,
+};
 use ethereum_types::Address as EthAddress;
 use serde_json::{json, Value as Json};
 
@@ -20,22 +24,13 @@ impl RpcCalls {
         pk: EthPrivateKey,
         eth_rpc_senders: EthRpcSenders,
     ) -> Result<Json, SentinelError> {
-        const REQUIRED_NUM_PARAMS: usize = 3;
-
-        // NOTE: If another arg is passed in let's use it as a gas price.
-        let maybe_gas_price = if params.len() > REQUIRED_NUM_PARAMS {
-            let p = params[REQUIRED_NUM_PARAMS + 1].clone().parse::<u64>()?;
-            Some(p)
-        } else {
-            None
-        };
-
-        let checked_params = Self::check_params(params, REQUIRED_NUM_PARAMS)?;
+        // NOTE: If a gas price is passed in let's use it as a legacy gas price.
+        let maybe_gas_price = params.optional_u64("gasPrice")?;
 
-        let duration = checked_params[0].parse::<u64>()?; // TODO sanity check?
-        let network_id = NetworkId::from_str(&checked_params[1])?;
+        let duration = params.u64("duration")?; // TODO sanity check?
+        let network_id = params.network_id("networkId")?;
 
-        let registration_manager = EthAddress::from_str(&checked_params[2])?;
+        let registration_manager = params.eth_address("registrationManager")?;
 
         let sender = eth_rpc_senders.sender(&network_id)?;
 
@@ -44,21 +39,20 @@ impl RpcCalls {
         sender.send(nonce_msg).await?;
         let nonce = nonce_rx.await??;
 
-        let gas_price = if let Some(p) = maybe_gas_price {
-            p
+        let fees = if let Some(p) = maybe_gas_price {
+            EthFees::legacy(p)
         } else {
-            let (gas_price_msg, gas_price_rx) = EthRpcMessages::get_gas_price_msg(network_id);
-            sender.send(gas_price_msg).await?;
-            gas_price_rx.await??
+            let (fees_msg, fees_rx) = EthRpcMessages::get_fees_msg(network_id);
+            sender.send(fees_msg).await?;
+            fees_rx.await??
         };
 
-        let signed_tx =
-            get_registration_extension_tx(nonce, duration, gas_price, network_id, &pk, registration_manager)?;
+        let signed_tx = get_registration_extension_tx(nonce, duration, fees, network_id, &pk, registration_manager)?;
 
         let json = json!({
             "nonce": nonce,
             "duration": duration,
-            "gas_price": gas_price,
+            "fees": fees,
             "signer": format!("0x{}", hex::encode(address)),
             "registration_manager": format!("0x{}", hex::encode(registration_manager)),
             "tx": format!("0x{}", signed_tx.serialize_hex()),
@@ -1,7 +1,4 @@
-use std::str::FromStr;
-
 use common_sentinel::{call_core, SentinelError, WebSocketMessagesEncodable};
-use ethereum_types::Address as EthAddress;
 
 use crate::{
     rpc_server::{RpcCalls, RpcParams, STRONGBOX_TIMEOUT},
@@ -18,17 +15,14 @@ impl RpcCalls {
     ) -> Result<WebSocketMessagesEncodable, SentinelError> {
         Self::check_core_is_connected(core_cxn)?;
 
-        const MIN_NUM_PARAMS: usize = 2;
-        let checked_params = Self::check_params(params, MIN_NUM_PARAMS)?;
-
-        let owner_address = EthAddress::from_str(&checked_params[0])?;
-        let nonce = checked_params[1].parse::<u64>()?;
-        let sig = checked_params.get(MIN_NUM_PARAMS);
+        let owner_address = params.eth_address("owner")?;
+        let nonce = params.u64("nonce")?;
+        let sig = params.debug_signature("signature")?;
 
         debug!("owner address: {owner_address}");
         debug!("        nonce: {nonce}");
 
-        let msg = WebSocketMessagesEncodable::GetRegistrationSignature(owner_address, nonce, sig.into());
+        let msg = WebSocketMessagesEncodable::GetRegistrationSignature(owner_address, nonce, sig);
 
         call_core(STRONGBOX_TIMEOUT, websocket_tx.clone(), msg).await
     }
@@ -1,4 +1,3 @@
-use common_debug_signers::DebugSignature;
 use common_sentinel::{call_core, SentinelError, WebSocketMessagesEncodable};
 
 use crate::{
@@ -14,11 +13,9 @@ impl RpcCalls {
     ) -> Result<WebSocketMessagesEncodable, SentinelError> {
         Self::check_core_is_connected(core_cxn)?;
 
-        let debug_sig = if params.is_empty() {
-            DebugSignature::new(None)
-        } else {
-            DebugSignature::new(params.get(1).cloned())
-        };
+        // NOTE: Any number of debug signatures may follow, as required by the core's debug signature
+        // policy for this command.
+        let debug_sig = params.debug_signature("signatures")?;
 
         let msg = WebSocketMessagesEncodable::HardReset(debug_sig);
 
@@ -0,0 +1,29 @@
+use common_sentinel::{call_core, EncryptedCoreStateArchive, SentinelError, WebSocketMessagesEncodable};
+
+use crate::{
+    rpc_server::{RpcCalls, RpcParams, STRONGBOX_TIMEOUT},
+    type_aliases::WebSocketTx,
+};
+
+// NOTE: Imports a core state archive created via `exportCoreState`. Since archives can be large, the
+// first param is a path to a file containing the archive json, rather than the json itself. It's
+// followed by any number of debug signatures. The core only imports an archive signed by the core
+// set via `setTrustedArchiveSigner`.
+impl RpcCalls {
+    pub(crate) async fn handle_import_core_state(
+        params: RpcParams,
+        websocket_tx: WebSocketTx,
+        core_cxn: bool,
+    ) -> Result<WebSocketMessagesEncodable, SentinelError> {
+        Self::check_core_is_connected(core_cxn)?;
+
+        let path = params.string("path")?;
+        debug!("reading core state archive from {path}...");
+        let archive = EncryptedCoreStateArchive::try_from(tokio::fs::read_to_string(&path).await?.as_str())?;
+        let sig = params.debug_signature("signatures")?;
+
+        let msg = WebSocketMessagesEncodable::ImportCoreState(Box::new(archive), sig);
+
+        call_core(STRONGBOX_TIMEOUT, websocket_tx.clone(), msg).await
+    }
+}
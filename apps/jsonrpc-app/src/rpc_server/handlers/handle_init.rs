@@ -24,7 +24,22 @@ impl RpcCalls {
     ) -> Result<WebSocketMessagesEncodable, SentinelError> {
         Self::check_core_is_connected(core_cxn)?;
 
-        let mut args = WebSocketMessagesInitArgs::try_from(params)?;
+        let args = WebSocketMessagesInitArgs::new(
+            params.bool("validate")?,
+            params.eth_address("hub")?,
+            params.u64("tailLength")?,
+            params.u64("confirmations")?,
+            params.network_id("networkId")?,
+        );
+
+        Self::init_core(websocket_tx, eth_rpc_senders, args).await
+    }
+
+    pub(super) async fn init_core(
+        websocket_tx: WebSocketTx,
+        eth_rpc_senders: EthRpcSenders,
+        mut args: WebSocketMessagesInitArgs,
+    ) -> Result<WebSocketMessagesEncodable, SentinelError> {
         let network_id = *args.network_id();
 
         match eth_rpc_senders.sender(&network_id) {
@@ -43,6 +58,15 @@ impl RpcCalls {
                 sender.send(b_msg).await?;
                 let sub_mat = b_rx.await??;
 
+                // NOTE: The raw txs are only needed in order for the core to validate the receipts.
+                let sub_mat = if *args.validate() && !sub_mat.receipts.is_empty() {
+                    let (t_msg, t_rx) = EthRpcMessages::get_raw_txs_msg(network_id, latest_block_num);
+                    sender.send(t_msg).await?;
+                    sub_mat.add_raw_transactions(t_rx.await??)?
+                } else {
+                    sub_mat
+                };
+
                 // NOTE: Now we need to add the sub mat to the args to send to strongbox
                 args.add_sub_mat(sub_mat);
 
@@ -1,5 +1,4 @@
 use common_eth::EthSubmissionMaterials;
-use common_network_ids::NetworkId;
 use common_sentinel::{
     call_core,
     EthRpcMessages,
@@ -25,8 +24,7 @@ impl RpcCalls {
         core_cxn: bool,
     ) -> Result<WebSocketMessagesEncodable, SentinelError> {
         Self::check_core_is_connected(core_cxn)?;
-        let checked_params = Self::check_params(params, 4)?;
-        let network_id = NetworkId::try_from(&checked_params[0])?;
+        let network_id = params.network_id("networkId")?;
 
         match eth_rpc_senders.sender(&network_id) {
             Err(e) => {
@@ -34,21 +32,31 @@ impl RpcCalls {
                 Err(WebSocketMessagesError::Unsupported(network_id).into())
             },
             Ok(sender) => {
-                let block_num = checked_params[1].parse::<u64>()?;
+                let block_num = params.u64("blockNum")?;
                 let (eth_rpc_msg, rx) = EthRpcMessages::get_sub_mat_msg(network_id, block_num);
 
                 sender.send(eth_rpc_msg).await?;
                 let sub_mat = rx.await??;
 
-                let dry_run = matches!(checked_params[2].as_ref(), "true");
-                let reprocess = matches!(checked_params[3].as_ref(), "true");
+                // NOTE: The raw txs are only needed in order for the core to validate the receipts.
+                let validate = config.validate(&network_id)?;
+                let sub_mat = if validate && !sub_mat.receipts.is_empty() {
+                    let (eth_rpc_msg, rx) = EthRpcMessages::get_raw_txs_msg(network_id, block_num);
+                    sender.send(eth_rpc_msg).await?;
+                    sub_mat.add_raw_transactions(rx.await??)?
+                } else {
+                    sub_mat
+                };
+
+                let dry_run = params.bool("dryRun")?;
+                let reprocess = params.bool("reprocess")?;
                 // NOTE: The processor always works on batches
                 let batch = EthSubmissionMaterials::new(vec![sub_mat]);
 
                 let network_config = config.networks().get(&network_id).unwrap();
 
                 let submit_args = WebSocketMessagesProcessBatchArgs::new(
-                    config.validate(&network_id)?,
+                    validate,
                     dry_run,
                     reprocess,
                     network_config.clone(),
@@ -0,0 +1,30 @@
+use common_sentinel::{call_core, SentinelConfig, SentinelError, WebSocketMessagesEncodable};
+
+use crate::{
+    rpc_server::{RpcCalls, RpcParams, STRONGBOX_TIMEOUT},
+    type_aliases::WebSocketTx,
+};
+
+// NOTE: Registers the EVM chain configured for the given network in the core, which doesn't load the
+// config and so otherwise knows nothing of it. Params are the network id, followed by any number of
+// debug signatures, which commit to the chain's config.
+impl RpcCalls {
+    pub(crate) async fn handle_register_evm_chain(
+        config: SentinelConfig,
+        params: RpcParams,
+        websocket_tx: WebSocketTx,
+        core_cxn: bool,
+    ) -> Result<WebSocketMessagesEncodable, SentinelError> {
+        Self::check_core_is_connected(core_cxn)?;
+
+        let network_id = params.network_id("networkId")?;
+        let evm_chain = config
+            .evm_chain(&network_id)?
+            .ok_or_else(|| SentinelError::Custom(format!("no evm chain configured for network {network_id}")))?;
+        let sig = params.debug_signature("signatures")?;
+
+        let msg = WebSocketMessagesEncodable::RegisterEvmChain(Box::new(evm_chain), sig);
+
+        call_core(STRONGBOX_TIMEOUT, websocket_tx.clone(), msg).await
+    }
+}
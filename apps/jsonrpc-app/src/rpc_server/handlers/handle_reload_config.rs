@@ -0,0 +1,67 @@
+use common_sentinel::{
+    BroadcastChannelMessages,
+    EthRpcBroadcastChannelMessages,
+    RpcServerBroadcastChannelMessages,
+    SentinelConfig,
+    SentinelError,
+    SyncerBroadcastChannelMessages,
+};
+use serde_json::{json, Value as Json};
+
+use crate::{
+    rpc_server::{RpcCalls, RpcParams},
+    type_aliases::BroadcastChannelTx,
+};
+
+impl RpcCalls {
+    // NOTE: Reloads the config from the passed in path, else from wherever it was originally loaded
+    // from, and pushes any changes which can be hot swapped to the components they affect. Changes
+    // which cannot be hot swapped are not applied, but are reported so the operator knows a restart
+    // is required for them.
+    pub(crate) async fn handle_reload_config(
+        config: SentinelConfig,
+        params: RpcParams,
+        broadcast_channel_tx: BroadcastChannelTx,
+    ) -> Result<Json, SentinelError> {
+        let path = params
+            .optional_string("path")?
+            .unwrap_or_else(|| config.path().to_string());
+        debug!("reloading config from path '{path}'...");
+
+        let new_config = SentinelConfig::new(&path)?;
+        let (updated_config, diff) = config.hot_swap(&new_config);
+        let updated_config = Box::new(updated_config);
+
+        let mut msgs = vec![];
+        for network_id in diff.changed_networks().iter().cloned() {
+            msgs.push(BroadcastChannelMessages::EthRpc(
+                network_id,
+                EthRpcBroadcastChannelMessages::UpdateConfig(updated_config.clone()),
+            ));
+            msgs.push(BroadcastChannelMessages::Syncer(
+                network_id,
+                SyncerBroadcastChannelMessages::UpdateConfig(updated_config.clone()),
+            ));
+        }
+
+        // NOTE: The rpc server holds the entire config, so it needs any applied changes.
+        if !diff.changed_networks().is_empty() || *diff.rpc_auth_changed() {
+            msgs.push(BroadcastChannelMessages::RpcServer(
+                RpcServerBroadcastChannelMessages::UpdateConfig(updated_config),
+            ));
+        };
+
+        for msg in msgs {
+            broadcast_channel_tx.send(msg)?;
+        }
+
+        if !diff.requires_restart().is_empty() {
+            warn!(
+                "config changes requiring a restart were not applied: {:?}",
+                diff.requires_restart()
+            );
+        };
+
+        Ok(json!({ "path": path, "diff": diff }))
+    }
+}
@@ -1,4 +1,3 @@
-use common_debug_signers::DebugSignature;
 use common_sentinel::{call_core, SentinelError, WebSocketMessagesEncodable};
 
 use crate::{
@@ -13,16 +12,13 @@ impl RpcCalls {
         core_cxn: bool,
     ) -> Result<WebSocketMessagesEncodable, SentinelError> {
         Self::check_core_is_connected(core_cxn)?;
-        let checked_params = Self::check_params(params, 1)?;
 
         // NOTE: We expect the signer to be the eth address in hex format
-        let signer = checked_params[0].clone();
+        let signer = params.eth_address_string("signer")?;
 
-        let sig = if checked_params.len() > 1 {
-            DebugSignature::new(checked_params.get(1).cloned())
-        } else {
-            DebugSignature::new(None)
-        };
+        // NOTE: Followed by any number of debug signatures, as required by the core's debug signature
+        // policies.
+        let sig = params.debug_signature("signatures")?;
 
         let msg = WebSocketMessagesEncodable::RemoveDebugSigner(signer, sig);
 
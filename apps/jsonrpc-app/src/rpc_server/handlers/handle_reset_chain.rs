@@ -23,7 +23,14 @@ impl RpcCalls {
         core_cxn: bool,
     ) -> Result<WebSocketMessagesEncodable, SentinelError> {
         Self::check_core_is_connected(core_cxn)?;
-        let mut args = WebSocketMessagesResetChainArgs::try_from(params)?;
+        let mut args = WebSocketMessagesResetChainArgs::new(
+            params.network_id("networkId")?,
+            params.block_num("blockNum")?,
+            params.u64("confirmations")?,
+            params.bool("validate")?,
+            params.optional_eth_address("hub")?,
+            params.debug_signature("signatures")?,
+        );
         let network_id = *args.network_id();
 
         match eth_rpc_senders.sender(&network_id) {
@@ -45,6 +52,15 @@ impl RpcCalls {
                 let (eth_rpc_msg, responder) = EthRpcMessages::get_sub_mat_msg(network_id, block_num);
                 sender.send(eth_rpc_msg).await?;
                 let sub_mat = responder.await??;
+
+                // NOTE: The raw txs are only needed in order for the core to validate the receipts.
+                let sub_mat = if *args.validate() && !sub_mat.receipts.is_empty() {
+                    let (eth_rpc_msg, responder) = EthRpcMessages::get_raw_txs_msg(network_id, block_num);
+                    sender.send(eth_rpc_msg).await?;
+                    sub_mat.add_raw_transactions(responder.await??)?
+                } else {
+                    sub_mat
+                };
                 args.add_sub_mat(sub_mat);
                 let msg = WebSocketMessagesEncodable::ResetChain(Box::new(args));
                 call_core(STRONGBOX_TIMEOUT, websocket_tx.clone(), msg).await
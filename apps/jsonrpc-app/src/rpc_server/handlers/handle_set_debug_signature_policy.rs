@@ -0,0 +1,29 @@
+use common_sentinel::{call_core, SentinelError, WebSocketMessagesEncodable, WebSocketMessagesError};
+
+use crate::{
+    rpc_server::{RpcCalls, RpcParams, STRONGBOX_TIMEOUT},
+    type_aliases::WebSocketTx,
+};
+
+// NOTE: Sets the number of distinct debug signatories required to run the given debug command. The
+// command is named as it is in the core, eg `hard_reset` or `put`. Params are that command name, the
+// threshold, and then any number of debug signatures.
+impl RpcCalls {
+    pub(crate) async fn handle_set_debug_signature_policy(
+        params: RpcParams,
+        websocket_tx: WebSocketTx,
+        core_cxn: bool,
+    ) -> Result<WebSocketMessagesEncodable, SentinelError> {
+        Self::check_core_is_connected(core_cxn)?;
+
+        let debug_command = params.string("command")?;
+        let threshold = params.u64("threshold")?;
+        let threshold =
+            usize::try_from(threshold).map_err(|_| WebSocketMessagesError::ParseInt(threshold.to_string()))?;
+        let sig = params.debug_signature("signatures")?;
+
+        let msg = WebSocketMessagesEncodable::SetDebugSignaturePolicy(debug_command, threshold, sig);
+
+        call_core(STRONGBOX_TIMEOUT, websocket_tx.clone(), msg).await
+    }
+}
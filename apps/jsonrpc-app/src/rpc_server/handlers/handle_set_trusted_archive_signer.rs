@@ -0,0 +1,25 @@
+use common_sentinel::{call_core, SentinelError, WebSocketMessagesEncodable};
+
+use crate::{
+    rpc_server::{RpcCalls, RpcParams, STRONGBOX_TIMEOUT},
+    type_aliases::WebSocketTx,
+};
+
+// NOTE: Sets the address of the core whose state archives this core may import, ie that of the core
+// which will export them. Params are that address, followed by any number of debug signatures.
+impl RpcCalls {
+    pub(crate) async fn handle_set_trusted_archive_signer(
+        params: RpcParams,
+        websocket_tx: WebSocketTx,
+        core_cxn: bool,
+    ) -> Result<WebSocketMessagesEncodable, SentinelError> {
+        Self::check_core_is_connected(core_cxn)?;
+
+        let signer = params.eth_address("signer")?;
+        let sig = params.debug_signature("signatures")?;
+
+        let msg = WebSocketMessagesEncodable::SetTrustedArchiveSigner(signer, sig);
+
+        call_core(STRONGBOX_TIMEOUT, websocket_tx.clone(), msg).await
+    }
+}
@@ -1,6 +1,5 @@
 use std::{process::Command, str::from_utf8};
 
-use common::strip_hex_prefix;
 use common_eth::{EthPrivateKey, EthSigningCapabilities};
 use common_sentinel::SentinelError;
 use serde_json::{json, Value as Json};
@@ -36,10 +35,9 @@ impl RpcCalls {
     /// in path. NOTE: This function makes a linux command call, thus only works on linux.
     pub(crate) async fn handle_sign_message(params: RpcParams) -> Result<Json, SentinelError> {
         debug!("handling sign message...");
-        let checked_params = Self::check_params(params, 2)?;
-        let pk = EthPrivateKey::from_slice(&decode_hex(&decrypt_pk(&checked_params[0])?)?)?;
+        let pk = EthPrivateKey::from_slice(&decode_hex(&decrypt_pk(&params.string("encryptedKey")?)?)?)?;
         let signer = format!("0x{}", hex::encode(pk.to_address().as_bytes()));
-        let msg = decode_hex(&strip_hex_prefix(&checked_params[1]))?;
+        let msg = params.hex("message")?;
         let signature = pk.hash_and_sign_msg_with_eth_prefix(&msg)?;
         Ok(json!({
             "signer": signer,
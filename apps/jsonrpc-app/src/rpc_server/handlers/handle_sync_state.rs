@@ -11,6 +11,8 @@ use common_sentinel::{
 use serde_json::json;
 
 use crate::{
+    health::HEALTH,
+    metrics::METRICS,
     rpc_server::{RpcCalls, STRONGBOX_TIMEOUT},
     type_aliases::WebSocketTx,
 };
@@ -52,6 +54,8 @@ impl RpcCalls {
         }
 
         let state = SyncState::from((network_ids, core_latest_block_numbers, rpc_latest_block_nums));
+        METRICS.set_sync_state(&state);
+        HEALTH.set_sync_state(&state);
 
         Ok(WebSocketMessagesEncodable::Success(json!(state)))
     }
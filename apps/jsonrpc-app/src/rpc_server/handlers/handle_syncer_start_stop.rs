@@ -1,4 +1,3 @@
-use common_network_ids::{NetworkId, NetworkIdError};
 use common_sentinel::{BroadcastChannelMessages, SentinelError, SyncerBroadcastChannelMessages};
 use serde_json::{json, Value as Json};
 
@@ -18,14 +17,7 @@ impl RpcCalls {
         debug!("handling {m} syncer rpc call...");
         Self::check_core_is_connected(core_cxn)?;
 
-        if params.is_empty() {
-            return Err(SentinelError::Custom("please provide 1 or more chain ids".to_string()));
-        }
-
-        let network_ids = params
-            .iter()
-            .map(NetworkId::try_from)
-            .collect::<Result<Vec<NetworkId>, NetworkIdError>>()?;
+        let network_ids = params.network_ids("networkIds")?;
 
         let jsons = network_ids
             .iter()
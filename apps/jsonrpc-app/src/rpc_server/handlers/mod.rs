@@ -1,20 +1,31 @@
 mod handle_add_debug_signers;
+mod handle_add_remove_network;
+mod handle_create_key;
 mod handle_db_ops;
+mod handle_debug_command_queue;
+mod handle_export_core_state;
 mod handle_get_address;
 mod handle_get_attestation_certificate;
 mod handle_get_attestation_signature;
+mod handle_get_audit_log;
 mod handle_get_balances;
 mod handle_get_core_state;
+mod handle_get_pending_txs;
 mod handle_get_public_key;
 mod handle_get_registration_extension_tx;
 mod handle_get_registration_signature;
 mod handle_get_status;
 mod handle_hard_reset;
+mod handle_import_core_state;
 mod handle_init;
 mod handle_latest_block_infos;
 mod handle_process_block;
+mod handle_register_evm_chain;
+mod handle_reload_config;
 mod handle_remove_debug_signer;
 mod handle_reset_chain;
+mod handle_set_debug_signature_policy;
+mod handle_set_trusted_archive_signer;
 mod handle_sign_message;
 mod handle_sync_state;
 mod handle_syncer_start_stop;
@@ -1,22 +1,21 @@
 use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
+use serde_json::Value as Json;
 
-use super::type_aliases::{RpcId, RpcParams};
+use super::type_aliases::RpcId;
 
+// NOTE: Params may be either an array of positional params or an object of named params, and are
+// converted into `RpcParams` via the method's schema. See `rpc_schemas.rs`.
 #[derive(Debug, Serialize, Deserialize, Getters)]
 pub struct JsonRpcRequest {
     id: RpcId,
     #[getter(skip)]
     method: String,
-    #[getter(skip)]
-    params: RpcParams,
+    #[serde(default)]
+    params: Json,
 }
 
 impl JsonRpcRequest {
-    pub(super) fn params(&self) -> RpcParams {
-        self.params.clone()
-    }
-
     pub(super) fn method(&self) -> String {
         self.method.clone()
     }
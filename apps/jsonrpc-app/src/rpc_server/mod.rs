@@ -1,7 +1,11 @@
+mod auth;
+mod client_cert_tls;
 mod constants;
 mod handlers;
 mod json_rpc_request;
 mod rpc_calls;
+mod rpc_params;
+mod rpc_schemas;
 mod rpc_server_loop;
 mod type_aliases;
 
@@ -9,6 +13,7 @@ pub(crate) use self::rpc_server_loop::rpc_server_loop;
 use self::{
     constants::STRONGBOX_TIMEOUT,
     json_rpc_request::JsonRpcRequest,
-    rpc_calls::RpcCalls,
-    type_aliases::RpcParams,
+    rpc_calls::{create_json_rpc_error, RpcCalls},
+    rpc_params::RpcParams,
+    rpc_schemas::RpcMethodSchema,
 };
@@ -1,20 +1,18 @@
 use common_sentinel::{
-    Env,
     EthRpcSenders,
+    JsonRpcError,
+    JsonRpcErrorCode,
+    RpcRole,
     SentinelConfig,
     SentinelError,
     WebSocketMessagesEncodable,
-    WebSocketMessagesError,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value as Json};
-use warp::{reject::Reject, Rejection};
+use warp::reject::Reject;
 
-use super::{
-    type_aliases::{RpcId, RpcParams},
-    JsonRpcRequest,
-};
-use crate::type_aliases::{BroadcastChannelTx, CoreCxnStatus, WebSocketTx};
+use super::{type_aliases::RpcId, JsonRpcRequest, RpcMethodSchema, RpcParams};
+use crate::type_aliases::{BroadcastChannelTx, CoreCxnStatus, NetworkManagerTx, WebSocketTx};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct Error(String);
@@ -25,39 +23,47 @@ fn create_json_rpc_response<T: Serialize>(id: RpcId, t: T) -> Json {
     json!({ "id": id, "result": t, "jsonrpc": "2.0" })
 }
 
-fn create_json_rpc_error(id: RpcId, code: u64, msg: &str) -> Json {
-    json!({ "id": id, "error": { "code": code, "message": msg, }, "jsonrpc": "2.0" })
-}
-
-fn create_json_rpc_error_with_json<T: Serialize>(id: RpcId, code: u64, msg: &T) -> Json {
-    json!({ "id": id, "error": { "code": code, "message": msg, }, "jsonrpc": "2.0" })
+pub(super) fn create_json_rpc_error(id: RpcId, e: JsonRpcError) -> Json {
+    json!({ "id": id, "error": e, "jsonrpc": "2.0" })
 }
 
-// FIXME make a type for error code
-fn create_json_rpc_response_from_result<T: Serialize>(id: RpcId, r: Result<T, SentinelError>, error_code: u64) -> Json {
+fn create_json_rpc_response_from_result<T: Serialize>(id: RpcId, r: Result<T, SentinelError>) -> Json {
     match r {
         Ok(r) => create_json_rpc_response(id, r),
-        Err(SentinelError::Json(ref j)) => create_json_rpc_error_with_json(id, error_code, j),
-        Err(e) => create_json_rpc_error(id, error_code, &e.to_string()),
+        Err(ref e) => create_json_rpc_error(id, e.into()),
     }
 }
 
 pub(crate) enum RpcCalls {
     Ping(RpcId),
+    Discover(RpcId),
     Unknown(RpcId, String),
+    InvalidParams(RpcId, Box<JsonRpcError>),
     SignMessage(RpcId, RpcParams),
     GetAddress(RpcId, WebSocketTx, CoreCxnStatus),
     GetPublicKey(RpcId, WebSocketTx, CoreCxnStatus),
+    CreateKey(RpcId, RpcParams, WebSocketTx, CoreCxnStatus),
+    GetPendingTxs(RpcId, WebSocketTx, CoreCxnStatus),
     Get(RpcId, WebSocketTx, RpcParams, CoreCxnStatus),
     Put(RpcId, WebSocketTx, RpcParams, CoreCxnStatus),
     Delete(RpcId, WebSocketTx, RpcParams, CoreCxnStatus),
     GetStatus(RpcId, WebSocketTx, RpcParams, CoreCxnStatus),
     HardReset(RpcId, RpcParams, WebSocketTx, CoreCxnStatus),
     GetCoreState(RpcId, RpcParams, WebSocketTx, CoreCxnStatus),
+    ExportCoreState(RpcId, RpcParams, WebSocketTx, CoreCxnStatus),
+    ImportCoreState(RpcId, RpcParams, WebSocketTx, CoreCxnStatus),
     GetAttestionCertificate(RpcId, WebSocketTx, CoreCxnStatus),
     AddDebugSigners(RpcId, RpcParams, WebSocketTx, CoreCxnStatus),
     StopSyncer(RpcId, BroadcastChannelTx, RpcParams, CoreCxnStatus),
     RemoveDebugSigner(RpcId, RpcParams, WebSocketTx, CoreCxnStatus),
+    RegisterEvmChain(RpcId, Box<SentinelConfig>, RpcParams, WebSocketTx, CoreCxnStatus),
+    SetDebugSignaturePolicy(RpcId, RpcParams, WebSocketTx, CoreCxnStatus),
+    SetTrustedArchiveSigner(RpcId, RpcParams, WebSocketTx, CoreCxnStatus),
+    SetDebugCommandDelay(RpcId, RpcParams, WebSocketTx, CoreCxnStatus),
+    CancelQueued(RpcId, RpcParams, WebSocketTx, CoreCxnStatus),
+    ExecuteQueued(RpcId, RpcParams, WebSocketTx, CoreCxnStatus),
+    GetAuditLog(RpcId, RpcParams, WebSocketTx, CoreCxnStatus),
+    ReloadConfig(RpcId, Box<SentinelConfig>, RpcParams, BroadcastChannelTx),
     StartSyncer(RpcId, BroadcastChannelTx, RpcParams, CoreCxnStatus),
     GetBalances(RpcId, Box<SentinelConfig>, RpcParams, EthRpcSenders),
     GetAttestionSignature(RpcId, RpcParams, WebSocketTx, CoreCxnStatus),
@@ -82,6 +88,23 @@ pub(crate) enum RpcCalls {
         CoreCxnStatus,
     ),
     ProcessBlock(RpcId, Box<SentinelConfig>, EthRpcSenders, WebSocketTx, RpcParams, bool),
+    AddNetwork(
+        RpcId,
+        Box<SentinelConfig>,
+        EthRpcSenders,
+        WebSocketTx,
+        NetworkManagerTx,
+        BroadcastChannelTx,
+        RpcParams,
+        CoreCxnStatus,
+    ),
+    RemoveNetwork(
+        RpcId,
+        Box<SentinelConfig>,
+        NetworkManagerTx,
+        BroadcastChannelTx,
+        RpcParams,
+    ),
 }
 
 impl RpcCalls {
@@ -90,64 +113,74 @@ impl RpcCalls {
         config: SentinelConfig,
         websocket_tx: WebSocketTx,
         eth_rpc_senders: EthRpcSenders,
+        network_manager_tx: NetworkManagerTx,
         broadcast_channel_tx: BroadcastChannelTx,
         core_cxn: bool,
     ) -> Self {
-        match r.method().as_ref() {
-            "ping" => Self::Ping(*r.id()),
-            "get" => Self::Get(*r.id(), websocket_tx, r.params(), core_cxn),
-            "put" => Self::Put(*r.id(), websocket_tx, r.params(), core_cxn),
-            "signMessage" | "sign" => Self::SignMessage(*r.id(), r.params()),
-            "delete" => Self::Delete(*r.id(), websocket_tx, r.params(), core_cxn),
-            "hardReset" => Self::HardReset(*r.id(), r.params(), websocket_tx.clone(), core_cxn),
-            "stopSyncer" => Self::StopSyncer(*r.id(), broadcast_channel_tx, r.params(), core_cxn),
-            "getStatus" | "status" => Self::GetStatus(*r.id(), websocket_tx, r.params(), core_cxn),
-            "startSyncer" => Self::StartSyncer(*r.id(), broadcast_channel_tx, r.params(), core_cxn),
-            "getBalances" => Self::GetBalances(*r.id(), Box::new(config), r.params(), eth_rpc_senders),
-            "removeDebugSigner" => Self::RemoveDebugSigner(*r.id(), r.params(), websocket_tx, core_cxn),
-            "getAttestationCertificate" => Self::GetAttestionCertificate(*r.id(), websocket_tx, core_cxn),
-            "getAttestationSignature" => Self::GetAttestionSignature(*r.id(), r.params(), websocket_tx, core_cxn),
-            "getPublicKey" => Self::GetPublicKey(*r.id(), websocket_tx, core_cxn),
-            "getAddress" => Self::GetAddress(*r.id(), websocket_tx, core_cxn),
-            "addDebugSigners" | "addDebugSigner" => Self::AddDebugSigners(*r.id(), r.params(), websocket_tx, core_cxn),
+        let id = *r.id();
+        let schema = match RpcMethodSchema::get(&r.method()) {
+            Some(s) => s,
+            None => return Self::Unknown(id, r.method()),
+        };
+        let params = match schema.to_rpc_params(r.params()) {
+            Ok(p) => p,
+            Err(e) => return Self::InvalidParams(id, Box::new(e)),
+        };
+
+        match schema.name() {
+            "rpc.discover" => Self::Discover(id),
+            "ping" => Self::Ping(id),
+            "get" => Self::Get(id, websocket_tx, params, core_cxn),
+            "put" => Self::Put(id, websocket_tx, params, core_cxn),
+            "signMessage" => Self::SignMessage(id, params),
+            "delete" => Self::Delete(id, websocket_tx, params, core_cxn),
+            "hardReset" => Self::HardReset(id, params, websocket_tx.clone(), core_cxn),
+            "stopSyncer" => Self::StopSyncer(id, broadcast_channel_tx, params, core_cxn),
+            "getStatus" => Self::GetStatus(id, websocket_tx, params, core_cxn),
+            "startSyncer" => Self::StartSyncer(id, broadcast_channel_tx, params, core_cxn),
+            "getBalances" => Self::GetBalances(id, Box::new(config), params, eth_rpc_senders),
+            "removeDebugSigner" => Self::RemoveDebugSigner(id, params, websocket_tx, core_cxn),
+            "registerEvmChain" => Self::RegisterEvmChain(id, Box::new(config), params, websocket_tx, core_cxn),
+            "setDebugSignaturePolicy" => Self::SetDebugSignaturePolicy(id, params, websocket_tx, core_cxn),
+            "setTrustedArchiveSigner" => Self::SetTrustedArchiveSigner(id, params, websocket_tx, core_cxn),
+            "setDebugCommandDelay" => Self::SetDebugCommandDelay(id, params, websocket_tx, core_cxn),
+            "cancelQueued" => Self::CancelQueued(id, params, websocket_tx, core_cxn),
+            "executeQueued" => Self::ExecuteQueued(id, params, websocket_tx, core_cxn),
+            "getAuditLog" => Self::GetAuditLog(id, params, websocket_tx, core_cxn),
+            "reloadConfig" => Self::ReloadConfig(id, Box::new(config), params, broadcast_channel_tx),
+            "getAttestationCertificate" => Self::GetAttestionCertificate(id, websocket_tx, core_cxn),
+            "getAttestationSignature" => Self::GetAttestionSignature(id, params, websocket_tx, core_cxn),
+            "getPublicKey" => Self::GetPublicKey(id, websocket_tx, core_cxn),
+            "createKey" => Self::CreateKey(id, params, websocket_tx, core_cxn),
+            "getPendingTxs" => Self::GetPendingTxs(id, websocket_tx, core_cxn),
+            "getAddress" => Self::GetAddress(id, websocket_tx, core_cxn),
+            "addDebugSigners" => Self::AddDebugSigners(id, params, websocket_tx, core_cxn),
             "getRegistrationExtensionTx" => {
-                Self::GetRegistrationExtensionTx(*r.id(), Box::new(config.clone()), r.params(), eth_rpc_senders.clone())
+                Self::GetRegistrationExtensionTx(id, Box::new(config.clone()), params, eth_rpc_senders.clone())
             },
-            "getRegistrationSignature" | "getRegSig" => {
-                Self::GetRegistrationSignature(*r.id(), websocket_tx, r.params(), core_cxn)
-            },
-            "getLatestBlockInfos" | "latest" => {
-                Self::LatestBlockInfos(*r.id(), Box::new(config.clone()), websocket_tx, core_cxn)
-            },
-            "getCoreState" | "getEnclaveState" | "state" => {
-                Self::GetCoreState(*r.id(), r.params(), websocket_tx, core_cxn)
-            },
-            "getSyncState" => Self::GetSyncState(*r.id(), Box::new(config), websocket_tx, eth_rpc_senders, core_cxn),
-            "reset" | "resetChain" => Self::ResetChain(
-                *r.id(),
-                Box::new(config),
-                eth_rpc_senders,
-                websocket_tx,
-                r.params(),
-                core_cxn,
-            ),
-            "init" => Self::Init(
-                *r.id(),
-                Box::new(config),
-                eth_rpc_senders,
-                websocket_tx,
-                r.params(),
-                core_cxn,
-            ),
-            "processBlock" | "process" | "submitBlock" | "submit" => Self::ProcessBlock(
-                *r.id(),
+            "getRegistrationSignature" => Self::GetRegistrationSignature(id, websocket_tx, params, core_cxn),
+            "getLatestBlockInfos" => Self::LatestBlockInfos(id, Box::new(config.clone()), websocket_tx, core_cxn),
+            "getCoreState" => Self::GetCoreState(id, params, websocket_tx, core_cxn),
+            "exportCoreState" => Self::ExportCoreState(id, params, websocket_tx, core_cxn),
+            "importCoreState" => Self::ImportCoreState(id, params, websocket_tx, core_cxn),
+            "getSyncState" => Self::GetSyncState(id, Box::new(config), websocket_tx, eth_rpc_senders, core_cxn),
+            "resetChain" => Self::ResetChain(id, Box::new(config), eth_rpc_senders, websocket_tx, params, core_cxn),
+            "init" => Self::Init(id, Box::new(config), eth_rpc_senders, websocket_tx, params, core_cxn),
+            "processBlock" => Self::ProcessBlock(id, Box::new(config), eth_rpc_senders, websocket_tx, params, core_cxn),
+            "addNetwork" => Self::AddNetwork(
+                id,
                 Box::new(config),
                 eth_rpc_senders,
                 websocket_tx,
-                r.params(),
+                network_manager_tx,
+                broadcast_channel_tx,
+                params,
                 core_cxn,
             ),
-            _ => Self::Unknown(*r.id(), r.method()),
+            "removeNetwork" => {
+                Self::RemoveNetwork(id, Box::new(config), network_manager_tx, broadcast_channel_tx, params)
+            },
+            _ => Self::Unknown(id, r.method()),
         }
     }
 
@@ -159,26 +192,54 @@ impl RpcCalls {
         }
     }
 
-    pub(crate) fn create_args(cmd: &str, params: RpcParams) -> RpcParams {
-        [vec![cmd.to_string()], params].concat()
-    }
-
-    pub(crate) fn check_params(
-        params: RpcParams,
-        min_required_num_params: usize,
-    ) -> Result<RpcParams, WebSocketMessagesError> {
-        if params.len() < min_required_num_params {
-            Err(WebSocketMessagesError::NotEnoughArgs {
-                got: params.len(),
-                expected: min_required_num_params,
-                args: params,
-            })
-        } else {
-            Ok(params)
+    // NOTE: Anything able to alter the state of the core, or to sign with its keys, is admin only.
+    pub(super) fn required_role(&self) -> RpcRole {
+        match self {
+            Self::Ping(..)
+            | Self::Unknown(..)
+            | Self::Discover(..)
+            | Self::InvalidParams(..)
+            | Self::GetAddress(..)
+            | Self::GetStatus(..)
+            | Self::GetAuditLog(..)
+            | Self::GetBalances(..)
+            | Self::GetPublicKey(..)
+            | Self::GetCoreState(..)
+            | Self::GetPendingTxs(..)
+            | Self::GetSyncState(..)
+            | Self::LatestBlockInfos(..)
+            | Self::GetAttestionCertificate(..) => RpcRole::ReadOnly,
+            Self::Get(..)
+            | Self::StopSyncer(..)
+            | Self::StartSyncer(..)
+            | Self::ProcessBlock(..)
+            | Self::GetAttestionSignature(..) => RpcRole::Operator,
+            Self::Put(..)
+            | Self::Init(..)
+            | Self::Delete(..)
+            | Self::HardReset(..)
+            | Self::ResetChain(..)
+            | Self::SignMessage(..)
+            | Self::CreateKey(..)
+            | Self::CancelQueued(..)
+            | Self::ExecuteQueued(..)
+            | Self::ExportCoreState(..)
+            | Self::ImportCoreState(..)
+            | Self::AddNetwork(..)
+            | Self::ReloadConfig(..)
+            | Self::RemoveNetwork(..)
+            | Self::AddDebugSigners(..)
+            | Self::RemoveDebugSigner(..)
+            | Self::RegisterEvmChain(..)
+            | Self::SetDebugCommandDelay(..)
+            | Self::SetDebugSignaturePolicy(..)
+            | Self::SetTrustedArchiveSigner(..)
+            | Self::GetRegistrationSignature(..)
+            | Self::GetRegistrationExtensionTx(..) => RpcRole::Admin,
         }
     }
 
-    pub(super) async fn handle(self) -> Result<impl warp::Reply, Rejection> {
+    pub(super) async fn handle(self) -> Json {
         match self {
             Self::GetSyncState(id, config, websocket_tx, eth_rpc_senders, core_cxn) => Self::handle_ws_result(
                 id,
@@ -191,6 +252,31 @@ impl RpcCalls {
                 id,
                 Self::handle_remove_debug_signer(params, websocket_tx, core_cxn).await,
             ),
+            Self::RegisterEvmChain(id, config, params, websocket_tx, core_cxn) => Self::handle_ws_result(
+                id,
+                Self::handle_register_evm_chain(*config, params, websocket_tx, core_cxn).await,
+            ),
+            Self::SetDebugSignaturePolicy(id, params, websocket_tx, core_cxn) => Self::handle_ws_result(
+                id,
+                Self::handle_set_debug_signature_policy(params, websocket_tx, core_cxn).await,
+            ),
+            Self::SetTrustedArchiveSigner(id, params, websocket_tx, core_cxn) => Self::handle_ws_result(
+                id,
+                Self::handle_set_trusted_archive_signer(params, websocket_tx, core_cxn).await,
+            ),
+            Self::SetDebugCommandDelay(id, params, websocket_tx, core_cxn) => Self::handle_ws_result(
+                id,
+                Self::handle_set_debug_command_delay(params, websocket_tx, core_cxn).await,
+            ),
+            Self::CancelQueued(id, params, websocket_tx, core_cxn) => {
+                Self::handle_ws_result(id, Self::handle_cancel_queued(params, websocket_tx, core_cxn).await)
+            },
+            Self::ExecuteQueued(id, params, websocket_tx, core_cxn) => {
+                Self::handle_ws_result(id, Self::handle_execute_queued(params, websocket_tx, core_cxn).await)
+            },
+            Self::GetAuditLog(id, params, websocket_tx, core_cxn) => {
+                Self::handle_ws_result(id, Self::handle_get_audit_log(params, websocket_tx, core_cxn).await)
+            },
             Self::HardReset(id, params, websocket_tx, core_cxn) => {
                 Self::handle_ws_result(id, Self::handle_hard_reset(params, websocket_tx, core_cxn).await)
             },
@@ -199,23 +285,22 @@ impl RpcCalls {
                 Self::handle_get_registration_signature(websocket_tx, params, core_cxn).await,
             ),
             Self::GetRegistrationExtensionTx(id, config, params, eth_rpc_senders) => {
-                let err_msg = "could not get private key from environment!";
-                if let Err(e) = Env::init() {
-                    error!("{e}");
-                    return Ok(warp::reply::json(&create_json_rpc_error(id, 1337, err_msg)));
-                };
+                let err = JsonRpcError::new(
+                    JsonRpcErrorCode::Env,
+                    "could not get private key from secrets or environment!",
+                    None,
+                );
 
-                let pk = match Env::get_private_key() {
+                let pk = match config.private_key() {
                     Ok(k) => k,
                     Err(e) => {
                         error!("{e}");
-                        return Ok(warp::reply::json(&create_json_rpc_error(id, 1337, err_msg)));
+                        return create_json_rpc_error(id, err);
                     },
                 };
 
                 let result = Self::handle_get_registration_extension_tx(*config, params, pk, eth_rpc_senders).await;
-                let json = create_json_rpc_response_from_result(id, result, 1337);
-                Ok(warp::reply::json(&json))
+                create_json_rpc_response_from_result(id, result)
             },
             Self::Get(id, websocket_tx, params, core_cxn) => {
                 Self::handle_ws_result(id, Self::handle_get(websocket_tx, params, core_cxn).await)
@@ -225,8 +310,7 @@ impl RpcCalls {
             },
             Self::SignMessage(id, params) => {
                 let result = Self::handle_sign_message(params).await;
-                let json = create_json_rpc_response_from_result(id, result, 1337);
-                Ok(warp::reply::json(&json))
+                create_json_rpc_response_from_result(id, result)
             },
             Self::Put(id, websocket_tx, params, core_cxn) => {
                 Self::handle_ws_result(id, Self::handle_put(websocket_tx, params, core_cxn).await)
@@ -238,21 +322,49 @@ impl RpcCalls {
                 id,
                 Self::handle_reset_chain(*config, eth_rpc_senders, websocket_tx, params, core_cxn).await,
             ),
+            Self::ReloadConfig(id, config, params, broadcast_channel_tx) => {
+                let result = Self::handle_reload_config(*config, params, broadcast_channel_tx).await;
+                create_json_rpc_response_from_result(id, result)
+            },
+            Self::AddNetwork(
+                id,
+                config,
+                eth_rpc_senders,
+                websocket_tx,
+                network_manager_tx,
+                broadcast_channel_tx,
+                params,
+                core_cxn,
+            ) => {
+                let result = Self::handle_add_network(
+                    *config,
+                    eth_rpc_senders,
+                    websocket_tx,
+                    network_manager_tx,
+                    broadcast_channel_tx,
+                    params,
+                    core_cxn,
+                )
+                .await;
+                create_json_rpc_response_from_result(id, result)
+            },
+            Self::RemoveNetwork(id, config, network_manager_tx, broadcast_channel_tx, params) => {
+                let result =
+                    Self::handle_remove_network(*config, network_manager_tx, broadcast_channel_tx, params).await;
+                create_json_rpc_response_from_result(id, result)
+            },
             Self::StopSyncer(id, broadcast_channel_tx, params, core_cxn) => {
                 let result = Self::handle_syncer_start_stop(broadcast_channel_tx, params, true, core_cxn).await;
-                let json = create_json_rpc_response_from_result(id, result, 1337);
-                Ok(warp::reply::json(&json))
+                create_json_rpc_response_from_result(id, result)
             },
             Self::GetBalances(id, config, params, eth_rpc_senders) => {
                 let result = Self::handle_get_balances(*config, params, eth_rpc_senders).await;
-                let json = create_json_rpc_response_from_result(id, result, 1337);
-                Ok(warp::reply::json(&json))
+                create_json_rpc_response_from_result(id, result)
             },
             Self::StartSyncer(id, broadcast_channel_tx, params, core_cxn) => {
                 // TODO enum for syncer state
                 let result = Self::handle_syncer_start_stop(broadcast_channel_tx, params, false, core_cxn).await;
-                let json = create_json_rpc_response_from_result(id, result, 1337);
-                Ok(warp::reply::json(&json))
+                create_json_rpc_response_from_result(id, result)
             },
             Self::LatestBlockInfos(id, config, websocket_tx, core_cxn) => Self::handle_ws_result(
                 id,
@@ -262,7 +374,9 @@ impl RpcCalls {
                 id,
                 Self::handle_process_block(*config, eth_rpc_senders, websocket_tx, params, core_cxn).await,
             ),
-            Self::Ping(id) => Ok(warp::reply::json(&create_json_rpc_response(id, "pong"))),
+            Self::Ping(id) => create_json_rpc_response(id, "pong"),
+            Self::Discover(id) => create_json_rpc_response(id, RpcMethodSchema::open_rpc_document()),
+            Self::InvalidParams(id, e) => create_json_rpc_error(id, *e),
             Self::Init(id, config, eth_rpc_senders, websocket_tx, params, core_cxn) => Self::handle_ws_result(
                 id,
                 Self::handle_init(*config, websocket_tx, eth_rpc_senders, params, core_cxn).await,
@@ -270,6 +384,12 @@ impl RpcCalls {
             Self::GetCoreState(id, params, websocket_tx, core_cxn) => {
                 Self::handle_ws_result(id, Self::handle_get_core_state(params, websocket_tx, core_cxn).await)
             },
+            Self::ExportCoreState(id, params, websocket_tx, core_cxn) => {
+                Self::handle_ws_result(id, Self::handle_export_core_state(params, websocket_tx, core_cxn).await)
+            },
+            Self::ImportCoreState(id, params, websocket_tx, core_cxn) => {
+                Self::handle_ws_result(id, Self::handle_import_core_state(params, websocket_tx, core_cxn).await)
+            },
             Self::GetAttestionCertificate(id, websocket_tx, core_cxn) => Self::handle_ws_result(
                 id,
                 Self::handle_get_attestation_certificate(websocket_tx, core_cxn).await,
@@ -281,47 +401,28 @@ impl RpcCalls {
             Self::GetPublicKey(id, websocket_tx, core_cxn) => {
                 Self::handle_ws_result(id, Self::handle_get_public_key(websocket_tx, core_cxn).await)
             },
+            Self::CreateKey(id, params, websocket_tx, core_cxn) => {
+                Self::handle_ws_result(id, Self::handle_create_key(params, websocket_tx, core_cxn).await)
+            },
+            Self::GetPendingTxs(id, websocket_tx, core_cxn) => {
+                Self::handle_ws_result(id, Self::handle_get_pending_txs(websocket_tx, core_cxn).await)
+            },
             Self::GetAddress(id, websocket_tx, core_cxn) => {
                 Self::handle_ws_result(id, Self::handle_get_address(websocket_tx, core_cxn).await)
             },
-            Self::Unknown(id, method) => Ok(warp::reply::json(&create_json_rpc_error(
-                id,
-                1, // FIXME arbitrary
-                &format!("unknown method: {method}"),
-            ))),
+            Self::Unknown(id, method) => create_json_rpc_error(id, JsonRpcError::method_not_found(&method)),
         }
     }
 
     // NOTE: This is because anything involving the core returns an encodable result from which we
     // need to extract either the successful json response, or turn an error into an error response.
-    fn handle_ws_result(
-        id: RpcId,
-        r: Result<WebSocketMessagesEncodable, SentinelError>,
-    ) -> Result<warp::reply::Json, Rejection> {
+    fn handle_ws_result(id: RpcId, r: Result<WebSocketMessagesEncodable, SentinelError>) -> Json {
         debug!("handling websocket encodable result: {r:?}");
-        let error_code = 1337; // FIXME
-        let j = match r {
+        match r {
             Ok(WebSocketMessagesEncodable::Success(j)) => create_json_rpc_response(id, j),
-            Ok(WebSocketMessagesEncodable::Error(WebSocketMessagesError::Json(ref j))) => {
-                // NOTE: Otherwise we end up double stringifying the json
-                create_json_rpc_error_with_json(id, error_code, j)
-            },
-            Ok(WebSocketMessagesEncodable::Error(e)) => {
-                let s = e.to_string();
-                // NOTE: We can't actually _get_ the exceptions from JNI on the core side of
-                // things, we can only ask the JNI env to print them to console for us. So alas we
-                // can't really do much. We can't even get a string to manually parse for common
-                // errors etc. As such, this is literally the best we can do.
-                let err_msg = if s.contains("Java exception was thrown") {
-                    "a java exception was thrown - please see core logs for details".to_string()
-                } else {
-                    s
-                };
-                create_json_rpc_error(id, error_code, &err_msg)
-            },
-            other => create_json_rpc_response_from_result(id, other, error_code),
-        };
-        Ok(warp::reply::json(&j))
+            Ok(WebSocketMessagesEncodable::Error(ref e)) => create_json_rpc_error(id, e.into()),
+            other => create_json_rpc_response_from_result(id, other),
+        }
     }
 }
 
@@ -331,12 +432,17 @@ mod tests {
 
     #[test]
     fn json_rpc_error_messages_should_not_be_double_stringified() {
-        let error_code = 666;
         let id = Some(1337);
         let j = json!({"some": "string"});
-        let r = create_json_rpc_error(id, error_code, &j.to_string());
-        let x = create_json_rpc_error_with_json(id, error_code, &j);
-        assert!(r.to_string().contains("\\\""));
-        assert!(!x.to_string().contains("\\\""));
+        let r = create_json_rpc_error(id, (&SentinelError::Json(j.clone())).into());
+        assert!(!r.to_string().contains("\\\""));
+        assert_eq!(r["error"]["data"]["details"], j);
+    }
+
+    #[test]
+    fn unknown_method_should_have_method_not_found_error_code() {
+        let r = create_json_rpc_error(Some(1), JsonRpcError::method_not_found("foo"));
+        assert_eq!(r["error"]["code"], json!(-32601));
+        assert_eq!(r["error"]["data"]["kind"], json!("METHOD_NOT_FOUND"));
     }
 }
@@ -0,0 +1,213 @@
+use std::str::FromStr;
+
+use common::strip_hex_prefix;
+use common_debug_signers::DebugSignature;
+use common_network_ids::NetworkId;
+use common_sentinel::{SentinelError, WebSocketMessagesError};
+use ethereum_types::Address as EthAddress;
+
+use super::rpc_schemas::{RpcMethodSchema, RpcParamType};
+
+// NOTE: The params of a request, once validated against its method's schema. Handlers may only get
+// at them by name, via accessors which check that the schema declares that param with the type
+// being asked for, so that a handler cannot parse a param differently to how it was validated.
+#[derive(Clone, Debug)]
+pub(crate) struct RpcParams {
+    schema: &'static RpcMethodSchema,
+    values: Vec<String>,
+}
+
+impl RpcParams {
+    pub(super) fn new(schema: &'static RpcMethodSchema, values: Vec<String>) -> Self {
+        Self { schema, values }
+    }
+
+    #[cfg(test)]
+    pub(super) fn values(&self) -> &[String] {
+        &self.values
+    }
+
+    fn get(&self, name: &str, param_types: &[RpcParamType]) -> Result<&[String], SentinelError> {
+        let method = self.schema.name();
+        let (i, param) = self
+            .schema
+            .param(name)
+            .ok_or_else(|| SentinelError::Custom(format!("{method} has no param named {name}")))?;
+
+        if !param_types.contains(&param.param_type()) {
+            return Err(SentinelError::Custom(format!(
+                "{method} param {name} is a {}, not a {}",
+                param.param_type().name(),
+                param_types.iter().map(|t| t.name()).collect::<Vec<_>>().join(" or "),
+            )));
+        };
+
+        let values = if param.is_variadic() {
+            self.values.get(i..)
+        } else {
+            self.values.get(i..i + 1)
+        };
+
+        Ok(values.unwrap_or_default())
+    }
+
+    // NOTE: An omitted optional param may be passed positionally as an empty string, in order to
+    // supply those after it.
+    fn optional(&self, name: &str, param_type: RpcParamType) -> Result<Option<&str>, SentinelError> {
+        Ok(self
+            .get(name, &[param_type])?
+            .first()
+            .map(String::as_str)
+            .filter(|s| !s.is_empty()))
+    }
+
+    fn required(&self, name: &str, param_type: RpcParamType) -> Result<&str, SentinelError> {
+        self.optional(name, param_type)?
+            .ok_or_else(|| SentinelError::Custom(format!("{} is missing param {name}", self.schema.name())))
+    }
+
+    fn parse_u64(s: &str) -> Result<u64, WebSocketMessagesError> {
+        s.parse::<u64>().map_err(|_| WebSocketMessagesError::ParseInt(s.into()))
+    }
+
+    fn parse_network_ids(s: &str) -> Result<Vec<NetworkId>, WebSocketMessagesError> {
+        s.split(',')
+            .map(|id| NetworkId::try_from(id.trim()).map_err(|_| WebSocketMessagesError::ParseNetworkId(id.into())))
+            .collect()
+    }
+
+    pub(crate) fn bool(&self, name: &str) -> Result<bool, SentinelError> {
+        Ok(self.required(name, RpcParamType::Bool)? == "true")
+    }
+
+    pub(crate) fn u64(&self, name: &str) -> Result<u64, SentinelError> {
+        Ok(Self::parse_u64(self.required(name, RpcParamType::U64)?)?)
+    }
+
+    pub(crate) fn optional_u64(&self, name: &str) -> Result<Option<u64>, SentinelError> {
+        Ok(self
+            .optional(name, RpcParamType::U64)?
+            .map(Self::parse_u64)
+            .transpose()?)
+    }
+
+    pub(crate) fn string(&self, name: &str) -> Result<String, SentinelError> {
+        Ok(self.required(name, RpcParamType::String)?.to_string())
+    }
+
+    pub(crate) fn optional_string(&self, name: &str) -> Result<Option<String>, SentinelError> {
+        Ok(self.optional(name, RpcParamType::String)?.map(str::to_string))
+    }
+
+    pub(crate) fn strings(&self, name: &str) -> Result<Vec<String>, SentinelError> {
+        Ok(self.get(name, &[RpcParamType::String])?.to_vec())
+    }
+
+    pub(crate) fn hex(&self, name: &str) -> Result<Vec<u8>, SentinelError> {
+        Ok(hex::decode(strip_hex_prefix(self.required(name, RpcParamType::Hex)?))?)
+    }
+
+    pub(crate) fn eth_address(&self, name: &str) -> Result<EthAddress, SentinelError> {
+        Ok(EthAddress::from_str(self.required(name, RpcParamType::EthAddress)?)?)
+    }
+
+    // NOTE: For where the address is hashed as passed in, eg in a debug command hash.
+    pub(crate) fn eth_address_string(&self, name: &str) -> Result<String, SentinelError> {
+        Ok(self.required(name, RpcParamType::EthAddress)?.to_string())
+    }
+
+    pub(crate) fn optional_eth_address(&self, name: &str) -> Result<Option<EthAddress>, SentinelError> {
+        Ok(self
+            .optional(name, RpcParamType::EthAddress)?
+            .map(EthAddress::from_str)
+            .transpose()?)
+    }
+
+    pub(crate) fn network_id(&self, name: &str) -> Result<NetworkId, SentinelError> {
+        Ok(NetworkId::try_from(self.required(name, RpcParamType::NetworkId)?)?)
+    }
+
+    // NOTE: Handles both a variadic network id param and a single comma separated network ids one.
+    pub(crate) fn network_ids(&self, name: &str) -> Result<Vec<NetworkId>, SentinelError> {
+        let values = self.get(name, &[RpcParamType::NetworkId, RpcParamType::NetworkIds])?;
+        if values.is_empty() {
+            return Err(SentinelError::Custom(format!(
+                "{} is missing param {name}",
+                self.schema.name()
+            )));
+        };
+        Ok(values
+            .iter()
+            .map(|s| Self::parse_network_ids(s))
+            .collect::<Result<Vec<Vec<NetworkId>>, WebSocketMessagesError>>()?
+            .concat())
+    }
+
+    // NOTE: Returns `None` when the block number is `latest`.
+    pub(crate) fn block_num(&self, name: &str) -> Result<Option<u64>, SentinelError> {
+        match self.required(name, RpcParamType::BlockNum)? {
+            s if s.eq_ignore_ascii_case("latest") => Ok(None),
+            s => Ok(Some(Self::parse_u64(s)?)),
+        }
+    }
+
+    // NOTE: Handles both a single debug signature param and a variadic one, the latter of which are
+    // comma separated into a single debug signature.
+    pub(crate) fn debug_signature(&self, name: &str) -> Result<DebugSignature, SentinelError> {
+        Ok(DebugSignature::from(self.get(name, &[RpcParamType::DebugSignature])?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn get_params(method: &str, params: serde_json::Value) -> RpcParams {
+        RpcMethodSchema::get(method).unwrap().to_rpc_params(&params).unwrap()
+    }
+
+    #[test]
+    fn should_get_typed_params_by_name() {
+        let params = get_params(
+            "resetChain",
+            json!(["EthereumMainnet", "latest", 10, true, "", "0xa", "0xb"]),
+        );
+        assert_eq!(
+            params.network_id("networkId").unwrap(),
+            NetworkId::try_from("EthereumMainnet").unwrap()
+        );
+        assert_eq!(params.block_num("blockNum").unwrap(), None);
+        assert_eq!(params.u64("confirmations").unwrap(), 10);
+        assert!(params.bool("validate").unwrap());
+        assert_eq!(params.optional_eth_address("hub").unwrap(), None);
+        assert_eq!(params.debug_signature("signatures").unwrap().to_string(), "0xa,0xb");
+    }
+
+    #[test]
+    fn should_get_network_ids_from_variadic_and_comma_separated_params() {
+        let expected = vec![
+            NetworkId::try_from("EthereumMainnet").unwrap(),
+            NetworkId::try_from("BscMainnet").unwrap(),
+        ];
+        let params = get_params("getStatus", json!(["EthereumMainnet", "BscMainnet"]));
+        assert_eq!(params.network_ids("networkIds").unwrap(), expected);
+        let params = get_params("exportCoreState", json!(["0xc0ffee", "EthereumMainnet,BscMainnet"]));
+        assert_eq!(params.network_ids("networkIds").unwrap(), expected);
+    }
+
+    #[test]
+    fn should_get_none_for_omitted_optional_params() {
+        let params = get_params("getAuditLog", json!({ "offset": 10 }));
+        assert_eq!(params.optional_u64("offset").unwrap(), Some(10));
+        assert_eq!(params.optional_u64("limit").unwrap(), None);
+    }
+
+    #[test]
+    fn should_not_get_param_as_a_type_other_than_that_of_its_schema() {
+        let params = get_params("processBlock", json!(["EthereumMainnet", 1337, false, true]));
+        assert!(params.string("blockNum").is_err());
+        assert!(params.u64("notAParam").is_err());
+    }
+}
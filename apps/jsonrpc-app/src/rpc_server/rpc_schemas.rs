@@ -0,0 +1,631 @@
+use std::str::FromStr;
+
+use common::strip_hex_prefix;
+use common_network_ids::NetworkId;
+use common_sentinel::{JsonRpcError, JsonRpcErrorCode};
+use ethereum_types::Address as EthAddress;
+use serde_json::{json, Map, Value as Json};
+
+use super::RpcParams;
+
+const OPEN_RPC_VERSION: &str = "1.2.6";
+
+// NOTE: Params are checked against these types so that malformed params are rejected before any
+// handler sees them. Handlers then get them by name & type via `RpcParams`, which refuses any param
+// the schema doesn't declare with that type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum RpcParamType {
+    Bool,
+    U64,
+    Hex,
+    String,
+    BlockNum,
+    NetworkId,
+    NetworkIds,
+    EthAddress,
+    DebugSignature,
+}
+
+impl RpcParamType {
+    fn is_valid(&self, s: &str) -> bool {
+        match self {
+            Self::String | Self::DebugSignature => true,
+            Self::Bool => matches!(s, "true" | "false"),
+            Self::U64 => s.parse::<u64>().is_ok(),
+            Self::Hex => hex::decode(strip_hex_prefix(s)).is_ok(),
+            Self::NetworkId => NetworkId::try_from(s).is_ok(),
+            Self::EthAddress => EthAddress::from_str(s).is_ok(),
+            Self::BlockNum => s.eq_ignore_ascii_case("latest") || s.parse::<u64>().is_ok(),
+            Self::NetworkIds => s.split(',').all(|id| NetworkId::try_from(id.trim()).is_ok()),
+        }
+    }
+
+    fn json_schema(&self) -> Json {
+        match self {
+            Self::Bool => json!({ "type": "boolean" }),
+            Self::U64 => json!({ "type": "integer", "minimum": 0 }),
+            Self::String => json!({ "type": "string" }),
+            Self::Hex => json!({ "type": "string", "pattern": "^(0x)?([0-9a-fA-F]{2})*$" }),
+            Self::EthAddress => json!({ "type": "string", "pattern": "^(0x)?[0-9a-fA-F]{40}$" }),
+            Self::NetworkId => json!({ "type": "string", "description": "a network id, eg `EthereumMainnet`" }),
+            Self::DebugSignature => json!({
+                "type": "string",
+                "description": "a debug signature, multiple signatures may be comma separated"
+            }),
+            Self::BlockNum => json!({
+                "oneOf": [{ "type": "integer", "minimum": 0 }, { "type": "string", "enum": ["latest"] }]
+            }),
+            Self::NetworkIds => json!({
+                "oneOf": [
+                    { "type": "array", "items": { "type": "string" } },
+                    { "type": "string", "description": "comma separated network ids" },
+                ]
+            }),
+        }
+    }
+
+    pub(super) fn name(&self) -> &'static str {
+        match self {
+            Self::Bool => "bool",
+            Self::U64 => "u64",
+            Self::Hex => "hex",
+            Self::String => "string",
+            Self::BlockNum => "block number or `latest`",
+            Self::NetworkId => "network id",
+            Self::NetworkIds => "network ids",
+            Self::EthAddress => "eth address",
+            Self::DebugSignature => "debug signature",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(super) struct RpcParamSchema {
+    name: &'static str,
+    param_type: RpcParamType,
+    required: bool,
+    // NOTE: A variadic param must be the last one in a method's schema, and collects all remaining
+    // positional params. When named, it may be given as either an array or a single value.
+    variadic: bool,
+    // NOTE: Used in place of an omitted optional param when a later param _is_ supplied by name.
+    default: Option<&'static str>,
+}
+
+impl RpcParamSchema {
+    const fn required(name: &'static str, param_type: RpcParamType) -> Self {
+        Self {
+            name,
+            param_type,
+            required: true,
+            variadic: false,
+            default: None,
+        }
+    }
+
+    const fn optional(name: &'static str, param_type: RpcParamType, default: Option<&'static str>) -> Self {
+        Self {
+            name,
+            param_type,
+            default,
+            required: false,
+            variadic: false,
+        }
+    }
+
+    const fn variadic(name: &'static str, param_type: RpcParamType, required: bool) -> Self {
+        Self {
+            name,
+            required,
+            param_type,
+            default: None,
+            variadic: true,
+        }
+    }
+
+    const fn signatures() -> Self {
+        Self::variadic("signatures", RpcParamType::DebugSignature, false)
+    }
+
+    pub(super) fn param_type(&self) -> RpcParamType {
+        self.param_type
+    }
+
+    pub(super) fn is_variadic(&self) -> bool {
+        self.variadic
+    }
+
+    fn is_valid(&self, s: &str) -> bool {
+        self.default == Some(s) || self.param_type.is_valid(s)
+    }
+
+    fn to_open_rpc(self) -> Json {
+        let schema = if self.variadic {
+            json!({ "oneOf": [{ "type": "array", "items": self.param_type.json_schema() }, self.param_type.json_schema()] })
+        } else {
+            self.param_type.json_schema()
+        };
+        json!({ "name": self.name, "required": self.required, "schema": schema })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(super) struct RpcMethodSchema {
+    name: &'static str,
+    aliases: &'static [&'static str],
+    summary: &'static str,
+    params: &'static [RpcParamSchema],
+}
+
+macro_rules! rpc_method_schemas {
+    ($($name:expr, [$($alias:expr),*], $summary:expr, [$($param:expr),* $(,)?];)*) => {
+        pub(super) const RPC_METHOD_SCHEMAS: &[RpcMethodSchema] = &[
+            $(
+                RpcMethodSchema {
+                    name: $name,
+                    aliases: &[$($alias),*],
+                    summary: $summary,
+                    params: &[$($param),*],
+                },
+            )*
+        ];
+    }
+}
+
+use RpcParamSchema as P;
+use RpcParamType as T;
+
+rpc_method_schemas!(
+    "addDebugSigners", ["addDebugSigner"], "Add debug signers to the core.", [
+        // NOTE: Pairs of names and addresses, optionally followed by a debug signature.
+        P::variadic("namesAndAddresses", T::String, true),
+    ];
+    "addNetwork", [], "Add a network to the running sentinel, initializing the core for it if need be.", [
+        P::required("networkId", T::NetworkId),
+        // NOTE: The core is only initialized for the network if both of these are passed in.
+        P::optional("tailLength", T::U64, Some("")),
+        P::optional("confirmations", T::U64, Some("")),
+        P::optional("path", T::String, None),
+    ];
+    "cancelQueued", [], "Cancel a queued debug command.", [
+        P::required("id", T::U64),
+        P::optional("signature", T::DebugSignature, None),
+    ];
+    "createKey", [], "Create the core's signing key if it has none yet, returning its public key.", [
+        P::signatures(),
+    ];
+    "delete", [], "Delete a key from the core's database.", [
+        P::required("key", T::Hex),
+        P::signatures(),
+    ];
+    "executeQueued", [], "Execute a queued debug command whose delay has elapsed.", [
+        P::required("id", T::U64),
+    ];
+    "exportCoreState", [], "Export an encrypted archive of the core's state.", [
+        P::required("recipient", T::Hex),
+        P::required("networkIds", T::NetworkIds),
+        P::signatures(),
+    ];
+    "get", [], "Get a value from the core's database.", [
+        P::required("key", T::Hex),
+        P::signatures(),
+    ];
+    "getAddress", [], "Get the core's signing address.", [];
+    "getAttestationCertificate", [], "Get the core's attestation certificate.", [];
+    "getAttestationSignature", [], "Get an attestation signature over the passed in bytes.", [
+        P::required("bytes", T::Hex),
+    ];
+    "getAuditLog", [], "Get a page of the core's debug command audit log.", [
+        P::optional("offset", T::U64, Some("0")),
+        P::optional("limit", T::U64, None),
+    ];
+    "getBalances", [], "Get the balances of an address on each configured network.", [
+        P::required("address", T::EthAddress),
+    ];
+    "getCoreState", ["getEnclaveState", "state"], "Get the core's state for the given networks.", [
+        P::variadic("networkIds", T::NetworkId, true),
+    ];
+    "getLatestBlockInfos", ["latest"], "Get the latest block infos for each configured network.", [];
+    "getPendingTxs", [], "Get the txs submitted by the sentinel which have yet to be mined.", [];
+    "getPublicKey", [], "Get the core's signing public key.", [];
+    "getRegistrationExtensionTx", [], "Get a signed registration extension transaction.", [
+        P::required("duration", T::U64),
+        P::required("networkId", T::NetworkId),
+        P::required("registrationManager", T::EthAddress),
+        P::optional("gasPrice", T::U64, None),
+    ];
+    "getRegistrationSignature", ["getRegSig"], "Get a registration signature from the core.", [
+        P::required("owner", T::EthAddress),
+        P::required("nonce", T::U64),
+        P::optional("signature", T::DebugSignature, None),
+    ];
+    "getStatus", ["status"], "Get the status of the core for the given networks.", [
+        P::variadic("networkIds", T::NetworkId, true),
+    ];
+    "getSyncState", [], "Get the sync state of each configured network.", [];
+    "hardReset", [], "Hard reset the core, dropping its database.", [
+        // NOTE: This param is unused, and remains only so that positional signatures are unchanged.
+        P::optional("confirmation", T::String, Some("")),
+        P::signatures(),
+    ];
+    "importCoreState", [], "Import an encrypted core state archive from the given path.", [
+        P::required("path", T::String),
+        P::signatures(),
+    ];
+    "init", [], "Initialize the core for a network.", [
+        P::required("validate", T::Bool),
+        P::required("hub", T::EthAddress),
+        P::required("tailLength", T::U64),
+        P::required("confirmations", T::U64),
+        P::required("networkId", T::NetworkId),
+    ];
+    "ping", [], "Check that the rpc server is alive.", [];
+    "processBlock", ["process", "submitBlock", "submit"], "Process a block.", [
+        P::required("networkId", T::NetworkId),
+        P::required("blockNum", T::U64),
+        P::required("dryRun", T::Bool),
+        P::required("reprocess", T::Bool),
+    ];
+    "put", [], "Put a value in the core's database.", [
+        P::required("key", T::Hex),
+        P::required("value", T::Hex),
+        P::signatures(),
+    ];
+    "registerEvmChain", [], "Register the evm chain configured for a network in the core.", [
+        P::required("networkId", T::NetworkId),
+        P::signatures(),
+    ];
+    "reloadConfig", [], "Reload the config, applying any changes which can be hot swapped.", [
+        P::optional("path", T::String, None),
+    ];
+    "removeDebugSigner", [], "Remove a debug signer from the core.", [
+        P::required("signer", T::EthAddress),
+        P::signatures(),
+    ];
+    "removeNetwork", [], "Remove a network from the running sentinel.", [
+        P::required("networkId", T::NetworkId),
+    ];
+    "resetChain", ["reset"], "Reset a chain in the core. Requires debug signatures.", [
+        P::required("networkId", T::NetworkId),
+        P::required("blockNum", T::BlockNum),
+        P::required("confirmations", T::U64),
+        P::required("validate", T::Bool),
+        P::optional("hub", T::EthAddress, Some("")),
+        P::signatures(),
+    ];
+    "rpc.discover", [], "Get the OpenRPC document describing this rpc server.", [];
+    "setDebugCommandDelay", [], "Set the delay before queued debug commands may be executed.", [
+        P::required("delay", T::U64),
+        P::signatures(),
+    ];
+    "setDebugSignaturePolicy", [], "Set the number of signatories required for a debug command.", [
+        P::required("command", T::String),
+        P::required("threshold", T::U64),
+        P::signatures(),
+    ];
+    "setTrustedArchiveSigner", [], "Set the address of the core whose state archives may be imported.", [
+        P::required("signer", T::EthAddress),
+        P::signatures(),
+    ];
+    "signMessage", ["sign"], "Sign a message with a gpg encrypted private key.", [
+        P::required("encryptedKey", T::String),
+        P::required("message", T::Hex),
+    ];
+    "startSyncer", [], "Start the syncers for the given networks.", [
+        P::variadic("networkIds", T::NetworkId, true),
+    ];
+    "stopSyncer", [], "Stop the syncers for the given networks.", [
+        P::variadic("networkIds", T::NetworkId, true),
+    ];
+);
+
+fn invalid_params(method: &str, msg: &str, param: Option<&RpcParamSchema>) -> JsonRpcError {
+    let details = json!({
+        "method": method,
+        "param": param.map(|p| p.name),
+        "expected": param.map(|p| p.param_type.name()),
+    });
+    JsonRpcError::new(JsonRpcErrorCode::InvalidParams, msg, Some(details))
+}
+
+impl RpcMethodSchema {
+    pub(super) fn get(method: &str) -> Option<&'static Self> {
+        RPC_METHOD_SCHEMAS
+            .iter()
+            .find(|s| s.name == method || s.aliases.contains(&method))
+    }
+
+    pub(super) fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub(super) fn param(&self, name: &str) -> Option<(usize, &'static RpcParamSchema)> {
+        self.params.iter().enumerate().find(|(_, p)| p.name == name)
+    }
+
+    // NOTE: Converts the `params` field of a request, be it absent, an array of positional params or
+    // an object of named params, into positional params, validating them against this method's
+    // schema along the way.
+    pub(super) fn to_rpc_params(&'static self, params: &Json) -> Result<RpcParams, JsonRpcError> {
+        let rpc_params = match params {
+            Json::Null => vec![],
+            Json::Array(a) => a
+                .iter()
+                .map(|v| self.to_param_string(v, None))
+                .collect::<Result<Vec<String>, JsonRpcError>>()?,
+            Json::Object(o) => self.named_to_positional(o)?,
+            _ => {
+                return Err(JsonRpcError::new(
+                    JsonRpcErrorCode::InvalidRequest,
+                    "params must be an array or an object",
+                    None,
+                ))
+            },
+        };
+        self.validate(&rpc_params)?;
+        Ok(RpcParams::new(self, rpc_params))
+    }
+
+    fn to_param_string(&self, v: &Json, param: Option<&RpcParamSchema>) -> Result<String, JsonRpcError> {
+        match v {
+            Json::String(s) => Ok(s.clone()),
+            Json::Bool(b) => Ok(b.to_string()),
+            Json::Number(n) => Ok(n.to_string()),
+            Json::Array(a) if param.map(|p| p.param_type) == Some(RpcParamType::NetworkIds) => Ok(a
+                .iter()
+                .map(|v| self.to_param_string(v, param))
+                .collect::<Result<Vec<String>, JsonRpcError>>()?
+                .join(",")),
+            _ => Err(invalid_params(self.name, &format!("invalid param: {v}"), param)),
+        }
+    }
+
+    fn named_to_positional(&self, o: &Map<String, Json>) -> Result<Vec<String>, JsonRpcError> {
+        if let Some(k) = o.keys().find(|k| !self.params.iter().any(|p| p.name == k.as_str())) {
+            return Err(invalid_params(self.name, &format!("unknown param: {k}"), None));
+        };
+
+        let mut positional: Vec<Option<String>> = vec![];
+        let mut variadic: Vec<String> = vec![];
+
+        for param in self.params {
+            match (o.get(param.name), param.variadic) {
+                (None, true) => (),
+                (None, false) => positional.push(None),
+                (Some(v), false) => positional.push(Some(self.to_param_string(v, Some(param))?)),
+                (Some(Json::Array(a)), true) => {
+                    for v in a {
+                        variadic.push(self.to_param_string(v, Some(param))?)
+                    }
+                },
+                (Some(v), true) => variadic.push(self.to_param_string(v, Some(param))?),
+            }
+        }
+
+        // NOTE: Omitted params may only be skipped over if they have a default to stand in for them.
+        let num_present = if variadic.is_empty() {
+            positional.iter().rposition(Option::is_some).map_or(0, |i| i + 1)
+        } else {
+            positional.len()
+        };
+
+        let mut rpc_params = positional
+            .into_iter()
+            .zip(self.params)
+            .take(num_present)
+            .map(|(v, param)| match (v, param.default) {
+                (Some(v), _) => Ok(v),
+                (None, Some(d)) => Ok(d.to_string()),
+                (None, None) => Err(invalid_params(
+                    self.name,
+                    &format!("missing param: {}", param.name),
+                    Some(param),
+                )),
+            })
+            .collect::<Result<Vec<String>, JsonRpcError>>()?;
+        rpc_params.extend(variadic);
+
+        Ok(rpc_params)
+    }
+
+    fn validate(&self, rpc_params: &[String]) -> Result<(), JsonRpcError> {
+        for (i, param) in self.params.iter().enumerate() {
+            let values = if param.variadic {
+                rpc_params.get(i..).unwrap_or_default()
+            } else {
+                rpc_params.get(i..i + 1).unwrap_or_default()
+            };
+
+            if values.is_empty() && param.required {
+                let msg = format!("missing param: {}", param.name);
+                return Err(invalid_params(self.name, &msg, Some(param)));
+            };
+
+            if let Some(v) = values.iter().find(|v| !param.is_valid(v)) {
+                let msg = format!(
+                    "invalid param {}: '{v}' is not a valid {}",
+                    param.name,
+                    param.param_type.name()
+                );
+                return Err(invalid_params(self.name, &msg, Some(param)));
+            };
+        }
+
+        if !self.params.iter().any(|p| p.variadic) && rpc_params.len() > self.params.len() {
+            let msg = format!(
+                "too many params: expected at most {}, got {}",
+                self.params.len(),
+                rpc_params.len()
+            );
+            return Err(invalid_params(self.name, &msg, None));
+        };
+
+        Ok(())
+    }
+
+    fn to_open_rpc(self) -> Json {
+        let mut description = self.summary.to_string();
+        if !self.aliases.is_empty() {
+            description.push_str(&format!(" Aliases: {}.", self.aliases.join(", ")));
+        };
+        json!({
+            "name": self.name,
+            "summary": self.summary,
+            "description": description,
+            "params": self.params.iter().map(|p| p.to_open_rpc()).collect::<Vec<Json>>(),
+            "result": { "name": "result", "schema": {} },
+        })
+    }
+
+    // NOTE: Generated from the above schemas, so that the served document cannot drift from what
+    // the rpc server actually accepts.
+    pub(super) fn open_rpc_document() -> Json {
+        json!({
+            "openrpc": OPEN_RPC_VERSION,
+            "info": {
+                "title": "pTokens Sentinel JSON-RPC",
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+            "methods": RPC_METHOD_SCHEMAS.iter().map(|s| s.to_open_rpc()).collect::<Vec<Json>>(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_schema(method: &str) -> &'static RpcMethodSchema {
+        RpcMethodSchema::get(method).unwrap()
+    }
+
+    #[test]
+    fn should_get_schema_by_alias() {
+        assert_eq!(get_schema("submit").name(), "processBlock");
+        assert!(RpcMethodSchema::get("notAMethod").is_none());
+    }
+
+    #[test]
+    fn schema_method_names_and_aliases_should_be_unique() {
+        let mut names = RPC_METHOD_SCHEMAS
+            .iter()
+            .flat_map(|s| [&[s.name][..], s.aliases].concat())
+            .collect::<Vec<&str>>();
+        let n = names.len();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), n);
+    }
+
+    #[test]
+    fn variadic_params_should_only_be_last() {
+        RPC_METHOD_SCHEMAS.iter().for_each(|s| {
+            assert!(s.params.iter().rev().skip(1).all(|p| !p.variadic), "{}", s.name);
+        })
+    }
+
+    #[test]
+    fn should_accept_positional_params_of_any_scalar_type() {
+        let params = json!(["EthereumMainnet", 1337, false, "true"]);
+        let result = get_schema("processBlock").to_rpc_params(&params).unwrap();
+        assert_eq!(result.values(), vec!["EthereumMainnet", "1337", "false", "true"]);
+    }
+
+    #[test]
+    fn should_convert_named_params_to_positional_params() {
+        let params = json!({
+            "reprocess": true,
+            "dryRun": false,
+            "blockNum": 1337,
+            "networkId": "EthereumMainnet",
+        });
+        let result = get_schema("processBlock").to_rpc_params(&params).unwrap();
+        assert_eq!(result.values(), vec!["EthereumMainnet", "1337", "false", "true"]);
+    }
+
+    #[test]
+    fn should_fill_omitted_optional_params_with_defaults_when_later_params_are_named() {
+        let params = json!({
+            "networkId": "EthereumMainnet",
+            "blockNum": "latest",
+            "confirmations": 10,
+            "validate": true,
+            "signatures": ["0xsig1", "0xsig2"],
+        });
+        let result = get_schema("resetChain").to_rpc_params(&params).unwrap();
+        let expected = vec!["EthereumMainnet", "latest", "10", "true", "", "0xsig1", "0xsig2"];
+        assert_eq!(result.values(), expected);
+    }
+
+    #[test]
+    fn should_not_fill_trailing_omitted_optional_params() {
+        let params = json!({ "offset": 10 });
+        let result = get_schema("getAuditLog").to_rpc_params(&params).unwrap();
+        assert_eq!(result.values(), vec!["10"]);
+        let params = json!({ "limit": 5 });
+        let result = get_schema("getAuditLog").to_rpc_params(&params).unwrap();
+        assert_eq!(result.values(), vec!["0", "5"]);
+    }
+
+    #[test]
+    fn should_join_named_network_ids_arrays() {
+        let params = json!({ "recipient": "0xc0ffee", "networkIds": ["EthereumMainnet", "BscMainnet"] });
+        let result = get_schema("exportCoreState").to_rpc_params(&params).unwrap();
+        assert_eq!(result.values(), vec!["0xc0ffee", "EthereumMainnet,BscMainnet"]);
+    }
+
+    #[test]
+    fn should_accept_absent_params() {
+        let result = get_schema("ping").to_rpc_params(&Json::Null).unwrap();
+        assert!(result.values().is_empty());
+    }
+
+    #[test]
+    fn should_fail_on_invalid_param_type() {
+        let params = json!(["EthereumMainnet", "not a number", false, true]);
+        let err = get_schema("processBlock").to_rpc_params(&params).unwrap_err();
+        assert_eq!(*err.code(), -32602);
+        assert_eq!(err.data()["details"]["param"], json!("blockNum"));
+        assert_eq!(err.data()["details"]["expected"], json!("u64"));
+    }
+
+    #[test]
+    fn should_fail_on_missing_required_param() {
+        let params = json!({ "networkId": "EthereumMainnet" });
+        let err = get_schema("processBlock").to_rpc_params(&params).unwrap_err();
+        assert_eq!(err.data()["details"]["param"], json!("blockNum"));
+    }
+
+    #[test]
+    fn should_fail_on_unknown_named_param() {
+        let params = json!({ "networkIds": ["EthereumMainnet"], "foo": "bar" });
+        let err = get_schema("getStatus").to_rpc_params(&params).unwrap_err();
+        assert_eq!(err.message(), "unknown param: foo");
+    }
+
+    #[test]
+    fn should_fail_on_too_many_params() {
+        let params = json!(["0xc0ffee", "extra"]);
+        let err = get_schema("getAttestationSignature")
+            .to_rpc_params(&params)
+            .unwrap_err();
+        assert_eq!(*err.code(), -32602);
+    }
+
+    #[test]
+    fn should_fail_on_non_structured_params() {
+        let err = get_schema("ping").to_rpc_params(&json!("pong")).unwrap_err();
+        assert_eq!(*err.code(), -32600);
+    }
+
+    #[test]
+    fn should_generate_open_rpc_document() {
+        let doc = RpcMethodSchema::open_rpc_document();
+        assert_eq!(doc["openrpc"], json!(OPEN_RPC_VERSION));
+        let methods = doc["methods"].as_array().unwrap();
+        assert_eq!(methods.len(), RPC_METHOD_SCHEMAS.len());
+        let process_block = methods.iter().find(|m| m["name"] == json!("processBlock")).unwrap();
+        assert_eq!(process_block["params"][1]["name"], json!("blockNum"));
+        assert_eq!(process_block["params"][1]["required"], json!(true));
+        assert!(process_block["description"].as_str().unwrap().contains("submit"));
+    }
+}
@@ -1,19 +1,215 @@
+use std::sync::{Arc, RwLock, RwLockReadGuard};
+
 use common_sentinel::{
     BroadcastChannelMessages,
     EthRpcSenders,
+    JsonRpcError,
+    JsonRpcErrorCode,
+    LogContext,
     RpcServerBroadcastChannelMessages,
     SentinelConfig,
     SentinelError,
 };
-use warp::Filter;
+use serde_json::Value as Json;
+use warp::{http::StatusCode, hyper::body::Bytes, Filter, Rejection, Reply};
+
+use super::{
+    auth::{
+        AuthenticatedClient,
+        ClientCertFingerprint,
+        RpcAuth,
+        AUTHORIZATION_HEADER,
+        SIGNATURE_HEADER,
+        SIGNATURE_TIMESTAMP_HEADER,
+    },
+    client_cert_tls::{get_client_cert_tls_acceptor, serve_with_client_certs},
+    create_json_rpc_error,
+    type_aliases::{RpcId, SharedConfig},
+    JsonRpcRequest,
+    RpcCalls,
+};
+use crate::{
+    health::{HealthReport, HEALTH},
+    metrics::METRICS,
+    type_aliases::{BroadcastChannelRx, BroadcastChannelTx, NetworkManagerTx, WebSocketTx},
+};
+
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+fn create_error_reply(id: RpcId, e: JsonRpcError, status: StatusCode) -> Box<dyn Reply> {
+    Box::new(warp::reply::with_status(
+        warp::reply::json(&create_json_rpc_error(id, e)),
+        status,
+    ))
+}
+
+// NOTE: Each call in a batch is authorized and rate limited individually, and so the returned status
+// code is only used for non-batch requests, since a batch response must be an array of responses.
+#[allow(clippy::too_many_arguments)]
+async fn handle_rpc_call(
+    auth: &RpcAuth,
+    client: &AuthenticatedClient,
+    call: Json,
+    config: SentinelConfig,
+    websocket_tx: WebSocketTx,
+    eth_rpc_senders: EthRpcSenders,
+    network_manager_tx: NetworkManagerTx,
+    broadcast_channel_tx: BroadcastChannelTx,
+    core_cxn: bool,
+) -> (Json, StatusCode) {
+    if let Err(e) = auth.check_rate_limit(client) {
+        warn!("{}", e.message());
+        return (create_json_rpc_error(None, e), StatusCode::TOO_MANY_REQUESTS);
+    };
+
+    let request = match serde_json::from_value::<JsonRpcRequest>(call) {
+        Ok(r) => r,
+        Err(e) => {
+            let e = JsonRpcError::new(JsonRpcErrorCode::InvalidRequest, &e.to_string(), None);
+            return (create_json_rpc_error(None, e), StatusCode::BAD_REQUEST);
+        },
+    };
 
-use super::{JsonRpcRequest, RpcCalls};
-use crate::type_aliases::{BroadcastChannelRx, BroadcastChannelTx, WebSocketTx};
+    let id = *request.id();
+    let rpc_call = RpcCalls::new(
+        request,
+        config,
+        websocket_tx,
+        eth_rpc_senders,
+        network_manager_tx,
+        broadcast_channel_tx,
+        core_cxn,
+    );
+
+    if let Err(e) = auth.authorize(client, rpc_call.required_role()) {
+        warn!("{}", e.message());
+        return (create_json_rpc_error(id, e), StatusCode::FORBIDDEN);
+    };
+
+    // NOTE: Any messages sent to the core whilst handling this call share its correlation id.
+    LogContext::for_rpc_call(id)
+        .scope(async move {
+            debug!("handling rpc request from client '{}'", client.name());
+            (rpc_call.handle().await, StatusCode::OK)
+        })
+        .await
+}
+
+// NOTE: The body is taken as raw bytes, rather than as json, since signed requests commit to the
+// exact bytes of the body. Said body may be either a single request or a batch of them.
+#[allow(clippy::too_many_arguments)]
+async fn handle_rpc_request(
+    auth: Arc<RpcAuth>,
+    authorization: Option<String>,
+    signature: Option<String>,
+    signature_timestamp: Option<String>,
+    cert_fingerprint: Option<ClientCertFingerprint>,
+    body: Bytes,
+    config: SentinelConfig,
+    websocket_tx: WebSocketTx,
+    eth_rpc_senders: EthRpcSenders,
+    network_manager_tx: NetworkManagerTx,
+    broadcast_channel_tx: BroadcastChannelTx,
+    core_cxn: bool,
+) -> Result<Box<dyn Reply>, Rejection> {
+    let client = match auth.authenticate(
+        authorization.as_deref(),
+        signature.as_deref(),
+        signature_timestamp.as_deref(),
+        cert_fingerprint.as_ref(),
+        &body,
+    ) {
+        Ok(c) => c,
+        Err(e) => {
+            warn!("unauthenticated rpc request: {}", e.message());
+            return Ok(create_error_reply(None, e, StatusCode::UNAUTHORIZED));
+        },
+    };
+
+    let calls = match serde_json::from_slice::<Json>(&body) {
+        Ok(Json::Array(calls)) if calls.is_empty() => {
+            let e = JsonRpcError::new(JsonRpcErrorCode::InvalidRequest, "empty batch", None);
+            return Ok(create_error_reply(None, e, StatusCode::BAD_REQUEST));
+        },
+        Ok(Json::Array(calls)) => calls,
+        Ok(call) => {
+            let (json, status) = handle_rpc_call(
+                &auth,
+                &client,
+                call,
+                config,
+                websocket_tx,
+                eth_rpc_senders,
+                network_manager_tx,
+                broadcast_channel_tx,
+                core_cxn,
+            )
+            .await;
+            return Ok(Box::new(warp::reply::with_status(warp::reply::json(&json), status)));
+        },
+        Err(e) => {
+            let e = JsonRpcError::new(JsonRpcErrorCode::ParseError, &e.to_string(), None);
+            return Ok(create_error_reply(None, e, StatusCode::BAD_REQUEST));
+        },
+    };
+
+    // NOTE: Calls within a batch are handled in order, since many of them alter the core's state.
+    let mut responses = vec![];
+    for call in calls {
+        let (json, _) = handle_rpc_call(
+            &auth,
+            &client,
+            call,
+            config.clone(),
+            websocket_tx.clone(),
+            eth_rpc_senders.clone(),
+            network_manager_tx.clone(),
+            broadcast_channel_tx.clone(),
+            core_cxn,
+        )
+        .await;
+        responses.push(json);
+    }
+
+    Ok(Box::new(warp::reply::json(&responses)))
+}
+
+// NOTE: Served in the prometheus text exposition format. Any authenticated client may scrape these,
+// via a bearer token or client cert when auth is enabled.
+async fn handle_metrics_request(
+    auth: Arc<RpcAuth>,
+    authorization: Option<String>,
+    cert_fingerprint: Option<ClientCertFingerprint>,
+) -> Result<Box<dyn Reply>, Rejection> {
+    if let Err(e) = auth.authenticate(authorization.as_deref(), None, None, cert_fingerprint.as_ref(), &[]) {
+        warn!("unauthenticated metrics request: {}", e.message());
+        return Ok(create_error_reply(None, e, StatusCode::UNAUTHORIZED));
+    };
+
+    Ok(Box::new(warp::reply::with_header(
+        METRICS.to_prometheus_string(),
+        "content-type",
+        PROMETHEUS_CONTENT_TYPE,
+    )))
+}
+
+// NOTE: These are unauthenticated, since they're for the likes of kubernetes' probes & load balancers,
+// and so the reports contain nothing sensitive.
+async fn handle_health_request(report: HealthReport) -> Result<Box<dyn Reply>, Rejection> {
+    let status = if report.is_ok() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    Ok(Box::new(warp::reply::with_status(warp::reply::json(&report), status)))
+}
 
 async fn start_rpc_server(
     eth_rpc_senders: EthRpcSenders,
     websocket_tx: WebSocketTx,
-    config: SentinelConfig,
+    network_manager_tx: NetworkManagerTx,
+    config: SharedConfig,
+    auth: Arc<RpcAuth>,
     broadcast_channel_tx: BroadcastChannelTx,
     core_cxn: bool,
 ) -> Result<(), SentinelError> {
@@ -21,34 +217,103 @@ async fn start_rpc_server(
     let core_cxn_filter = warp::any().map(move || core_cxn);
     let websocket_tx_filter = warp::any().map(move || websocket_tx.clone());
     let eth_rpc_senders_filter = warp::any().map(move || eth_rpc_senders.clone());
+    let network_manager_tx_filter = warp::any().map(move || network_manager_tx.clone());
     let broadcast_channel_tx_filter = warp::any().map(move || broadcast_channel_tx.clone());
-    let server_address = *config.core().rpc_server_address();
+    // NOTE: Neither of these can be hot swapped, since they're used to start the server itself.
+    let server_address = *read_config(&config).core().rpc_server_address();
+    let auth_config = read_config(&config).core().auth().clone();
+    let auth_filter = warp::any().map(move || auth.clone());
+    let liveness_config = config.clone();
+    let readiness_config = config.clone();
 
     let rpc = warp::path("v1")
         .and(warp::path("rpc"))
         .and(warp::path::end())
         .and(warp::post())
         .and(warp::body::content_length_limit(1024 * 16)) // FIXME make configurable
-        .and(warp::body::json::<JsonRpcRequest>())
-        .and(warp::any().map(move || config.clone()))
+        .and(auth_filter.clone())
+        .and(warp::header::optional::<String>(AUTHORIZATION_HEADER))
+        .and(warp::header::optional::<String>(SIGNATURE_HEADER))
+        .and(warp::header::optional::<String>(SIGNATURE_TIMESTAMP_HEADER))
+        .and(warp::ext::optional::<ClientCertFingerprint>())
+        .and(warp::body::bytes())
+        .and(warp::any().map(move || read_config(&config).clone()))
         .and(websocket_tx_filter.clone())
         .and(eth_rpc_senders_filter.clone())
+        .and(network_manager_tx_filter)
         .and(broadcast_channel_tx_filter.clone())
         .and(core_cxn_filter)
-        .map(RpcCalls::new)
-        .and_then(|r: RpcCalls| async move { r.handle().await });
+        .and_then(handle_rpc_request);
+
+    let metrics = warp::path("metrics")
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(auth_filter)
+        .and(warp::header::optional::<String>(AUTHORIZATION_HEADER))
+        .and(warp::ext::optional::<ClientCertFingerprint>())
+        .and_then(handle_metrics_request);
+
+    let healthz = warp::path("healthz")
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(warp::any().map(move || HEALTH.liveness(read_config(&liveness_config).core().health())))
+        .and_then(handle_health_request);
+
+    let readyz = warp::path("readyz")
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(warp::any().map(move || HEALTH.readiness(read_config(&readiness_config).core().health())))
+        .and_then(handle_health_request);
 
-    warp::serve(rpc).run(server_address).await;
+    let routes = rpc.or(metrics).or(healthz).or(readyz);
+
+    if !auth_config.enabled() {
+        warn!("rpc server auth is disabled - any request to the rpc server will be allowed");
+    };
+
+    match auth_config.tls() {
+        Some(tls) => match tls.client_ca_path() {
+            Some(ca_path) => {
+                info!("rpc server using tls & requiring client certificates");
+                let acceptor = get_client_cert_tls_acceptor(tls.cert_path(), tls.key_path(), ca_path)?;
+                serve_with_client_certs(warp::service(routes), acceptor, server_address).await?
+            },
+            None => {
+                info!("rpc server using tls");
+                warp::serve(routes)
+                    .tls()
+                    .cert_path(tls.cert_path())
+                    .key_path(tls.key_path())
+                    .run(server_address)
+                    .await
+            },
+        },
+        None => warp::serve(routes).run(server_address).await,
+    };
 
     Ok(())
 }
 
+fn read_config(config: &SharedConfig) -> RwLockReadGuard<SentinelConfig> {
+    config.read().unwrap_or_else(|e| e.into_inner())
+}
+
+// NOTE: Config updates are applied here, rather than being returned to the `rpc_server_loop`, since
+// doing so would restart the rpc server, likely before it has responded to whomever requested the
+// update.
 async fn broadcast_channel_loop(
     mut broadcast_channel_rx: BroadcastChannelRx,
-    _core_connection: bool,
+    config: SharedConfig,
+    auth: Arc<RpcAuth>,
 ) -> Result<RpcServerBroadcastChannelMessages, SentinelError> {
     'broadcast_channel_loop: loop {
         match broadcast_channel_rx.recv().await {
+            Ok(BroadcastChannelMessages::RpcServer(RpcServerBroadcastChannelMessages::UpdateConfig(c))) => {
+                info!("updating rpc server config");
+                auth.update_config(c.core().auth().clone());
+                *config.write().unwrap_or_else(|e| e.into_inner()) = *c;
+                continue 'broadcast_channel_loop;
+            },
             Ok(BroadcastChannelMessages::RpcServer(msg)) => {
                 // NOTE: We have a pertinent message, break and send it to the rpc_server_loop...
                 break 'broadcast_channel_loop Ok(msg);
@@ -62,6 +327,7 @@ async fn broadcast_channel_loop(
 pub async fn rpc_server_loop(
     eth_rpc_senders: EthRpcSenders,
     websocket_tx: WebSocketTx,
+    network_manager_tx: NetworkManagerTx,
     config: SentinelConfig,
     broadcast_channel_tx: BroadcastChannelTx,
 ) -> Result<(), SentinelError> {
@@ -69,10 +335,12 @@ pub async fn rpc_server_loop(
 
     let rpc_server_is_enabled = true; // FIXME rm
     let mut core_connection_status = false;
+    let auth = Arc::new(RpcAuth::new(config.core().auth().clone()));
+    let config = Arc::new(RwLock::new(config));
 
     'rpc_server_loop: loop {
         tokio::select! {
-            r = broadcast_channel_loop(broadcast_channel_tx.subscribe(), core_connection_status) => {
+            r = broadcast_channel_loop(broadcast_channel_tx.subscribe(), config.clone(), auth.clone()) => {
                 match r {
                     Ok(RpcServerBroadcastChannelMessages::CoreConnected) => {
                         core_connection_status = true;
@@ -82,13 +350,17 @@ pub async fn rpc_server_loop(
                         core_connection_status = false;
                         continue 'rpc_server_loop
                     },
+                    // NOTE: These are handled in the broadcast channel loop itself.
+                    Ok(RpcServerBroadcastChannelMessages::UpdateConfig(_)) => continue 'rpc_server_loop,
                     Err(e) => break 'rpc_server_loop Err(e),
                 }
             },
             r = start_rpc_server(
                 eth_rpc_senders.clone(),
                 websocket_tx.clone(),
+                network_manager_tx.clone(),
                 config.clone(),
+                auth.clone(),
                 broadcast_channel_tx.clone(),
                 core_connection_status,
             ), if rpc_server_is_enabled => {
@@ -110,3 +382,50 @@ pub async fn rpc_server_loop(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use common_sentinel::RpcAuthConfig;
+    use serde_json::json;
+    use tokio::sync::{broadcast, mpsc};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_reject_call_from_client_without_required_role() {
+        let auth_config: RpcAuthConfig = serde_json::from_value(json!({
+            "enabled": true,
+            "tls": null,
+            "clients": [
+                { "name": "monitoring", "role": "read_only", "bearer_token": "token", "eth_address": null, "rate_limit": null },
+            ],
+        }))
+        .unwrap();
+        let auth = RpcAuth::new(auth_config);
+        let client = auth.authenticate(Some("Bearer token"), None, None, None, b"").unwrap();
+        let config = SentinelConfig::new("../../common/sentinel/src/config/test_utils/sample-config").unwrap();
+        let (websocket_tx, _websocket_rx) = mpsc::channel(1);
+        let (network_manager_tx, _network_manager_rx) = mpsc::channel(1);
+        let (broadcast_channel_tx, _broadcast_channel_rx) = broadcast::channel(1);
+        let call = json!({ "jsonrpc": "2.0", "id": 1, "method": "hardReset", "params": [] });
+        let (result, status) = handle_rpc_call(
+            &auth,
+            &client,
+            call,
+            config,
+            websocket_tx,
+            EthRpcSenders::new(HashMap::new()),
+            network_manager_tx,
+            broadcast_channel_tx,
+            true,
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert!(result["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("cannot call a method requiring role 'admin'"));
+    }
+}
@@ -1,2 +1,6 @@
+use std::sync::{Arc, RwLock};
+
+use common_sentinel::SentinelConfig;
+
 pub(super) type RpcId = Option<u64>;
-pub(super) type RpcParams = Vec<String>;
+pub(super) type SharedConfig = Arc<RwLock<SentinelConfig>>;
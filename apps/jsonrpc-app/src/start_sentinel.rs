@@ -1,14 +1,6 @@
 use std::result::Result;
 
-use common_sentinel::{
-    flatten_join_handle,
-    Batch,
-    EthRpcChannels,
-    EthRpcSenders,
-    SentinelConfig,
-    SentinelError,
-    WebSocketMessages,
-};
+use common_sentinel::{flatten_join_handle, EthRpcSenders, SentinelConfig, SentinelError, WebSocketMessages};
 use futures::future::try_join_all;
 use serde_json::json;
 use tokio::sync::{
@@ -17,72 +9,102 @@ use tokio::sync::{
     mpsc::{Receiver as MpscRx, Sender as MpscTx},
 };
 
-use crate::{eth_rpc::eth_rpc_loop, rpc_server::rpc_server_loop, syncer::syncer, ws_server::ws_server_loop};
+use crate::{
+    challenge_responder::challenge_responder_loop,
+    heartbeat::heartbeat_loop,
+    network_manager::network_manager_loop,
+    registration::registration_loop,
+    rpc_server::rpc_server_loop,
+    tx_manager::tx_manager_loop,
+    type_aliases::{NetworkManagerRx, NetworkManagerTx, TxManagerRx, TxManagerTx},
+    ws_server::ws_server_loop,
+};
 
 const MAX_CHANNEL_CAPACITY: usize = 1337;
 
 pub async fn start_sentinel(config: &SentinelConfig, disable: bool) -> Result<String, SentinelError> {
-    let network_ids = config.network_ids();
-    let eth_rpc_channels = EthRpcChannels::from(network_ids);
+    // NOTE: This is populated by the network manager as it spawns the eth rpc task for each network.
+    let eth_rpc_senders = EthRpcSenders::default();
 
     let (broadcast_channel_tx, _) = broadcast::channel(MAX_CHANNEL_CAPACITY);
 
     let (websocket_tx, websocket_rx): (MpscTx<WebSocketMessages>, MpscRx<WebSocketMessages>) =
         mpsc::channel(MAX_CHANNEL_CAPACITY);
 
+    let (network_manager_tx, network_manager_rx): (NetworkManagerTx, NetworkManagerRx) =
+        mpsc::channel(MAX_CHANNEL_CAPACITY);
+
+    let (tx_manager_tx, tx_manager_rx): (TxManagerTx, TxManagerRx) = mpsc::channel(MAX_CHANNEL_CAPACITY);
+
     let rpc_server_thread = tokio::spawn(rpc_server_loop(
-        EthRpcSenders::from(&eth_rpc_channels),
+        eth_rpc_senders.clone(),
         websocket_tx.clone(),
+        network_manager_tx,
         config.clone(),
         broadcast_channel_tx.clone(),
     ));
 
     let ws_server_thread = tokio::spawn(ws_server_loop(
         websocket_rx,
-        config.clone(),
+        eth_rpc_senders.clone(),
         broadcast_channel_tx.clone(),
     ));
 
-    let mut threads = vec![];
-
-    // NOTE: For each network defined in the config, a thread is created to handle a module that
-    // syncs that blockchain...
-    let mut syncer_threads = config
-        .network_ids()
-        .iter()
-        .map(|id| {
-            Ok(tokio::spawn(syncer(
-                Batch::new_from_config(*id, config)?,
-                config.clone(),
-                EthRpcSenders::from(&eth_rpc_channels),
-                websocket_tx.clone(),
-                broadcast_channel_tx.clone(),
-                disable,
-            )))
-        })
-        .collect::<Result<Vec<_>, SentinelError>>()?;
-    threads.append(&mut syncer_threads);
-
-    // NOTE: For each network defined in the config, a thread is create dedicated to handling eth
-    // RPC calls for that network...
-    let mut eth_rpc_threads = eth_rpc_channels
-        .to_receivers()
-        .into_iter()
-        .map(|(network_id, receiver)| {
-            tokio::spawn(eth_rpc_loop(
-                receiver,
-                config.clone(),
-                network_id,
-                broadcast_channel_tx.clone(),
-                broadcast_channel_tx.subscribe(),
-            ))
-        })
-        .collect::<Vec<_>>();
-    threads.append(&mut eth_rpc_threads);
-
-    // NOTE: These final threads are all single modules, not dynamically generated.
-    let mut other_threads = vec![ws_server_thread, rpc_server_thread];
-    threads.append(&mut other_threads);
+    // NOTE: Periodically publishes the core's signed status, if enabled in the config.
+    let heartbeat_thread = tokio::spawn(heartbeat_loop(
+        config.clone(),
+        websocket_tx.clone(),
+        eth_rpc_senders.clone(),
+        tx_manager_tx.clone(),
+    ));
+
+    // NOTE: Periodically checks the sentinel's registration, renewing it before it expires if so
+    // configured.
+    let registration_thread = tokio::spawn(registration_loop(
+        config.clone(),
+        websocket_tx.clone(),
+        eth_rpc_senders.clone(),
+        tx_manager_tx.clone(),
+    ));
+
+    // NOTE: Answers any challenges the hub makes to the sentinel, which the core finds whilst
+    // processing blocks, before they expire.
+    let challenge_responder_thread = tokio::spawn(challenge_responder_loop(
+        config.clone(),
+        websocket_tx.clone(),
+        eth_rpc_senders.clone(),
+        tx_manager_tx,
+    ));
+
+    // NOTE: Submits any txs the sentinel sends itself, tracking their nonces & replacing any which
+    // get stuck with ones paying higher fees.
+    let tx_manager_thread = tokio::spawn(tx_manager_loop(
+        tx_manager_rx,
+        config.clone(),
+        websocket_tx.clone(),
+        eth_rpc_senders.clone(),
+    ));
+
+    // NOTE: The network manager spawns a syncer & an eth rpc thread for each network defined in the
+    // config, and adds or removes them as networks are added to or removed from the sentinel.
+    let network_manager_thread = tokio::spawn(network_manager_loop(
+        network_manager_rx,
+        config.clone(),
+        eth_rpc_senders,
+        websocket_tx,
+        broadcast_channel_tx,
+        disable,
+    ));
+
+    let threads = vec![
+        ws_server_thread,
+        rpc_server_thread,
+        heartbeat_thread,
+        registration_thread,
+        challenge_responder_thread,
+        tx_manager_thread,
+        network_manager_thread,
+    ];
 
     match try_join_all(threads.into_iter().map(flatten_join_handle).collect::<Vec<_>>()).await {
         Ok(r) => Ok(json!({ "jsonrpc": "2.0", "result": r }).to_string()),
@@ -1,15 +1,26 @@
-use common_sentinel::{Batch, EthRpcSenders, SentinelConfig, SentinelError, SyncerBroadcastChannelMessages};
+use common_sentinel::{
+    Batch,
+    EthRpcSenders,
+    LogContext,
+    SentinelConfig,
+    SentinelError,
+    SyncerBroadcastChannelMessages,
+};
 
 use super::{broadcast_channel_loop, syncer_loop};
-use crate::type_aliases::{BroadcastChannelTx, WebSocketTx};
+use crate::{
+    health::HEALTH,
+    type_aliases::{BroadcastChannelTx, WebSocketTx},
+};
 
 pub async fn syncer(
-    batch: Batch,
-    config: SentinelConfig,
+    mut batch: Batch,
+    mut config: SentinelConfig,
     eth_rpc_senders: EthRpcSenders,
     websocket_tx: WebSocketTx,
     broadcast_channel_tx: BroadcastChannelTx,
     disable_syncer: bool,
+    mut core_is_connected: bool,
 ) -> Result<(), SentinelError> {
     batch.check_endpoint().await?;
 
@@ -18,10 +29,14 @@ pub async fn syncer(
     let eth_rpc_tx = eth_rpc_senders.sender(&network_id)?;
     let name = format!("{network_id} syncer");
 
-    let mut core_is_connected = false;
-    warn!("{name} not syncing yet due to no core connection");
+    if !core_is_connected {
+        warn!("{name} not syncing yet due to no core connection");
+    };
 
     let mut syncer_is_enabled = !disable_syncer;
+    HEALTH.set_syncer_enabled(&network_id, syncer_is_enabled);
+    // NOTE: So that a syncer which never manages to submit a batch is seen to have stalled.
+    HEALTH.set_batch(&batch);
     if !syncer_is_enabled {
         warn!("{name} not sycning is disabled - you can enable it via an RPC call");
     };
@@ -36,11 +51,13 @@ pub async fn syncer(
                             SyncerBroadcastChannelMessages::Stop => {
                                 debug!("msg received to stop the {name} {note}");
                                 syncer_is_enabled = false;
+                                HEALTH.set_syncer_enabled(&network_id, false);
                                 continue 'syncer_loop
                             },
                             SyncerBroadcastChannelMessages::Start => {
                                 debug!("msg received to start the {name} {note}");
                                 syncer_is_enabled = true;
+                                HEALTH.set_syncer_enabled(&network_id, true);
                                 continue 'syncer_loop
                             },
                             SyncerBroadcastChannelMessages::CoreConnected => {
@@ -53,19 +70,32 @@ pub async fn syncer(
                                 core_is_connected = false;
                                 continue 'syncer_loop
                             },
+                            SyncerBroadcastChannelMessages::UpdateConfig(new_config) => {
+                                // NOTE: Continuing restarts the syncer loop, which resumes syncing
+                                // from the core's latest block using the updated config.
+                                match Batch::new_from_config(network_id, &new_config) {
+                                    Ok(b) => {
+                                        info!("updating config for {name} {note}");
+                                        batch = b;
+                                        config = *new_config;
+                                    },
+                                    Err(e) => warn!("could not update config for {name}: {e}"),
+                                };
+                                continue 'syncer_loop
+                            },
                         }
                     },
                     Err(e) => break 'syncer_loop Err(e),
                 }
             },
-            r = syncer_loop(
+            r = LogContext::for_network(network_id).scope(syncer_loop(
                 batch.clone(),
                 config.clone(),
                 eth_rpc_tx.clone(),
                 websocket_tx.clone(),
                 &core_is_connected,
                 &core_time_limit,
-            ), if core_is_connected && syncer_is_enabled => {
+            )), if core_is_connected && syncer_is_enabled => {
                 match r {
                     Ok(_)  => {
                         warn!("{name} returned, restarting {name} now...");
@@ -4,6 +4,7 @@ use common_sentinel::{
     Batch,
     EthRpcMessages,
     LatestBlockInfos,
+    LogContext,
     ProcessorOutput,
     SentinelConfig,
     SentinelError,
@@ -15,7 +16,11 @@ use common_sentinel::{
 };
 use tokio::time::{sleep, Duration};
 
-use crate::type_aliases::{EthRpcTx, WebSocketTx};
+use crate::{
+    health::HEALTH,
+    metrics::METRICS,
+    type_aliases::{EthRpcTx, WebSocketTx},
+};
 
 const SLEEP_TIME: u64 = 10; // FIXME make configurable
 
@@ -82,16 +87,28 @@ pub(super) async fn syncer_loop(
             return Err(SentinelError::NoCore);
         };
 
+        LogContext::set_block_num(batch.get_block_num());
         let (msg, rx) = EthRpcMessages::get_sub_mat_msg(network_id, batch.get_block_num());
         eth_rpc_tx.send(msg).await?;
         match rx.await? {
             Ok(block) => {
+                // NOTE: The raw txs are only needed in order for the core to validate the receipts
+                // the block is submitted with, so we only get them if there will be any.
+                let block = if validate && batch.keeps_receipts(&block) {
+                    let (msg, rx) = EthRpcMessages::get_raw_txs_msg(network_id, batch.get_block_num());
+                    eth_rpc_tx.send(msg).await?;
+                    block.add_raw_transactions(rx.await??)?
+                } else {
+                    block
+                };
                 batch.push(block);
+                METRICS.set_batch(&batch);
                 if !batch.is_ready_to_submit() {
                     batch.increment_block_num();
                     continue 'main_loop;
                 }
                 // TODO check if batch is chained correctly!
+                LogContext::set_batch_id(Some(batch.id()));
                 info!("{log_prefix} batch is ready to submit!");
                 let args = WebSocketMessagesProcessBatchArgs::new_for_syncer(
                     validate,
@@ -126,11 +143,27 @@ pub(super) async fn syncer_loop(
                         }
                         batch.update_bpm(&processor_output);
                         batch.increment_block_num();
+
+                        METRICS.inc_signed_events(&network_id, processor_output.signed_events().len());
+                        METRICS.set_core_latest_block_num(&network_id, *processor_output.latest_block_num());
+                        HEALTH.set_core_latest_block_num(&network_id, *processor_output.latest_block_num());
+
+                        for e in processor_output.governance_events().iter() {
+                            if e.is_critical() {
+                                error!("{log_prefix} governance event concerning this sentinel: {e}");
+                            } else {
+                                info!("{log_prefix} governance event concerning this sentinel: {e}");
+                            };
+                        }
+                        for c in processor_output.challenges().iter() {
+                            warn!("{log_prefix} challenge to this sentinel: {c}");
+                        }
                     },
                     Ok(WebSocketMessagesEncodable::Error(WebSocketMessagesError::NoParent(e))) => {
                         let n = e.block_num();
                         warn!("{log_prefix} returned no parent err for {n}!");
                         batch.drain();
+                        LogContext::set_batch_id(None);
                         batch.set_block_num(n - 1);
                         batch.set_single_submissions_flag();
                         continue 'main_loop;
@@ -138,6 +171,7 @@ pub(super) async fn syncer_loop(
                     Ok(WebSocketMessagesEncodable::Error(WebSocketMessagesError::BlockAlreadyInDb { num, .. })) => {
                         warn!("{log_prefix} block {num} already in the db!");
                         batch.drain();
+                        LogContext::set_batch_id(None);
                         batch.set_block_num(num + 1);
                         batch.set_single_submissions_flag();
                         continue 'main_loop;
@@ -154,6 +188,9 @@ pub(super) async fn syncer_loop(
                 };
 
                 batch.drain();
+                METRICS.set_batch(&batch);
+                HEALTH.set_batch(&batch);
+                LogContext::set_batch_id(None);
                 continue 'main_loop;
             },
             Err(SentinelError::NoBlock(_)) => {
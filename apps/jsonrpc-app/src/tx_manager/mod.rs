@@ -0,0 +1,3 @@
+mod tx_manager_loop;
+
+pub(crate) use self::tx_manager_loop::tx_manager_loop;
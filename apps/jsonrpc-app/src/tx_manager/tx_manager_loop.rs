@@ -0,0 +1,240 @@
+use std::result::Result;
+
+use common::{crypto_utils::keccak_hash_bytes, Bytes};
+use common_eth::{EthPrivateKey, EthTransaction, EthTxInfoCompatible};
+use common_network_ids::NetworkId;
+use common_sentinel::{
+    call_core,
+    get_utc_timestamp,
+    EthRpcMessages,
+    EthRpcSenders,
+    ManagedTx,
+    PendingTxs,
+    SentinelConfig,
+    SentinelError,
+    TxManagerMessages,
+    WebSocketMessagesEncodable,
+};
+use ethereum_types::{Address as EthAddress, H256 as EthHash};
+use tokio::time::{interval, Duration, MissedTickBehavior};
+
+use crate::{
+    health::HEALTH,
+    type_aliases::{EthRpcTx, TxManagerRx, WebSocketTx},
+};
+
+// NOTE: The pending txs are persisted in the core, so that they survive restarts. They're only ever
+// altered by this loop, which handles one message or poll at a time, and so there is no need to
+// guard against concurrent updates.
+async fn get_pending_txs(config: &SentinelConfig, websocket_tx: WebSocketTx) -> Result<PendingTxs, SentinelError> {
+    let msg = WebSocketMessagesEncodable::GetPendingTxs;
+    let r = call_core(*config.core().timeout(), websocket_tx, msg).await?;
+    PendingTxs::try_from(r)
+}
+
+async fn save_pending_txs(
+    config: &SentinelConfig,
+    websocket_tx: WebSocketTx,
+    pending_txs: PendingTxs,
+) -> Result<(), SentinelError> {
+    let msg = WebSocketMessagesEncodable::UpdatePendingTxs(Box::new(pending_txs));
+    call_core(*config.core().timeout(), websocket_tx, msg).await?;
+    Ok(())
+}
+
+async fn get_chain_nonce(sender: &EthRpcTx, network_id: NetworkId, address: EthAddress) -> Result<u64, SentinelError> {
+    let (msg, rx) = EthRpcMessages::get_nonce_msg(network_id, address);
+    sender.send(msg).await?;
+    rx.await?
+}
+
+async fn push_tx(sender: &EthRpcTx, tx: EthTransaction, network_id: NetworkId) -> Result<EthHash, SentinelError> {
+    let (msg, rx) = EthRpcMessages::get_push_tx_msg(tx, network_id);
+    sender.send(msg).await?;
+    rx.await?
+}
+
+async fn push_managed_tx(sender: &EthRpcTx, tx: &ManagedTx, pk: &EthPrivateKey) -> Result<EthHash, SentinelError> {
+    push_tx(sender, tx.sign(pk)?, *tx.network_id()).await
+}
+
+// NOTE: The tx is persisted along with its hash before it is pushed, since a node may accept it
+// even if we never hear back, and a tx we're not tracking would have its nonce reused by the next
+// one. Only a tx which a node rejects outright is dropped again, freeing its nonce. Any other
+// failure leaves the tx pending, whence polling will replace it once it's stuck should no node have
+// received it.
+async fn submit_tx(
+    config: &SentinelConfig,
+    websocket_tx: WebSocketTx,
+    eth_rpc_senders: &EthRpcSenders,
+    network_id: NetworkId,
+    to: EthAddress,
+    data: Bytes,
+    gas_limit: usize,
+) -> Result<EthHash, SentinelError> {
+    let pk = config.private_key()?;
+    let sender = eth_rpc_senders.sender(&network_id)?;
+
+    let mut pending_txs = get_pending_txs(config, websocket_tx.clone()).await?;
+    let chain_nonce = get_chain_nonce(&sender, network_id, pk.to_address()).await?;
+    let nonce = pending_txs.next_nonce(&network_id, chain_nonce);
+
+    let (fees_msg, fees_rx) = EthRpcMessages::get_fees_msg(network_id);
+    sender.send(fees_msg).await?;
+    let fees = fees_rx.await??;
+
+    let now = get_utc_timestamp()?;
+    let mut tx = ManagedTx::new(network_id, nonce, to, data, gas_limit, fees, now);
+    let signed_tx = tx.sign(&pk)?;
+    let tx_hash = keccak_hash_bytes(&signed_tx.serialize_bytes());
+    tx.add_tx_hash(tx_hash, now);
+    pending_txs.add(tx)?;
+    save_pending_txs(config, websocket_tx.clone(), pending_txs.clone()).await?;
+
+    match push_tx(&sender, signed_tx, network_id).await {
+        Ok(_) => {
+            info!("{network_id} tx with nonce {nonce} submitted with hash 0x{tx_hash:x}");
+            Ok(tx_hash)
+        },
+        Err(SentinelError::Endpoint(e)) if e.is_tx_rejection() => {
+            pending_txs.remove(&network_id, nonce)?;
+            save_pending_txs(config, websocket_tx, pending_txs).await?;
+            Err(e.into())
+        },
+        Err(e) => {
+            warn!("{network_id} tx with nonce {nonce} and hash 0x{tx_hash:x} may not have been submitted: {e}");
+            Ok(tx_hash)
+        },
+    }
+}
+
+// NOTE: Returns `None` if the tx is no longer pending, else the tx, which may have been replaced by
+// one paying higher fees if it was stuck.
+async fn check_pending_tx(
+    config: &SentinelConfig,
+    eth_rpc_senders: &EthRpcSenders,
+    pk: &EthPrivateKey,
+    tx: ManagedTx,
+    now: u64,
+) -> Result<Option<ManagedTx>, SentinelError> {
+    let network_id = *tx.network_id();
+    let nonce = *tx.nonce();
+    let sender = eth_rpc_senders.sender(&network_id)?;
+
+    // NOTE: Until the chain's nonce has passed that of the tx, none of its hashes can have been mined.
+    let chain_nonce = get_chain_nonce(&sender, network_id, pk.to_address()).await?;
+    if chain_nonce > nonce {
+        for tx_hash in tx.tx_hashes().iter().rev() {
+            let (msg, rx) = EthRpcMessages::get_tx_receipt_msg(network_id, *tx_hash);
+            sender.send(msg).await?;
+            if let Some(receipt) = rx.await?? {
+                if receipt.status {
+                    info!("{network_id} tx with nonce {nonce} mined with hash 0x{tx_hash:x}");
+                } else {
+                    warn!("{network_id} tx with nonce {nonce} reverted with hash 0x{tx_hash:x}");
+                };
+                return Ok(None);
+            };
+        }
+        warn!("{network_id} nonce {nonce} was used by a tx the sentinel did not submit, dropping its pending tx");
+        return Ok(None);
+    };
+
+    let tx_manager_config = config.tx_manager();
+    if !tx.is_stuck(now, *tx_manager_config.stuck_after()) {
+        return Ok(Some(tx));
+    };
+
+    if tx.num_bumps() >= tx_manager_config.max_fee_bumps() {
+        warn!("{network_id} tx with nonce {nonce} is stuck, but its fees have already been bumped the max number of times");
+        return Ok(Some(tx));
+    };
+
+    let mut replacement = tx.clone();
+    replacement.bump_fees(*tx_manager_config.fee_bump_percent());
+    let tx_hash = push_managed_tx(&sender, &replacement, pk).await?;
+    info!(
+        "{network_id} stuck tx with nonce {nonce} replaced with hash 0x{tx_hash:x} ({})",
+        replacement.fees()
+    );
+    replacement.add_tx_hash(tx_hash, now);
+    Ok(Some(replacement))
+}
+
+async fn poll_pending_txs(
+    config: &SentinelConfig,
+    websocket_tx: WebSocketTx,
+    eth_rpc_senders: &EthRpcSenders,
+) -> Result<(), SentinelError> {
+    if !HEALTH.core_connected() {
+        debug!("not polling pending txs, since no core is connected");
+        return Ok(());
+    };
+
+    let pending_txs = get_pending_txs(config, websocket_tx.clone()).await?;
+    if pending_txs.is_empty() {
+        return Ok(());
+    };
+
+    let pk = config.private_key()?;
+    let now = get_utc_timestamp()?;
+    let mut updated_pending_txs = pending_txs.clone();
+
+    for tx in pending_txs.txs().iter().cloned() {
+        let (network_id, nonce) = (*tx.network_id(), *tx.nonce());
+        match check_pending_tx(config, eth_rpc_senders, &pk, tx, now).await {
+            Ok(Some(tx)) => updated_pending_txs.update(tx)?,
+            Ok(None) => {
+                updated_pending_txs.remove(&network_id, nonce)?;
+            },
+            Err(e) => warn!("could not check {network_id} pending tx with nonce {nonce}: {e}"),
+        };
+    }
+
+    if updated_pending_txs != pending_txs {
+        save_pending_txs(config, websocket_tx, updated_pending_txs).await?;
+    };
+
+    Ok(())
+}
+
+pub async fn tx_manager_loop(
+    mut tx_manager_rx: TxManagerRx,
+    config: SentinelConfig,
+    websocket_tx: WebSocketTx,
+    eth_rpc_senders: EthRpcSenders,
+) -> Result<(), SentinelError> {
+    let name = "tx manager";
+    let mut poll_interval = interval(Duration::from_secs(*config.tx_manager().poll_interval()));
+    poll_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    'tx_manager_loop: loop {
+        tokio::select! {
+            r = tx_manager_rx.recv() => match r {
+                Some(TxManagerMessages::SubmitTx((network_id, to, data, gas_limit, responder))) => {
+                    let r = submit_tx(&config, websocket_tx.clone(), &eth_rpc_senders, network_id, to, data, gas_limit).await;
+                    if let Err(ref e) = r {
+                        error!("{network_id} could not submit tx: {e}");
+                    };
+                    let _ = responder.send(r);
+                    continue 'tx_manager_loop
+                },
+                None => {
+                    let m = format!("all {name} senders dropped!");
+                    error!("{m}");
+                    break 'tx_manager_loop Err(SentinelError::Custom(m))
+                },
+            },
+            _ = poll_interval.tick() => {
+                if let Err(e) = poll_pending_txs(&config, websocket_tx.clone(), &eth_rpc_senders).await {
+                    warn!("{name} could not poll pending txs: {e}");
+                };
+                continue 'tx_manager_loop
+            },
+            _ = tokio::signal::ctrl_c() => {
+                warn!("{name} shutting down...");
+                break 'tx_manager_loop Err(SentinelError::SigInt(name.into()))
+            },
+        }
+    }
+}
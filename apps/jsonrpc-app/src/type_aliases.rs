@@ -1,4 +1,10 @@
-use common_sentinel::{BroadcastChannelMessages, EthRpcMessages, WebSocketMessages};
+use common_sentinel::{
+    BroadcastChannelMessages,
+    EthRpcMessages,
+    NetworkManagerMessages,
+    TxManagerMessages,
+    WebSocketMessages,
+};
 use tokio::sync::{
     broadcast::{Receiver as MpmcRx, Sender as MpmcTx},
     mpsc::{Receiver as MpscRx, Sender as MpscTx},
@@ -10,3 +16,7 @@ pub(crate) type WebSocketRx = MpscRx<WebSocketMessages>;
 pub(crate) type WebSocketTx = MpscTx<WebSocketMessages>;
 pub(crate) type BroadcastChannelTx = MpmcTx<BroadcastChannelMessages>;
 pub(crate) type BroadcastChannelRx = MpmcRx<BroadcastChannelMessages>;
+pub(crate) type NetworkManagerTx = MpscTx<NetworkManagerMessages>;
+pub(crate) type NetworkManagerRx = MpscRx<NetworkManagerMessages>;
+pub(crate) type TxManagerTx = MpscTx<TxManagerMessages>;
+pub(crate) type TxManagerRx = MpscRx<TxManagerMessages>;
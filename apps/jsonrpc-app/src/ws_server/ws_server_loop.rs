@@ -1,4 +1,10 @@
-use std::{net::SocketAddr, path::PathBuf, result::Result, sync::Arc, time::Duration};
+use std::{
+    net::SocketAddr,
+    path::PathBuf,
+    result::Result,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use axum::{
     extract::{
@@ -11,15 +17,17 @@ use axum::{
     Router,
     TypedHeader,
 };
-use common_network_ids::NetworkId;
 use common_sentinel::{
     BroadcastChannelMessages,
+    EthRpcSenders,
+    JsonRpcErrorCode,
+    LogContext,
     RpcServerBroadcastChannelMessages,
-    SentinelConfig,
     SentinelError,
     SyncerBroadcastChannelMessages,
     WebSocketMessages,
     WebSocketMessagesEncodable,
+    WebSocketMessagesEnvelope,
     WebSocketMessagesError,
 };
 use derive_getters::Getters;
@@ -27,14 +35,18 @@ use futures::{stream::StreamExt, SinkExt};
 use tokio::{sync::Mutex, time::sleep};
 use tower_http::services::ServeDir;
 
-use crate::type_aliases::{BroadcastChannelTx, WebSocketRx};
+use crate::{
+    health::HEALTH,
+    metrics::METRICS,
+    type_aliases::{BroadcastChannelTx, WebSocketRx},
+};
 
 async fn handle_socket(
     mut socket: WebSocket,
     who: SocketAddr,
     websocket_rx: Arc<Mutex<WebSocketRx>>,
     broadcast_channel_tx: BroadcastChannelTx,
-    network_ids: Vec<NetworkId>,
+    eth_rpc_senders: EthRpcSenders,
 ) -> Result<(), SentinelError> {
     if socket.send(Message::Ping(vec![1, 3, 3, 7])).await.is_ok() {
         debug!("pinged {}...", who);
@@ -69,7 +81,9 @@ async fn handle_socket(
 
     // FIXME Need a better way/single location for all the services that need to know about this
     // core cxn status
-    for network_id in network_ids.iter().cloned() {
+    // NOTE: The senders' network ids are those currently running, since networks may be added or
+    // removed whilst the sentinel is running.
+    for network_id in eth_rpc_senders.network_ids() {
         // NOTE: Tell the various components that a core is connected.
         broadcast_channel_tx.send(BroadcastChannelMessages::Syncer(
             network_id,
@@ -79,34 +93,78 @@ async fn handle_socket(
     broadcast_channel_tx.send(BroadcastChannelMessages::RpcServer(
         RpcServerBroadcastChannelMessages::CoreConnected,
     ))?;
+    HEALTH.set_core_connected(true);
+
+    // NOTE: Set once the connected core is found to predate envelopes, after which msgs are sent to it
+    // in the legacy format.
+    let mut core_is_legacy = false;
 
     'ws_loop: loop {
         tokio::select! {
             r = rx.recv() => {
-                if let Some(WebSocketMessages(msg, responder)) = r {
-                    // NOTE: Pass the message on to whomever is connected to the server.
-                    sender.send(Message::Text(msg.try_into()?)).await?;
-
-                    const STRONGBOX_TIMEOUT_MS: u64 = 30000; // TODO make configurable
-                     // NOTE: We race the response against a timeout
-                    tokio::select! {
-                        _ = sleep(Duration::from_millis(STRONGBOX_TIMEOUT_MS)) => {
-                            let response = WebSocketMessagesError::Timedout(STRONGBOX_TIMEOUT_MS);
-                            let _ = responder.send(Err(response.into()));
-                            continue 'ws_loop
-                        },
-                        r = receiver.next() => {
-                            match r {
-                                Some(Ok(Message::Text(m))) => {
-                                    let _ = responder.send(WebSocketMessagesEncodable::try_from(m));
-                                    continue 'ws_loop
-                                },
-                                r => {
-                                    error!("websocket did not return with expected response: {r:?}");
-                                    break 'ws_loop
+                if let Some(WebSocketMessages(msg, responder, correlation_id)) = r {
+                    // NOTE: Any logs made whilst handling this msg are thus tagged with its
+                    // correlation id, as are the core's own logs for it.
+                    LogContext::set_correlation_id(Some(correlation_id.clone()));
+                    debug!("sending msg {msg} to core with correlation id {correlation_id}");
+
+                    'send_loop: loop {
+                        // NOTE: Pass the message on to whomever is connected to the server.
+                        let envelope = if core_is_legacy {
+                            WebSocketMessagesEnvelope::legacy(msg.clone())
+                        } else {
+                            WebSocketMessagesEnvelope::new(Some(correlation_id.clone()), msg.clone())
+                        };
+                        sender.send(Message::Text(envelope.try_into()?)).await?;
+                        let sent_at = Instant::now();
+
+                        const STRONGBOX_TIMEOUT_MS: u64 = 30000; // TODO make configurable
+                         // NOTE: We race the response against a timeout
+                        tokio::select! {
+                            _ = sleep(Duration::from_millis(STRONGBOX_TIMEOUT_MS)) => {
+                                METRICS.inc_core_timeouts();
+                                let response = WebSocketMessagesError::Timedout(STRONGBOX_TIMEOUT_MS);
+                                let _ = responder.send(Err(response.into()));
+                                continue 'ws_loop
+                            },
+                            r = receiver.next() => {
+                                match r {
+                                    Some(Ok(Message::Text(m))) => {
+                                        METRICS.observe_core_call(sent_at.elapsed());
+                                        let r = WebSocketMessagesEnvelope::try_from(m);
+                                        // NOTE: A core which predates envelopes fails to parse them, &
+                                        // responds with a bare error before having handled the msg. So
+                                        // it's safe to resend that msg in the format such a core expects.
+                                        if !core_is_legacy && matches!(r, Ok(ref e) if e.is_legacy()) {
+                                            warn!("core does not support websocket envelopes - resending msg in the legacy format");
+                                            core_is_legacy = true;
+                                            continue 'send_loop
+                                        };
+                                        let r = r.map(|envelope| {
+                                            let (_, response_correlation_id, msg) = envelope.dissolve();
+                                            match response_correlation_id {
+                                                Some(ref id) if id != &correlation_id => {
+                                                    warn!("core responded with correlation id {id}, expected {correlation_id}");
+                                                },
+                                                _ => debug!("core responded to msg with correlation id {correlation_id}"),
+                                            };
+                                            msg
+                                        });
+                                        if let Ok(WebSocketMessagesEncodable::Error(ref e)) = r {
+                                            if JsonRpcErrorCode::from(e) == JsonRpcErrorCode::DbIntegrity {
+                                                METRICS.inc_db_integrity_failures();
+                                            }
+                                        };
+                                        let _ = responder.send(r);
+                                        continue 'ws_loop
+                                    },
+                                    r => {
+                                        error!("websocket did not return with expected response: {r:?}");
+                                        break 'ws_loop
+                                    }
                                 }
-                            }
-                        },
+                            },
+                        }
                     }
                 } else {
                     error!("all websocket senders dropped");
@@ -141,7 +199,7 @@ async fn handle_socket(
         }
     }
 
-    for network_id in network_ids {
+    for network_id in eth_rpc_senders.network_ids() {
         // NOTE: Tell the various components that a core is no longer connected.
         broadcast_channel_tx.send(BroadcastChannelMessages::Syncer(
             network_id,
@@ -151,6 +209,7 @@ async fn handle_socket(
     broadcast_channel_tx.send(BroadcastChannelMessages::RpcServer(
         RpcServerBroadcastChannelMessages::CoreDisconnected,
     ))?;
+    HEALTH.set_core_connected(false);
 
     error!("websocket context {who} destroyed");
     Ok(())
@@ -158,15 +217,19 @@ async fn handle_socket(
 
 #[derive(Clone, Getters)]
 struct AppState {
-    network_ids: Vec<NetworkId>,
+    eth_rpc_senders: EthRpcSenders,
     websocket_rx: Arc<Mutex<WebSocketRx>>,
     broadcast_channel_tx: BroadcastChannelTx,
 }
 
 impl AppState {
-    fn new(websocket_rx: WebSocketRx, broadcast_channel_tx: BroadcastChannelTx, network_ids: Vec<NetworkId>) -> Self {
+    fn new(
+        websocket_rx: WebSocketRx,
+        broadcast_channel_tx: BroadcastChannelTx,
+        eth_rpc_senders: EthRpcSenders,
+    ) -> Self {
         Self {
-            network_ids,
+            eth_rpc_senders,
             broadcast_channel_tx,
             websocket_rx: Arc::new(Mutex::new(websocket_rx)),
         }
@@ -186,15 +249,16 @@ async fn ws_handler(
     };
     debug!("`{user_agent}` at {addr} connected.");
     ws.on_upgrade(move |socket| async move {
-        match handle_socket(
-            socket,
-            addr,
-            state.websocket_rx,
-            state.broadcast_channel_tx,
-            state.network_ids,
-        )
-        .await
-        {
+        let r = LogContext::default()
+            .scope(handle_socket(
+                socket,
+                addr,
+                state.websocket_rx,
+                state.broadcast_channel_tx,
+                state.eth_rpc_senders,
+            ))
+            .await;
+        match r {
             // FIXME what to return from here?
             Ok(_) => (),
             Err(e) => {
@@ -206,7 +270,7 @@ async fn ws_handler(
 
 async fn start_ws_server(
     websocket_rx: WebSocketRx,
-    config: SentinelConfig,
+    eth_rpc_senders: EthRpcSenders,
     broadcast_channel_tx: BroadcastChannelTx,
 ) -> Result<(), SentinelError> {
     let assets_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src/bin/sentinel/ws_server/assets");
@@ -214,7 +278,7 @@ async fn start_ws_server(
     let app = Router::new()
         .fallback_service(ServeDir::new(assets_dir).append_index_html_on_directories(true))
         .route("/ws", get(ws_handler))
-        .with_state(AppState::new(websocket_rx, broadcast_channel_tx, config.network_ids()));
+        .with_state(AppState::new(websocket_rx, broadcast_channel_tx, eth_rpc_senders));
 
     let addr = SocketAddr::from(([127, 0, 0, 1], 3000)); // FIXME make configurable
     debug!("ws server listening on {}", addr);
@@ -226,7 +290,7 @@ async fn start_ws_server(
 
 pub async fn ws_server_loop(
     websocket_rx: WebSocketRx,
-    config: SentinelConfig,
+    eth_rpc_senders: EthRpcSenders,
     broadcast_channel_tx: BroadcastChannelTx,
 ) -> Result<(), SentinelError> {
     let name = "ws server";
@@ -235,7 +299,7 @@ pub async fn ws_server_loop(
     tokio::select! {
         r = start_ws_server(
             websocket_rx,
-            config.clone(),
+            eth_rpc_senders,
             broadcast_channel_tx.clone(),
         ), if ws_server_is_enabled => r,
         _ = tokio::signal::ctrl_c() => {
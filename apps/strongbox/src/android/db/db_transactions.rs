@@ -4,7 +4,7 @@ use jni::objects::JString;
 use super::DbJsonResponse;
 use crate::android::{check_and_handle_java_exceptions, constants::PRINT_JAVA_ERRORS, Database};
 
-// NOTE: Unlike other methods in the java db interface, these two return a json string which needs
+// NOTE: Unlike other methods in the java db interface, the first two return a json string which needs
 // parsing in order to know the integrity of the database.
 
 impl<'a> Database<'a> {
@@ -55,4 +55,14 @@ impl<'a> Database<'a> {
             },
         }
     }
+
+    pub fn cancel_transaction(&self) -> Result<(), SentinelError> {
+        debug!("cancelling db transaction...");
+
+        let env = self.env();
+        match env.call_method(*self.db_java_class(), "cancelTransaction", "()V", &[]) {
+            Err(e) => self.handle_error(Err(e), PRINT_JAVA_ERRORS),
+            Ok(_) => check_and_handle_java_exceptions(env, PRINT_JAVA_ERRORS),
+        }
+    }
 }
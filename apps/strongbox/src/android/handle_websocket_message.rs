@@ -1,38 +1,40 @@
 use std::result::Result;
 
+use common::DatabaseInterface;
+use common_debug_signers::DebugSignatories;
 use common_sentinel::{
+    AuditLog,
+    AuditLogSignatory,
+    DebugCommandQueue,
     SentinelError,
     WebSocketMessagesEncodable as Msg,
     WebSocketMessagesEncodableDbOps,
     WebSocketMessagesError,
 };
 
-use crate::android::{check_and_handle_java_exceptions, constants::PRINT_JAVA_ERRORS, State};
+use crate::android::{check_and_handle_java_exceptions, constants::PRINT_JAVA_ERRORS, Database, State};
 
-pub fn handle_websocket_message(state: State) -> Result<State, SentinelError> {
-    info!("handling web socket message...");
+// NOTE: A hard reset drops the db, and so its db transaction is handled on the java side. However if
+// the debug command queue is enabled, a hard reset is merely queued, and it's the later execution of
+// that queued command which drops the db.
+fn will_drop_db<D: DatabaseInterface>(msg: &Msg, db: &D) -> bool {
+    match msg {
+        Msg::HardReset(_) => !DebugCommandQueue::get(db).is_enabled(),
+        Msg::ExecuteQueued(id) => DebugCommandQueue::get(db)
+            .get_command(*id)
+            .map(|queued| queued.command().is_hard_reset())
+            .unwrap_or_default(),
+        _ => false,
+    }
+}
 
+fn handle_msg(state: State) -> Result<State, SentinelError> {
     let msg = state.msg();
-
-    if msg.is_hard_reset() {
-        // NOTE: For a hard reset, the db transaction is handled on the java side.
-        warn!("skipping starting db transaction due to hard reset");
-    } else {
-        match state.db().start_transaction() {
-            Err(e) => {
-                error!("error starting db tx: {e}");
-                check_and_handle_java_exceptions(state.env(), PRINT_JAVA_ERRORS)?;
-                Err(e)
-            },
-            Ok(_) => {
-                check_and_handle_java_exceptions(state.env(), PRINT_JAVA_ERRORS)?;
-                Ok(())
-            },
-        }?;
+    match state.correlation_id() {
+        Some(id) => info!("handling websocket msg: '{msg}' with correlation id {id}..."),
+        None => info!("handling websocket msg: '{msg}'..."),
     };
-
-    info!("handling websocket msg: '{msg}'...");
-    let final_state = match msg {
+    match msg {
         Msg::Initialize(args) => super::handlers::init(*args.clone(), state),
         Msg::GetStatus(nids) => super::handlers::get_status(nids.clone(), state),
         Msg::ResetChain(args) => super::handlers::reset_chain(*args.clone(), state),
@@ -44,12 +46,35 @@ pub fn handle_websocket_message(state: State) -> Result<State, SentinelError> {
         Msg::GetLatestBlockInfos(nids) => super::handlers::get_latest_block_infos(nids.clone(), state),
         Msg::GetAttestationSignature(bytes) => super::handlers::get_attestation_signature(bytes.clone(), state),
         Msg::GetPublicKey => super::handlers::get_public_key(state),
+        Msg::CreateKey(sig) => super::handlers::create_key(sig.clone(), state),
         Msg::GetAddress => super::handlers::get_address(state),
         Msg::AddDebugSigners(signers, sig) => super::handlers::add_debug_signers(signers.clone(), sig.clone(), state),
         Msg::RemoveDebugSigner(signer, sig) => super::handlers::remove_debug_signer(signer.clone(), sig.clone(), state),
+        Msg::RegisterEvmChain(config, sig) => super::handlers::register_evm_chain(*config.clone(), sig.clone(), state),
+        Msg::SetDebugSignaturePolicy(cmd, threshold, sig) => {
+            super::handlers::set_debug_signature_policy(cmd.clone(), *threshold, sig.clone(), state)
+        },
+        Msg::SetDebugCommandDelay(delay, sig) => super::handlers::set_debug_command_delay(*delay, sig.clone(), state),
+        Msg::CancelQueued(id, sig) => super::handlers::cancel_queued(*id, sig.clone(), state),
+        Msg::ExecuteQueued(id) => super::handlers::execute_queued(*id, state).and_then(handle_msg),
+        Msg::GetAuditLog(offset, limit) => super::handlers::get_audit_log(*offset, *limit, state),
+        Msg::GetPendingTxs => super::handlers::get_pending_txs(state),
+        Msg::UpdatePendingTxs(pending_txs) => super::handlers::update_pending_txs(*pending_txs.clone(), state),
+        Msg::GetChallenges => super::handlers::get_challenges(state),
+        Msg::GetChallengeResponse(id, nids) => super::handlers::get_challenge_response(*id, nids.clone(), state),
+        Msg::SetChallengeState(id, challenge_state) => {
+            super::handlers::set_challenge_state(*id, challenge_state.clone(), state)
+        },
         Msg::GetRegistrationSignature(owner, nonce, sig) => {
             super::handlers::get_registration_signature(*owner, *nonce, sig.clone(), state)
         },
+        Msg::ExportCoreState(nids, recipient, sig) => {
+            super::handlers::export_core_state(nids.clone(), recipient.clone(), sig.clone(), state)
+        },
+        Msg::ImportCoreState(archive, sig) => super::handlers::import_core_state(*archive.clone(), sig.clone(), state),
+        Msg::SetTrustedArchiveSigner(signer, sig) => {
+            super::handlers::set_trusted_archive_signer(*signer, sig.clone(), state)
+        },
         Msg::DbOps(WebSocketMessagesEncodableDbOps::Get(k, sig)) => super::handlers::get(k.clone(), sig.clone(), state),
         Msg::DbOps(WebSocketMessagesEncodableDbOps::Delete(k, sig)) => {
             super::handlers::delete(k.clone(), sig.clone(), state)
@@ -58,11 +83,91 @@ pub fn handle_websocket_message(state: State) -> Result<State, SentinelError> {
             super::handlers::put(k.clone(), v.clone(), sig.clone(), state)
         },
         m => Err(WebSocketMessagesError::Unhandled(m.to_string()).into()),
-    }?;
+    }
+}
+
+// NOTE: Any debug command which runs successfully is recorded in the audit log, including those
+// which are merely queued, and the later execution of those queued commands. The latter has no
+// signatories of its own, but shares its command hash with the entry recording its queueing. Those
+// which are rejected or which fail are recorded too, via `record_failure_in_audit_log`, so long as
+// a debug signatory signed over them.
+fn maybe_record_in_audit_log(state: &State, signatories_before: Option<DebugSignatories>) -> Result<(), SentinelError> {
+    let command = state.msg();
+    match (signatories_before, state.res()) {
+        (Some(signatories), Some(res)) if command.is_debug_command() => {
+            AuditLog::record(state.db(), command, &signatories, res)?;
+            Ok(())
+        },
+        _ => Ok(()),
+    }
+}
 
-    if final_state.msg().is_hard_reset() {
+// NOTE: A msg which errors has its db tx cancelled, rolling back whatever it wrote, and so a failed
+// debug command is recorded in a fresh tx of its own, committed before its error is returned. The
+// cancellation in `callCore` that follows then has no open tx left to cancel. Those who signed over
+// the command are found before the rollback undoes their nonce increments.
+fn record_failure_in_audit_log(
+    db: &Database,
+    command: &Msg,
+    signatories_before: &DebugSignatories,
+    error: &SentinelError,
+) -> Result<(), SentinelError> {
+    let signatories = AuditLogSignatory::from_nonce_changes(signatories_before, &DebugSignatories::get_from_db(db)?);
+    db.cancel_transaction()?;
+    db.start_transaction()?;
+    AuditLog::record_failure(db, command, signatories, error)?;
+    db.end_transaction()
+}
+
+pub fn handle_websocket_message(state: State) -> Result<State, SentinelError> {
+    info!("handling web socket message...");
+
+    let skip_db_tx = will_drop_db(state.msg(), state.db());
+
+    if skip_db_tx {
+        warn!("skipping starting db transaction due to hard reset");
+    } else {
+        match state.db().start_transaction() {
+            Err(e) => {
+                error!("error starting db tx: {e}");
+                check_and_handle_java_exceptions(state.env(), PRINT_JAVA_ERRORS)?;
+                Err(e)
+            },
+            Ok(_) => {
+                check_and_handle_java_exceptions(state.env(), PRINT_JAVA_ERRORS)?;
+                Ok(())
+            },
+        }?;
+    };
+
+    // NOTE: A hard reset drops the db, and the audit log along with it.
+    let is_auditable = state.msg().is_debug_command() || matches!(state.msg(), Msg::ExecuteQueued(_));
+    let signatories_before = if is_auditable && !skip_db_tx {
+        Some(DebugSignatories::get_from_db(state.db())?)
+    } else {
+        None
+    };
+
+    let db = Database::new(*state.env(), *state.db().db_java_class());
+    let msg = state.msg().clone();
+
+    let final_state = match handle_msg(state) {
+        Ok(s) => s,
+        Err(e) => {
+            if let Some(ref signatories) = signatories_before {
+                if let Err(audit_err) = record_failure_in_audit_log(&db, &msg, signatories, &e) {
+                    error!("could not record failed debug command in audit log: {audit_err}");
+                };
+            };
+            return Err(e);
+        },
+    };
+
+    if skip_db_tx {
         warn!("skipping ending db transaction due to hard reset");
     } else {
+        maybe_record_in_audit_log(&final_state, signatories_before)?;
+
         match final_state.db().end_transaction() {
             Err(e) => {
                 error!("error ending db tx: {e}");
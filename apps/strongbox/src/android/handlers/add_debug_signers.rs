@@ -1,8 +1,13 @@
-use common_debug_signers::{debug_add_multiple_debug_signers_with_options, DebugSignature};
+use common_debug_signers::{
+    debug_add_multiple_debug_signers_with_options,
+    validate_debug_add_multiple_debug_signers_signatures,
+    DebugSignature,
+};
 use common_sentinel::{SentinelError, WebSocketMessagesEncodable};
 use ethereum_types::Address as EthAddress;
 use serde_json::{json, Value as Json};
 
+use super::maybe_queue_debug_command;
 use crate::android::{State, CORE_TYPE};
 
 pub fn add_debug_signers(
@@ -17,13 +22,28 @@ pub fn add_debug_signers(
 
     let signers_json = json!(jsons);
 
-    // NOTE: The `debug_add_...` fxn in the debug_signers crate handleds the validation of a signature for
-    // itself, so we don't have to do it in here. It also gives us the option to use db txs which
-    // we don't want since that'll cause duplicate tx errors in java, and we don't want to use the
-    // safe addresses for initial validation since they're not relevant in v3 bridges.
-
+    // NOTE: We don't use the db txs of the `debug_add_...` fxns in the debug_signers crate since
+    // that'll cause duplicate tx errors in java, and we don't want to use the safe addresses for
+    // initial validation since they're not relevant in v3 bridges. We validate the signature(s)
+    // ourselves since the addition is time lockable, and a queued execution's signatures were
+    // already validated (and their nonces incremented) when it was queued.
     let use_db_tx = false;
     let use_safe_addresses = false;
+    let validate_signatures = false;
+
+    if !*state.is_queued_execution() {
+        validate_debug_add_multiple_debug_signers_signatures(
+            state.db(),
+            &signers_json.to_string(),
+            &CORE_TYPE,
+            &sig.to_string(),
+            use_safe_addresses,
+        )?;
+    };
+
+    if let Some(r) = maybe_queue_debug_command(&state)? {
+        return Ok(state.add_response(r));
+    };
 
     debug_add_multiple_debug_signers_with_options(
         state.db(),
@@ -32,6 +52,7 @@ pub fn add_debug_signers(
         &sig.to_string(),
         use_safe_addresses,
         use_db_tx,
+        validate_signatures,
     )?;
 
     let msg = WebSocketMessagesEncodable::Success(json!({"debugSignersAdded": signers_json}));
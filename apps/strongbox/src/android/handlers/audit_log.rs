@@ -0,0 +1,21 @@
+use common_eth::ChainDbUtils;
+use common_sentinel::{AuditLog, SentinelError, WebSocketMessagesEncodable, MAX_AUDIT_LOG_PAGE_SIZE};
+use serde_json::json;
+
+use crate::android::State;
+
+pub fn get_audit_log(offset: u64, limit: u64, state: State) -> Result<State, SentinelError> {
+    debug!("handling `getAuditLog` message in strongbox...");
+    let db = state.db();
+    let audit_log = AuditLog::get(db);
+    let entries = audit_log.get_entries(db, offset, limit)?;
+    let pk = ChainDbUtils::new(db).get_pk()?;
+
+    let r = WebSocketMessagesEncodable::Success(json!({
+        "offset": offset,
+        "limit": std::cmp::min(limit, MAX_AUDIT_LOG_PAGE_SIZE),
+        "head": audit_log.to_signed_json(&pk)?,
+        "entries": entries.iter().map(|e| e.to_json()).collect::<Vec<_>>(),
+    }));
+    Ok(state.add_response(r))
+}
@@ -0,0 +1,36 @@
+use common_eth::ChainDbUtils;
+use common_network_ids::NetworkId;
+use common_sentinel::{ChallengeResponse, ChallengeState, Challenges, SentinelError, WebSocketMessagesEncodable};
+use ethereum_types::H256 as EthHash;
+
+use super::get_status::get_sentinel_status;
+use crate::android::State;
+
+pub fn get_challenges(state: State) -> Result<State, SentinelError> {
+    debug!("handling `getChallenges` message in strongbox...");
+    let challenges = Challenges::get(state.db());
+    let r = WebSocketMessagesEncodable::Success(serde_json::to_value(challenges)?);
+    Ok(state.add_response(r))
+}
+
+// NOTE: Only challenges the core itself has seen are answered, so that the response can not be
+// got for a challenge which was never made.
+pub fn get_challenge_response(id: EthHash, network_ids: Vec<NetworkId>, state: State) -> Result<State, SentinelError> {
+    debug!("handling `getChallengeResponse` message in strongbox...");
+    let challenges = Challenges::get(state.db());
+    let challenge = challenges.get_pending_challenge(&id)?;
+    let status = get_sentinel_status(state.db(), &network_ids)?;
+    let pk = ChainDbUtils::new(state.db()).get_pk()?;
+    let response = ChallengeResponse::new(&pk, challenge, status)?;
+    let r = WebSocketMessagesEncodable::Success(serde_json::to_value(response)?);
+    Ok(state.add_response(r))
+}
+
+pub fn set_challenge_state(id: EthHash, challenge_state: ChallengeState, state: State) -> Result<State, SentinelError> {
+    debug!("handling `setChallengeState` message in strongbox...");
+    let mut challenges = Challenges::get(state.db());
+    challenges.set_state(&id, challenge_state)?;
+    challenges.save(state.db())?;
+    let r = WebSocketMessagesEncodable::Success(serde_json::to_value(challenges)?);
+    Ok(state.add_response(r))
+}
@@ -0,0 +1,45 @@
+use common_debug_signers::{validate_debug_command_signatures, DebugSignatories, DebugSignature};
+use common_eth::{ChainDbUtils, EthPrivateKey};
+use common_sentinel::{SentinelError, WebSocketMessagesEncodable};
+use function_name::named;
+use serde_json::json;
+
+use crate::android::{State, CORE_TYPE};
+
+// NOTE: A fresh core won't have a key until a chain is initialized. However a fresh core is also the
+// recipient of a core state archive when migrating devices, which is encrypted to its public key. So
+// this creates the key ahead of time if need be, just as chain initialization would. Creating one
+// requires debug signatures, unless the core has no debug signatories yet to check them against, in
+// the same way that the first debug signers may be added without any.
+#[named]
+pub fn create_key(sig: DebugSignature, state: State) -> Result<State, SentinelError> {
+    let db_utils = ChainDbUtils::new(state.db());
+
+    let created = if db_utils.get_pk().is_err() {
+        if !DebugSignatories::get_from_db(state.db())?.is_empty() {
+            let h = get_debug_command_hash!(function_name!())()?;
+            validate_debug_command_signatures(
+                state.db(),
+                &CORE_TYPE,
+                function_name!(),
+                &sig.to_string(),
+                &h,
+                cfg!(test),
+            )?;
+        };
+        debug!("no pk exists yet, creating and saving one...");
+        db_utils.put_pk(&EthPrivateKey::generate_random()?)?;
+        true
+    } else {
+        debug!("pk already exists, not creating another");
+        false
+    };
+
+    let j = json!({
+        "created": created,
+        "publicKey": format!("0x{}", hex::encode(db_utils.get_public_key()?.public_key.serialize_uncompressed())),
+    });
+
+    let r = WebSocketMessagesEncodable::Success(j);
+    Ok(state.add_response(r))
+}
@@ -1,9 +1,10 @@
 use common::MIN_DATA_SENSITIVITY_LEVEL;
-use common_debug_signers::{validate_debug_command_signature, DebugSignature};
+use common_debug_signers::{validate_debug_command_signatures, DebugSignature};
 use common_sentinel::{SentinelError, WebSocketMessagesEncodable};
 use function_name::named;
 use serde_json::json;
 
+use super::maybe_queue_debug_command;
 use crate::android::{State, CORE_TYPE};
 
 // TODO/FIXME: Handle different data sensitivities
@@ -16,7 +17,14 @@ fn to_prefixed_hex_string(bs: &[u8]) -> String {
 #[named]
 pub fn get(k: Bytes, sig: DebugSignature, state: State) -> Result<State, SentinelError> {
     let h = get_debug_command_hash!(function_name!(), &k)()?;
-    validate_debug_command_signature(state.db(), &CORE_TYPE, &sig.to_string(), &h, cfg!(test))?;
+    validate_debug_command_signatures(
+        state.db(),
+        &CORE_TYPE,
+        function_name!(),
+        &sig.to_string(),
+        &h,
+        cfg!(test),
+    )?;
 
     let v = state.db().get(&k, MIN_DATA_SENSITIVITY_LEVEL)?;
     let msg = WebSocketMessagesEncodable::Success(json!({
@@ -30,7 +38,18 @@ pub fn get(k: Bytes, sig: DebugSignature, state: State) -> Result<State, Sentine
 #[named]
 pub fn put(k: Bytes, v: Bytes, sig: DebugSignature, state: State) -> Result<State, SentinelError> {
     let h = get_debug_command_hash!(function_name!(), &k, &v)()?;
-    validate_debug_command_signature(state.db(), &CORE_TYPE, &sig.to_string(), &h, cfg!(test))?;
+    validate_debug_command_signatures(
+        state.db(),
+        &CORE_TYPE,
+        function_name!(),
+        &sig.to_string(),
+        &h,
+        cfg!(test) || *state.is_queued_execution(),
+    )?;
+
+    if let Some(r) = maybe_queue_debug_command(&state)? {
+        return Ok(state.add_response(r));
+    };
 
     let r = state.db().put(&k, &v, MIN_DATA_SENSITIVITY_LEVEL);
     let msg = WebSocketMessagesEncodable::Success(json!({
@@ -45,7 +64,18 @@ pub fn put(k: Bytes, v: Bytes, sig: DebugSignature, state: State) -> Result<Stat
 #[named]
 pub fn delete(k: Bytes, sig: DebugSignature, state: State) -> Result<State, SentinelError> {
     let h = get_debug_command_hash!(function_name!(), &k)()?;
-    validate_debug_command_signature(state.db(), &CORE_TYPE, &sig.to_string(), &h, cfg!(test))?;
+    validate_debug_command_signatures(
+        state.db(),
+        &CORE_TYPE,
+        function_name!(),
+        &sig.to_string(),
+        &h,
+        cfg!(test) || *state.is_queued_execution(),
+    )?;
+
+    if let Some(r) = maybe_queue_debug_command(&state)? {
+        return Ok(state.add_response(r));
+    };
 
     let r = state.db().delete(&k);
     let msg = WebSocketMessagesEncodable::Success(json!({
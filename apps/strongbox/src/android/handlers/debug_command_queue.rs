@@ -0,0 +1,65 @@
+use common_debug_signers::{validate_debug_command_signature, validate_debug_command_signatures, DebugSignature};
+use common_sentinel::{get_utc_timestamp, DebugCommandQueue, SentinelError, WebSocketMessagesEncodable};
+use function_name::named;
+use serde_json::json;
+
+use crate::android::{State, CORE_TYPE};
+
+// NOTE: Called by time lockable handlers once they've validated their debug signature(s). If the
+// queue is enabled, the msg is queued and the returned response should be used in lieu of running
+// the command.
+pub(crate) fn maybe_queue_debug_command(state: &State) -> Result<Option<WebSocketMessagesEncodable>, SentinelError> {
+    let q = DebugCommandQueue::get(state.db());
+    if *state.is_queued_execution() || !q.is_enabled() {
+        Ok(None)
+    } else {
+        let queued = q.enqueue(state.db(), state.msg().clone(), get_utc_timestamp()?)?;
+        Ok(Some(WebSocketMessagesEncodable::Success(
+            json!({"debugCommandQueued": queued.to_json()}),
+        )))
+    }
+}
+
+#[named]
+pub fn set_debug_command_delay(delay: u64, sig: DebugSignature, state: State) -> Result<State, SentinelError> {
+    debug!("setting debug command delay to {delay}s...");
+    let h = get_debug_command_hash!(function_name!(), &delay)()?;
+    validate_debug_command_signatures(
+        state.db(),
+        &CORE_TYPE,
+        function_name!(),
+        &sig.to_string(),
+        &h,
+        cfg!(test) || *state.is_queued_execution(),
+    )?;
+
+    // NOTE: Changing the delay is itself time locked, else the queue could be trivially bypassed.
+    if let Some(r) = maybe_queue_debug_command(&state)? {
+        return Ok(state.add_response(r));
+    };
+
+    let q = DebugCommandQueue::get(state.db()).set_delay(state.db(), delay)?;
+    let r = WebSocketMessagesEncodable::Success(json!({"debugCommandQueue": q.to_json()}));
+    Ok(state.add_response(r))
+}
+
+#[named]
+pub fn cancel_queued(id: u64, sig: DebugSignature, state: State) -> Result<State, SentinelError> {
+    debug!("cancelling queued debug command {id}...");
+    let h = get_debug_command_hash!(function_name!(), &id)()?;
+
+    // NOTE: Any single debug signatory may cancel a queued command, regardless of any debug
+    // signature policies, since that's what gives the operators their window to stop it.
+    validate_debug_command_signature(state.db(), &CORE_TYPE, &sig.to_string(), &h, cfg!(test))?;
+
+    let cancelled = DebugCommandQueue::get(state.db()).cancel(state.db(), id)?;
+    let r = WebSocketMessagesEncodable::Success(json!({"debugCommandCancelled": cancelled.to_json()}));
+    Ok(state.add_response(r))
+}
+
+pub fn execute_queued(id: u64, state: State) -> Result<State, SentinelError> {
+    debug!("executing queued debug command {id}...");
+    let queued = DebugCommandQueue::get(state.db()).dequeue(state.db(), id, get_utc_timestamp()?)?;
+    info!("executing queued debug command: {}", queued.to_json());
+    Ok(state.add_queued_msg(queued.command().clone()))
+}
@@ -0,0 +1,41 @@
+use common_debug_signers::{validate_debug_management_command_signatures, DebugSignature};
+use common_eth::{ChainDbUtils, EthPublicKey};
+use common_network_ids::NetworkId;
+use common_sentinel::{CoreStateArchive, EncryptedCoreStateArchive, SentinelError, WebSocketMessagesEncodable};
+use function_name::named;
+use serde_json::json;
+
+use crate::android::{State, CORE_TYPE};
+
+// NOTE: An archive holds all of the core's chain state & its debug signatories & their policies, and
+// so exporting one requires as many signatures as any command managing those.
+#[named]
+pub fn export_core_state(
+    network_ids: Vec<NetworkId>,
+    recipient: Vec<u8>,
+    sig: DebugSignature,
+    state: State,
+) -> Result<State, SentinelError> {
+    debug!("handling `exportCoreState` in strongbox...");
+    let h = get_debug_command_hash!(function_name!(), &network_ids, &recipient)()?;
+    validate_debug_management_command_signatures(
+        state.db(),
+        &CORE_TYPE,
+        function_name!(),
+        &sig.to_string(),
+        &h,
+        cfg!(test),
+    )?;
+
+    let recipient_public_key = EthPublicKey::from_slice(&recipient)?;
+    let pk = ChainDbUtils::new(state.db()).get_pk()?;
+    let archive = CoreStateArchive::from_db(state.db(), network_ids)?;
+    let num_entries = archive.entries().len();
+    let encrypted_archive = EncryptedCoreStateArchive::new(&archive, &pk, &recipient_public_key)?;
+
+    let r = WebSocketMessagesEncodable::Success(json!({
+        "numEntries": num_entries,
+        "archive": encrypted_archive,
+    }));
+    Ok(state.add_response(r))
+}
@@ -1,4 +1,4 @@
-use common_debug_signers::{validate_debug_command_signature, DebugSignature};
+use common_debug_signers::{validate_debug_command_signatures, DebugSignature};
 use common_eth::ChainDbUtils;
 use common_sentinel::{get_registration_signature as get_reg_sig, SentinelError, WebSocketMessagesEncodable};
 use ethereum_types::Address as EthAddress;
@@ -15,7 +15,14 @@ pub fn get_registration_signature(
     state: State,
 ) -> Result<State, SentinelError> {
     let h = get_debug_command_hash!(function_name!(), &a, &n)()?;
-    validate_debug_command_signature(state.db(), &CORE_TYPE, &sig.to_string(), &h, cfg!(test))?;
+    validate_debug_command_signatures(
+        state.db(),
+        &CORE_TYPE,
+        function_name!(),
+        &sig.to_string(),
+        &h,
+        cfg!(test),
+    )?;
 
     let owner = format!("0x{}", hex::encode(a));
     debug!("handling `GetRegistationSignature` for owner address {owner} in strongbox...");
@@ -1,14 +1,17 @@
+use common::DatabaseInterface;
 use common_eth::{Chain, ChainDbUtils};
 use common_metadata::MetadataChainId;
 use common_network_ids::{NetworkId, NetworkIdError};
-use common_sentinel::{SentinelError, SentinelStatus, WebSocketMessagesEncodable};
+use common_sentinel::{DebugCommandQueue, GovernanceState, SentinelError, SentinelStatus, WebSocketMessagesEncodable};
 use serde_json::json;
 
 use crate::android::State;
 
-pub fn get_status(network_ids: Vec<NetworkId>, state: State) -> Result<State, SentinelError> {
-    debug!("handling `getStatus` message in strongbox...");
-    let db_utils = ChainDbUtils::new(state.db());
+pub(super) fn get_sentinel_status<D: DatabaseInterface>(
+    db: &D,
+    network_ids: &[NetworkId],
+) -> Result<SentinelStatus, SentinelError> {
+    let db_utils = ChainDbUtils::new(db);
     let mcids = network_ids
         .iter()
         .map(MetadataChainId::try_from)
@@ -20,8 +23,21 @@ pub fn get_status(network_ids: Vec<NetworkId>, state: State) -> Result<State, Se
 
     let key = db_utils.get_pk()?;
 
-    let status = SentinelStatus::new(&key, chains)?;
+    SentinelStatus::new(&key, chains)
+}
+
+pub fn get_status(network_ids: Vec<NetworkId>, state: State) -> Result<State, SentinelError> {
+    debug!("handling `getStatus` message in strongbox...");
+    let status = get_sentinel_status(state.db(), &network_ids)?;
+
+    // NOTE: The debug command queue & governance state are not part of the signed status, and so are
+    // added alongside it.
+    let mut j = json!(status);
+    if let Some(m) = j.as_object_mut() {
+        m.insert("debugCommandQueue".into(), DebugCommandQueue::get(state.db()).to_json());
+        m.insert("governance".into(), json!(GovernanceState::get(state.db())));
+    };
 
-    let r = WebSocketMessagesEncodable::Success(json!(status));
+    let r = WebSocketMessagesEncodable::Success(j);
     Ok(state.add_response(r))
 }
@@ -0,0 +1,50 @@
+use common_debug_signers::{validate_debug_management_command_signatures, DebugSignature};
+use common_eth::ChainDbUtils;
+use common_sentinel::{EncryptedCoreStateArchive, SentinelError, TrustedArchiveSigner, WebSocketMessagesEncodable};
+use function_name::named;
+use serde_json::json;
+
+use super::maybe_queue_debug_command;
+use crate::android::{State, CORE_TYPE};
+
+// NOTE: Since an archive replaces the debug signatories & their policies, importing one requires as
+// many signatures as any command managing those, and it must have been signed by the core this one
+// has been told to trust via `set_trusted_archive_signer`.
+#[named]
+pub fn import_core_state(
+    encrypted_archive: EncryptedCoreStateArchive,
+    sig: DebugSignature,
+    state: State,
+) -> Result<State, SentinelError> {
+    debug!("handling `importCoreState` in strongbox...");
+    let h = get_debug_command_hash!(function_name!(), &encrypted_archive)()?;
+    validate_debug_management_command_signatures(
+        state.db(),
+        &CORE_TYPE,
+        function_name!(),
+        &sig.to_string(),
+        &h,
+        cfg!(test) || *state.is_queued_execution(),
+    )?;
+
+    if let Some(r) = maybe_queue_debug_command(&state)? {
+        return Ok(state.add_response(r));
+    };
+
+    // NOTE: The db integrity has already been checked upon starting the db transaction for this
+    // message, so here we need only verify the archive itself before writing anything.
+    let signer = TrustedArchiveSigner::get(state.db()).get_signer()?;
+    encrypted_archive.verify(&signer)?;
+    let pk = ChainDbUtils::new(state.db()).get_pk()?;
+    let archive = encrypted_archive.decrypt(&pk)?;
+    archive.write_to_db(state.db())?;
+
+    let r = WebSocketMessagesEncodable::Success(json!({
+        "coreStateImported": true,
+        "signer": signer,
+        "networkIds": archive.network_ids(),
+        "numEntries": archive.entries().len(),
+        "archiveTimestamp": archive.timestamp(),
+    }));
+    Ok(state.add_response(r))
+}
@@ -1,6 +1,6 @@
 use common_eth::{Chain, ChainDbUtils};
 use common_metadata::MetadataChainId;
-use common_sentinel::{SentinelError, WebSocketMessagesEncodable, WebSocketMessagesInitArgs};
+use common_sentinel::{EvmChains, SentinelError, WebSocketMessagesEncodable, WebSocketMessagesInitArgs};
 use serde_json::json;
 
 use crate::android::State;
@@ -8,6 +8,8 @@ use crate::android::State;
 pub fn init(args: WebSocketMessagesInitArgs, state: State) -> Result<State, SentinelError> {
     let network_id = *args.network_id();
     let mcid = MetadataChainId::try_from(network_id)?;
+    // NOTE: So that the fork schedule of any EVM chain registered in the core is used when validating.
+    EvmChains::get(state.db()).register_all()?;
     Chain::init(
         &ChainDbUtils::new(state.db()),
         *args.hub(),
@@ -1,6 +1,11 @@
 mod add_debug_signers;
+mod audit_log;
+mod challenges;
 mod check_init;
+mod create_key;
 mod db_ops;
+mod debug_command_queue;
+mod export_core_state;
 mod get_address;
 mod get_attestation_certificate;
 mod get_attestation_signature;
@@ -10,15 +15,25 @@ mod get_public_key;
 mod get_registration_signature;
 mod get_status;
 mod hard_reset;
+mod import_core_state;
 mod init;
+mod pending_txs;
 mod process_batch;
+mod register_evm_chain;
 mod remove_debug_signer;
 mod reset_chain;
+mod set_debug_signature_policy;
+mod set_trusted_archive_signer;
 
 pub(crate) use self::{
     add_debug_signers::add_debug_signers,
+    audit_log::get_audit_log,
+    challenges::{get_challenge_response, get_challenges, set_challenge_state},
     check_init::check_init,
+    create_key::create_key,
     db_ops::{delete, get, put},
+    debug_command_queue::{cancel_queued, execute_queued, maybe_queue_debug_command, set_debug_command_delay},
+    export_core_state::export_core_state,
     get_address::get_address,
     get_attestation_certificate::get_attestation_certificate,
     get_attestation_signature::get_attestation_signature,
@@ -28,8 +43,13 @@ pub(crate) use self::{
     get_registration_signature::get_registration_signature,
     get_status::get_status,
     hard_reset::hard_reset,
+    import_core_state::import_core_state,
     init::init,
+    pending_txs::{get_pending_txs, update_pending_txs},
     process_batch::process_batch,
+    register_evm_chain::register_evm_chain,
     remove_debug_signer::remove_debug_signer,
     reset_chain::reset_chain,
+    set_debug_signature_policy::set_debug_signature_policy,
+    set_trusted_archive_signer::set_trusted_archive_signer,
 };
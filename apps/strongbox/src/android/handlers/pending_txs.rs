@@ -0,0 +1,18 @@
+use common_sentinel::{PendingTxs, SentinelError, WebSocketMessagesEncodable};
+
+use crate::android::State;
+
+pub fn get_pending_txs(state: State) -> Result<State, SentinelError> {
+    debug!("handling `getPendingTxs` message in strongbox...");
+    let pending_txs = PendingTxs::get(state.db());
+    let r = WebSocketMessagesEncodable::Success(serde_json::to_value(pending_txs)?);
+    Ok(state.add_response(r))
+}
+
+// NOTE: The tx manager in the app owns the pending txs, and the core merely persists them for it.
+pub fn update_pending_txs(pending_txs: PendingTxs, state: State) -> Result<State, SentinelError> {
+    debug!("handling `updatePendingTxs` message in strongbox...");
+    pending_txs.save(state.db())?;
+    let r = WebSocketMessagesEncodable::Success(serde_json::to_value(pending_txs)?);
+    Ok(state.add_response(r))
+}
@@ -20,6 +20,7 @@ pub fn process_batch(args: WebSocketMessagesProcessBatchArgs, state: State) -> R
         network_config,
         *args.reprocess(),
         *args.dry_run(),
+        *args.governance_address(),
     );
 
     let response = match result {
@@ -0,0 +1,40 @@
+use common_chain_ids::EvmChainConfig;
+use common_debug_signers::{validate_debug_command_signatures, DebugSignature};
+use common_sentinel::{EvmChains, SentinelError, WebSocketMessagesEncodable};
+use function_name::named;
+use serde_json::json;
+
+use super::maybe_queue_debug_command;
+use crate::android::{State, CORE_TYPE};
+
+// NOTE: An EVM chain's fork schedule determines which of its blocks the core deems valid, and so
+// registering one requires debug signatures, which commit to the whole of its config.
+#[named]
+pub fn register_evm_chain(config: EvmChainConfig, sig: DebugSignature, state: State) -> Result<State, SentinelError> {
+    debug!(
+        "registering evm chain '{}' with chain id {}...",
+        config.name, config.chain_id
+    );
+    let h = get_debug_command_hash!(function_name!(), &config)()?;
+    validate_debug_command_signatures(
+        state.db(),
+        &CORE_TYPE,
+        function_name!(),
+        &sig.to_string(),
+        &h,
+        cfg!(test) || *state.is_queued_execution(),
+    )?;
+
+    if let Some(r) = maybe_queue_debug_command(&state)? {
+        return Ok(state.add_response(r));
+    };
+
+    let (name, chain_id) = (config.name.clone(), config.chain_id);
+    let mut evm_chains = EvmChains::get(state.db());
+    evm_chains.add(config)?;
+    evm_chains.save(state.db())?;
+
+    Ok(state.add_response(WebSocketMessagesEncodable::Success(
+        json!({"evmChainRegistered": {"name": name, "chainId": chain_id}}),
+    )))
+}
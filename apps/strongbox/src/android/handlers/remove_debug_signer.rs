@@ -1,16 +1,37 @@
-use common_debug_signers::{debug_remove_debug_signer_with_options, DebugSignature};
+use common_debug_signers::{
+    debug_remove_debug_signer_with_options,
+    validate_debug_remove_debug_signer_signatures,
+    DebugSignature,
+};
 use common_sentinel::{SentinelError, WebSocketMessagesEncodable};
 use serde_json::json;
 
+use super::maybe_queue_debug_command;
 use crate::android::{State, CORE_TYPE};
 
 pub fn remove_debug_signer(signer: String, sig: DebugSignature, state: State) -> Result<State, SentinelError> {
-    // NOTE: The `debug_remove_...` fxn in the debug_signers crate handleds the validation of a signature for
-    // itself, so we don't have to do it in here. It also gives us the option to use db txs which
-    // we don't want since that'll cause duplicate tx errors in java.
+    // NOTE: We don't use the db txs of the `debug_remove_...` fxn in the debug_signers crate since
+    // that'll cause duplicate tx errors in java. As with adding signers, we validate the
+    // signature(s) ourselves so that a queued removal isn't validated twice.
     let use_db_tx = false;
+    let validate_signatures = false;
 
-    debug_remove_debug_signer_with_options(state.db(), &signer, &CORE_TYPE, &sig.to_string(), use_db_tx)?;
+    if !*state.is_queued_execution() {
+        validate_debug_remove_debug_signer_signatures(state.db(), &signer, &CORE_TYPE, &sig.to_string())?;
+    };
+
+    if let Some(r) = maybe_queue_debug_command(&state)? {
+        return Ok(state.add_response(r));
+    };
+
+    debug_remove_debug_signer_with_options(
+        state.db(),
+        &signer,
+        &CORE_TYPE,
+        &sig.to_string(),
+        use_db_tx,
+        validate_signatures,
+    )?;
 
     let msg = WebSocketMessagesEncodable::Success(json!({"debugSignerRemoved": signer}));
 
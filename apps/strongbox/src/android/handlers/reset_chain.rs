@@ -1,17 +1,63 @@
+use common::DatabaseInterface;
+use common_debug_signers::validate_debug_command_signatures;
 use common_eth::{Chain, ChainDbUtils};
 use common_metadata::MetadataChainId;
 use common_sentinel::{
+    EvmChains,
     SentinelError,
     WebSocketMessagesEncodable,
     WebSocketMessagesError,
     WebSocketMessagesResetChainArgs,
 };
+use function_name::named;
 use serde_json::json;
 
-use crate::android::State;
+use super::maybe_queue_debug_command;
+use crate::android::{State, CORE_TYPE};
 
+// NOTE: Resetting a chain changes which blocks the core will sign events from, and so it requires
+// debug signatures, just like any other debug command. The sub mat is fetched by the rpc server
+// after the command is signed, so the signature commits to the requested block number instead,
+// which is `None` when resetting to the latest.
+fn validate_signatures<D: DatabaseInterface>(
+    db: &D,
+    debug_command: &str,
+    args: &WebSocketMessagesResetChainArgs,
+    skip_validation: bool,
+) -> Result<(), SentinelError> {
+    let h = get_debug_command_hash!(
+        debug_command,
+        args.network_id(),
+        args.block_num(),
+        args.confs(),
+        args.validate(),
+        args.hub()
+    )()?;
+    validate_debug_command_signatures(
+        db,
+        &CORE_TYPE,
+        debug_command,
+        &args.sig().to_string(),
+        &h,
+        skip_validation,
+    )?;
+    Ok(())
+}
+
+#[named]
 pub fn reset_chain(args: WebSocketMessagesResetChainArgs, state: State) -> Result<State, SentinelError> {
-    let (confs, validate, network_id, _, _, maybe_hub, maybe_sub_mat) = args.dissolve();
+    validate_signatures(
+        state.db(),
+        function_name!(),
+        &args,
+        cfg!(test) || *state.is_queued_execution(),
+    )?;
+    let (confs, validate, network_id, _, _, maybe_hub, maybe_sub_mat, _) = args.dissolve();
+
+    if let Some(r) = maybe_queue_debug_command(&state)? {
+        return Ok(state.add_response(r));
+    };
+
     let mcid = MetadataChainId::try_from(network_id)?;
     debug!("resetting {network_id} chain...");
 
@@ -24,6 +70,8 @@ pub fn reset_chain(args: WebSocketMessagesResetChainArgs, state: State) -> Resul
     }?;
 
     let n = Chain::block_num(&sub_mat)?;
+    // NOTE: So that the fork schedule of any EVM chain registered in the core is used when validating.
+    EvmChains::get(state.db()).register_all()?;
 
     Chain::reset(
         &ChainDbUtils::new(state.db()),
@@ -38,3 +86,29 @@ pub fn reset_chain(args: WebSocketMessagesResetChainArgs, state: State) -> Resul
         json!({"mcid": mcid, "chainResetTo": n}),
     )))
 }
+
+#[cfg(test)]
+mod tests {
+    use common::get_test_database;
+    use common_debug_signers::{DebugSignatories, DebugSignatory};
+    use ethereum_types::Address as EthAddress;
+
+    use super::*;
+
+    #[test]
+    fn should_reject_unsigned_reset_chain() {
+        let db = get_test_database();
+        DebugSignatories::new(vec![DebugSignatory::new("signer", &EthAddress::random())])
+            .put_in_db(&db)
+            .unwrap();
+        let args = WebSocketMessagesResetChainArgs::try_from(
+            ["EthereumMainnet", "latest", "10", "true"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<String>>(),
+        )
+        .unwrap();
+        let skip_validation = false;
+        assert!(validate_signatures(&db, "reset_chain", &args, skip_validation).is_err());
+    }
+}
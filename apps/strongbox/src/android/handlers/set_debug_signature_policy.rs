@@ -0,0 +1,52 @@
+use common_debug_signers::{
+    debug_set_debug_signature_policy_with_options,
+    validate_debug_set_debug_signature_policy_signatures,
+    DebugSignature,
+};
+use common_sentinel::{SentinelError, WebSocketMessagesEncodable};
+use serde_json::Value as Json;
+
+use super::maybe_queue_debug_command;
+use crate::android::{State, CORE_TYPE};
+
+pub fn set_debug_signature_policy(
+    debug_command: String,
+    threshold: usize,
+    sig: DebugSignature,
+    state: State,
+) -> Result<State, SentinelError> {
+    debug!("setting debug signature policy for '{debug_command}' to {threshold}...");
+    // NOTE: As with the other debug signer fxns, we don't use the debug_signers crate's db txs
+    // since that'll cause duplicate tx errors in java, and we validate the signature(s) ourselves
+    // so that a queued policy change isn't validated twice.
+    let use_db_tx = false;
+    let validate_signatures = false;
+
+    if !*state.is_queued_execution() {
+        validate_debug_set_debug_signature_policy_signatures(
+            state.db(),
+            &debug_command,
+            threshold,
+            &CORE_TYPE,
+            &sig.to_string(),
+        )?;
+    };
+
+    if let Some(r) = maybe_queue_debug_command(&state)? {
+        return Ok(state.add_response(r));
+    };
+
+    let s = debug_set_debug_signature_policy_with_options(
+        state.db(),
+        &debug_command,
+        threshold,
+        &CORE_TYPE,
+        &sig.to_string(),
+        use_db_tx,
+        validate_signatures,
+    )?;
+
+    let msg = WebSocketMessagesEncodable::Success(serde_json::from_str::<Json>(&s)?);
+
+    Ok(state.add_response(msg))
+}
@@ -0,0 +1,38 @@
+use common_debug_signers::{validate_debug_management_command_signatures, DebugSignature};
+use common_sentinel::{SentinelError, TrustedArchiveSigner, WebSocketMessagesEncodable};
+use ethereum_types::Address as EthAddress;
+use function_name::named;
+use serde_json::json;
+
+use super::maybe_queue_debug_command;
+use crate::android::{State, CORE_TYPE};
+
+// NOTE: Sets the address of the core whose state archives this core will import. Since that's
+// tantamount to choosing the next debug signatories, it requires the management threshold.
+#[named]
+pub fn set_trusted_archive_signer(
+    signer: EthAddress,
+    sig: DebugSignature,
+    state: State,
+) -> Result<State, SentinelError> {
+    debug!("handling `setTrustedArchiveSigner` in strongbox...");
+    let h = get_debug_command_hash!(function_name!(), &signer)()?;
+    validate_debug_management_command_signatures(
+        state.db(),
+        &CORE_TYPE,
+        function_name!(),
+        &sig.to_string(),
+        &h,
+        cfg!(test) || *state.is_queued_execution(),
+    )?;
+
+    if let Some(r) = maybe_queue_debug_command(&state)? {
+        return Ok(state.add_response(r));
+    };
+
+    TrustedArchiveSigner::new(Some(signer)).save(state.db())?;
+
+    Ok(state.add_response(WebSocketMessagesEncodable::Success(
+        json!({"trustedArchiveSignerSet": signer}),
+    )))
+}
@@ -1,4 +1,4 @@
-use common_sentinel::{SentinelError, WebSocketMessagesEncodable};
+use common_sentinel::{CorrelationId, SentinelError, WebSocketMessagesEncodable, WebSocketMessagesEnvelope};
 use derive_getters::Getters;
 use jni::{
     objects::{JObject, JString},
@@ -26,6 +26,20 @@ pub struct State<'a> {
     // definitely don't need the db pointer nor JNI env nor empty return values serialized, hence the
     // above skips.
     msg: WebSocketMessagesEncodable,
+
+    // NOTE: Set when the msg came from the debug command queue, whose entries had their debug
+    // signatures validated when they were queued.
+    #[serde(skip_serializing)]
+    is_queued_execution: bool,
+
+    // NOTE: Returned with the response so that the app's logs for this msg may be matched with ours.
+    #[serde(skip_serializing)]
+    correlation_id: Option<CorrelationId>,
+
+    // NOTE: Set when the msg came from an app which predates envelopes, and which thus needs its
+    // response in that same legacy format.
+    #[serde(skip_serializing)]
+    is_legacy_envelope: bool,
 }
 
 impl<'a> State<'a> {
@@ -34,6 +48,12 @@ impl<'a> State<'a> {
         self
     }
 
+    pub fn add_queued_msg(mut self, m: WebSocketMessagesEncodable) -> Self {
+        self.msg = m;
+        self.is_queued_execution = true;
+        self
+    }
+
     pub fn new(
         env: &'a JNIEnv<'a>,
         strongbox_java_class: JObject<'a>,
@@ -42,7 +62,12 @@ impl<'a> State<'a> {
     ) -> Result<Self, SentinelError> {
         let db = Database::new(env, db_java_class);
         let input_string: String = env.get_string(input)?.into();
-        let msg = WebSocketMessagesEncodable::try_from(input_string)?;
+        let envelope = WebSocketMessagesEnvelope::try_from(input_string)?;
+        let is_legacy_envelope = envelope.is_legacy();
+        let (_, correlation_id, msg) = envelope.dissolve();
+        if let Some(ref id) = correlation_id {
+            info!("received msg '{msg}' with correlation id {id}");
+        };
         let strongbox = Strongbox::new(env, strongbox_java_class);
         strongbox.initialize_keystore()?;
         Ok(State {
@@ -51,20 +76,32 @@ impl<'a> State<'a> {
             db,
             res: None,
             strongbox,
+            correlation_id,
+            is_legacy_envelope,
+            is_queued_execution: false,
         })
     }
 
     pub fn to_response(&self) -> Result<*mut JavaPointer, SentinelError> {
-        let s: String = match self.res.clone() {
+        let r = match self.res.clone() {
             // FIXME rm this clone
-            Some(r) => r.try_into(),
+            Some(r) => r,
             None => {
                 // NOTE: We haven't error, but we also don't have a response for some reason
                 // FIXME Should this be an error?
                 warn!("no response in state");
-                WebSocketMessagesEncodable::Null.try_into()
+                WebSocketMessagesEncodable::Null
             },
-        }?;
+        };
+        if let Some(ref id) = self.correlation_id {
+            info!("responding with '{r}' to msg with correlation id {id}");
+        };
+        let envelope = if self.is_legacy_envelope {
+            WebSocketMessagesEnvelope::legacy(r)
+        } else {
+            WebSocketMessagesEnvelope::new(self.correlation_id.clone(), r)
+        };
+        let s: String = envelope.try_into()?;
         self.to_return_value_pointer(&s)
     }
 
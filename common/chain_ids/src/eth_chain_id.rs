@@ -11,7 +11,7 @@ use ethereum_types::{H256 as KeccakHash, U256};
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 
-use super::ChainIdT;
+use super::{ChainIdT, EvmChainRegistry};
 
 #[derive(Clone, Debug, PartialEq, Eq, EnumIter, Serialize, Deserialize)]
 pub enum EthChainId {
@@ -67,9 +67,10 @@ impl FromStr for EthChainId {
             "arbitrummainnet" | "arbitrum" | "42161" => Ok(Self::ArbitrumMainnet),
             "ethmainnet" | "ethereummainnet" | "mainnet" | "1" => Ok(Self::Mainnet),
             "luxomainnet" | "luxo" | "luxochain" | "110" => Ok(Self::LuxochainMainnet),
-            _ => match s.parse::<u64>() {
-                Ok(u_64) => Ok(Self::Unknown(u_64)),
-                Err(_) => Err(format!("✘ Unrecognized ETH network: '{}'!", s).into()),
+            _ => match (s.parse::<u64>(), EvmChainRegistry::get_by_name(s)) {
+                (Ok(u_64), _) => Ok(Self::Unknown(u_64)),
+                (Err(_), Some(c)) => Ok(Self::Unknown(c.chain_id)),
+                (Err(_), None) => Err(format!("✘ Unrecognized ETH network: '{}'!", s).into()),
             },
         }
     }
@@ -93,53 +94,40 @@ const ERC20_VAULT_CHANGE_SUPPORTED_TOKEN_GAS_LIMIT: usize = 100_000;
 const ERC20_VAULT_PEGOUT_WITHOUT_USER_DATA_GAS_LIMIT: usize = 250_000;
 
 impl EthChainId {
-    pub fn get_erc777_change_pnetwork_gas_limit(&self) -> usize {
+    fn gas_multiplier(&self) -> usize {
         match self {
-            Self::ArbitrumMainnet => ARBITRUM_GAS_MULTIPLIER * ERC777_CHANGE_PNETWORK_GAS_LIMIT,
-            _ => ERC777_CHANGE_PNETWORK_GAS_LIMIT,
+            Self::ArbitrumMainnet => ARBITRUM_GAS_MULTIPLIER,
+            Self::Unknown(id) => EvmChainRegistry::get(*id).map_or(1, |c| c.gas_multiplier),
+            _ => 1,
         }
     }
 
+    pub fn get_erc777_change_pnetwork_gas_limit(&self) -> usize {
+        self.gas_multiplier() * ERC777_CHANGE_PNETWORK_GAS_LIMIT
+    }
+
     pub fn get_erc777_mint_with_data_gas_limit(&self) -> usize {
-        match self {
-            Self::ArbitrumMainnet => ARBITRUM_GAS_MULTIPLIER * ERC777_MINT_WITH_DATA_GAS_LIMIT,
-            _ => ERC777_MINT_WITH_DATA_GAS_LIMIT,
-        }
+        self.gas_multiplier() * ERC777_MINT_WITH_DATA_GAS_LIMIT
     }
 
     pub fn get_erc777_mint_with_no_data_gas_limit(&self) -> usize {
-        match self {
-            Self::ArbitrumMainnet => ARBITRUM_GAS_MULTIPLIER * ERC777_MINT_WITH_NO_DATA_GAS_LIMIT,
-            _ => ERC777_MINT_WITH_NO_DATA_GAS_LIMIT,
-        }
+        self.gas_multiplier() * ERC777_MINT_WITH_NO_DATA_GAS_LIMIT
     }
 
     pub fn get_erc20_vault_migrate_gas_limit(&self) -> usize {
-        match self {
-            Self::ArbitrumMainnet => ARBITRUM_GAS_MULTIPLIER * ERC20_VAULT_MIGRATE_GAS_LIMIT,
-            _ => ERC20_VAULT_MIGRATE_GAS_LIMIT,
-        }
+        self.gas_multiplier() * ERC20_VAULT_MIGRATE_GAS_LIMIT
     }
 
     pub fn get_erc20_vault_pegout_without_user_data_gas_limit(&self) -> usize {
-        match self {
-            Self::ArbitrumMainnet => ARBITRUM_GAS_MULTIPLIER * ERC20_VAULT_PEGOUT_WITHOUT_USER_DATA_GAS_LIMIT,
-            _ => ERC20_VAULT_PEGOUT_WITHOUT_USER_DATA_GAS_LIMIT,
-        }
+        self.gas_multiplier() * ERC20_VAULT_PEGOUT_WITHOUT_USER_DATA_GAS_LIMIT
     }
 
     pub fn get_erc20_vault_pegout_with_user_data_gas_limit(&self) -> usize {
-        match self {
-            Self::ArbitrumMainnet => ARBITRUM_GAS_MULTIPLIER * ERC20_VAULT_PEGOUT_WITH_USER_DATA_GAS_LIMIT,
-            _ => ERC20_VAULT_PEGOUT_WITH_USER_DATA_GAS_LIMIT,
-        }
+        self.gas_multiplier() * ERC20_VAULT_PEGOUT_WITH_USER_DATA_GAS_LIMIT
     }
 
     pub fn get_erc20_vault_change_supported_token_gas_limit(&self) -> usize {
-        match self {
-            Self::ArbitrumMainnet => ARBITRUM_GAS_MULTIPLIER * ERC20_VAULT_CHANGE_SUPPORTED_TOKEN_GAS_LIMIT,
-            _ => ERC20_VAULT_CHANGE_SUPPORTED_TOKEN_GAS_LIMIT,
-        }
+        self.gas_multiplier() * ERC20_VAULT_CHANGE_SUPPORTED_TOKEN_GAS_LIMIT
     }
 
     pub fn unknown() -> Self {
@@ -235,7 +223,10 @@ impl EthChainId {
 impl fmt::Display for EthChainId {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Self::Unknown(_) => write!(f, "EthUnknown"),
+            Self::Unknown(id) => match EvmChainRegistry::get(*id) {
+                Some(c) => write!(f, "{}", c.name),
+                None => write!(f, "EthUnknown"),
+            },
             Self::Goerli => write!(f, "EthereumGoerli"),
             Self::BscMainnet => write!(f, "BscMainnet"),
             Self::Sepolia => write!(f, "SepoliaTestnet"),
@@ -355,4 +346,29 @@ mod tests {
             .enumerate()
             .for_each(|(i, chain_id_hex)| assert_eq!(chain_id_hex, expected_chain_ids_keccak_hashes[i]));
     }
+
+    #[test]
+    fn should_use_registered_evm_chain_for_name_and_gas_multiplier() {
+        use crate::EvmChainConfig;
+
+        let _registry = EvmChainRegistry::lock_for_test();
+        EvmChainRegistry::register(EvmChainConfig {
+            chain_id: 81457,
+            name: "blast".into(),
+            gas_multiplier: 3,
+            ..Default::default()
+        })
+        .unwrap();
+        let id = EthChainId::from_str("blast").unwrap();
+        assert_eq!(id, EthChainId::Unknown(81457));
+        assert_eq!(id.to_string(), "blast");
+        assert_eq!(
+            id.get_erc777_mint_with_no_data_gas_limit(),
+            3 * ERC777_MINT_WITH_NO_DATA_GAS_LIMIT
+        );
+        assert_eq!(
+            EthChainId::Unknown(1337).get_erc777_mint_with_no_data_gas_limit(),
+            ERC777_MINT_WITH_NO_DATA_GAS_LIMIT
+        );
+    }
 }
@@ -0,0 +1,205 @@
+#[cfg(any(test, feature = "test-utils"))]
+use std::sync::{Mutex, MutexGuard};
+use std::{collections::HashMap, sync::RwLock};
+
+use common::types::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::EthChainId;
+
+lazy_static! {
+    static ref EVM_CHAIN_REGISTRY: RwLock<HashMap<u64, EvmChainConfig>> = RwLock::new(HashMap::new());
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+lazy_static! {
+    static ref EVM_CHAIN_REGISTRY_TEST_LOCK: Mutex<()> = Mutex::new(());
+}
+
+fn default_gas_multiplier() -> usize {
+    1
+}
+
+/// The activation of a named hardfork, at either a block number or a timestamp.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EvmForkConfig {
+    pub fork: String,
+    pub block: Option<u64>,
+    pub timestamp: Option<u64>,
+}
+
+/// Describes an EVM chain which has no `EthChainId` variant of its own, so that it may be supported
+/// without a code release.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EvmChainConfig {
+    pub name: String,
+    pub chain_id: u64,
+    // NOTE: Optional, since the metadata chain id is derived from the chain id. If set, it's
+    // checked against that derived value.
+    #[serde(default)]
+    pub metadata_chain_id: Option<String>,
+    #[serde(default = "default_gas_multiplier")]
+    pub gas_multiplier: usize,
+    #[serde(default)]
+    pub forks: Vec<EvmForkConfig>,
+}
+
+pub struct EvmChainRegistry;
+
+impl EvmChainRegistry {
+    /// Adds the given chain to the registry, replacing any prior entry for the same chain id.
+    pub fn register(config: EvmChainConfig) -> Result<()> {
+        let chain_id = config.chain_id;
+        let name = config.name.to_lowercase();
+
+        if chain_id == 0 {
+            return Err("cannot register an evm chain with a chain id of 0".into());
+        };
+
+        if !matches!(EthChainId::try_from(chain_id)?, EthChainId::Unknown(_)) {
+            return Err(format!("evm chain id {chain_id} is already supported and cannot be registered").into());
+        };
+
+        if name.is_empty() || name.parse::<u64>().is_ok() {
+            return Err(format!("invalid name '{}' for evm chain id {chain_id}", config.name).into());
+        };
+
+        if config.gas_multiplier == 0 {
+            return Err(format!("gas multiplier for evm chain id {chain_id} must be greater than 0").into());
+        };
+
+        let mut registry = EVM_CHAIN_REGISTRY
+            .write()
+            .map_err(|e| format!("evm chain registry lock poisoned: {e}"))?;
+
+        if registry
+            .values()
+            .any(|c| c.chain_id != chain_id && c.name.to_lowercase() == name)
+        {
+            return Err(format!("evm chain name '{}' is already registered", config.name).into());
+        };
+
+        info!("registering evm chain '{}' with chain id {chain_id}", config.name);
+        registry.insert(chain_id, config);
+        Ok(())
+    }
+
+    pub fn get(chain_id: u64) -> Option<EvmChainConfig> {
+        EVM_CHAIN_REGISTRY
+            .read()
+            .ok()
+            .and_then(|registry| registry.get(&chain_id).cloned())
+    }
+
+    pub fn get_by_name(name: &str) -> Option<EvmChainConfig> {
+        let name = name.to_lowercase();
+        EVM_CHAIN_REGISTRY
+            .read()
+            .ok()
+            .and_then(|registry| registry.values().find(|c| c.name.to_lowercase() == name).cloned())
+    }
+
+    pub fn chain_ids() -> Vec<u64> {
+        EVM_CHAIN_REGISTRY
+            .read()
+            .map(|registry| registry.keys().copied().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn is_registered(chain_id: u64) -> bool {
+        Self::get(chain_id).is_some()
+    }
+
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn clear() {
+        if let Ok(mut registry) = EVM_CHAIN_REGISTRY.write() {
+            registry.clear()
+        };
+    }
+
+    /// Test-only exclusive use of the registry, which is cleared both upon acquiring the returned
+    /// guard and upon dropping it, so that tests which register chains are run one at a time and
+    /// can neither see nor leak into each other's registrations.
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn lock_for_test() -> EvmChainRegistryTestGuard {
+        // NOTE: A test which panics whilst holding the lock poisons it, but the registry is cleared
+        // regardless, so it's safe to carry on.
+        let lock = EVM_CHAIN_REGISTRY_TEST_LOCK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        Self::clear();
+        EvmChainRegistryTestGuard { _lock: lock }
+    }
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+pub struct EvmChainRegistryTestGuard {
+    _lock: MutexGuard<'static, ()>,
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl Drop for EvmChainRegistryTestGuard {
+    fn drop(&mut self) {
+        EvmChainRegistry::clear()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_sample_config(chain_id: u64, name: &str) -> EvmChainConfig {
+        EvmChainConfig {
+            chain_id,
+            name: name.into(),
+            gas_multiplier: 1,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn should_register_and_get_evm_chain() {
+        let _registry = EvmChainRegistry::lock_for_test();
+        let config = get_sample_config(8453, "base");
+        EvmChainRegistry::register(config.clone()).unwrap();
+        assert_eq!(EvmChainRegistry::get(8453), Some(config.clone()));
+        assert_eq!(EvmChainRegistry::get_by_name("BASE"), Some(config));
+        assert!(EvmChainRegistry::chain_ids().contains(&8453));
+    }
+
+    #[test]
+    fn should_not_register_already_supported_chain() {
+        let result = EvmChainRegistry::register(get_sample_config(1, "ethereum-again"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_not_register_chain_with_zero_chain_id() {
+        assert!(EvmChainRegistry::register(get_sample_config(0, "zero")).is_err());
+    }
+
+    #[test]
+    fn should_not_register_same_name_for_different_chain_ids() {
+        let _registry = EvmChainRegistry::lock_for_test();
+        EvmChainRegistry::register(get_sample_config(59144, "linea")).unwrap();
+        assert!(EvmChainRegistry::register(get_sample_config(59145, "Linea")).is_err());
+    }
+
+    #[test]
+    fn should_not_register_chain_with_zero_gas_multiplier() {
+        let mut config = get_sample_config(10, "optimism");
+        config.gas_multiplier = 0;
+        assert!(EvmChainRegistry::register(config).is_err());
+    }
+
+    #[test]
+    fn should_clear_registry_once_test_guard_is_dropped() {
+        {
+            let _registry = EvmChainRegistry::lock_for_test();
+            EvmChainRegistry::register(get_sample_config(42161, "arbitrum")).unwrap();
+            assert!(EvmChainRegistry::is_registered(42161));
+        }
+        let _registry = EvmChainRegistry::lock_for_test();
+        assert!(EvmChainRegistry::chain_ids().is_empty());
+    }
+}
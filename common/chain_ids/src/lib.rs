@@ -3,13 +3,17 @@ mod btc_chain_id;
 mod chain_id_traits;
 mod eos_chain_id;
 mod eth_chain_id;
+mod evm_chain_registry;
 
+#[cfg(any(test, feature = "test-utils"))]
+pub use self::evm_chain_registry::EvmChainRegistryTestGuard;
 pub use self::{
     algo_chain_id::AlgoChainId,
     btc_chain_id::BtcChainId,
     chain_id_traits::ChainIdT,
     eos_chain_id::EosChainId,
     eth_chain_id::EthChainId,
+    evm_chain_registry::{EvmChainConfig, EvmChainRegistry, EvmForkConfig},
 };
 
 #[macro_use]
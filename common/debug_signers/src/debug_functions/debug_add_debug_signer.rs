@@ -4,7 +4,13 @@ use common::{core_type::CoreType, traits::DatabaseInterface, types::Result};
 use common_eth::{convert_hex_to_eth_address, convert_hex_to_h256, EthSignature};
 use serde_json::json;
 
-use crate::{DebugSignatories, DebugSignatory, SAFE_DEBUG_SIGNATORIES};
+use crate::{
+    validate_debug_command_signature::parse_debug_signatures,
+    DebugSignatories,
+    DebugSignatory,
+    DebugSignaturePolicies,
+    SAFE_DEBUG_SIGNATORIES,
+};
 
 /// Debug Add Debug Signer With Options
 ///
@@ -13,6 +19,8 @@ use crate::{DebugSignatories, DebugSignatory, SAFE_DEBUG_SIGNATORIES};
 /// empty, we have a chicken and egg scenario. And so to solve this, if the addition is the first_
 /// one, we instead require a signature from the `SAFE_ETH_ADDRESS` in order to validate the
 /// command. This requirement can be disabled with a passed in boolean, as can the use of db txs.
+/// Subsequent additions require as many signatures as the strictest debug signature policy, passed
+/// in comma separated.
 pub fn debug_add_debug_signer_with_options<D: DatabaseInterface>(
     db: &D,
     signatory_name: &str,
@@ -57,7 +65,13 @@ pub fn debug_add_debug_signer_with_options<D: DatabaseInterface>(
                 }
             } else {
                 debug_signatories
-                    .maybe_validate_signature_and_increment_nonce_in_db(db, core_type, &debug_command_hash, &signature)
+                    .maybe_validate_signatures_and_increment_nonces_in_db(
+                        db,
+                        core_type,
+                        &debug_command_hash,
+                        &parse_debug_signatures(signature_str)?,
+                        DebugSignaturePolicies::get_from_db(db)?.get_management_threshold("debug_add_debug_signer"),
+                    )
                     .and_then(|_| DebugSignatories::get_from_db(db))
                     .and_then(|debug_signatories| debug_signatories.add_and_update_in_db(db, &debug_signatory_to_add))
             }
@@ -6,7 +6,13 @@ use derive_more::Deref;
 use serde::Deserialize;
 use serde_json::json;
 
-use crate::{DebugSignatories, DebugSignatory, SAFE_DEBUG_SIGNATORIES};
+use crate::{
+    validate_debug_command_signature::parse_debug_signatures,
+    DebugSignatories,
+    DebugSignatory,
+    DebugSignaturePolicies,
+    SAFE_DEBUG_SIGNATORIES,
+};
 
 #[derive(Deserialize, Deref)]
 struct DebugSignersJson(Vec<DebugSignerJson>);
@@ -45,6 +51,52 @@ impl FromStr for DebugSignerJson {
     }
 }
 
+/// Validate Debug Add Multiple Debug Signers Signatures
+///
+/// Validates the signature(s) over the addition of the given debug signers, as per
+/// `debug_add_multiple_debug_signers_with_options`, incrementing the nonces of those who signed,
+/// but without adding anyone. Useful where the addition itself happens later, eg once the command
+/// has waited out a time lock.
+pub fn validate_debug_add_multiple_debug_signers_signatures<D: DatabaseInterface>(
+    db: &D,
+    debug_signers_json: &str,
+    core_type: &CoreType,
+    signature_str: &str,
+    use_safe_debug_signers: bool,
+) -> Result<()> {
+    let debug_signatories = DebugSignatories::get_from_db(db)?;
+    let debug_command_hash = convert_hex_to_h256(&get_debug_command_hash!(
+        "debug_add_multiple_debug_signers", /* NOTE: Cannot use function name else it wouldn't be backwards
+                                             * compatible */
+        debug_signers_json,
+        core_type
+    )()?)?;
+
+    if debug_signatories.is_empty() {
+        let msg = "validating debug signers addition using the safe address...";
+        if use_safe_debug_signers {
+            debug!("{msg}");
+            SAFE_DEBUG_SIGNATORIES.maybe_validate_signature_and_increment_nonce_in_db(
+                db,
+                core_type,
+                &debug_command_hash,
+                &EthSignature::from_str(signature_str)?,
+            )
+        } else {
+            debug!("not {msg}");
+            Ok(())
+        }
+    } else {
+        debug_signatories.maybe_validate_signatures_and_increment_nonces_in_db(
+            db,
+            core_type,
+            &debug_command_hash,
+            &parse_debug_signatures(signature_str)?,
+            DebugSignaturePolicies::get_from_db(db)?.get_management_threshold("debug_add_multiple_debug_signers"),
+        )
+    }
+}
+
 /// Debug Add Multiple Debug Signers With Options
 ///
 /// Adds new debug signatories to the list. Since this is a debug function, it requires a valid
@@ -52,6 +104,9 @@ impl FromStr for DebugSignerJson {
 /// empty, we have a chicken and egg scenario. And so to solve this, if the addition is the _first_
 /// one, we instead require a signature from the `SAFE_ETH_ADDRESS` in order to validate the
 /// command. This requirement can be disabled with a passed in boolean, as can the use of db txs.
+/// Subsequent additions require as many signatures as the strictest debug signature policy, passed
+/// in comma separated. The validation may be skipped entirely if the caller has already done it
+/// via `validate_debug_add_multiple_debug_signers_signatures`.
 pub fn debug_add_multiple_debug_signers_with_options<D: DatabaseInterface>(
     db: &D,
     debug_signers_json: &str,
@@ -59,6 +114,7 @@ pub fn debug_add_multiple_debug_signers_with_options<D: DatabaseInterface>(
     signature_str: &str,
     use_safe_debug_signers: bool,
     use_db_tx: bool,
+    validate_signatures: bool,
 ) -> Result<String> {
     info!("adding multiple debug signer to list...");
     let debug_signatories_to_add = DebugSignersJson::from_str(debug_signers_json)?.to_debug_signatories()?;
@@ -69,39 +125,24 @@ pub fn debug_add_multiple_debug_signers_with_options<D: DatabaseInterface>(
 
     DebugSignatories::get_from_db(db)
         .and_then(|debug_signatories| {
-            let debug_command_hash = convert_hex_to_h256(&get_debug_command_hash!(
-                "debug_add_multiple_debug_signers", /* NOTE: Cannot use function name else it wouldn't be backwards
-                                                     * compatible */
-                debug_signers_json,
-                core_type
-            )()?)?;
-            let signature = EthSignature::from_str(signature_str)?;
-
+            if validate_signatures {
+                validate_debug_add_multiple_debug_signers_signatures(
+                    db,
+                    debug_signers_json,
+                    core_type,
+                    signature_str,
+                    use_safe_debug_signers,
+                )?;
+            };
+            // NOTE: Validating via the safe address would write it to the db as a signatory, so
+            // when starting from an empty list we add to that instead of whatever's now in the db.
             if debug_signatories.is_empty() {
-                let msg = "validating debug signers addition using the safe address...";
-                if use_safe_debug_signers {
-                    debug!("{msg}");
-                    SAFE_DEBUG_SIGNATORIES
-                        .maybe_validate_signature_and_increment_nonce_in_db(
-                            db,
-                            core_type,
-                            &debug_command_hash,
-                            &signature,
-                        )
-                        .and_then(|_| debug_signatories.add_multi_and_update_in_db(db, &debug_signatories_to_add))
-                } else {
-                    debug!("not {msg}");
-                    debug_signatories.add_multi_and_update_in_db(db, &debug_signatories_to_add)
-                }
+                Ok(debug_signatories)
             } else {
-                debug_signatories
-                    .maybe_validate_signature_and_increment_nonce_in_db(db, core_type, &debug_command_hash, &signature)
-                    .and_then(|_| DebugSignatories::get_from_db(db))
-                    .and_then(|debug_signatories| {
-                        debug_signatories.add_multi_and_update_in_db(db, &debug_signatories_to_add)
-                    })
+                DebugSignatories::get_from_db(db)
             }
         })
+        .and_then(|debug_signatories| debug_signatories.add_multi_and_update_in_db(db, &debug_signatories_to_add))
         .and_then(|_| if use_db_tx { db.end_transaction() } else { Ok(()) })
         .map(|_| {
             json!({
@@ -122,7 +163,7 @@ pub fn debug_add_multiple_debug_signers<D: DatabaseInterface>(
     core_type: &CoreType,
     signature_str: &str,
 ) -> Result<String> {
-    debug_add_multiple_debug_signers_with_options(db, debug_signers_json, core_type, signature_str, true, true)
+    debug_add_multiple_debug_signers_with_options(db, debug_signers_json, core_type, signature_str, true, true, true)
 }
 
 #[cfg(test)]
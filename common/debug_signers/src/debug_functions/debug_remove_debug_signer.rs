@@ -1,43 +1,71 @@
-use std::str::FromStr;
-
 use common::{core_type::CoreType, traits::DatabaseInterface, types::Result};
-use common_eth::{convert_hex_to_eth_address, convert_hex_to_h256, EthSignature};
+use common_eth::{convert_hex_to_eth_address, convert_hex_to_h256};
 use serde_json::json;
 
-use crate::DebugSignatories;
+use crate::{validate_debug_command_signature::parse_debug_signatures, DebugSignatories, DebugSignaturePolicies};
+
+const DEBUG_COMMAND: &str = "debug_remove_debug_signer";
+
+/// Validate Debug Remove Debug Signer Signatures
+///
+/// Validates the signature(s) over the removal of the given debug signatory, as per
+/// `debug_remove_debug_signer_with_options`, incrementing the nonces of those who signed, but
+/// without removing anyone.
+pub fn validate_debug_remove_debug_signer_signatures<D: DatabaseInterface>(
+    db: &D,
+    eth_address_str: &str,
+    core_type: &CoreType,
+    signature_str: &str,
+) -> Result<()> {
+    let debug_command_hash = convert_hex_to_h256(&get_debug_command_hash!(
+        DEBUG_COMMAND, // NOTE: Can't use fxn name else it would be backwards compatible
+        eth_address_str,
+        core_type
+    )()?)?;
+    DebugSignatories::get_from_db(db)?.maybe_validate_signatures_and_increment_nonces_in_db(
+        db,
+        core_type,
+        &debug_command_hash,
+        &parse_debug_signatures(signature_str)?,
+        DebugSignaturePolicies::get_from_db(db)?.get_management_threshold(DEBUG_COMMAND),
+    )
+}
 
 /// Debug Remove Debug Signer With Options
 ///
 /// Removes a debug signatory from the list. Requires a valid signature from an existing debug
-/// signatory in order to do so. If the supplied eth address is not in the list of debug
-/// debug_signatories, nothing is removed. Can optionally use db txs.
+/// signatory in order to do so, or as many as the strictest debug signature policy requires, in
+/// which case the signatures are passed in comma separated. If the supplied eth address is not in
+/// the list of debug debug_signatories, nothing is removed. A signatory cannot be removed if doing
+/// so would leave too few signatories to satisfy any debug signature policy. Can optionally use db
+/// txs, and can skip the validation if the caller has already done it via
+/// `validate_debug_remove_debug_signer_signatures`.
 pub fn debug_remove_debug_signer_with_options<D: DatabaseInterface>(
     db: &D,
     eth_address_str: &str,
     core_type: &CoreType,
     signature_str: &str,
     use_db_tx: bool,
+    validate_signatures: bool,
 ) -> Result<String> {
+    let eth_address = convert_hex_to_eth_address(eth_address_str)?;
+
     if use_db_tx {
         db.start_transaction()?
     };
 
-    DebugSignatories::get_from_db(db)
-        .and_then(|debug_signatories| {
-            let signature = EthSignature::from_str(signature_str)?;
-            let eth_address = convert_hex_to_eth_address(eth_address_str)?;
-            let debug_command_hash = convert_hex_to_h256(&get_debug_command_hash!(
-                "debug_remove_debug_signer", // NOTE: Can't use fxn name else it would be backwards compatible
-                eth_address_str,
-                core_type
-            )()?)?;
-            debug_signatories
-                .maybe_validate_signature_and_increment_nonce_in_db(db, core_type, &debug_command_hash, &signature)
-                .and_then(|_| DebugSignatories::get_from_db(db))
-                .and_then(|debug_signatories| debug_signatories.remove_and_update_in_db(db, &eth_address))
-        })
-        .and_then(|_| if use_db_tx { db.end_transaction() } else { Ok(()) })
-        .map(|_| json!({"debugRemoveSignatorySuccess":true, "ethAddress": eth_address_str}).to_string())
+    (if validate_signatures {
+        validate_debug_remove_debug_signer_signatures(db, eth_address_str, core_type, signature_str)
+    } else {
+        Ok(())
+    })
+    .and_then(|_| DebugSignatories::get_from_db(db))
+    .and_then(|debug_signatories| {
+        DebugSignaturePolicies::get_from_db(db)?.check_satisfiable(&debug_signatories.remove(&eth_address))?;
+        debug_signatories.remove_and_update_in_db(db, &eth_address)
+    })
+    .and_then(|_| if use_db_tx { db.end_transaction() } else { Ok(()) })
+    .map(|_| json!({"debugRemoveSignatorySuccess":true, "ethAddress": eth_address_str}).to_string())
 }
 
 /// Debug Remove Debug Signer
@@ -50,5 +78,5 @@ pub fn debug_remove_debug_signer<D: DatabaseInterface>(
     core_type: &CoreType,
     signature_str: &str,
 ) -> Result<String> {
-    debug_remove_debug_signer_with_options(db, eth_address_str, core_type, signature_str, true)
+    debug_remove_debug_signer_with_options(db, eth_address_str, core_type, signature_str, true, true)
 }
@@ -0,0 +1,133 @@
+use common::{core_type::CoreType, traits::DatabaseInterface, types::Result};
+use common_eth::convert_hex_to_h256;
+use serde_json::json;
+
+use crate::{validate_debug_command_signature::parse_debug_signatures, DebugSignatories, DebugSignaturePolicies};
+
+const DEBUG_COMMAND: &str = "debug_set_debug_signature_policy";
+
+/// Validate Debug Set Debug Signature Policy Signatures
+///
+/// Validates the signature(s) over the setting of the given debug signature policy, as per
+/// `debug_set_debug_signature_policy_with_options`, incrementing the nonces of those who signed,
+/// but without setting anything.
+pub fn validate_debug_set_debug_signature_policy_signatures<D: DatabaseInterface>(
+    db: &D,
+    debug_command: &str,
+    threshold: usize,
+    core_type: &CoreType,
+    signature_str: &str,
+) -> Result<()> {
+    let debug_command_hash = convert_hex_to_h256(&get_debug_command_hash!(
+        DEBUG_COMMAND,
+        debug_command,
+        &threshold,
+        core_type
+    )()?)?;
+    DebugSignatories::get_from_db(db)?.maybe_validate_signatures_and_increment_nonces_in_db(
+        db,
+        core_type,
+        &debug_command_hash,
+        &parse_debug_signatures(signature_str)?,
+        DebugSignaturePolicies::get_from_db(db)?.get_management_threshold(DEBUG_COMMAND),
+    )
+}
+
+/// Debug Set Debug Signature Policy With Options
+///
+/// Sets the number of distinct debug signatories required to sign over the given debug command in
+/// order for it to run. Requires as many signatures as the strictest existing policy, passed in
+/// comma separated, so that a threshold can only be lowered by as many signatories as it took to
+/// raise it. The threshold must be at least one, and cannot exceed the number of debug
+/// signatories. Can optionally use db txs, and can skip the validation if the caller has already
+/// done it via `validate_debug_set_debug_signature_policy_signatures`.
+pub fn debug_set_debug_signature_policy_with_options<D: DatabaseInterface>(
+    db: &D,
+    debug_command: &str,
+    threshold: usize,
+    core_type: &CoreType,
+    signature_str: &str,
+    use_db_tx: bool,
+    validate_signatures: bool,
+) -> Result<String> {
+    info!("✔ Setting debug signature policy for '{debug_command}' to {threshold}...");
+    if use_db_tx {
+        db.start_transaction()?
+    };
+
+    (if validate_signatures {
+        validate_debug_set_debug_signature_policy_signatures(db, debug_command, threshold, core_type, signature_str)
+    } else {
+        Ok(())
+    })
+    .and_then(|_| DebugSignaturePolicies::get_from_db(db))
+    .and_then(|policies| policies.set_and_update_in_db(db, debug_command, threshold))
+    .and_then(|policies| {
+        if use_db_tx {
+            db.end_transaction()?
+        };
+        Ok(policies)
+    })
+    .map(|policies| {
+        json!({
+            "debugSetDebugSignaturePolicySuccess": true,
+            "debugCommand": debug_command,
+            "threshold": threshold,
+            "policies": policies.to_enclave_state_json(),
+        })
+        .to_string()
+    })
+}
+
+/// Debug Set Debug Signature Policy
+///
+/// NOTE: Uses db txs, as per the other debug signer functions in this crate.
+pub fn debug_set_debug_signature_policy<D: DatabaseInterface>(
+    db: &D,
+    debug_command: &str,
+    threshold: usize,
+    core_type: &CoreType,
+    signature_str: &str,
+) -> Result<String> {
+    debug_set_debug_signature_policy_with_options(db, debug_command, threshold, core_type, signature_str, true, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::test_utils::get_test_database;
+
+    use super::*;
+    use crate::test_utils::get_n_random_debug_signatories;
+
+    #[test]
+    fn should_set_debug_signature_policy_without_validating_already_validated_signatures() {
+        let db = get_test_database();
+        get_n_random_debug_signatories(3).put_in_db(&db).unwrap();
+        let core_type = CoreType::BtcOnInt;
+        let debug_command = "debug_hard_reset";
+        let threshold = 2;
+        let signature_str = "";
+        assert!(validate_debug_set_debug_signature_policy_signatures(
+            &db,
+            debug_command,
+            threshold,
+            &core_type,
+            signature_str
+        )
+        .is_err());
+        let validate_signatures = false;
+        let use_db_tx = false;
+        debug_set_debug_signature_policy_with_options(
+            &db,
+            debug_command,
+            threshold,
+            &core_type,
+            signature_str,
+            use_db_tx,
+            validate_signatures,
+        )
+        .unwrap();
+        let result = DebugSignaturePolicies::get_from_db(&db).unwrap();
+        assert_eq!(result.get_threshold(debug_command), threshold);
+    }
+}
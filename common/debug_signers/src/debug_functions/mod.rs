@@ -1,12 +1,23 @@
 mod debug_add_debug_signer;
 mod debug_add_multiple_debug_signers;
 mod debug_remove_debug_signer;
+mod debug_set_debug_signature_policy;
 
 pub use self::{
     debug_add_debug_signer::{debug_add_debug_signer, debug_add_debug_signer_with_options},
     debug_add_multiple_debug_signers::{
         debug_add_multiple_debug_signers,
         debug_add_multiple_debug_signers_with_options,
+        validate_debug_add_multiple_debug_signers_signatures,
+    },
+    debug_remove_debug_signer::{
+        debug_remove_debug_signer,
+        debug_remove_debug_signer_with_options,
+        validate_debug_remove_debug_signer_signatures,
+    },
+    debug_set_debug_signature_policy::{
+        debug_set_debug_signature_policy,
+        debug_set_debug_signature_policy_with_options,
+        validate_debug_set_debug_signature_policy_signatures,
     },
-    debug_remove_debug_signer::{debug_remove_debug_signer, debug_remove_debug_signer_with_options},
 };
@@ -145,7 +145,7 @@ impl DebugSignatories {
             })
     }
 
-    fn remove(&self, eth_address: &EthAddress) -> Self {
+    pub(crate) fn remove(&self, eth_address: &EthAddress) -> Self {
         Self(
             self.iter()
                 .filter(|debug_signatory| debug_signatory.eth_address != *eth_address)
@@ -262,6 +262,48 @@ impl DebugSignatories {
         }
     }
 
+    pub fn maybe_validate_signatures_and_increment_nonces_in_db<D: DatabaseInterface>(
+        &self,
+        db: &D,
+        core_type: &CoreType,
+        debug_command_hash: &H256,
+        signatures: &[EthSignature],
+        threshold: usize,
+    ) -> Result<()> {
+        // NOTE: Each signatory counts at most once, no matter how many of the passed in signatures
+        // it made, so that a single key cannot satisfy an M-of-N policy on its own.
+        let valid_signatories = self
+            .iter()
+            .filter(|signatory| {
+                signatures
+                    .iter()
+                    .any(|signature| signatory.validate(signature, core_type, debug_command_hash).is_ok())
+            })
+            .cloned()
+            .collect::<Vec<DebugSignatory>>();
+        let num_valid = valid_signatories.len();
+        info!("✔ {num_valid} of a required {threshold} debug signatories signed over the command");
+
+        if threshold == 0 || num_valid < threshold {
+            let mut info = self.to_signature_info_json(core_type, debug_command_hash, signatures.first())?;
+            if let Some(map) = info.as_object_mut() {
+                map.insert("threshold".to_string(), json!(threshold));
+                map.insert("numValidSignatures".to_string(), json!(num_valid));
+            };
+            error!("{info}");
+            Err(AppError::Json(info))
+        } else {
+            // NOTE: We only increment nonces once the threshold is met, and we do them all in one go
+            // so that no increment clobbers another.
+            valid_signatories
+                .iter()
+                .try_fold(self.clone(), |debug_signatories, signatory| {
+                    debug_signatories.replace(&signatory.increment_nonce())
+                })
+                .and_then(|debug_signatories| debug_signatories.put_in_db(db))
+        }
+    }
+
     pub fn to_enclave_state_json(&self) -> JsonValue {
         json!(self
             .iter()
@@ -273,7 +315,7 @@ impl DebugSignatories {
 #[cfg(test)]
 mod tests {
     use common::{errors::AppError, test_utils::get_test_database};
-    use common_eth::convert_hex_to_eth_address;
+    use common_eth::{convert_hex_to_eth_address, EthPrivateKey};
 
     use super::*;
     use crate::test_utils::{
@@ -532,6 +574,81 @@ mod tests {
         assert_eq!(debug_signatories_before, debug_signatories_after);
     }
 
+    #[test]
+    fn should_validate_signatures_meeting_threshold_and_increment_nonces_in_db() {
+        let db = get_test_database();
+        let core_type = CoreType::BtcOnInt;
+        let debug_command_hash = H256::random();
+        let pk_1 = EthPrivateKey::generate_random().unwrap();
+        let pk_2 = EthPrivateKey::generate_random().unwrap();
+        let debug_signatory_1 = DebugSignatory::new("one", &pk_1.to_public_key().to_address());
+        let debug_signatory_2 = DebugSignatory::new("two", &pk_2.to_public_key().to_address());
+        let debug_signatory_3 = DebugSignatory::random();
+        let debug_signatories = DebugSignatories::new(vec![
+            debug_signatory_1.clone(),
+            debug_signatory_2.clone(),
+            debug_signatory_3.clone(),
+        ]);
+        let signatures = vec![
+            debug_signatory_1.sign(&pk_1, &core_type, &debug_command_hash).unwrap(),
+            debug_signatory_2.sign(&pk_2, &core_type, &debug_command_hash).unwrap(),
+        ];
+        let threshold = 2;
+        debug_signatories
+            .maybe_validate_signatures_and_increment_nonces_in_db(
+                &db,
+                &core_type,
+                &debug_command_hash,
+                &signatures,
+                threshold,
+            )
+            .unwrap();
+        let updated_signatories = DebugSignatories::get_from_db(&db).unwrap();
+        assert_eq!(
+            updated_signatories.get(&debug_signatory_1.eth_address).unwrap().nonce,
+            1
+        );
+        assert_eq!(
+            updated_signatories.get(&debug_signatory_2.eth_address).unwrap().nonce,
+            1
+        );
+        assert_eq!(
+            updated_signatories.get(&debug_signatory_3.eth_address).unwrap().nonce,
+            debug_signatory_3.nonce
+        );
+    }
+
+    #[test]
+    fn should_not_count_same_signatory_twice_towards_threshold() {
+        let db = get_test_database();
+        let core_type = CoreType::BtcOnInt;
+        let debug_command_hash = H256::random();
+        let pk = EthPrivateKey::generate_random().unwrap();
+        let debug_signatory = DebugSignatory::new("one", &pk.to_public_key().to_address());
+        let debug_signatories = DebugSignatories::new(vec![debug_signatory.clone(), DebugSignatory::random()]);
+        debug_signatories.put_in_db(&db).unwrap();
+        let signature = debug_signatory.sign(&pk, &core_type, &debug_command_hash).unwrap();
+        let signatures = vec![signature.clone(), signature];
+        let threshold = 2;
+        match debug_signatories.maybe_validate_signatures_and_increment_nonces_in_db(
+            &db,
+            &core_type,
+            &debug_command_hash,
+            &signatures,
+            threshold,
+        ) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AppError::Json(error)) => {
+                assert_eq!(error["threshold"], json!(2));
+                assert_eq!(error["numValidSignatures"], json!(1));
+            },
+            Err(_) => panic!("Wrong error received!"),
+        }
+
+        // NOTE: And since the threshold was not met, no nonces should have been incremented.
+        assert_eq!(DebugSignatories::get_from_db(&db).unwrap(), debug_signatories);
+    }
+
     #[test]
     fn should_return_true_if_contains_name() {
         let debug_signatories = get_sample_debug_signatories();
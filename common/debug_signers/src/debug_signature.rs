@@ -10,6 +10,9 @@ use serde::{Deserialize, Serialize};
 // allows the function to run as expected. And thus an `Option` fits the above paradigm nicely. So
 // this type encloses over that, giving a couple of helper methods for converting to and from it
 // etc.
+//
+// NOTE: Where a debug signature policy requires more than one signatory, the enclosed string holds
+// each of their signatures, comma separated.
 
 #[derive(Debug, Clone, Eq, PartialEq, Deref, Constructor, Serialize, Deserialize)]
 pub struct DebugSignature(Option<String>);
@@ -31,6 +34,17 @@ impl From<Option<&String>> for DebugSignature {
     }
 }
 
+impl From<&[String]> for DebugSignature {
+    fn from(v: &[String]) -> Self {
+        let sigs = v.iter().filter(|s| !s.is_empty()).cloned().collect::<Vec<String>>();
+        if sigs.is_empty() {
+            Self::new(None)
+        } else {
+            Self::new(Some(sigs.join(",")))
+        }
+    }
+}
+
 impl From<&str> for DebugSignature {
     fn from(s: &str) -> Self {
         let o = match s {
@@ -40,3 +54,22 @@ impl From<&str> for DebugSignature {
         Self::new(o)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_debug_signature_from_multiple_signatures() {
+        let sigs = vec!["0xabc".to_string(), "0xdef".to_string()];
+        let result = DebugSignature::from(&sigs[..]);
+        assert_eq!(result.to_string(), "0xabc,0xdef");
+    }
+
+    #[test]
+    fn should_get_empty_debug_signature_from_no_signatures() {
+        let sigs: Vec<String> = vec![];
+        let result = DebugSignature::from(&sigs[..]);
+        assert_eq!(result, DebugSignature::new(None));
+    }
+}
@@ -0,0 +1,174 @@
+use std::collections::BTreeMap;
+
+use common::{
+    constants::MIN_DATA_SENSITIVITY_LEVEL,
+    traits::DatabaseInterface,
+    types::{Byte, Bytes, Result},
+};
+use derive_more::{Constructor, Deref};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+
+use crate::DebugSignatories;
+
+lazy_static! {
+    pub static ref DEBUG_SIGNATURE_POLICIES_DB_KEY: [u8; 32] =
+        common::utils::get_prefixed_db_key("debug_signature_policies_db_key");
+}
+
+pub const DEFAULT_DEBUG_SIGNATURE_THRESHOLD: usize = 1;
+
+// NOTE: A map of debug command names to the number of distinct debug signatories required to sign
+// over that command in order for it to run. The command name is the same one that's committed to
+// in that command's debug command hash. Any command without an entry requires a single signature,
+// and thus the behaviour of a core with no policies is identical to that before policies existed.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize, Deref, Constructor)]
+pub struct DebugSignaturePolicies(BTreeMap<String, usize>);
+
+impl DebugSignaturePolicies {
+    fn from_bytes(bytes: &[Byte]) -> Result<Self> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+
+    fn to_bytes(&self) -> Result<Bytes> {
+        Ok(serde_json::to_vec(&self)?)
+    }
+
+    pub fn get_from_db<D: DatabaseInterface>(db: &D) -> Result<Self> {
+        match db.get(DEBUG_SIGNATURE_POLICIES_DB_KEY.to_vec(), MIN_DATA_SENSITIVITY_LEVEL) {
+            Ok(bytes) => Self::from_bytes(&bytes),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    pub fn put_in_db<D: DatabaseInterface>(&self, db: &D) -> Result<()> {
+        db.put(
+            DEBUG_SIGNATURE_POLICIES_DB_KEY.to_vec(),
+            self.to_bytes()?,
+            MIN_DATA_SENSITIVITY_LEVEL,
+        )
+    }
+
+    pub fn get_threshold(&self, debug_command: &str) -> usize {
+        self.get(debug_command)
+            .copied()
+            .unwrap_or(DEFAULT_DEBUG_SIGNATURE_THRESHOLD)
+    }
+
+    pub fn max_threshold(&self) -> usize {
+        self.values()
+            .copied()
+            .max()
+            .unwrap_or(DEFAULT_DEBUG_SIGNATURE_THRESHOLD)
+    }
+
+    // NOTE: Commands which alter the set of debug signatories or these policies must require at
+    // least as many signatures as the strictest policy, else a single key could add its own
+    // co-signers or simply lower the threshold for the command it wishes to run.
+    pub fn get_management_threshold(&self, debug_command: &str) -> usize {
+        std::cmp::max(self.get_threshold(debug_command), self.max_threshold())
+    }
+
+    fn set(&self, debug_command: &str, threshold: usize) -> Self {
+        let mut mutable_self = self.0.clone();
+        if threshold == DEFAULT_DEBUG_SIGNATURE_THRESHOLD {
+            // NOTE: No need to store what is already the default.
+            mutable_self.remove(debug_command);
+        } else {
+            mutable_self.insert(debug_command.to_string(), threshold);
+        };
+        Self(mutable_self)
+    }
+
+    pub fn check_satisfiable(&self, debug_signatories: &DebugSignatories) -> Result<()> {
+        // NOTE: A policy requiring more signatories than exist would lock that command forever.
+        let num_signatories = debug_signatories.len();
+        match self.iter().find(|(_, threshold)| **threshold > num_signatories) {
+            Some((debug_command, threshold)) => Err(format!(
+                "debug signature policy for '{debug_command}' requires {threshold} signatures but only {num_signatories} debug signatories exist"
+            )
+            .into()),
+            None => Ok(()),
+        }
+    }
+
+    pub fn set_and_update_in_db<D: DatabaseInterface>(
+        &self,
+        db: &D,
+        debug_command: &str,
+        threshold: usize,
+    ) -> Result<Self> {
+        if threshold < DEFAULT_DEBUG_SIGNATURE_THRESHOLD {
+            return Err(
+                format!("debug signature threshold must be at least {DEFAULT_DEBUG_SIGNATURE_THRESHOLD}").into(),
+            );
+        };
+        let updated_self = self.set(debug_command, threshold);
+        updated_self.check_satisfiable(&DebugSignatories::get_from_db(db)?)?;
+        updated_self.put_in_db(db)?;
+        Ok(updated_self)
+    }
+
+    pub fn to_enclave_state_json(&self) -> JsonValue {
+        json!({
+            "defaultThreshold": DEFAULT_DEBUG_SIGNATURE_THRESHOLD,
+            "thresholds": self.0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::test_utils::get_test_database;
+
+    use super::*;
+    use crate::test_utils::get_n_random_debug_signatories;
+
+    #[test]
+    fn should_get_default_policies_if_none_in_db() {
+        let db = get_test_database();
+        let result = DebugSignaturePolicies::get_from_db(&db).unwrap();
+        assert_eq!(result, DebugSignaturePolicies::default());
+        assert_eq!(result.get_threshold("hard_reset"), DEFAULT_DEBUG_SIGNATURE_THRESHOLD);
+    }
+
+    #[test]
+    fn should_set_policy_and_update_in_db() {
+        let db = get_test_database();
+        get_n_random_debug_signatories(3).put_in_db(&db).unwrap();
+        let policies = DebugSignaturePolicies::get_from_db(&db).unwrap();
+        policies.set_and_update_in_db(&db, "hard_reset", 2).unwrap();
+        let result = DebugSignaturePolicies::get_from_db(&db).unwrap();
+        assert_eq!(result.get_threshold("hard_reset"), 2);
+        assert_eq!(result.get_threshold("put"), DEFAULT_DEBUG_SIGNATURE_THRESHOLD);
+        assert_eq!(result.max_threshold(), 2);
+        assert_eq!(result.get_management_threshold("debug_add_multiple_debug_signers"), 2);
+    }
+
+    #[test]
+    fn should_remove_policy_when_set_to_default_threshold() {
+        let policies = DebugSignaturePolicies::default().set("hard_reset", 2);
+        assert_eq!(policies.len(), 1);
+        let result = policies.set("hard_reset", DEFAULT_DEBUG_SIGNATURE_THRESHOLD);
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn should_not_set_unsatisfiable_policy() {
+        let db = get_test_database();
+        get_n_random_debug_signatories(2).put_in_db(&db).unwrap();
+        let result = DebugSignaturePolicies::default().set_and_update_in_db(&db, "hard_reset", 3);
+        assert!(result.is_err());
+        assert_eq!(
+            DebugSignaturePolicies::get_from_db(&db).unwrap(),
+            DebugSignaturePolicies::default()
+        );
+    }
+
+    #[test]
+    fn should_not_set_zero_threshold() {
+        let db = get_test_database();
+        let result = DebugSignaturePolicies::default().set_and_update_in_db(&db, "hard_reset", 0);
+        assert!(result.is_err());
+    }
+}
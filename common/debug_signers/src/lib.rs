@@ -2,6 +2,7 @@ mod debug_functions;
 mod debug_signatories;
 mod debug_signatory;
 mod debug_signature;
+mod debug_signature_policies;
 mod debug_signatures;
 mod eip_712_signature_hash_generator;
 mod get_debug_signature_info;
@@ -17,12 +18,26 @@ pub use self::{
         debug_add_multiple_debug_signers_with_options,
         debug_remove_debug_signer,
         debug_remove_debug_signer_with_options,
+        debug_set_debug_signature_policy,
+        debug_set_debug_signature_policy_with_options,
+        validate_debug_add_multiple_debug_signers_signatures,
+        validate_debug_remove_debug_signer_signatures,
+        validate_debug_set_debug_signature_policy_signatures,
     },
     debug_signatories::{DebugSignatories, DEBUG_SIGNATORIES_DB_KEY, SAFE_DEBUG_SIGNATORIES},
     debug_signatory::DebugSignatory,
     debug_signature::DebugSignature,
+    debug_signature_policies::{
+        DebugSignaturePolicies,
+        DEBUG_SIGNATURE_POLICIES_DB_KEY,
+        DEFAULT_DEBUG_SIGNATURE_THRESHOLD,
+    },
     get_debug_signature_info::get_debug_signature_info,
-    validate_debug_command_signature::validate_debug_command_signature,
+    validate_debug_command_signature::{
+        validate_debug_command_signature,
+        validate_debug_command_signatures,
+        validate_debug_management_command_signatures,
+    },
 };
 
 #[macro_use]
@@ -3,7 +3,18 @@ use std::str::FromStr;
 use common::{core_type::CoreType, traits::DatabaseInterface, types::Result};
 use common_eth::{convert_hex_to_h256, EthSignature};
 
-use crate::DebugSignatories;
+use crate::{DebugSignatories, DebugSignaturePolicies};
+
+pub(crate) const DEBUG_SIGNATURES_SEPARATOR: char = ',';
+
+// NOTE: Multiple signatures may be passed in as a single, comma separated string. An empty string
+// yields a single empty signature, so that the failure message remains one asking for a signature.
+pub(crate) fn parse_debug_signatures(signatures: &str) -> Result<Vec<EthSignature>> {
+    signatures
+        .split(DEBUG_SIGNATURES_SEPARATOR)
+        .map(|s| EthSignature::from_str(s.trim()))
+        .collect()
+}
 
 /// Validate Debug Command Signature
 ///
@@ -30,3 +41,99 @@ pub fn validate_debug_command_signature<D: DatabaseInterface>(
         })
     }
 }
+
+/// Validate Debug Command Signatures
+///
+/// This function will take in the passed debug command name & hash, the comma separated signatures
+/// and the database, and check that enough distinct debug signatories have signed over that
+/// command hash to satisfy the debug signature policy for that command. Commands without a policy
+/// require only a single signature.
+pub fn validate_debug_command_signatures<D: DatabaseInterface>(
+    db: &D,
+    core_type: &CoreType,
+    debug_command: &str,
+    signatures: &str,
+    debug_command_hash: &str,
+    is_test: bool,
+) -> Result<()> {
+    if is_test {
+        warn!("✘ Skipping debug signatures check!");
+        Ok(())
+    } else {
+        let threshold = DebugSignaturePolicies::get_from_db(db)?.get_threshold(debug_command);
+        validate_debug_command_signatures_with_threshold(
+            db,
+            core_type,
+            debug_command,
+            signatures,
+            debug_command_hash,
+            threshold,
+        )
+    }
+}
+
+/// Validate Debug Management Command Signatures
+///
+/// As `validate_debug_command_signatures`, but for those commands which replace or expose the debug
+/// signatories or their policies, and so must satisfy that command's management threshold.
+pub fn validate_debug_management_command_signatures<D: DatabaseInterface>(
+    db: &D,
+    core_type: &CoreType,
+    debug_command: &str,
+    signatures: &str,
+    debug_command_hash: &str,
+    is_test: bool,
+) -> Result<()> {
+    if is_test {
+        warn!("✘ Skipping debug signatures check!");
+        Ok(())
+    } else {
+        let threshold = DebugSignaturePolicies::get_from_db(db)?.get_management_threshold(debug_command);
+        validate_debug_command_signatures_with_threshold(
+            db,
+            core_type,
+            debug_command,
+            signatures,
+            debug_command_hash,
+            threshold,
+        )
+    }
+}
+
+fn validate_debug_command_signatures_with_threshold<D: DatabaseInterface>(
+    db: &D,
+    core_type: &CoreType,
+    debug_command: &str,
+    signatures: &str,
+    debug_command_hash: &str,
+    threshold: usize,
+) -> Result<()> {
+    debug!("debug command '{debug_command}' requires {threshold} signature(s)");
+    DebugSignatories::get_from_db(db)?.maybe_validate_signatures_and_increment_nonces_in_db(
+        db,
+        core_type,
+        &convert_hex_to_h256(debug_command_hash)?,
+        &parse_debug_signatures(signatures)?,
+        threshold,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_multiple_debug_signatures() {
+        let sig_1 = EthSignature::random().unwrap();
+        let sig_2 = EthSignature::random().unwrap();
+        let s = format!("{}, {}", sig_1.to_0x_string(), sig_2.to_0x_string());
+        let result = parse_debug_signatures(&s).unwrap();
+        assert_eq!(result, vec![sig_1, sig_2]);
+    }
+
+    #[test]
+    fn should_parse_empty_string_to_single_empty_signature() {
+        let result = parse_debug_signatures("").unwrap();
+        assert_eq!(result, vec![EthSignature::empty()]);
+    }
+}
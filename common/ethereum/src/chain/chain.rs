@@ -300,12 +300,37 @@ impl Chain {
                 return Err(ChainError::InvalidBlock(*mcid, h, n));
             }
 
-            // NOTE Receipts may have bene prefiltered outside of the TEE
+            // NOTE Receipts may have bene prefiltered outside of the TEE, in which case those that
+            // remain are each validated via their own inclusion proof against the receipts root.
             if !sub_mat.receipts.is_empty() {
-                if let Err(e) = sub_mat.receipts_are_valid() {
-                    error!("invalid receipts: {e}");
+                let receipts_root_is_valid = if sub_mat.receipt_proofs.is_some() {
+                    sub_mat.receipt_proofs_are_valid()
+                } else {
+                    sub_mat.receipts_are_valid()
+                };
+                let receipts_are_valid = receipts_root_is_valid.and_then(|is_valid| {
+                    Ok(is_valid && sub_mat.logs_blooms_are_valid()? && sub_mat.receipts_match_transactions()?)
+                });
+                if !matches!(receipts_are_valid, Ok(true)) {
+                    error!("invalid receipts: {receipts_are_valid:?}");
                     return Err(ChainError::InvalidReceipts(*mcid, h, n));
                 }
+
+                // NOTE: Without the raw txs, the tx hashes the receipts were matched against above
+                // cannot be tied to the block's txs root, and so a sub mat lacking them is invalid.
+                // Pre-filtered receipts instead come with proofs of their txs against that root.
+                if sub_mat.receipt_proofs.is_some() {
+                    if !matches!(sub_mat.transaction_proofs_are_valid(), Ok(true)) {
+                        error!("invalid transaction proofs for block {n} on chain {cid}");
+                        return Err(ChainError::InvalidTransactions(*mcid, h, n));
+                    }
+                } else if sub_mat.raw_transactions.is_none() {
+                    error!("no raw txs in sub mat, cannot validate transactions root for block {n} on chain {cid}");
+                    return Err(ChainError::InvalidTransactions(*mcid, h, n));
+                } else if !matches!(sub_mat.transactions_root_is_valid(), Ok(true)) {
+                    error!("invalid transactions root for block {n} on chain {cid}");
+                    return Err(ChainError::InvalidTransactions(*mcid, h, n));
+                }
             }
             Ok(())
         } else {
@@ -450,6 +475,20 @@ impl Chain {
             })
     }
 
+    pub fn get_db_key(mcid: &MetadataChainId) -> Result<Vec<u8>, ChainError> {
+        Ok(Self::db_key_from_chain_id(mcid)?.to_vec())
+    }
+
+    pub fn get_db_keys(&self) -> Result<Vec<Vec<u8>>, ChainError> {
+        // NOTE: The key under which the chain itself is stored, followed by the keys of every sub
+        // mat it references.
+        let mut keys = vec![self.db_key()?.to_vec()];
+        for d in self.chain.iter().flatten() {
+            keys.push(DbKey::from(self.chain_id(), *d.hash())?.to_vec());
+        }
+        Ok(keys)
+    }
+
     fn save_in_db<D: DatabaseInterface>(&self, db_utils: &ChainDbUtils<D>) -> Result<(), ChainError> {
         let key = self.db_key()?;
         let value = serde_json::to_vec(self)?;
@@ -617,9 +656,18 @@ mod tests {
     use super::*;
     use crate::{chain::ChainState, test_utils::get_sequential_eth_blocks_and_receipts};
 
+    // NOTE: The sample sub mats lack the raw txs required to validate their receipts, so those are
+    // removed in order to validate the blocks alone.
+    fn get_sub_mats() -> Vec<EthSubMat> {
+        get_sequential_eth_blocks_and_receipts()
+            .iter()
+            .map(EthSubMat::remove_receipts)
+            .collect()
+    }
+
     #[test]
     fn should_init_core_correctly() {
-        let sub_mat = get_sequential_eth_blocks_and_receipts()[0].clone();
+        let sub_mat = get_sub_mats()[0].clone();
         let mcid = MetadataChainId::EthereumMainnet;
         let validate = true;
         let hub = EthAddress::zero();
@@ -631,6 +679,20 @@ mod tests {
         Chain::init(&db_utils, hub, tail_length, confirmations, sub_mat, mcid, validate).unwrap();
     }
 
+    #[test]
+    fn should_get_chain_db_keys() {
+        let sub_mat = get_sub_mats()[0].clone();
+        let mcid = MetadataChainId::EthereumMainnet;
+        let db = get_test_database();
+        let db_utils = ChainDbUtils::new(&db);
+        Chain::init(&db_utils, EthAddress::zero(), 2, 3, sub_mat, mcid, true).unwrap();
+        let chain = Chain::get(&db_utils, mcid).unwrap();
+        let keys = chain.get_db_keys().unwrap();
+        assert_eq!(keys.len(), 2);
+        keys.into_iter()
+            .for_each(|k| assert!(db.get(k, MIN_DATA_SENSITIVITY_LEVEL).is_ok()));
+    }
+
     #[test]
     fn should_get_not_initted_error() {
         let db = get_test_database();
@@ -644,9 +706,29 @@ mod tests {
     }
 
     #[test]
-    fn should_get_already_initted_error() {
+    fn should_not_validate_sub_mat_with_receipts_but_no_raw_txs() {
         let sub_mat = get_sequential_eth_blocks_and_receipts()[0].clone();
         let mcid = MetadataChainId::EthereumMainnet;
+        let n = Chain::block_num(&sub_mat).unwrap();
+        let h = Chain::block_hash(&sub_mat).unwrap();
+        assert!(!sub_mat.receipts.is_empty());
+        assert!(sub_mat.raw_transactions.is_none());
+        match Chain::validate(&mcid, &sub_mat, true) {
+            Ok(_) => panic!("should not have succeeded"),
+            Err(ChainError::InvalidTransactions(id, hash, num)) => {
+                assert_eq!(id, mcid);
+                assert_eq!(hash, h);
+                assert_eq!(num, n);
+            },
+            Err(e) => panic!("wrong error received {e}"),
+        }
+        assert!(Chain::validate(&mcid, &sub_mat, false).is_ok());
+    }
+
+    #[test]
+    fn should_get_already_initted_error() {
+        let sub_mat = get_sub_mats()[0].clone();
+        let mcid = MetadataChainId::EthereumMainnet;
         let validate = true;
         let hub = EthAddress::zero();
         let db = get_test_database();
@@ -677,7 +759,7 @@ mod tests {
         use simple_logger; // FIXME rm
         simple_logger::init_with_level(log::Level::Debug).unwrap();
 
-        let sub_mats = get_sequential_eth_blocks_and_receipts();
+        let sub_mats = get_sub_mats();
         let mcid = MetadataChainId::EthereumMainnet;
         let validate = true;
         let sub_mat = sub_mats[0].clone();
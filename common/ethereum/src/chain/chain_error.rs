@@ -40,6 +40,9 @@ pub enum ChainError {
     #[error("invalid receipts for block number '{0}', hash '{1}' on chain '{2}'")]
     InvalidReceipts(MetadataChainId, EthHash, u64),
 
+    #[error("invalid transactions for block number '{0}', hash '{1}' on chain '{2}'")]
+    InvalidTransactions(MetadataChainId, EthHash, u64),
+
     #[error("invalid block for block number '{0}', hash '{1}' on chain '{2}'")]
     InvalidBlock(MetadataChainId, EthHash, u64),
 
@@ -10,8 +10,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Value as JsonValue};
 
 use crate::{
-    eip_1559::Eip1559,
-    eip_4844::Eip4844,
+    eth_fork_schedule::{EthForkSchedule, EthHeaderField},
     eth_utils::{
         convert_dec_str_to_u256,
         convert_hex_strings_to_h256s,
@@ -45,13 +44,18 @@ pub struct EthBlock {
     pub transactions_root: EthHash,
     pub uncles: Vec<EthHash>,
     pub base_fee_per_gas: Option<U256>,
-    // NOTE: The following new fields are EIP-4844 specific and hence they are
-    // optional. Non EIP-4844 blocks or layer 2s or other forks may not have
-    // these fields.
+    // NOTE: The following fields were added to headers by later hardforks and hence they are
+    // optional. Which of them a given block must have is decided by its chain's `EthForkSchedule`.
     pub withdrawals_root: Option<EthHash>,
     pub blob_gas_used: Option<U256>,
     pub excess_blob_gas: Option<U256>,
     pub parent_beacon_block_root: Option<EthHash>,
+    pub requests_hash: Option<EthHash>,
+    // NOTE: Arbitrum returns these alongside its headers. They are not rlp encoded themselves,
+    // but are instead packed into the `extra_data` & `mix_hash` fields, which are.
+    pub send_root: Option<EthHash>,
+    pub send_count: Option<U256>,
+    pub l1_block_number: Option<U256>,
 }
 
 impl EthBlock {
@@ -84,6 +88,11 @@ impl EthBlock {
             .ok_or(NoneError("Could not unwrap 'parent_beacon_block_root' from ETH block!"))
     }
 
+    pub fn get_requests_hash(&self) -> Result<EthHash> {
+        self.requests_hash
+            .ok_or(NoneError("Could not unwrap 'requests_hash' from ETH block!"))
+    }
+
     pub fn to_json(&self) -> Result<JsonValue> {
         let encoded_transactions = self
             .transactions
@@ -160,6 +169,16 @@ impl EthBlock {
                 None => None,
                 Some(hex) => Some(convert_hex_to_h256(hex)?),
             },
+            requests_hash: match json.requests_hash.as_ref() {
+                None => None,
+                Some(hex) => Some(convert_hex_to_h256(hex)?),
+            },
+            send_root: match json.send_root.as_ref() {
+                None => None,
+                Some(hex) => Some(convert_hex_to_h256(hex)?),
+            },
+            send_count: json.send_count.map(U256::from),
+            l1_block_number: json.l1_block_number.map(U256::from),
         })
     }
 
@@ -178,19 +197,10 @@ impl EthBlock {
     }
 
     pub fn rlp_encode(&self, chain_id: &EthChainId) -> Result<Bytes> {
+        let header_fields = EthForkSchedule::from(chain_id).validate_header_fields(self)?;
         let mut rlp_stream = RlpStream::new();
-        let mut num_items = 15;
-        let eip_1559_is_active = Eip1559::new().is_active(chain_id, self.number)?;
-        let eip_4844_is_active = Eip4844::new().is_active(self);
-
-        if eip_1559_is_active {
-            num_items += 1;
-        }
-        if eip_4844_is_active {
-            num_items += 4;
-        }
         rlp_stream
-            .begin_list(num_items)
+            .begin_list(15 + header_fields.len())
             .append(&self.parent_hash)
             .append(&self.sha3_uncles)
             .append(&self.miner)
@@ -206,14 +216,15 @@ impl EthBlock {
             .append(&self.extra_data)
             .append(&self.mix_hash)
             .append(&self.nonce);
-        if eip_1559_is_active {
-            rlp_stream.append(&self.get_base_fee_per_gas()?);
-        };
-        if eip_4844_is_active {
-            rlp_stream.append(&self.get_withdrawals_root()?);
-            rlp_stream.append(&self.get_blob_gas_used()?);
-            rlp_stream.append(&self.get_excess_blob_gas()?);
-            rlp_stream.append(&self.get_parent_beacon_block_root()?);
+        for field in header_fields {
+            match field {
+                EthHeaderField::BaseFeePerGas => rlp_stream.append(&self.get_base_fee_per_gas()?),
+                EthHeaderField::WithdrawalsRoot => rlp_stream.append(&self.get_withdrawals_root()?),
+                EthHeaderField::BlobGasUsed => rlp_stream.append(&self.get_blob_gas_used()?),
+                EthHeaderField::ExcessBlobGas => rlp_stream.append(&self.get_excess_blob_gas()?),
+                EthHeaderField::ParentBeaconBlockRoot => rlp_stream.append(&self.get_parent_beacon_block_root()?),
+                EthHeaderField::RequestsHash => rlp_stream.append(&self.get_requests_hash()?),
+            };
         }
 
         Ok(rlp_stream.out().to_vec())
@@ -223,11 +234,28 @@ impl EthBlock {
         self.rlp_encode(chain_id).map(|bytes| keccak_hash_bytes(&bytes))
     }
 
+    // NOTE: Arbitrum packs the `sendRoot` into the `extra_data`, and the big-endian `sendCount` &
+    // `l1BlockNumber` into the first & second 8 bytes of the `mix_hash` respectively. Since those
+    // fields are part of the block hash, checking them against each other authenticates the former.
+    fn arbitrum_fields_are_valid(&self) -> bool {
+        let mix_hash_u64_at = |i: usize| U256::from_big_endian(&self.mix_hash.as_bytes()[i * 8..(i + 1) * 8]);
+        let send_root_is_valid = self
+            .send_root
+            .map_or(true, |send_root| self.extra_data == send_root.as_bytes());
+        let send_count_is_valid = self.send_count.map_or(true, |count| count == mix_hash_u64_at(0));
+        let l1_block_number_is_valid = self.l1_block_number.map_or(true, |number| number == mix_hash_u64_at(1));
+        if !(send_root_is_valid && send_count_is_valid && l1_block_number_is_valid) {
+            warn!("✘ Arbitrum fields do not match the `extra_data` & `mix_hash` of the block!");
+            return false;
+        };
+        true
+    }
+
     pub fn is_valid(&self, chain_id: &EthChainId) -> Result<bool> {
         self.hash(chain_id).map(|calculated_hash| {
             debug!("✔ Block hash from from block: {}", self.hash);
             debug!("✔ Calculated block hash: {}", calculated_hash);
-            calculated_hash == self.hash
+            calculated_hash == self.hash && self.arbitrum_fields_are_valid()
         })
     }
 }
@@ -261,6 +289,10 @@ pub struct EthBlockJson {
     pub blob_gas_used: Option<String>,
     pub excess_blob_gas: Option<String>,
     pub parent_beacon_block_root: Option<String>,
+    pub requests_hash: Option<String>,
+    pub send_root: Option<String>,
+    pub send_count: Option<u64>,
+    pub l1_block_number: Option<u64>,
 }
 
 #[cfg(test)]
@@ -450,4 +482,34 @@ mod tests {
         let result = block.is_valid(&chain_id).unwrap();
         assert!(!result);
     }
+
+    fn get_sample_block_with_arbitrum_fields() -> EthBlock {
+        let mut block = get_sample_eip1559_mainnet_submission_material().block.unwrap();
+        let send_root = EthHash::random();
+        let mut mix_hash = [0u8; 32];
+        mix_hash[..8].copy_from_slice(&1337u64.to_be_bytes());
+        mix_hash[8..16].copy_from_slice(&19_000_000u64.to_be_bytes());
+        block.extra_data = send_root.as_bytes().to_vec();
+        block.mix_hash = EthHash::from(mix_hash);
+        block.send_root = Some(send_root);
+        block.send_count = Some(U256::from(1337));
+        block.l1_block_number = Some(U256::from(19_000_000));
+        block.hash = block.hash(&EthChainId::Mainnet).unwrap();
+        block
+    }
+
+    #[test]
+    fn block_with_arbitrum_fields_should_be_valid() {
+        let block = get_sample_block_with_arbitrum_fields();
+        let result = block.is_valid(&EthChainId::Mainnet).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn block_with_mismatched_arbitrum_fields_should_not_be_valid() {
+        let mut block = get_sample_block_with_arbitrum_fields();
+        block.l1_block_number = Some(U256::from(19_000_001));
+        let result = block.is_valid(&EthChainId::Mainnet).unwrap();
+        assert!(!result);
+    }
 }
@@ -44,6 +44,10 @@ pub struct EthBlockJsonFromRpc {
     blob_gas_used: Option<String>,
     excess_blob_gas: Option<String>,
     parent_beacon_block_root: Option<String>,
+    requests_hash: Option<String>,
+    send_root: Option<String>,
+    send_count: Option<String>,
+    l1_block_number: Option<String>,
 }
 
 impl EthSubmissionMaterial {
@@ -58,6 +62,9 @@ impl EthSubmissionMaterial {
             block_number: Some(block.number),
             parent_hash: Some(block.parent_hash),
             receipts_root: Some(block.receipts_root),
+            raw_transactions: None,
+            receipt_proofs: None,
+            transaction_proofs: None,
             block: Some(block),
         })
     }
@@ -111,6 +118,22 @@ impl EthBlock {
                 None => None,
                 Some(hex) => Some(convert_hex_to_h256(hex)?),
             },
+            requests_hash: match json.requests_hash.as_ref() {
+                None => None,
+                Some(hex) => Some(convert_hex_to_h256(hex)?),
+            },
+            send_root: match json.send_root.as_ref() {
+                None => None,
+                Some(hex) => Some(convert_hex_to_h256(hex)?),
+            },
+            send_count: match json.send_count.as_ref() {
+                None => None,
+                Some(hex) => Some(U256::from_str_radix(&strip_hex_prefix(hex), radix)?),
+            },
+            l1_block_number: match json.l1_block_number.as_ref() {
+                None => None,
+                Some(hex) => Some(U256::from_str_radix(&strip_hex_prefix(hex), radix)?),
+            },
         })
     }
 }
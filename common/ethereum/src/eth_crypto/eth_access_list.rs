@@ -0,0 +1,70 @@
+use common::types::Result;
+use derive_getters::Getters;
+use derive_more::{Constructor, Deref};
+use ethereum_types::{Address as EthAddress, H256 as EthHash};
+use rlp::{Encodable, Rlp, RlpStream};
+use serde::{Deserialize, Serialize};
+
+/// An entry in an EIP-2930 access list, declaring an address and the storage slots of it that a
+/// transaction intends to touch.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Getters, Constructor, Serialize, Deserialize)]
+pub struct EthAccessListItem {
+    address: EthAddress,
+    storage_keys: Vec<EthHash>,
+}
+
+impl Encodable for EthAccessListItem {
+    fn rlp_append(&self, rlp_stream: &mut RlpStream) {
+        rlp_stream
+            .begin_list(2)
+            .append(&self.address)
+            .append_list(&self.storage_keys);
+    }
+}
+
+impl EthAccessListItem {
+    fn from_rlp(rlp: &Rlp) -> Result<Self> {
+        Ok(Self::new(rlp.val_at(0)?, rlp.list_at(1)?))
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Constructor, Deref, Serialize, Deserialize)]
+pub struct EthAccessList(Vec<EthAccessListItem>);
+
+impl Encodable for EthAccessList {
+    fn rlp_append(&self, rlp_stream: &mut RlpStream) {
+        rlp_stream.append_list(&self.0);
+    }
+}
+
+impl EthAccessList {
+    pub(super) fn from_rlp(rlp: &Rlp) -> Result<Self> {
+        Ok(Self::new(
+            rlp.iter()
+                .map(|item| EthAccessListItem::from_rlp(&item))
+                .collect::<Result<Vec<_>>>()?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_rlp_encode_and_decode_access_list() {
+        let access_list = EthAccessList::new(vec![
+            EthAccessListItem::new(EthAddress::random(), vec![EthHash::random(), EthHash::random()]),
+            EthAccessListItem::new(EthAddress::random(), vec![]),
+        ]);
+        let bytes = rlp::encode(&access_list);
+        let result = EthAccessList::from_rlp(&Rlp::new(&bytes)).unwrap();
+        assert_eq!(result, access_list);
+    }
+
+    #[test]
+    fn should_rlp_encode_empty_access_list_as_empty_list() {
+        let result = rlp::encode(&EthAccessList::default()).to_vec();
+        assert_eq!(result, vec![0xc0]);
+    }
+}
@@ -10,6 +10,7 @@ use common::{
 };
 use ethereum_types::{Address as EthAddress, H256};
 use secp256k1::{
+    ecdh::SharedSecret,
     key::{PublicKey, SecretKey, ONE_KEY},
     Message,
     Secp256k1,
@@ -65,6 +66,11 @@ impl EthPrivateKey {
         }
     }
 
+    pub fn get_shared_secret(&self, public_key: &EthPublicKey) -> H256 {
+        // NOTE: This is ECDH, where the secret is the sha256 hash of the compressed shared point.
+        H256::from_slice(&SharedSecret::new(&public_key.public_key, &self.0)[..])
+    }
+
     pub fn write_to_database<D>(&self, db: &D, key: &[Byte]) -> Result<()>
     where
         D: DatabaseInterface,
@@ -216,6 +222,15 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
+    #[test]
+    fn should_get_same_shared_secret_from_either_side() {
+        let pk_a = get_sample_eth_private_key();
+        let pk_b = EthPrivateKey::generate_random().unwrap();
+        let result_a = pk_a.get_shared_secret(&pk_b.to_public_key());
+        let result_b = pk_b.get_shared_secret(&pk_a.to_public_key());
+        assert_eq!(result_a, result_b);
+    }
+
     #[test]
     fn should_get_address_from_pk() {
         let pk = get_sample_eth_private_key();
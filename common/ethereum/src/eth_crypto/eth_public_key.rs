@@ -1,4 +1,7 @@
-use common::{crypto_utils::keccak_hash_bytes, types::Bytes};
+use common::{
+    crypto_utils::keccak_hash_bytes,
+    types::{Byte, Bytes, Result},
+};
 use ethereum_types::Address as EthAddress;
 use secp256k1::key::PublicKey;
 
@@ -9,6 +12,13 @@ pub struct EthPublicKey {
 }
 
 impl EthPublicKey {
+    pub fn from_slice(slice: &[Byte]) -> Result<Self> {
+        Ok(Self {
+            compressed: true,
+            public_key: PublicKey::from_slice(slice)?,
+        })
+    }
+
     pub fn to_bytes(self) -> Bytes {
         self.public_key.serialize_uncompressed().to_vec()
     }
@@ -22,6 +32,7 @@ impl EthPublicKey {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::test_utils::{
         get_sample_eth_address_string,
         get_sample_eth_public_key,
@@ -42,4 +53,11 @@ mod tests {
         let result = public_key.to_address();
         assert_eq!(hex::encode(result.as_bytes()), get_sample_eth_address_string());
     }
+
+    #[test]
+    fn should_get_public_key_from_slice() {
+        let public_key = get_sample_eth_public_key();
+        let result = EthPublicKey::from_slice(&get_sample_eth_public_key_bytes()).unwrap();
+        assert_eq!(result, public_key);
+    }
 }
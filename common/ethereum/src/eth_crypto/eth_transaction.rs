@@ -2,11 +2,12 @@ use common::types::{Byte, Bytes, Result};
 use common_chain_ids::EthChainId;
 use derive_more::{Constructor, Deref};
 use ethereum_types::{Address as EthAddress, U256};
-use rlp::RlpStream;
+use rlp::{Rlp, RlpStream};
 use serde::{Deserialize, Serialize};
 
 use crate::{
     encode_erc777_mint_fxn_maybe_with_data,
+    EthAccessList,
     EthPrivateKey,
     EthSignature,
     EthSigningCapabilities,
@@ -32,6 +33,26 @@ impl EthTransactions {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum EthTransactionType {
+    #[default]
+    Legacy,
+    AccessList,
+    DynamicFee,
+}
+
+impl EthTransactionType {
+    // NOTE: Per EIP-2718, typed txs are prefixed with their type byte, whereas legacy txs start
+    // with an rlp list prefix, which is always >= 0xc0.
+    fn to_byte(self) -> Option<Byte> {
+        match self {
+            Self::Legacy => None,
+            Self::AccessList => Some(0x01),
+            Self::DynamicFee => Some(0x02),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Default, Serialize, Deserialize)]
 pub struct EthTransaction {
     pub v: u64,
@@ -44,10 +65,26 @@ pub struct EthTransaction {
     pub gas_limit: U256,
     pub gas_price: U256,
     pub chain_id: EthChainId,
+    #[serde(default)]
+    pub tx_type: EthTransactionType,
+    #[serde(default)]
+    pub max_fee_per_gas: U256,
+    #[serde(default)]
+    pub max_priority_fee_per_gas: U256,
+    #[serde(default)]
+    pub access_list: EthAccessList,
 }
 
 impl EthTransaction {
     pub fn from_bytes(tx_bytes: &[Byte]) -> Result<EthTransaction> {
+        match tx_bytes.first() {
+            Some(0x01) => Self::from_typed_bytes(EthTransactionType::AccessList, &tx_bytes[1..]),
+            Some(0x02) => Self::from_typed_bytes(EthTransactionType::DynamicFee, &tx_bytes[1..]),
+            _ => Self::from_legacy_bytes(tx_bytes),
+        }
+    }
+
+    fn from_legacy_bytes(tx_bytes: &[Byte]) -> Result<EthTransaction> {
         let decoded_tx: Vec<Bytes> = rlp::decode_list(tx_bytes);
         if decoded_tx.len() != 9 {
             // FIXME Magic number (Well, it's the number of elements in a signed ETH tx...)!
@@ -64,9 +101,47 @@ impl EthTransaction {
                 r: U256::from_big_endian(&decoded_tx[7]),
                 s: U256::from_big_endian(&decoded_tx[8]),
                 chain_id: EthChainId::default(), // NOTE: This isn't calculated!
+                ..Default::default()
             })
         }
     }
+
+    fn from_typed_bytes(tx_type: EthTransactionType, payload: &[Byte]) -> Result<EthTransaction> {
+        let rlp = Rlp::new(payload);
+        let (expected_num_fields, fees_len) = match tx_type {
+            EthTransactionType::DynamicFee => (12, 2),
+            _ => (11, 1),
+        };
+        let num_fields = rlp.item_count()?;
+        if num_fields != expected_num_fields {
+            return Err(format!("{tx_type:?} tx has {num_fields} fields, expected {expected_num_fields}").into());
+        };
+
+        let mut tx = EthTransaction {
+            tx_type,
+            chain_id: EthChainId::try_from(rlp.val_at::<u64>(0)?)?,
+            nonce: rlp.val_at(1)?,
+            ..Default::default()
+        };
+        if tx_type == EthTransactionType::DynamicFee {
+            tx.max_priority_fee_per_gas = rlp.val_at(2)?;
+            tx.max_fee_per_gas = rlp.val_at(3)?;
+        } else {
+            tx.gas_price = rlp.val_at(2)?;
+        };
+
+        // NOTE: After the fee fields, both tx types share the same layout.
+        let i = 2 + fees_len;
+        tx.gas_limit = rlp.val_at(i)?;
+        tx.to = rlp.val_at(i + 1)?;
+        tx.value = rlp.val_at(i + 2)?;
+        tx.data = rlp.val_at(i + 3)?;
+        tx.access_list = EthAccessList::from_rlp(&rlp.at(i + 4)?)?;
+        tx.v = rlp.val_at(i + 5)?;
+        tx.r = rlp.val_at(i + 6)?;
+        tx.s = rlp.val_at(i + 7)?;
+        Ok(tx)
+    }
 }
 
 impl EthTransaction {
@@ -90,6 +165,45 @@ impl EthTransaction {
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_unsigned_eip_2930(
+        data: Bytes,
+        nonce: u64,
+        value: usize,
+        to: EthAddress,
+        chain_id: &EthChainId,
+        gas_limit: usize,
+        gas_price: u64,
+        access_list: EthAccessList,
+    ) -> EthTransaction {
+        EthTransaction {
+            access_list,
+            v: 0, // NOTE: Typed txs carry the y-parity of the sig rather than an EIP155 `v`.
+            tx_type: EthTransactionType::AccessList,
+            ..Self::new_unsigned(data, nonce, value, to, chain_id, gas_limit, gas_price)
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_unsigned_eip_1559(
+        data: Bytes,
+        nonce: u64,
+        value: usize,
+        to: EthAddress,
+        chain_id: &EthChainId,
+        gas_limit: usize,
+        max_fee_per_gas: u64,
+        max_priority_fee_per_gas: u64,
+    ) -> EthTransaction {
+        EthTransaction {
+            v: 0, // NOTE: Typed txs carry the y-parity of the sig rather than an EIP155 `v`.
+            tx_type: EthTransactionType::DynamicFee,
+            max_fee_per_gas: max_fee_per_gas.into(),
+            max_priority_fee_per_gas: max_priority_fee_per_gas.into(),
+            ..Self::new_unsigned(data, nonce, value, to, chain_id, gas_limit, 0)
+        }
+    }
+
     pub fn new_eth_tx(
         to: Bytes,
         data: Bytes,
@@ -110,13 +224,22 @@ impl EthTransaction {
             chain_id: chain_id.clone(),
             gas_limit: gas_limit.into(),
             gas_price: gas_price.into(),
+            ..Default::default()
         }
     }
 
+    pub fn with_access_list(mut self, access_list: EthAccessList) -> Self {
+        self.access_list = access_list;
+        self
+    }
+
     fn add_signature_to_transaction(mut self, sig: EthSignature) -> Self {
         self.r = sig[0..32].into();
         self.s = sig[32..64].into();
-        self.v = Self::calculate_v_from_chain_id(sig[64], &self.chain_id);
+        self.v = match self.tx_type {
+            EthTransactionType::Legacy => Self::calculate_v_from_chain_id(sig[64], &self.chain_id),
+            _ => sig[64] as u64,
+        };
         self
     }
 
@@ -124,8 +247,15 @@ impl EthTransaction {
         chain_id.to_u64() * 2 + sig_v as u64 + 35 // Per EIP155
     }
 
+    fn get_signing_payload(&self) -> Bytes {
+        match self.tx_type.to_byte() {
+            None => self.serialize_bytes(),
+            Some(type_byte) => self.serialize_typed_bytes(type_byte, false),
+        }
+    }
+
     pub fn sign<T: EthSigningCapabilities>(self, pk: &T) -> Result<Self> {
-        pk.hash_and_sign_msg(&self.serialize_bytes())
+        pk.hash_and_sign_msg(&self.get_signing_payload())
             .map(|sig| self.add_signature_to_transaction(sig))
     }
 
@@ -136,6 +266,46 @@ impl EthTransaction {
     pub fn to_bytes(&self) -> Bytes {
         self.serialize_bytes()
     }
+
+    fn serialize_legacy_bytes(&self) -> Bytes {
+        let mut rlp_stream = RlpStream::new();
+        rlp_stream.begin_list(9);
+        rlp_stream.append(&self.nonce);
+        rlp_stream.append(&self.gas_price);
+        rlp_stream.append(&self.gas_limit);
+        rlp_stream.append(&self.to);
+        rlp_stream.append(&self.value);
+        rlp_stream.append(&self.data);
+        rlp_stream.append(&self.v);
+        rlp_stream.append(&self.r);
+        rlp_stream.append(&self.s);
+        rlp_stream.out().to_vec()
+    }
+
+    fn serialize_typed_bytes(&self, type_byte: Byte, include_signature: bool) -> Bytes {
+        let mut rlp_stream = RlpStream::new();
+        rlp_stream.begin_unbounded_list();
+        rlp_stream.append(&self.chain_id.to_u64());
+        rlp_stream.append(&self.nonce);
+        if self.tx_type == EthTransactionType::DynamicFee {
+            rlp_stream.append(&self.max_priority_fee_per_gas);
+            rlp_stream.append(&self.max_fee_per_gas);
+        } else {
+            rlp_stream.append(&self.gas_price);
+        };
+        rlp_stream.append(&self.gas_limit);
+        rlp_stream.append(&self.to);
+        rlp_stream.append(&self.value);
+        rlp_stream.append(&self.data);
+        rlp_stream.append(&self.access_list);
+        if include_signature {
+            rlp_stream.append(&self.v);
+            rlp_stream.append(&self.r);
+            rlp_stream.append(&self.s);
+        };
+        rlp_stream.finalize_unbounded_list();
+        [vec![type_byte], rlp_stream.out().to_vec()].concat()
+    }
 }
 
 impl EthTxInfoCompatible for EthTransaction {
@@ -152,18 +322,10 @@ impl EthTxInfoCompatible for EthTransaction {
     }
 
     fn serialize_bytes(&self) -> Bytes {
-        let mut rlp_stream = RlpStream::new();
-        rlp_stream.begin_list(9);
-        rlp_stream.append(&self.nonce);
-        rlp_stream.append(&self.gas_price);
-        rlp_stream.append(&self.gas_limit);
-        rlp_stream.append(&self.to);
-        rlp_stream.append(&self.value);
-        rlp_stream.append(&self.data);
-        rlp_stream.append(&self.v);
-        rlp_stream.append(&self.r);
-        rlp_stream.append(&self.s);
-        rlp_stream.out().to_vec()
+        match self.tx_type.to_byte() {
+            None => self.serialize_legacy_bytes(),
+            Some(type_byte) => self.serialize_typed_bytes(type_byte, true),
+        }
     }
 }
 
@@ -221,8 +383,42 @@ pub fn get_signed_minting_tx(
 
 #[cfg(test)]
 mod tests {
+    use ethereum_types::H256 as EthHash;
+
     use super::*;
-    use crate::test_utils::{get_sample_eth_address, get_sample_eth_private_key, get_sample_unsigned_eth_transaction};
+    use crate::{
+        test_utils::{get_sample_eth_address, get_sample_eth_private_key, get_sample_unsigned_eth_transaction},
+        EthAccessListItem,
+    };
+
+    fn get_sample_unsigned_eip_1559_tx() -> EthTransaction {
+        let to = EthAddress::from_slice(&hex::decode("53c2048dad4fcfab44C3ef3D16E882b5178df42b").unwrap());
+        EthTransaction::new_unsigned_eip_1559(
+            vec![],
+            0,
+            1,
+            to,
+            &EthChainId::Rinkeby,
+            100_000,
+            20_000_000_000,
+            1_000_000_000,
+        )
+    }
+
+    fn get_sample_unsigned_eip_2930_tx() -> EthTransaction {
+        let to = EthAddress::from_slice(&hex::decode("53c2048dad4fcfab44C3ef3D16E882b5178df42b").unwrap());
+        let access_list = EthAccessList::new(vec![EthAccessListItem::new(to, vec![EthHash::from_low_u64_be(1)])]);
+        EthTransaction::new_unsigned_eip_2930(
+            vec![],
+            0,
+            1,
+            to,
+            &EthChainId::Rinkeby,
+            100_000,
+            20_000_000_000,
+            access_list,
+        )
+    }
 
     #[test]
     fn should_serialize_simple_eth_tx_to_bytes() {
@@ -315,4 +511,66 @@ mod tests {
         assert_eq!(tx_hash, expected_tx_hash);
         assert_eq!(result.serialize_hex(), expected_result);
     }
+
+    #[test]
+    fn should_get_eip_1559_signing_payload() {
+        let expected_result = "02e90480843b9aca008504a817c800830186a09453c2048dad4fcfab44c3ef3d16e882b5178df42b0180c0";
+        let tx = get_sample_unsigned_eip_1559_tx();
+        let result = hex::encode(tx.get_signing_payload());
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_get_eip_2930_signing_payload() {
+        let expected_result = "01f85d04808504a817c800830186a09453c2048dad4fcfab44c3ef3d16e882b5178df42b0180f838f79453c2048dad4fcfab44c3ef3d16e882b5178df42be1a00000000000000000000000000000000000000000000000000000000000000001";
+        let tx = get_sample_unsigned_eip_2930_tx();
+        let result = hex::encode(tx.get_signing_payload());
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_sign_eip_1559_tx_with_y_parity() {
+        let private_key = get_sample_eth_private_key();
+        let tx = get_sample_unsigned_eip_1559_tx();
+        let result = tx.sign(&private_key).unwrap();
+        assert!(result.v <= 1);
+        assert!(!result.r.is_zero());
+        assert!(!result.s.is_zero());
+        assert_eq!(result.serialize_bytes()[0], 0x02);
+    }
+
+    #[test]
+    fn should_round_trip_signed_eip_1559_tx_via_bytes() {
+        let private_key = get_sample_eth_private_key();
+        let tx = get_sample_unsigned_eip_1559_tx().sign(&private_key).unwrap();
+        let result = EthTransaction::from_bytes(&tx.to_bytes()).unwrap();
+        assert_eq!(result, tx);
+    }
+
+    #[test]
+    fn should_round_trip_signed_eip_2930_tx_via_bytes() {
+        let private_key = get_sample_eth_private_key();
+        let tx = get_sample_unsigned_eip_2930_tx().sign(&private_key).unwrap();
+        let result = EthTransaction::from_bytes(&tx.to_bytes()).unwrap();
+        assert_eq!(result, tx);
+    }
+
+    #[test]
+    fn should_still_decode_legacy_tx_bytes() {
+        let private_key = get_sample_eth_private_key();
+        let tx = get_sample_unsigned_eth_transaction().sign(&private_key).unwrap();
+        let result = EthTransaction::from_bytes(&tx.to_bytes()).unwrap();
+        assert_eq!(result.tx_type, EthTransactionType::Legacy);
+        assert_eq!(result.gas_price, tx.gas_price);
+        assert_eq!(result.r, tx.r);
+        assert_eq!(result.s, tx.s);
+    }
+
+    #[test]
+    fn should_deserialize_legacy_tx_json_without_typed_tx_fields() {
+        let json = r#"{"v":0,"r":"0x0","s":"0x0","to":[],"nonce":"0x0","value":"0x0","data":[],"gas_limit":"0x0","gas_price":"0x1","chain_id":"Mainnet"}"#;
+        let result: EthTransaction = serde_json::from_str(json).unwrap();
+        assert_eq!(result.tx_type, EthTransactionType::Legacy);
+        assert_eq!(result.gas_price, U256::one());
+    }
 }
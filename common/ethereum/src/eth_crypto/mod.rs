@@ -1,11 +1,13 @@
+mod eth_access_list;
 mod eth_private_key;
 mod eth_public_key;
 mod eth_signature;
 mod eth_transaction;
 
 pub use self::{
+    eth_access_list::{EthAccessList, EthAccessListItem},
     eth_private_key::EthPrivateKey,
     eth_public_key::EthPublicKey,
     eth_signature::{EthSignature, ETH_SIGNATURE_NUM_BYTES},
-    eth_transaction::{get_signed_minting_tx, EthTransaction, EthTransactions},
+    eth_transaction::{get_signed_minting_tx, EthTransaction, EthTransactionType, EthTransactions},
 };
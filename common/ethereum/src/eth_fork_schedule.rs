@@ -0,0 +1,456 @@
+use std::{fmt, str::FromStr};
+
+use common::{types::Result, AppError};
+use common_chain_ids::{EthChainId, EvmChainConfig, EvmChainRegistry, EvmForkConfig};
+use ethereum_types::U256;
+
+use crate::EthBlock;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EthHardfork {
+    London,
+    Shanghai,
+    Cancun,
+    Prague,
+    // NOTE: BSC specific forks.
+    Hertz,
+    Kepler,
+    Tycho,
+    Pascal,
+    // NOTE: Arbitrum specific forks.
+    Nitro,
+    // NOTE: OP-stack specific forks. Those which do not alter the header fields (Regolith,
+    // Fjord, Granite etc) are omitted.
+    Bedrock,
+    Canyon,
+    Ecotone,
+    Isthmus,
+}
+
+impl fmt::Display for EthHardfork {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl FromStr for EthHardfork {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_ref() {
+            "london" => Ok(Self::London),
+            "shanghai" => Ok(Self::Shanghai),
+            "cancun" => Ok(Self::Cancun),
+            "prague" => Ok(Self::Prague),
+            "hertz" => Ok(Self::Hertz),
+            "kepler" => Ok(Self::Kepler),
+            "tycho" => Ok(Self::Tycho),
+            "pascal" => Ok(Self::Pascal),
+            "nitro" => Ok(Self::Nitro),
+            "bedrock" => Ok(Self::Bedrock),
+            "canyon" => Ok(Self::Canyon),
+            "ecotone" => Ok(Self::Ecotone),
+            "isthmus" => Ok(Self::Isthmus),
+            _ => Err(format!("unrecognized hardfork: '{s}'").into()),
+        }
+    }
+}
+
+impl EthHardfork {
+    // NOTE: Chain specific forks do not always introduce the same header fields as their
+    // ethereum counterparts. Eg BSC's Kepler is its Shanghai, but the `withdrawals_root` is not
+    // added to its headers until Tycho.
+    fn header_fields(&self) -> &'static [EthHeaderField] {
+        match self {
+            Self::London | Self::Hertz | Self::Nitro | Self::Bedrock => &[EthHeaderField::BaseFeePerGas],
+            Self::Shanghai | Self::Canyon => &[EthHeaderField::WithdrawalsRoot],
+            Self::Kepler => &[],
+            Self::Cancun | Self::Ecotone => &[
+                EthHeaderField::BlobGasUsed,
+                EthHeaderField::ExcessBlobGas,
+                EthHeaderField::ParentBeaconBlockRoot,
+            ],
+            Self::Tycho => &[
+                EthHeaderField::WithdrawalsRoot,
+                EthHeaderField::BlobGasUsed,
+                EthHeaderField::ExcessBlobGas,
+                EthHeaderField::ParentBeaconBlockRoot,
+            ],
+            Self::Prague | Self::Pascal | Self::Isthmus => &[EthHeaderField::RequestsHash],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EthForkActivation {
+    Block(u64),
+    Timestamp(u64),
+}
+
+impl TryFrom<&EvmForkConfig> for EthForkActivation {
+    type Error = AppError;
+
+    fn try_from(config: &EvmForkConfig) -> Result<Self> {
+        match (config.block, config.timestamp) {
+            (Some(n), None) => Ok(Self::Block(n)),
+            (None, Some(t)) => Ok(Self::Timestamp(t)),
+            _ => Err(format!("fork `{}` needs exactly one of a block or a timestamp", config.fork).into()),
+        }
+    }
+}
+
+impl EthForkActivation {
+    fn is_active(&self, block: &EthBlock) -> bool {
+        match self {
+            Self::Block(n) => block.number >= U256::from(*n),
+            Self::Timestamp(t) => block.timestamp >= U256::from(*t),
+        }
+    }
+}
+
+// NOTE: These are the optional fields appended to a header after the original 15, in the order in
+// which they must be rlp encoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EthHeaderField {
+    BaseFeePerGas,
+    WithdrawalsRoot,
+    BlobGasUsed,
+    ExcessBlobGas,
+    ParentBeaconBlockRoot,
+    RequestsHash,
+}
+
+impl fmt::Display for EthHeaderField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::BaseFeePerGas => "base_fee_per_gas",
+            Self::WithdrawalsRoot => "withdrawals_root",
+            Self::BlobGasUsed => "blob_gas_used",
+            Self::ExcessBlobGas => "excess_blob_gas",
+            Self::ParentBeaconBlockRoot => "parent_beacon_block_root",
+            Self::RequestsHash => "requests_hash",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl EthHeaderField {
+    const ALL: [Self; 6] = [
+        Self::BaseFeePerGas,
+        Self::WithdrawalsRoot,
+        Self::BlobGasUsed,
+        Self::ExcessBlobGas,
+        Self::ParentBeaconBlockRoot,
+        Self::RequestsHash,
+    ];
+
+    fn is_present_in(&self, block: &EthBlock) -> bool {
+        match self {
+            Self::BaseFeePerGas => block.base_fee_per_gas.is_some(),
+            Self::WithdrawalsRoot => block.withdrawals_root.is_some(),
+            Self::BlobGasUsed => block.blob_gas_used.is_some(),
+            Self::ExcessBlobGas => block.excess_blob_gas.is_some(),
+            Self::ParentBeaconBlockRoot => block.parent_beacon_block_root.is_some(),
+            Self::RequestsHash => block.requests_hash.is_some(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EthForkSchedule(Option<Vec<(EthHardfork, EthForkActivation)>>);
+
+impl From<&EthChainId> for EthForkSchedule {
+    fn from(eth_chain_id: &EthChainId) -> Self {
+        use EthForkActivation::{Block, Timestamp};
+        use EthHardfork::*;
+
+        let forks = match eth_chain_id {
+            EthChainId::Mainnet => vec![
+                (London, Block(12_965_000)),
+                (Shanghai, Timestamp(1_681_338_455)),
+                (Cancun, Timestamp(1_710_338_135)),
+                (Prague, Timestamp(1_746_612_311)),
+            ],
+            EthChainId::Sepolia => vec![
+                (London, Block(0)),
+                (Shanghai, Timestamp(1_677_557_088)),
+                (Cancun, Timestamp(1_706_655_072)),
+                (Prague, Timestamp(1_741_159_776)),
+            ],
+            EthChainId::Goerli => vec![
+                (London, Block(5_062_605)),
+                (Shanghai, Timestamp(1_678_832_736)),
+                (Cancun, Timestamp(1_705_473_120)),
+            ],
+            EthChainId::Ropsten => vec![(London, Block(10_499_401))],
+            EthChainId::Rinkeby => vec![(London, Block(8_897_988))],
+            EthChainId::BscMainnet => vec![
+                (Hertz, Block(31_302_048)),
+                (Kepler, Timestamp(1_705_996_800)),
+                (Tycho, Timestamp(1_718_863_500)),
+                (Pascal, Timestamp(1_742_436_600)),
+            ],
+            EthChainId::XDaiMainnet => vec![
+                (London, Block(19_040_000)),
+                (Shanghai, Timestamp(1_690_889_660)),
+                (Cancun, Timestamp(1_710_181_820)),
+                (Prague, Timestamp(1_746_021_820)),
+            ],
+            // NOTE: Bor's own forks (Delhi, Indore, Napoli etc) do not alter the header fields.
+            EthChainId::PolygonMainnet => vec![(London, Block(23_850_000))],
+            EthChainId::ArbitrumMainnet => vec![(Nitro, Block(22_207_817))],
+            // NOTE: A registered chain's schedule is validated when it's registered, and so a
+            // malformed one is treated as unknown here.
+            EthChainId::Unknown(id) => {
+                return EvmChainRegistry::get(*id)
+                    .and_then(|c| Self::try_from(&c).ok())
+                    .unwrap_or(Self(None))
+            },
+            _ => return Self(None),
+        };
+
+        Self(Some(forks))
+    }
+}
+
+impl TryFrom<&EvmChainConfig> for EthForkSchedule {
+    type Error = AppError;
+
+    fn try_from(config: &EvmChainConfig) -> Result<Self> {
+        if config.forks.is_empty() {
+            return Ok(Self(None));
+        };
+        let forks = config
+            .forks
+            .iter()
+            .map(|f| Ok((EthHardfork::from_str(&f.fork)?, EthForkActivation::try_from(f)?)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self(Some(forks)))
+    }
+}
+
+impl EthForkSchedule {
+    pub fn is_known(&self) -> bool {
+        self.0.is_some()
+    }
+
+    pub fn is_active(&self, fork: &EthHardfork, block: &EthBlock) -> bool {
+        self.0.as_ref().map_or(false, |forks| {
+            forks
+                .iter()
+                .any(|(f, activation)| f == fork && activation.is_active(block))
+        })
+    }
+
+    /// Returns the optional header fields the given block must have according to this schedule,
+    /// in the order in which they are rlp encoded. Chains without a known schedule fall back to
+    /// whichever fields the block itself has.
+    pub fn header_fields(&self, block: &EthBlock) -> Vec<EthHeaderField> {
+        match self.0 {
+            None => EthHeaderField::ALL
+                .into_iter()
+                .filter(|field| field.is_present_in(block))
+                .collect(),
+            Some(ref forks) => {
+                let mut fields = forks
+                    .iter()
+                    .filter(|(_, activation)| activation.is_active(block))
+                    .flat_map(|(fork, _)| fork.header_fields().iter().copied())
+                    .collect::<Vec<_>>();
+                fields.sort();
+                fields.dedup();
+                fields
+            },
+        }
+    }
+
+    /// Checks that the block has exactly the optional header fields its chain's fork schedule
+    /// requires at its height, erroring with the offending field if not.
+    pub fn validate_header_fields(&self, block: &EthBlock) -> Result<Vec<EthHeaderField>> {
+        let fields = self.header_fields(block);
+        match EthHeaderField::ALL
+            .into_iter()
+            .find(|field| field.is_present_in(block) != fields.contains(field))
+        {
+            None => Ok(fields),
+            Some(field) if fields.contains(&field) => {
+                Err(format!("block #{} is missing header field `{field}`", block.number).into())
+            },
+            Some(field) => Err(format!(
+                "block #{} has header field `{field}` before the fork that introduces it",
+                block.number
+            )
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        get_sample_eip1559_mainnet_submission_material,
+        get_sample_eip4844_sepolia_submission_material,
+        get_sample_eth_submission_material,
+    };
+
+    #[test]
+    fn london_should_be_active_on_mainnet_after_activation_block() {
+        let schedule = EthForkSchedule::from(&EthChainId::Mainnet);
+        let pre_london_block = get_sample_eth_submission_material().block.unwrap();
+        let london_block = get_sample_eip1559_mainnet_submission_material().block.unwrap();
+        assert!(!schedule.is_active(&EthHardfork::London, &pre_london_block));
+        assert!(schedule.is_active(&EthHardfork::London, &london_block));
+        assert!(!schedule.is_active(&EthHardfork::Shanghai, &london_block));
+    }
+
+    #[test]
+    fn should_get_cancun_header_fields_for_sepolia_block() {
+        let schedule = EthForkSchedule::from(&EthChainId::Sepolia);
+        let block = get_sample_eip4844_sepolia_submission_material().block.unwrap();
+        let result = schedule.validate_header_fields(&block).unwrap();
+        let expected_result = vec![
+            EthHeaderField::BaseFeePerGas,
+            EthHeaderField::WithdrawalsRoot,
+            EthHeaderField::BlobGasUsed,
+            EthHeaderField::ExcessBlobGas,
+            EthHeaderField::ParentBeaconBlockRoot,
+        ];
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_only_add_withdrawals_root_to_shanghai_but_pre_cancun_headers() {
+        let schedule = EthForkSchedule::from(&EthChainId::Mainnet);
+        let mut block = get_sample_eip1559_mainnet_submission_material().block.unwrap();
+        block.timestamp = U256::from(1_700_000_000);
+        let result = schedule.header_fields(&block);
+        let expected_result = vec![EthHeaderField::BaseFeePerGas, EthHeaderField::WithdrawalsRoot];
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_add_requests_hash_to_prague_headers() {
+        let schedule = EthForkSchedule::from(&EthChainId::Mainnet);
+        let mut block = get_sample_eip1559_mainnet_submission_material().block.unwrap();
+        block.timestamp = U256::from(1_750_000_000);
+        let result = schedule.header_fields(&block);
+        assert_eq!(result.last(), Some(&EthHeaderField::RequestsHash));
+        assert_eq!(result.len(), 6);
+    }
+
+    #[test]
+    fn bsc_should_not_add_withdrawals_root_until_tycho() {
+        let schedule = EthForkSchedule::from(&EthChainId::BscMainnet);
+        let mut block = get_sample_eip1559_mainnet_submission_material().block.unwrap();
+        block.number = U256::from(35_000_000);
+        block.timestamp = U256::from(1_710_000_000);
+        assert_eq!(schedule.header_fields(&block), vec![EthHeaderField::BaseFeePerGas]);
+        block.timestamp = U256::from(1_720_000_000);
+        assert!(schedule
+            .header_fields(&block)
+            .contains(&EthHeaderField::WithdrawalsRoot));
+    }
+
+    #[test]
+    fn should_error_if_block_is_missing_a_header_field_its_fork_requires() {
+        let schedule = EthForkSchedule::from(&EthChainId::Sepolia);
+        let mut block = get_sample_eip4844_sepolia_submission_material().block.unwrap();
+        block.withdrawals_root = None;
+        let err = schedule.validate_header_fields(&block).unwrap_err().to_string();
+        assert!(err.contains("missing header field `withdrawals_root`"));
+    }
+
+    #[test]
+    fn should_error_if_block_has_a_header_field_before_its_fork() {
+        let schedule = EthForkSchedule::from(&EthChainId::Mainnet);
+        let mut block = get_sample_eth_submission_material().block.unwrap();
+        block.base_fee_per_gas = Some(U256::from(1));
+        let err = schedule.validate_header_fields(&block).unwrap_err().to_string();
+        assert!(err.contains("`base_fee_per_gas` before the fork"));
+    }
+
+    #[test]
+    fn unknown_chains_should_fall_back_to_fields_present_in_block() {
+        let schedule = EthForkSchedule::from(&EthChainId::Unknown(1337));
+        let block = get_sample_eip4844_sepolia_submission_material().block.unwrap();
+        assert!(!schedule.is_known());
+        assert_eq!(schedule.header_fields(&block).len(), 5);
+    }
+
+    #[test]
+    fn should_get_fork_schedule_from_evm_chain_config() {
+        let config = EvmChainConfig {
+            chain_id: 324,
+            name: "zksync".into(),
+            gas_multiplier: 1,
+            forks: vec![
+                EvmForkConfig {
+                    fork: "London".into(),
+                    block: Some(0),
+                    timestamp: None,
+                },
+                EvmForkConfig {
+                    fork: "shanghai".into(),
+                    block: None,
+                    timestamp: Some(1_700_000_000),
+                },
+            ],
+            ..Default::default()
+        };
+        let result = EthForkSchedule::try_from(&config).unwrap();
+        let expected_result = EthForkSchedule(Some(vec![
+            (EthHardfork::London, EthForkActivation::Block(0)),
+            (EthHardfork::Shanghai, EthForkActivation::Timestamp(1_700_000_000)),
+        ]));
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_error_on_malformed_evm_fork_config() {
+        let mut config = EvmChainConfig {
+            chain_id: 1101,
+            name: "polygon-zkevm".into(),
+            forks: vec![EvmForkConfig {
+                fork: "london".into(),
+                block: Some(0),
+                timestamp: Some(0),
+            }],
+            ..Default::default()
+        };
+        assert!(EthForkSchedule::try_from(&config).is_err());
+        config.forks[0] = EvmForkConfig {
+            fork: "not-a-fork".into(),
+            block: Some(0),
+            timestamp: None,
+        };
+        assert!(EthForkSchedule::try_from(&config).is_err());
+    }
+
+    #[test]
+    fn should_get_op_stack_header_fields_from_evm_chain_config() {
+        let fork = |fork: &str, timestamp: Option<u64>| EvmForkConfig {
+            fork: fork.into(),
+            block: timestamp.map_or(Some(0), |_| None),
+            timestamp,
+        };
+        let config = EvmChainConfig {
+            chain_id: 8453,
+            name: "base".into(),
+            forks: vec![
+                fork("bedrock", None),
+                fork("canyon", Some(1_704_992_401)),
+                fork("ecotone", Some(1_710_374_401)),
+                fork("isthmus", Some(1_746_806_401)),
+            ],
+            ..Default::default()
+        };
+        let schedule = EthForkSchedule::try_from(&config).unwrap();
+        let mut block = get_sample_eip1559_mainnet_submission_material().block.unwrap();
+        block.timestamp = U256::from(1_705_000_000);
+        let expected_result = vec![EthHeaderField::BaseFeePerGas, EthHeaderField::WithdrawalsRoot];
+        assert_eq!(schedule.header_fields(&block), expected_result);
+        block.timestamp = U256::from(1_750_000_000);
+        assert_eq!(schedule.header_fields(&block).len(), 6);
+    }
+}
@@ -139,6 +139,8 @@ pub struct EthReceiptJson {
     pub contract_address: serde_json::Value,
     #[serde(rename = "type")]
     pub receipt_type: Option<String>,
+    pub deposit_nonce: Option<u64>,
+    pub deposit_receipt_version: Option<u64>,
 }
 
 impl FromStr for EthReceiptJson {
@@ -164,6 +166,10 @@ pub struct EthReceipt {
     pub logs: EthLogs,
     pub logs_bloom: Bloom,
     pub receipt_type: Option<EthReceiptType>,
+    // NOTE: OP-stack deposit receipts carry these extra fields from the Regolith and Canyon
+    // hardforks respectively, and they form part of the receipt's rlp encoding.
+    pub deposit_nonce: Option<u64>,
+    pub deposit_receipt_version: Option<u64>,
 }
 
 impl FromStr for EthReceipt {
@@ -191,10 +197,22 @@ impl EthReceipt {
             .iter()
             .map(|eth_log| eth_log.to_json())
             .collect::<Result<Vec<JsonValue>>>()?;
-        if self.receipt_type.is_none() {
+        let json = if self.receipt_type.is_none() {
             self.to_json_legacy(encoded_logs)
         } else {
             self.to_eip_2718_json(encoded_logs)
+        }?;
+        self.add_deposit_fields_to_json(json)
+    }
+
+    fn add_deposit_fields_to_json(&self, json: JsonValue) -> Result<JsonValue> {
+        let json = match self.deposit_nonce {
+            Some(nonce) => add_key_and_value_to_json("depositNonce", json!(nonce), json)?,
+            None => json,
+        };
+        match self.deposit_receipt_version {
+            Some(version) => add_key_and_value_to_json("depositReceiptVersion", json!(version), json),
+            None => Ok(json),
         }
     }
 
@@ -251,6 +269,8 @@ impl EthReceipt {
                 Some(ref hex) => Some(EthReceiptType::from_byte(&hex::decode(strip_hex_prefix(hex))?[0])),
                 None => None,
             },
+            deposit_nonce: json.deposit_nonce,
+            deposit_receipt_version: json.deposit_receipt_version,
             logs,
         })
     }
@@ -269,6 +289,10 @@ impl EthReceipt {
                 trace!("RLP encoding LEGACY receipt...");
                 self.rlp_encode_legacy()
             },
+            EthReceiptType::OptimismDepositTxType => {
+                trace!("RLP encoding OP-stack DEPOSIT receipt...");
+                self.encode_optimism_deposit()
+            },
             receipt_type => {
                 trace!("RLP encoding NON LEGACY receipt type: {}", receipt_type);
                 self.encode_non_legacy(&receipt_type)
@@ -285,9 +309,36 @@ impl EthReceipt {
         Ok([receipt_type.to_bytes(), self.rlp_encode_legacy()?].concat())
     }
 
-    fn rlp_encode_legacy(&self) -> Result<Bytes> {
+    fn encode_optimism_deposit(&self) -> Result<Bytes> {
+        // NOTE: The `ReceiptPayload` for OP-stack deposits is that of a legacy receipt, optionally
+        // followed by the deposit nonce & then the deposit receipt version. Being optional rlp
+        // fields, the version may only be present if the nonce is too.
+        let extra_fields = match (self.deposit_nonce, self.deposit_receipt_version) {
+            (None, None) => vec![],
+            (Some(nonce), None) => vec![nonce],
+            (Some(nonce), Some(version)) => vec![nonce, version],
+            (None, Some(_)) => {
+                return Err(format!(
+                    "deposit receipt for tx 0x{:x} has a receipt version but no nonce",
+                    self.transaction_hash
+                )
+                .into())
+            },
+        };
         let mut rlp_stream = RlpStream::new();
-        rlp_stream.begin_list(4);
+        rlp_stream.begin_list(4 + extra_fields.len());
+        self.append_legacy_fields(&mut rlp_stream);
+        extra_fields.iter().for_each(|field| {
+            rlp_stream.append(field);
+        });
+        Ok([
+            EthReceiptType::OptimismDepositTxType.to_bytes(),
+            rlp_stream.out().to_vec(),
+        ]
+        .concat())
+    }
+
+    fn append_legacy_fields(&self, rlp_stream: &mut RlpStream) {
         match &self.status {
             true => rlp_stream.append(&self.status),
             false => rlp_stream.append_empty_data(),
@@ -296,10 +347,16 @@ impl EthReceipt {
             .append(&self.cumulative_gas_used)
             .append(&self.logs_bloom)
             .append_list(&self.logs);
+    }
+
+    fn rlp_encode_legacy(&self) -> Result<Bytes> {
+        let mut rlp_stream = RlpStream::new();
+        rlp_stream.begin_list(4);
+        self.append_legacy_fields(&mut rlp_stream);
         Ok(rlp_stream.out().to_vec())
     }
 
-    fn rlp_encode_transaction_index(&self) -> Bytes {
+    pub fn rlp_encode_transaction_index(&self) -> Bytes {
         let mut rlp_stream = RlpStream::new();
         rlp_stream.append(&self.transaction_index.as_usize());
         rlp_stream.out().to_vec()
@@ -576,4 +633,58 @@ mod tests {
             EthHash::from_str("0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421").unwrap();
         assert_eq!(result, expected_result);
     }
+
+    fn get_sample_optimism_deposit_receipt() -> EthReceipt {
+        EthReceipt {
+            status: true,
+            cumulative_gas_used: U256::from(21_000),
+            receipt_type: Some(EthReceiptType::OptimismDepositTxType),
+            deposit_nonce: Some(5),
+            deposit_receipt_version: Some(1),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn should_encode_optimism_deposit_receipt_correctly() {
+        let receipt = get_sample_optimism_deposit_receipt();
+        let result = hex::encode(receipt.rlp_encode().unwrap());
+        let expected_result = format!("7ef9010a01825208b90100{}c00501", "00".repeat(256));
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_encode_pre_canyon_optimism_deposit_receipt_correctly() {
+        let mut receipt = get_sample_optimism_deposit_receipt();
+        receipt.deposit_receipt_version = None;
+        let result = hex::encode(receipt.rlp_encode().unwrap());
+        let expected_result = format!("7ef9010901825208b90100{}c005", "00".repeat(256));
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_encode_pre_regolith_optimism_deposit_receipt_correctly() {
+        let mut receipt = get_sample_optimism_deposit_receipt();
+        receipt.deposit_nonce = None;
+        receipt.deposit_receipt_version = None;
+        let result = hex::encode(receipt.rlp_encode().unwrap());
+        let expected_result = format!("7ef9010801825208b90100{}c0", "00".repeat(256));
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_to_encode_optimism_deposit_receipt_with_version_but_no_nonce() {
+        let mut receipt = get_sample_optimism_deposit_receipt();
+        receipt.deposit_nonce = None;
+        assert!(receipt.rlp_encode().is_err());
+    }
+
+    #[test]
+    fn optimism_deposit_receipt_should_make_json_str_roundtrip() {
+        let mut receipt = get_sample_optimism_deposit_receipt();
+        receipt.logs_bloom = receipt.logs.get_bloom();
+        let s = receipt.to_string().unwrap();
+        let result = EthReceipt::from_str(&s).unwrap();
+        assert_eq!(result, receipt);
+    }
 }
@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use common::{types::Result, CommonError};
+use common::{types::Result, utils::strip_hex_prefix, CommonError};
 use ethereum_types::{Address as EthAddress, H160, U256};
 use serde::Deserialize;
 
@@ -29,6 +29,8 @@ pub struct EthReceiptFromJsonRpc {
     #[serde(rename = "type")]
     pub receipt_type: Option<String>,
     pub contract_address: Option<String>, // NOTE: Because it could be null if not a contract creation tx.
+    pub deposit_nonce: Option<String>,    // NOTE: Only present in OP-stack deposit receipts.
+    pub deposit_receipt_version: Option<String>, // NOTE: Ibid
 }
 
 impl TryFrom<Vec<EthReceiptFromJsonRpc>> for EthReceipts {
@@ -87,6 +89,14 @@ impl EthReceipt {
                 Some(ref s) => Some(EthReceiptType::from_str(s)?),
                 None => Some(EthReceiptType::Legacy),
             },
+            deposit_nonce: match json.deposit_nonce {
+                None => None,
+                Some(ref s) => Some(u64::from_str_radix(&strip_hex_prefix(s), radix)?),
+            },
+            deposit_receipt_version: match json.deposit_receipt_version {
+                None => None,
+                Some(ref s) => Some(u64::from_str_radix(&strip_hex_prefix(s), radix)?),
+            },
             status: matches!(json.status.as_ref(), "0x1" | "0x01"),
             logs,
         })
@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+
+use common::{
+    crypto_utils::keccak_hash_bytes,
+    types::{Byte, Bytes, Result},
+};
+use derive_more::{Constructor, Deref};
+use ethereum_types::H256 as EthHash;
+use rlp::Rlp;
+use serde::{Deserialize, Serialize};
+
+use crate::EthReceipt;
+
+const BRANCH_NODE_NUM_ITEMS: usize = 17;
+const LEAF_OR_EXTENSION_NODE_NUM_ITEMS: usize = 2;
+
+/// An inclusion proof for a receipt in a block's receipts trie, consisting of the rlp encoded
+/// nodes on the path from the root to the receipt.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Constructor, Deref)]
+pub struct EthReceiptProof(Vec<Bytes>);
+
+fn to_nibbles(bytes: &[Byte]) -> Vec<Byte> {
+    bytes.iter().flat_map(|b| [b >> 4, b & 0x0f]).collect()
+}
+
+// NOTE: Walks the passed in proof nodes from the root along the path of the key, returning the value
+// at the end of it, if any. Shared by receipt & tx proofs, since both tries are keyed alike.
+pub(crate) fn get_proven_value(proof: &[Bytes], root: &EthHash, key: &[Byte]) -> Result<Option<Bytes>> {
+    let nodes: HashMap<EthHash, &Bytes> = proof.iter().map(|n| (keccak_hash_bytes(n), n)).collect();
+    let key_nibbles = to_nibbles(key);
+    let mut path = &key_nibbles[..];
+    let mut node = match nodes.get(root) {
+        Some(n) => n.to_vec(),
+        None => return Ok(None),
+    };
+
+    loop {
+        let rlp = Rlp::new(&node);
+        let next = match rlp.item_count()? {
+            BRANCH_NODE_NUM_ITEMS => match path.first() {
+                None => {
+                    let value = rlp.at(16)?.data()?;
+                    return Ok(if value.is_empty() { None } else { Some(value.to_vec()) });
+                },
+                Some(nibble) => {
+                    path = &path[1..];
+                    rlp.at(*nibble as usize)?
+                },
+            },
+            LEAF_OR_EXTENSION_NODE_NUM_ITEMS => {
+                // NOTE: Paths are hex-prefix encoded, where the first nibble flags whether the
+                // node is a leaf, and whether the path has an odd number of nibbles.
+                let encoded_path = to_nibbles(rlp.at(0)?.data()?);
+                let (is_leaf, node_path) = match encoded_path.first() {
+                    Some(0) => (false, &encoded_path[2..]),
+                    Some(1) => (false, &encoded_path[1..]),
+                    Some(2) => (true, &encoded_path[2..]),
+                    Some(3) => (true, &encoded_path[1..]),
+                    _ => return Err("invalid hex prefix in proof node".into()),
+                };
+                if is_leaf {
+                    return Ok(if path == node_path {
+                        Some(rlp.at(1)?.data()?.to_vec())
+                    } else {
+                        None
+                    });
+                } else if !path.starts_with(node_path) {
+                    return Ok(None);
+                };
+                path = &path[node_path.len()..];
+                rlp.at(1)?
+            },
+            n => return Err(format!("invalid proof node with {n} items").into()),
+        };
+
+        // NOTE: Nodes whose encoding is shorter than 32 bytes are embedded in their parent
+        // rather than referenced by their hash.
+        let next_node = if next.is_list() {
+            next.as_raw().to_vec()
+        } else {
+            match next.data()? {
+                [] => return Ok(None),
+                hash if hash.len() == EthHash::len_bytes() => match nodes.get(&EthHash::from_slice(hash)) {
+                    Some(n) => n.to_vec(),
+                    None => return Ok(None),
+                },
+                _ => return Err("invalid node reference in proof".into()),
+            }
+        };
+        node = next_node;
+    }
+}
+
+impl EthReceiptProof {
+    pub fn verify(&self, receipts_root: &EthHash, receipt: &EthReceipt) -> Result<bool> {
+        let (key, value) = receipt.get_rlp_encoded_index_and_rlp_encoded_receipt_tuple()?;
+        let proven_value = get_proven_value(self, receipts_root, &key)?;
+        Ok(proven_value == Some(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethereum_types::U256;
+    use rlp::RlpStream;
+
+    use super::*;
+    use crate::{test_utils::get_sample_receipt_with_desired_topic, EthReceipts};
+
+    fn get_single_receipt_trie_proof(receipt: &EthReceipt) -> EthReceiptProof {
+        let (key, value) = receipt.get_rlp_encoded_index_and_rlp_encoded_receipt_tuple().unwrap();
+        let mut rlp_stream = RlpStream::new_list(2);
+        rlp_stream.append(&[vec![0x20], key].concat()).append(&value);
+        EthReceiptProof::new(vec![rlp_stream.out().to_vec()])
+    }
+
+    #[test]
+    fn should_verify_receipt_proof() {
+        let mut receipt = get_sample_receipt_with_desired_topic();
+        receipt.transaction_index = U256::zero();
+        let receipts_root = EthReceipts::new(vec![receipt.clone()]).get_merkle_root().unwrap();
+        let proof = get_single_receipt_trie_proof(&receipt);
+        let result = proof.verify(&receipts_root, &receipt).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn should_not_verify_receipt_proof_for_altered_receipt() {
+        let mut receipt = get_sample_receipt_with_desired_topic();
+        receipt.transaction_index = U256::zero();
+        let receipts_root = EthReceipts::new(vec![receipt.clone()]).get_merkle_root().unwrap();
+        let proof = get_single_receipt_trie_proof(&receipt);
+        receipt.status = !receipt.status;
+        let result = proof.verify(&receipts_root, &receipt).unwrap();
+        assert!(!result);
+    }
+
+    #[test]
+    fn should_not_verify_receipt_proof_against_wrong_root() {
+        let mut receipt = get_sample_receipt_with_desired_topic();
+        receipt.transaction_index = U256::zero();
+        let proof = get_single_receipt_trie_proof(&receipt);
+        let result = proof.verify(&EthHash::random(), &receipt).unwrap();
+        assert!(!result);
+    }
+}
@@ -21,6 +21,7 @@ pub enum EthReceiptType {
     ArbitrumContractTxType,
     ArbitrumInternalTxType,
     ArbitrumSubmitRetryableTxType,
+    OptimismDepositTxType, // NOTE: OP-stack L1 -> L2 deposits: https://specs.optimism.io/protocol/deposits.html
 }
 
 impl EthReceiptType {
@@ -37,6 +38,7 @@ impl EthReceiptType {
             0x78 => Self::ArbitrumLegacyTxType,
             0x6a => Self::ArbitrumInternalTxType,
             0x69 => Self::ArbitrumSubmitRetryableTxType,
+            0x7e => Self::OptimismDepositTxType,
             _ => Self::Legacy,
         }
     }
@@ -53,6 +55,7 @@ impl EthReceiptType {
             Self::ArbitrumContractTxType => 0x66,
             Self::ArbitrumInternalTxType => 0x6a,
             Self::ArbitrumSubmitRetryableTxType => 0x69,
+            Self::OptimismDepositTxType => 0x7e,
             // NOTE: This is to remain backwards compatible where an earlier bug caused
             // some submission materail to have a type "EIP2718" when it should in fact
             // have been "0x2" or "EIP1559".
@@ -79,6 +82,7 @@ impl fmt::Display for EthReceiptType {
             Self::ArbitrumContractTxType => "0x66",
             Self::ArbitrumInternalTxType => "0x6a",
             Self::ArbitrumSubmitRetryableTxType => "0x69",
+            Self::OptimismDepositTxType => "0x7e",
         };
         write!(f, "{}", s)
     }
@@ -100,6 +104,7 @@ impl FromStr for EthReceiptType {
             "ArbitrumContractTxType" | "0x66" | "66" => Ok(Self::ArbitrumContractTxType),
             "ArbitrumInternalTxType" | "0x6a" | "6a" => Ok(Self::ArbitrumInternalTxType),
             "ArbitrumSubmitRetryableTxType" | "0x69" | "69" => Ok(Self::ArbitrumSubmitRetryableTxType),
+            "OptimismDepositTxType" | "0x7e" | "7e" => Ok(Self::OptimismDepositTxType),
             _ => Err(format!("Unrecognized ETH receipt type: {s}").into()),
         }
     }
@@ -133,6 +138,14 @@ mod tests {
         assert_eq!(results, expected_results);
     }
 
+    #[test]
+    fn should_parse_optimism_deposit_receipt_type() {
+        let expected_result = EthReceiptType::OptimismDepositTxType;
+        assert_eq!(EthReceiptType::from_str("0x7e").unwrap(), expected_result);
+        assert_eq!(EthReceiptType::from_byte(&0x7e), expected_result);
+        assert_eq!(expected_result.to_string(), "0x7e");
+    }
+
     #[test]
     fn should_parse_submission_material_with_eip4844_receipt_type() {
         let p = "src/test_utils/goerli-sub-mat-with-eip-4844-receipt-type.json";
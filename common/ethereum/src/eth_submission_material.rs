@@ -1,17 +1,29 @@
 use std::{str::FromStr, time::Duration};
 
 use common::{
+    crypto_utils::keccak_hash_bytes,
     errors::AppError,
     traits::DatabaseInterface,
     types::{Byte, Bytes, NoneError, Result},
 };
 use common_chain_ids::EthChainId;
 use derive_more::{Constructor, Deref, DerefMut};
-use ethereum_types::{Address as EthAddress, H256 as EthHash, U256};
+use ethereum_types::{Address as EthAddress, Bloom, H256 as EthHash, U256};
+use keccak_hasher::KeccakHasher;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value as JsonValue};
-
-use crate::{EthBlock, EthBlockJson, EthReceipt, EthReceiptJson, EthReceipts, EthState};
+use triehash::trie_root;
+
+use crate::{
+    EthBlock,
+    EthBlockJson,
+    EthReceipt,
+    EthReceiptJson,
+    EthReceiptProof,
+    EthReceipts,
+    EthState,
+    EthTransactionProof,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq, Default, Deref, DerefMut, Constructor, Deserialize, Serialize)]
 pub struct EthSubmissionMaterials(Vec<EthSubmissionMaterial>);
@@ -96,6 +108,15 @@ pub struct EthSubmissionMaterial {
     pub receipts_root: Option<EthHash>,
     pub algo_first_valid_round: Option<u64>,
     pub timestamp: Option<U256>,
+    // NOTE: The raw, signed transactions of the block, in order. These are only required in order
+    // to validate the block's transactions root, and so are removed along with the receipts.
+    pub raw_transactions: Option<Vec<Bytes>>,
+    // NOTE: When present, the receipts are a pre-filtered subset of the block's receipts, each
+    // accompanied by its inclusion proof against the block's receipts root.
+    pub receipt_proofs: Option<Vec<EthReceiptProof>>,
+    // NOTE: Accompanies the receipt proofs in place of the raw txs, with a proof for each receipt's
+    // tx against the block's transactions root.
+    pub transaction_proofs: Option<Vec<EthTransactionProof>>,
 }
 
 impl PartialEq for EthSubmissionMaterial {
@@ -118,6 +139,9 @@ impl PartialEq for EthSubmissionMaterial {
             && self.eos_ref_block_num == other.eos_ref_block_num
             && self.eos_ref_block_prefix == other.eos_ref_block_prefix
             && self.algo_first_valid_round == other.algo_first_valid_round
+            && self.raw_transactions == other.raw_transactions
+            && self.receipt_proofs == other.receipt_proofs
+            && self.transaction_proofs == other.transaction_proofs
         // NOTE: We omit the timestamp!
     }
 }
@@ -163,6 +187,47 @@ impl EthSubmissionMaterial {
         }
     }
 
+    pub fn add_raw_transactions(mut self, raw_transactions: Vec<Bytes>) -> Result<Self> {
+        if self.raw_transactions.is_none() {
+            debug!("adding raw transactions to ETH submission material!");
+            self.raw_transactions = Some(raw_transactions);
+            Ok(self)
+        } else {
+            Err("Cannot add raw transactions to ETH sub mat - some already exist!".into())
+        }
+    }
+
+    pub fn replace_receipts_with_proofs(
+        mut self,
+        receipts: EthReceipts,
+        receipt_proofs: Vec<EthReceiptProof>,
+        transaction_proofs: Option<Vec<EthTransactionProof>>,
+    ) -> Result<Self> {
+        if receipts.len() != receipt_proofs.len() {
+            Err(format!(
+                "Cannot add receipts to ETH sub mat - got {} receipts but {} proofs!",
+                receipts.len(),
+                receipt_proofs.len()
+            )
+            .into())
+        } else if matches!(transaction_proofs, Some(ref proofs) if proofs.len() != receipts.len()) {
+            Err(format!(
+                "Cannot add receipts to ETH sub mat - got {} receipts but a different number of tx proofs!",
+                receipts.len(),
+            )
+            .into())
+        } else {
+            debug!("replacing receipts in ETH submission material with pre-filtered receipts & proofs!");
+            self.receipts = receipts;
+            self.receipt_proofs = Some(receipt_proofs);
+            // NOTE: A receipt's proof commits to its index but not to its tx hash, so rather than all
+            // of the block's raw txs, each receipt is accompanied by a proof of its tx instead.
+            self.raw_transactions = None;
+            self.transaction_proofs = transaction_proofs;
+            Ok(self)
+        }
+    }
+
     pub fn get_tx_hashes(&self) -> Result<Vec<EthHash>> {
         info!("[+] Getting tx hashes from ETH submission material...");
         match self.block {
@@ -189,6 +254,9 @@ impl EthSubmissionMaterial {
             parent_hash: Some(block.parent_hash),
             receipts_root: Some(block.receipts_root),
             block: Some(block),
+            raw_transactions: None,
+            receipt_proofs: None,
+            transaction_proofs: None,
         }
     }
 
@@ -301,6 +369,9 @@ impl EthSubmissionMaterial {
                 eos_ref_block_num: json.eos_ref_block_num,
                 eos_ref_block_prefix: json.eos_ref_block_prefix,
                 block: Some(block),
+                raw_transactions: None,
+                receipt_proofs: None,
+                transaction_proofs: None,
                 algo_first_valid_round: json.algo_first_valid_round,
             }),
             None => {
@@ -317,6 +388,9 @@ impl EthSubmissionMaterial {
                     receipts,
                     timestamp,
                     block: None,
+                    raw_transactions: None,
+                    receipt_proofs: None,
+                    transaction_proofs: None,
                     hash: json.hash,
                     parent_hash: json.parent_hash,
                     block_number: json.block_number,
@@ -362,6 +436,9 @@ impl EthSubmissionMaterial {
             debug!("REMOVING receipts from ETH sub mat because they do NOT contain pertinent logs!");
             let mut mutable_self = self;
             mutable_self.receipts = EthReceipts::new(vec![]);
+            mutable_self.raw_transactions = None;
+            mutable_self.receipt_proofs = None;
+            mutable_self.transaction_proofs = None;
             mutable_self
         }
     }
@@ -405,9 +482,148 @@ impl EthSubmissionMaterial {
         })
     }
 
+    pub fn logs_blooms_are_valid(&self) -> Result<bool> {
+        if let Some(receipt) = self.receipts.iter().find(|r| r.logs.get_bloom() != r.logs_bloom) {
+            warn!(
+                "✘ Logs bloom of receipt for tx 0x{:x} does not match its logs!",
+                receipt.transaction_hash
+            );
+            return Ok(false);
+        };
+        if self.receipt_proofs.is_some() {
+            // NOTE: A pre-filtered subset of receipts cannot reproduce the block's logs bloom.
+            return Ok(true);
+        };
+        let calculated_bloom = self.receipts.iter().fold(Bloom::default(), |mut bloom, receipt| {
+            bloom.accrue_bloom(&receipt.logs_bloom);
+            bloom
+        });
+        let block_bloom = self.get_block()?.logs_bloom;
+        debug!("✔    Block's logs bloom: 0x{block_bloom:x}");
+        debug!("✔ Calculated logs bloom: 0x{calculated_bloom:x}");
+        Ok(calculated_bloom == block_bloom)
+    }
+
+    // NOTE: The block's tx hashes are only to be trusted once its transactions root has been validated.
+    pub fn receipts_match_transactions(&self) -> Result<bool> {
+        let tx_hashes = self.get_tx_hashes()?;
+        if self.receipt_proofs.is_some() {
+            return Ok(self.receipts.iter().all(|receipt| {
+                let is_match = receipt.transaction_index < U256::from(tx_hashes.len())
+                    && tx_hashes[receipt.transaction_index.as_usize()] == receipt.transaction_hash;
+                if !is_match {
+                    warn!(
+                        "✘ Receipt for tx 0x{:x} does not match tx at its index in block!",
+                        receipt.transaction_hash
+                    );
+                };
+                is_match
+            }));
+        };
+        if tx_hashes.len() != self.receipts.len() {
+            warn!(
+                "✘ Number of receipts ({}) does not match number of txs in block ({})!",
+                self.receipts.len(),
+                tx_hashes.len()
+            );
+            return Ok(false);
+        };
+        Ok(self.receipts.iter().enumerate().all(|(i, receipt)| {
+            let is_match = receipt.transaction_index == U256::from(i) && receipt.transaction_hash == tx_hashes[i];
+            if !is_match {
+                warn!(
+                    "✘ Receipt at index {i} does not match tx 0x{:x} in block!",
+                    tx_hashes[i]
+                );
+            };
+            is_match
+        }))
+    }
+
+    pub fn receipt_proofs_are_valid(&self) -> Result<bool> {
+        let receipt_proofs = self
+            .receipt_proofs
+            .as_ref()
+            .ok_or(NoneError("✘ No `receipt_proofs` in ETH submission material!"))?;
+        if receipt_proofs.len() != self.receipts.len() {
+            warn!(
+                "✘ Number of receipt proofs ({}) does not match number of receipts ({})!",
+                receipt_proofs.len(),
+                self.receipts.len()
+            );
+            return Ok(false);
+        };
+        let receipts_root = self.get_receipts_root()?;
+        for (receipt, proof) in self.receipts.iter().zip(receipt_proofs.iter()) {
+            if !proof.verify(&receipts_root, receipt)? {
+                warn!("✘ Invalid proof for receipt of tx 0x{:x}!", receipt.transaction_hash);
+                return Ok(false);
+            };
+        }
+        Ok(true)
+    }
+
+    pub fn transaction_proofs_are_valid(&self) -> Result<bool> {
+        let transaction_proofs = self
+            .transaction_proofs
+            .as_ref()
+            .ok_or(NoneError("✘ No `transaction_proofs` in ETH submission material!"))?;
+        if transaction_proofs.len() != self.receipts.len() {
+            warn!(
+                "✘ Number of tx proofs ({}) does not match number of receipts ({})!",
+                transaction_proofs.len(),
+                self.receipts.len()
+            );
+            return Ok(false);
+        };
+        let transactions_root = self.get_block()?.transactions_root;
+        for (receipt, proof) in self.receipts.iter().zip(transaction_proofs.iter()) {
+            if !proof.verify(&transactions_root, receipt)? {
+                warn!("✘ Invalid proof for tx 0x{:x}!", receipt.transaction_hash);
+                return Ok(false);
+            };
+        }
+        Ok(true)
+    }
+
+    // NOTE: The key & value of each of the block's txs in its transactions trie.
+    pub fn get_rlp_encoded_indices_and_raw_transactions(&self) -> Result<Vec<(Bytes, Bytes)>> {
+        let raw_txs = self
+            .raw_transactions
+            .as_ref()
+            .ok_or(NoneError("✘ No `raw_transactions` in ETH submission material!"))?;
+        Ok(raw_txs
+            .iter()
+            .enumerate()
+            .map(|(i, tx)| (rlp::encode(&i).to_vec(), tx.clone()))
+            .collect())
+    }
+
+    pub fn transactions_root_is_valid(&self) -> Result<bool> {
+        let raw_txs = self
+            .raw_transactions
+            .as_ref()
+            .ok_or(NoneError("✘ No `raw_transactions` in ETH submission material!"))?;
+        let block = self.get_block()?;
+        let calculated_tx_hashes = raw_txs.iter().map(|tx| keccak_hash_bytes(tx)).collect::<Vec<_>>();
+        if calculated_tx_hashes != block.transactions {
+            warn!("✘ Hashes of raw txs do not match the block's tx hashes!");
+            return Ok(false);
+        };
+        let calculated_root = EthHash::from_slice(&trie_root::<KeccakHasher, _, _, _>(
+            self.get_rlp_encoded_indices_and_raw_transactions()?,
+        ));
+        info!("✔    Block's transactions root: {}", block.transactions_root);
+        info!("✔ Calculated transactions root: {}", calculated_root);
+        Ok(calculated_root == block.transactions_root)
+    }
+
     pub fn remove_receipts(&self) -> Self {
         let mut mutable_self = self.clone();
         mutable_self.receipts = vec![].into();
+        mutable_self.raw_transactions = None;
+        mutable_self.receipt_proofs = None;
+        mutable_self.transaction_proofs = None;
         mutable_self
     }
 
@@ -482,6 +698,7 @@ mod tests {
     use super::*;
     use crate::{
         convert_hex_to_eth_address,
+        convert_hex_to_h256,
         test_utils::{
             get_expected_block,
             get_expected_receipt,
@@ -496,6 +713,7 @@ mod tests {
             get_sample_eth_submission_materials,
             SAMPLE_RECEIPT_INDEX,
         },
+        EthLogs,
         ERC_777_REDEEM_EVENT_TOPIC_WITHOUT_USER_DATA,
     };
 
@@ -603,6 +821,123 @@ mod tests {
         assert!(result);
     }
 
+    #[test]
+    fn logs_blooms_of_sample_submission_material_should_be_valid() {
+        let sub_mat = get_sample_eth_submission_material();
+        let result = sub_mat.logs_blooms_are_valid().unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn should_not_validate_logs_blooms_if_logs_are_removed() {
+        let mut sub_mat = get_sample_eth_submission_material();
+        let receipt = sub_mat.receipts.0.iter_mut().find(|r| !r.logs.is_empty()).unwrap();
+        receipt.logs = EthLogs::default();
+        let result = sub_mat.logs_blooms_are_valid().unwrap();
+        assert!(!result);
+    }
+
+    #[test]
+    fn receipts_of_sample_submission_material_should_match_transactions() {
+        let sub_mat = get_sample_eth_submission_material();
+        let result = sub_mat.receipts_match_transactions().unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn should_not_match_transactions_if_receipt_tx_hash_is_swapped_despite_valid_receipts_root() {
+        let mut sub_mat = get_sample_eth_submission_material();
+        sub_mat.receipts.0[0].transaction_hash = EthHash::random();
+        assert!(sub_mat.receipts_are_valid().unwrap());
+        let result = sub_mat.receipts_match_transactions().unwrap();
+        assert!(!result);
+    }
+
+    #[test]
+    fn should_validate_transactions_root() {
+        let raw_txs = vec![vec![0xc0], hex::decode("02c0").unwrap(), vec![0x01, 0xc1, 0x80]];
+        let mut block = get_sample_eth_submission_material().get_block().unwrap();
+        block.transactions = raw_txs.iter().map(|tx| keccak_hash_bytes(tx)).collect();
+        block.transactions_root = EthHash::from_slice(&trie_root::<KeccakHasher, _, _, _>(
+            raw_txs
+                .iter()
+                .enumerate()
+                .map(|(i, tx)| (rlp::encode(&i).to_vec(), tx.clone())),
+        ));
+        let sub_mat = EthSubmissionMaterial::default()
+            .add_block(block)
+            .and_then(|s| s.add_raw_transactions(raw_txs))
+            .unwrap();
+        assert!(sub_mat.transactions_root_is_valid().unwrap());
+        let mut tampered_sub_mat = sub_mat.clone();
+        tampered_sub_mat.raw_transactions.as_mut().unwrap().swap(0, 1);
+        assert!(!tampered_sub_mat.transactions_root_is_valid().unwrap());
+    }
+
+    #[test]
+    fn empty_block_should_have_valid_transactions_root() {
+        let mut block = get_sample_eth_submission_material().get_block().unwrap();
+        block.transactions = vec![];
+        block.transactions_root =
+            convert_hex_to_h256("0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421").unwrap();
+        let sub_mat = EthSubmissionMaterial::default()
+            .add_block(block)
+            .and_then(|s| s.add_raw_transactions(vec![]))
+            .unwrap();
+        assert!(sub_mat.transactions_root_is_valid().unwrap());
+    }
+
+    #[test]
+    fn should_remove_raw_transactions_when_replacing_receipts_with_proofs() {
+        let sub_mat = get_sample_eth_submission_material()
+            .add_raw_transactions(vec![vec![0xc0]])
+            .unwrap();
+        let result = sub_mat
+            .replace_receipts_with_proofs(vec![].into(), vec![], Some(vec![]))
+            .unwrap();
+        assert!(result.raw_transactions.is_none());
+        assert_eq!(result.receipt_proofs, Some(vec![]));
+        assert_eq!(result.transaction_proofs, Some(vec![]));
+    }
+
+    #[test]
+    fn should_validate_transaction_proofs() {
+        let raw_tx = vec![0x01, 0xc1, 0x80];
+        let mut receipt = get_sample_eth_submission_material().receipts[0].clone();
+        receipt.transaction_index = U256::zero();
+        receipt.transaction_hash = keccak_hash_bytes(&raw_tx);
+        let mut rlp_stream = rlp::RlpStream::new_list(2);
+        rlp_stream
+            .append(&[vec![0x20], receipt.rlp_encode_transaction_index()].concat())
+            .append(&raw_tx);
+        let leaf_node = rlp_stream.out().to_vec();
+        let mut block = get_sample_eth_submission_material().get_block().unwrap();
+        block.transactions_root = keccak_hash_bytes(&leaf_node);
+        let sub_mat = EthSubmissionMaterial::default()
+            .add_block(block)
+            .and_then(|s| {
+                s.replace_receipts_with_proofs(
+                    EthReceipts::new(vec![receipt]),
+                    vec![EthReceiptProof::default()],
+                    Some(vec![EthTransactionProof::new(vec![leaf_node])]),
+                )
+            })
+            .unwrap();
+        assert!(sub_mat.transaction_proofs_are_valid().unwrap());
+        let mut tampered_sub_mat = sub_mat.clone();
+        tampered_sub_mat.receipts.0[0].transaction_hash = EthHash::random();
+        assert!(!tampered_sub_mat.transaction_proofs_are_valid().unwrap());
+    }
+
+    #[test]
+    fn should_remove_raw_transactions_when_removing_receipts() {
+        let sub_mat = get_sample_eth_submission_material()
+            .add_raw_transactions(vec![])
+            .unwrap();
+        let result = sub_mat.remove_receipts();
+        assert!(result.raw_transactions.is_none());
+    }
+
     #[test]
     fn should_remove_receipts_from_block_and_receipts() {
         let block_and_receipts = get_sample_eth_submission_material();
@@ -0,0 +1,250 @@
+use common::{
+    crypto_utils::keccak_hash_bytes,
+    types::{Bytes, Result},
+};
+use ethereum_types::{H256 as EthHash, U256};
+use rlp::RlpStream;
+use serde::Deserialize;
+
+use crate::{
+    eth_utils::{convert_hex_to_bytes, convert_hex_to_eth_address, convert_hex_to_h256},
+    EthAccessList,
+    EthAccessListItem,
+};
+
+#[derive(Clone, Default, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EthAccessListItemFromJsonRpc {
+    pub address: String,
+    pub storage_keys: Vec<String>,
+}
+
+// NOTE: A tx as returned by `eth_getBlockByNumber` when asked for full txs, from which its raw,
+// signed bytes can be rebuilt. Most fields are optional, since which are present depends on the tx
+// type, and chains may have types of their own.
+#[derive(Clone, Default, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EthTransactionFromJsonRpc {
+    pub hash: String,
+    #[serde(rename = "type")]
+    pub tx_type: Option<String>,
+    pub chain_id: Option<String>,
+    pub nonce: Option<String>,
+    pub gas: Option<String>,
+    pub gas_price: Option<String>,
+    pub max_fee_per_gas: Option<String>,
+    pub max_priority_fee_per_gas: Option<String>,
+    pub max_fee_per_blob_gas: Option<String>,
+    pub to: Option<String>, // NOTE: Null for contract creation txs.
+    pub value: Option<String>,
+    pub input: Option<String>,
+    pub access_list: Option<Vec<EthAccessListItemFromJsonRpc>>,
+    pub blob_versioned_hashes: Option<Vec<String>>,
+    pub v: Option<String>,
+    pub y_parity: Option<String>,
+    pub r: Option<String>,
+    pub s: Option<String>,
+}
+
+fn get_u256(maybe_hex: &Option<String>, name: &str) -> Result<U256> {
+    match maybe_hex {
+        Some(ref hex) => Ok(U256::from_str_radix(hex, 16)?),
+        None => Err(format!("no `{name}` in json rpc tx").into()),
+    }
+}
+
+impl EthTransactionFromJsonRpc {
+    pub fn hash(&self) -> Result<EthHash> {
+        convert_hex_to_h256(&self.hash)
+    }
+
+    fn get_access_list(&self) -> Result<EthAccessList> {
+        Ok(EthAccessList::new(
+            self.access_list
+                .iter()
+                .flatten()
+                .map(|item| {
+                    Ok(EthAccessListItem::new(
+                        convert_hex_to_eth_address(&item.address)?,
+                        item.storage_keys
+                            .iter()
+                            .map(|k| convert_hex_to_h256(k))
+                            .collect::<Result<Vec<EthHash>>>()?,
+                    ))
+                })
+                .collect::<Result<Vec<EthAccessListItem>>>()?,
+        ))
+    }
+
+    fn append_to(&self, rlp_stream: &mut RlpStream) -> Result<()> {
+        match self.to {
+            Some(ref s) if !s.is_empty() => rlp_stream.append(&convert_hex_to_eth_address(s)?),
+            _ => rlp_stream.append_empty_data(),
+        };
+        Ok(())
+    }
+
+    fn append_gas_to_input(&self, rlp_stream: &mut RlpStream) -> Result<()> {
+        rlp_stream.append(&get_u256(&self.gas, "gas")?);
+        self.append_to(rlp_stream)?;
+        rlp_stream.append(&get_u256(&self.value, "value")?);
+        rlp_stream.append(&convert_hex_to_bytes(self.input.as_deref().unwrap_or_default())?);
+        Ok(())
+    }
+
+    fn append_signature(&self, rlp_stream: &mut RlpStream, v: U256) -> Result<()> {
+        rlp_stream.append(&v);
+        rlp_stream.append(&get_u256(&self.r, "r")?);
+        rlp_stream.append(&get_u256(&self.s, "s")?);
+        Ok(())
+    }
+
+    fn to_legacy_bytes(&self) -> Result<Bytes> {
+        let mut rlp_stream = RlpStream::new_list(9);
+        rlp_stream.append(&get_u256(&self.nonce, "nonce")?);
+        rlp_stream.append(&get_u256(&self.gas_price, "gasPrice")?);
+        self.append_gas_to_input(&mut rlp_stream)?;
+        self.append_signature(&mut rlp_stream, get_u256(&self.v, "v")?)?;
+        Ok(rlp_stream.out().to_vec())
+    }
+
+    // NOTE: Per EIP-2718, a typed tx is its type byte followed by the rlp encoding of its fields,
+    // which for EIP-2930, EIP-1559 & EIP-4844 txs differ only in their fees and blob fields.
+    fn to_typed_bytes(&self, type_byte: u8) -> Result<Bytes> {
+        let mut rlp_stream = RlpStream::new();
+        rlp_stream.begin_unbounded_list();
+        rlp_stream.append(&get_u256(&self.chain_id, "chainId")?);
+        rlp_stream.append(&get_u256(&self.nonce, "nonce")?);
+        if type_byte == 0x01 {
+            rlp_stream.append(&get_u256(&self.gas_price, "gasPrice")?);
+        } else {
+            rlp_stream.append(&get_u256(&self.max_priority_fee_per_gas, "maxPriorityFeePerGas")?);
+            rlp_stream.append(&get_u256(&self.max_fee_per_gas, "maxFeePerGas")?);
+        };
+        self.append_gas_to_input(&mut rlp_stream)?;
+        rlp_stream.append(&self.get_access_list()?);
+        if type_byte == 0x03 {
+            rlp_stream.append(&get_u256(&self.max_fee_per_blob_gas, "maxFeePerBlobGas")?);
+            rlp_stream.append_list(
+                &self
+                    .blob_versioned_hashes
+                    .iter()
+                    .flatten()
+                    .map(|h| convert_hex_to_h256(h))
+                    .collect::<Result<Vec<EthHash>>>()?,
+            );
+        };
+        // NOTE: Some nodes only return the y-parity of a typed tx's signature as its `v`.
+        let y_parity = match self.y_parity {
+            Some(_) => get_u256(&self.y_parity, "yParity")?,
+            None => get_u256(&self.v, "v")?,
+        };
+        self.append_signature(&mut rlp_stream, y_parity)?;
+        rlp_stream.finalize_unbounded_list();
+        Ok([vec![type_byte], rlp_stream.out().to_vec()].concat())
+    }
+
+    // NOTE: Returns `None` for any tx type we don't know how to encode, eg chain specific ones such
+    // as OP-stack deposits, as well as for any tx whose encoding doesn't hash to its tx hash, since
+    // in either case its raw bytes must be got some other way.
+    pub fn to_raw_transaction(&self) -> Result<Option<Bytes>> {
+        let tx_type = match self.tx_type {
+            Some(_) => get_u256(&self.tx_type, "type")?,
+            None => U256::zero(),
+        };
+        let bytes = match tx_type.as_u64() {
+            0 => self.to_legacy_bytes()?,
+            t @ 1..=3 => self.to_typed_bytes(t as u8)?,
+            t => {
+                debug!("cannot encode tx 0x{:x} of type {t}", self.hash()?);
+                return Ok(None);
+            },
+        };
+        if keccak_hash_bytes(&bytes) == self.hash()? {
+            Ok(Some(bytes))
+        } else {
+            warn!("encoding of tx 0x{:x} does not match its hash", self.hash()?);
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_chain_ids::EthChainId;
+    use ethereum_types::Address as EthAddress;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{EthPrivateKey, EthTransaction};
+
+    fn to_hex<T: std::fmt::LowerHex>(t: T) -> String {
+        format!("0x{t:x}")
+    }
+
+    fn get_sample_json_rpc_tx(tx: &EthTransaction, tx_type: u8) -> EthTransactionFromJsonRpc {
+        serde_json::from_value(json!({
+            "hash": format!("0x{:x}", keccak_hash_bytes(&tx.to_bytes())),
+            "type": to_hex(tx_type),
+            "chainId": to_hex(tx.chain_id.to_u64()),
+            "nonce": to_hex(tx.nonce),
+            "gas": to_hex(tx.gas_limit),
+            "gasPrice": to_hex(tx.gas_price),
+            "maxFeePerGas": to_hex(tx.max_fee_per_gas),
+            "maxPriorityFeePerGas": to_hex(tx.max_priority_fee_per_gas),
+            "to": format!("0x{}", hex::encode(&tx.to)),
+            "value": to_hex(tx.value),
+            "input": format!("0x{}", hex::encode(&tx.data)),
+            "accessList": [],
+            "v": to_hex(tx.v),
+            "r": to_hex(tx.r),
+            "s": to_hex(tx.s),
+        }))
+        .unwrap()
+    }
+
+    fn get_sample_signed_txs() -> Vec<(EthTransaction, u8)> {
+        let pk = EthPrivateKey::generate_random().unwrap();
+        let to = EthAddress::random();
+        let chain_id = EthChainId::Mainnet;
+        let data = vec![0xc0, 0xff, 0xee];
+        vec![
+            (
+                EthTransaction::new_unsigned(data.clone(), 1, 1337, to, &chain_id, 21_000, 20)
+                    .sign(&pk)
+                    .unwrap(),
+                0,
+            ),
+            (
+                EthTransaction::new_unsigned_eip_1559(data, 2, 1337, to, &chain_id, 21_000, 20, 2)
+                    .sign(&pk)
+                    .unwrap(),
+                2,
+            ),
+        ]
+    }
+
+    #[test]
+    fn should_get_raw_transactions_from_json_rpc_txs() {
+        get_sample_signed_txs().iter().for_each(|(tx, tx_type)| {
+            let json_rpc_tx = get_sample_json_rpc_tx(tx, *tx_type);
+            let result = json_rpc_tx.to_raw_transaction().unwrap();
+            assert_eq!(result, Some(tx.to_bytes()));
+        })
+    }
+
+    #[test]
+    fn should_not_get_raw_transaction_whose_encoding_does_not_match_its_hash() {
+        let (tx, tx_type) = get_sample_signed_txs()[1].clone();
+        let mut json_rpc_tx = get_sample_json_rpc_tx(&tx, tx_type);
+        json_rpc_tx.nonce = Some("0x3".into());
+        assert!(json_rpc_tx.to_raw_transaction().unwrap().is_none());
+    }
+
+    #[test]
+    fn should_not_get_raw_transaction_of_unknown_type() {
+        let (tx, _) = get_sample_signed_txs()[0].clone();
+        let json_rpc_tx = get_sample_json_rpc_tx(&tx, 0x7e);
+        assert!(json_rpc_tx.to_raw_transaction().unwrap().is_none());
+    }
+}
@@ -0,0 +1,80 @@
+use common::{
+    crypto_utils::keccak_hash_bytes,
+    types::{Bytes, Result},
+};
+use derive_more::{Constructor, Deref};
+use ethereum_types::H256 as EthHash;
+use serde::{Deserialize, Serialize};
+
+use crate::{eth_receipt_proof::get_proven_value, EthReceipt};
+
+/// An inclusion proof for a tx in a block's transactions trie, consisting of the rlp encoded nodes
+/// on the path from the root to the raw, signed tx.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Constructor, Deref)]
+pub struct EthTransactionProof(Vec<Bytes>);
+
+impl EthTransactionProof {
+    // NOTE: Proves that the tx at the receipt's index in the block is the one the receipt is for.
+    pub fn verify(&self, transactions_root: &EthHash, receipt: &EthReceipt) -> Result<bool> {
+        let key = receipt.rlp_encode_transaction_index();
+        let proven_value = get_proven_value(self, transactions_root, &key)?;
+        Ok(matches!(proven_value, Some(raw_tx) if keccak_hash_bytes(&raw_tx) == receipt.transaction_hash))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethereum_types::U256;
+    use rlp::RlpStream;
+
+    use super::*;
+    use crate::test_utils::get_sample_receipt_with_desired_topic;
+
+    fn get_sample_raw_tx() -> Bytes {
+        hex::decode("02e80180808080940000000000000000000000000000000000000000808080c0808080").unwrap()
+    }
+
+    fn get_single_tx_trie_proof_and_root(receipt: &EthReceipt, raw_tx: &Bytes) -> (EthTransactionProof, EthHash) {
+        let key = receipt.rlp_encode_transaction_index();
+        let mut rlp_stream = RlpStream::new_list(2);
+        rlp_stream.append(&[vec![0x20], key].concat()).append(raw_tx);
+        let node = rlp_stream.out().to_vec();
+        let root = keccak_hash_bytes(&node);
+        (EthTransactionProof::new(vec![node]), root)
+    }
+
+    fn get_sample_receipt_for_raw_tx(raw_tx: &Bytes) -> EthReceipt {
+        let mut receipt = get_sample_receipt_with_desired_topic();
+        receipt.transaction_index = U256::zero();
+        receipt.transaction_hash = keccak_hash_bytes(raw_tx);
+        receipt
+    }
+
+    #[test]
+    fn should_verify_transaction_proof() {
+        let raw_tx = get_sample_raw_tx();
+        let receipt = get_sample_receipt_for_raw_tx(&raw_tx);
+        let (proof, transactions_root) = get_single_tx_trie_proof_and_root(&receipt, &raw_tx);
+        let result = proof.verify(&transactions_root, &receipt).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn should_not_verify_transaction_proof_for_receipt_of_other_tx() {
+        let raw_tx = get_sample_raw_tx();
+        let mut receipt = get_sample_receipt_for_raw_tx(&raw_tx);
+        let (proof, transactions_root) = get_single_tx_trie_proof_and_root(&receipt, &raw_tx);
+        receipt.transaction_hash = EthHash::random();
+        let result = proof.verify(&transactions_root, &receipt).unwrap();
+        assert!(!result);
+    }
+
+    #[test]
+    fn should_not_verify_transaction_proof_against_wrong_root() {
+        let raw_tx = get_sample_raw_tx();
+        let receipt = get_sample_receipt_for_raw_tx(&raw_tx);
+        let (proof, _) = get_single_tx_trie_proof_and_root(&receipt, &raw_tx);
+        let result = proof.verify(&EthHash::random(), &receipt).unwrap();
+        assert!(!result);
+    }
+}
@@ -6,8 +6,6 @@ mod chain;
 mod check_parent_exists;
 mod core_initialization;
 mod default_block_parameter;
-mod eip_1559;
-mod eip_4844;
 mod eth_block;
 mod eth_block_from_json_rpc;
 mod eth_constants;
@@ -16,15 +14,19 @@ mod eth_crypto;
 mod eth_database_transactions;
 mod eth_database_utils;
 mod eth_enclave_state;
+mod eth_fork_schedule;
 mod eth_log;
 mod eth_macros;
 mod eth_message_signer;
 mod eth_receipt;
 mod eth_receipt_from_json_rpc;
+mod eth_receipt_proof;
 mod eth_receipt_type;
 mod eth_state;
 mod eth_submission_material;
 mod eth_traits;
+mod eth_transaction_from_json_rpc;
+mod eth_transaction_proof;
 mod eth_types;
 mod eth_utils;
 mod increment_eth_account_nonce;
@@ -121,10 +123,13 @@ pub use self::{
     },
     eth_crypto::{
         get_signed_minting_tx,
+        EthAccessList,
+        EthAccessListItem,
         EthPrivateKey,
         EthPublicKey,
         EthSignature,
         EthTransaction,
+        EthTransactionType,
         EthTransactions,
         ETH_SIGNATURE_NUM_BYTES,
     },
@@ -139,6 +144,7 @@ pub use self::{
         EvmDbUtils as HostDbUtils,
     },
     eth_enclave_state::{EthEnclaveState, EvmEnclaveState, HostCoreState, NativeCoreState},
+    eth_fork_schedule::{EthForkActivation, EthForkSchedule, EthHardfork, EthHeaderField},
     eth_log::{EthLog, EthLogExt, EthLogs},
     eth_message_signer::{
         sign_ascii_msg_with_eth_key_with_no_prefix,
@@ -150,6 +156,7 @@ pub use self::{
     },
     eth_receipt::{EthReceipt, EthReceiptJson, EthReceipts},
     eth_receipt_from_json_rpc::EthReceiptFromJsonRpc,
+    eth_receipt_proof::EthReceiptProof,
     eth_state::{EthState, EthStateCompatible},
     eth_submission_material::{
         parse_eth_submission_material_and_put_in_state,
@@ -160,6 +167,8 @@ pub use self::{
         EthSubmissionMaterials,
     },
     eth_traits::{EthSigningCapabilities, EthTxInfoCompatible},
+    eth_transaction_from_json_rpc::{EthAccessListItemFromJsonRpc, EthTransactionFromJsonRpc},
+    eth_transaction_proof::EthTransactionProof,
     eth_types::{AnySenderSigningParams, EthSigningParams},
     eth_utils::{
         convert_eth_address_to_string,
@@ -6,7 +6,7 @@ use common::{
     types::{Byte, Bytes, Result},
     AppError,
 };
-use common_chain_ids::{AlgoChainId, BtcChainId, EosChainId, EthChainId};
+use common_chain_ids::{AlgoChainId, BtcChainId, EosChainId, EthChainId, EvmChainRegistry};
 use ethereum_types::H256 as KeccakHash;
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
@@ -51,6 +51,10 @@ pub enum MetadataChainId {
     EthereumGoerli,   // 0x00b4f6c5
     EthereumSepolia,  // 0x0030d6b5
     LitecoinMainnet,  // 0x01840435
+    // NOTE: Any EVM chain without a variant of its own, eg one from the `EvmChainRegistry`. Its
+    // bytes are derived from its chain id in the same way as for every other EVM chain.
+    #[strum(disabled)]
+    EvmChain(u64),
 }
 
 impl Default for MetadataChainId {
@@ -102,7 +106,10 @@ impl FromStr for MetadataChainId {
             // Ltc...
             "ltc" | "litecoin" | "litecoinmainnet" | "0x01840435" => Ok(Self::LitecoinMainnet),
 
-            _ => Err(format!("Unrecognised chain id: '{s}'").into()),
+            _ => match EvmChainRegistry::get_by_name(s) {
+                Some(c) => Ok(Self::EvmChain(c.chain_id)),
+                None => Err(format!("Unrecognised chain id: '{s}'").into()),
+            },
         }
     }
 }
@@ -122,7 +129,10 @@ impl From<&EthChainId> for MetadataChainId {
             EthChainId::PolygonMainnet => Self::PolygonMainnet,
             EthChainId::ArbitrumMainnet => Self::ArbitrumMainnet,
             EthChainId::LuxochainMainnet => Self::LuxochainMainnet,
-            EthChainId::Unknown(..) => Self::EthUnknown,
+            // NOTE: This mustn't depend on the `EvmChainRegistry`, else a chain's metadata chain id
+            // would differ depending on whether it happened to be registered at the time.
+            EthChainId::Unknown(0) => Self::EthUnknown,
+            EthChainId::Unknown(id) => Self::EvmChain(*id),
         }
     }
 }
@@ -155,6 +165,7 @@ impl MetadataChainId {
             | Self::EthereumRopsten
             | Self::ArbitrumMainnet
             | Self::LuxochainMainnet
+            | Self::EvmChain(_)
             | Self::PolygonMainnet => MetadataProtocolId::Ethereum,
         }
     }
@@ -171,6 +182,7 @@ impl MetadataChainId {
             Self::InterimChain => Ok(EthChainId::InterimChain),
             Self::FantomMainnet => Ok(EthChainId::FantomMainnet),
             Self::PolygonMainnet => Ok(EthChainId::PolygonMainnet),
+            Self::EvmChain(id) => Ok(EthChainId::Unknown(*id)),
             // NOTE: Important -> this catch all arm means that any NEW evm based metadata chain
             // ids will fall into this arm, unless they're explicitly added above.
             other => Err(MetadataChainIdError::CannotConvertTo(*other, "EthChainId".into())),
@@ -207,6 +219,7 @@ impl MetadataChainId {
             Self::EosJungleTestnet => Box::new(EosChainId::EosJungleTestnet),
             // NOTE: This is how LTC is handled in the forked library it uses
             Self::LitecoinMainnet => Box::new(BtcChainId::Bitcoin),
+            Self::EvmChain(id) => Box::new(EthChainId::Unknown(id)),
         }
     }
 
@@ -259,7 +272,7 @@ impl MetadataChainId {
             )
             .into())
         } else {
-            let maybe_self = Self::get_all()
+            let maybe_self = Self::get_all_including_registered()
                 .iter()
                 .map(|id| match id.to_bytes() {
                     Err(_) => None,
@@ -290,6 +303,32 @@ impl MetadataChainId {
         use strum::IntoEnumIterator;
         Self::iter().collect()
     }
+
+    fn get_all_including_registered() -> Vec<Self> {
+        let mut ids = Self::get_all();
+        ids.extend(EvmChainRegistry::chain_ids().into_iter().map(Self::EvmChain));
+        ids
+    }
+
+    /// Gets the metadata chain id of a registered EVM chain, checking that its bytes collide with
+    /// no other chain's, and that they match those configured for it, if any.
+    pub fn from_registered_evm_chain(chain_id: u64) -> Result<Self> {
+        let config =
+            EvmChainRegistry::get(chain_id).ok_or_else(|| format!("evm chain id {chain_id} is not registered"))?;
+        let id = Self::EvmChain(chain_id);
+        let bytes = id.to_bytes()?;
+        if Self::from_bytes(&bytes)? != id {
+            return Err(format!("metadata chain id for evm chain id {chain_id} collides with another").into());
+        };
+        match config.metadata_chain_id {
+            Some(ref expected) if !expected.eq_ignore_ascii_case(&id.to_hex()?) => Err(format!(
+                "metadata chain id for evm chain id {chain_id} is {}, not the configured {expected}",
+                id.to_hex()?
+            )
+            .into()),
+            _ => Ok(id),
+        }
+    }
 }
 
 impl fmt::Display for MetadataChainId {
@@ -323,6 +362,7 @@ impl fmt::Display for MetadataChainId {
             Self::LuxochainMainnet => write!(f, "Luxochain Mainnet: {}", hex),
             Self::EosJungleTestnet => write!(f, "EOS Jungle Testnet: {}", hex),
             Self::BscMainnet => write!(f, "Binance Chain (BSC) Mainnet: {}", hex),
+            Self::EvmChain(id) => write!(f, "EVM Chain {} ({}): {}", EthChainId::Unknown(*id), id, hex),
         }
     }
 }
@@ -399,4 +439,40 @@ mod tests {
             Err(_) => panic!("Wrong error received!"),
         };
     }
+
+    #[test]
+    fn should_get_metadata_chain_id_for_registered_evm_chain() {
+        use common_chain_ids::EvmChainConfig;
+
+        let _registry = EvmChainRegistry::lock_for_test();
+        let chain_id = 8453;
+        EvmChainRegistry::register(EvmChainConfig {
+            chain_id,
+            name: "base".into(),
+            gas_multiplier: 1,
+            ..Default::default()
+        })
+        .unwrap();
+        let result = MetadataChainId::from_registered_evm_chain(chain_id).unwrap();
+        assert_eq!(result, MetadataChainId::EvmChain(chain_id));
+        assert_eq!(result.to_protocol_id(), MetadataProtocolId::Ethereum);
+        assert_eq!(result.to_eth_chain_id().unwrap(), EthChainId::Unknown(chain_id));
+        assert_eq!(MetadataChainId::from(&EthChainId::Unknown(chain_id)), result);
+        assert_eq!(MetadataChainId::from_str("base").unwrap(), result);
+        let bytes = result.to_bytes().unwrap();
+        assert_eq!(MetadataChainId::from_bytes(&bytes).unwrap(), result);
+    }
+
+    #[test]
+    fn unknown_eth_chain_ids_should_map_to_evm_chains_whether_registered_or_not() {
+        assert_eq!(
+            MetadataChainId::from(&EthChainId::Unknown(1337)),
+            MetadataChainId::EvmChain(1337)
+        );
+        assert_eq!(
+            MetadataChainId::from(&EthChainId::unknown()),
+            MetadataChainId::EthUnknown
+        );
+        assert!(MetadataChainId::from_registered_evm_chain(1337).is_err());
+    }
 }
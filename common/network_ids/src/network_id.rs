@@ -1,7 +1,7 @@
 use std::{fmt, str::FromStr};
 
 use common::{Byte, Bytes};
-use common_chain_ids::EthChainId;
+use common_chain_ids::{EthChainId, EvmChainRegistry};
 use common_metadata::{MetadataChainId, MetadataChainIdError};
 use derive_more::{Constructor, Deref};
 use ethabi::{encode as ethabi_encode, Token};
@@ -120,7 +120,10 @@ impl TryFrom<&str> for NetworkId {
             "lux" | "luxochain" | "0x58920253" | "58920253" => Ok(Self::new_v1_for_evm(EthChainId::LuxochainMainnet)),
             "gno" | "gnosis" | "xdai" | "0xd41b1c5b" | "d41b1c5b" => Ok(Self::new_v1_for_evm(EthChainId::XDaiMainnet)),
             "00000000" | "0x00000000" | "unknown" | "ethunknown" => Ok(Self::new_v1_for_evm(EthChainId::Unknown(0))),
-            other => Err(NetworkIdError::InvalidNetworkId(other.to_string())),
+            other => match EvmChainRegistry::get_by_name(other) {
+                Some(c) => Ok(Self::new_v1_for_evm(EthChainId::Unknown(c.chain_id))),
+                None => Err(NetworkIdError::InvalidNetworkId(other.to_string())),
+            },
         }
     }
 }
@@ -173,6 +176,16 @@ impl NetworkId {
     pub fn to_bytes_4(&self) -> Result<Bytes4, NetworkIdError> {
         Bytes4::try_from(self.to_bytes())
     }
+
+    fn get_registered_evm_chain_name(hex: &str) -> Option<String> {
+        EvmChainRegistry::chain_ids().into_iter().find_map(|id| {
+            let network_id = Self::new_v1_for_evm(EthChainId::Unknown(id));
+            match network_id.to_bytes_4() {
+                Ok(b4) if hex::encode(*b4) == hex => EvmChainRegistry::get(id).map(|c| c.name),
+                _ => None,
+            }
+        })
+    }
 }
 
 impl TryFrom<&Vec<u8>> for NetworkId {
@@ -235,7 +248,10 @@ impl fmt::Display for NetworkId {
                     "58920253" => "luxochain".to_string(),
                     "d41b1c5b" => "gnosis".to_string(),
                     "00000000" => "ethUnknown".to_string(),
-                    other => format!("unknown: {other}"),
+                    other => match Self::get_registered_evm_chain_name(other) {
+                        Some(name) => name,
+                        None => format!("unknown: {other}"),
+                    },
                 };
                 write!(f, "{x}")
             },
@@ -261,6 +277,7 @@ impl TryFrom<&MetadataChainId> for NetworkId {
             MetadataChainId::PolygonMainnet => Ok(NetworkId::new_v1_for_evm(EthChainId::PolygonMainnet)),
             MetadataChainId::ArbitrumMainnet => Ok(NetworkId::new_v1_for_evm(EthChainId::ArbitrumMainnet)),
             MetadataChainId::LuxochainMainnet => Ok(NetworkId::new_v1_for_evm(EthChainId::LuxochainMainnet)),
+            MetadataChainId::EvmChain(id) => Ok(NetworkId::new_v1_for_evm(EthChainId::Unknown(*id))),
             mcid => Err(Self::Error::CannotConvertTo(*mcid, "NetworkId".to_string())),
         }
     }
@@ -296,7 +313,10 @@ impl TryFrom<&NetworkId> for MetadataChainId {
                 EthChainId::PolygonMainnet => Ok(MetadataChainId::PolygonMainnet),
                 EthChainId::ArbitrumMainnet => Ok(MetadataChainId::ArbitrumMainnet),
                 EthChainId::LuxochainMainnet => Ok(MetadataChainId::LuxochainMainnet),
-                EthChainId::Unknown(_) => Err(err),
+                // NOTE: Other EVM chains' metadata chain ids are derived from their chain ids, and
+                // so don't require the core to know of the `EvmChainRegistry`.
+                EthChainId::Unknown(0) => Err(err),
+                EthChainId::Unknown(id) => Ok(MetadataChainId::EvmChain(id)),
             }
         } else {
             Err(err)
@@ -334,4 +354,24 @@ mod tests {
         let expected_result = "00000000";
         assert_eq!(result, expected_result);
     }
+
+    #[test]
+    fn should_convert_registered_evm_chain_network_id() {
+        use common_chain_ids::EvmChainConfig;
+
+        let _registry = EvmChainRegistry::lock_for_test();
+        EvmChainRegistry::register(EvmChainConfig {
+            chain_id: 10,
+            name: "optimism".into(),
+            gas_multiplier: 1,
+            ..Default::default()
+        })
+        .unwrap();
+        let network_id = NetworkId::try_from("optimism").unwrap();
+        assert_eq!(network_id, NetworkId::new_v1_for_evm(EthChainId::Unknown(10)));
+        assert_eq!(network_id.to_string(), "optimism");
+        let mcid = MetadataChainId::try_from(&network_id).unwrap();
+        assert_eq!(mcid, MetadataChainId::EvmChain(10));
+        assert_eq!(NetworkId::try_from(&mcid).unwrap(), network_id);
+    }
 }
@@ -0,0 +1,345 @@
+use common::{crypto_utils::keccak_hash_bytes, Byte, Bytes, DatabaseInterface, MIN_DATA_SENSITIVITY_LEVEL};
+use common_debug_signers::DebugSignatories;
+use common_eth::{EthPrivateKey, EthSignature, EthSigningCapabilities};
+use derive_getters::Getters;
+use ethereum_types::H256 as EthHash;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as Json};
+
+use super::{AuditLogEntry, AuditLogError, AuditLogOutcome, AuditLogSignatory};
+use crate::{
+    db_utils::{DbKey, DbUtilsT, SentinelDbKeys, SentinelDbUtils},
+    get_utc_timestamp,
+    SentinelError,
+    WebSocketMessagesEncodable,
+};
+
+pub const MAX_AUDIT_LOG_PAGE_SIZE: u64 = 100;
+
+// NOTE: The head of an append-only, hash chained log of every debug command the core has run. The
+// entries themselves are stored individually, keyed by their index. The head is signed by the
+// core's key upon request, attesting that the log of that length ending in that hash is the one
+// this core holds.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLog {
+    num_entries: u64,
+    head_hash: EthHash,
+}
+
+impl DbUtilsT for AuditLog {
+    fn key(&self) -> Result<DbKey, SentinelError> {
+        Ok(SentinelDbKeys::get_audit_log_db_key())
+    }
+
+    fn sensitivity() -> Option<Byte> {
+        MIN_DATA_SENSITIVITY_LEVEL
+    }
+
+    fn from_bytes(bytes: &[Byte]) -> Result<Self, SentinelError> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
+
+impl AuditLog {
+    pub fn get<D: DatabaseInterface>(db: &D) -> Self {
+        let key = SentinelDbKeys::get_audit_log_db_key();
+        match Self::get_from_db(&SentinelDbUtils::new(db), &key) {
+            Ok(log) => log,
+            Err(_) => {
+                debug!("no audit log in db, using default");
+                Self::default()
+            },
+        }
+    }
+
+    pub fn append<D: DatabaseInterface>(
+        self,
+        db: &D,
+        command: &WebSocketMessagesEncodable,
+        signatories: Vec<AuditLogSignatory>,
+        outcome: AuditLogOutcome,
+        result: &WebSocketMessagesEncodable,
+    ) -> Result<AuditLogEntry, SentinelError> {
+        self.append_with_timestamp(db, command, signatories, outcome, result, get_utc_timestamp()?)
+    }
+
+    fn append_with_timestamp<D: DatabaseInterface>(
+        mut self,
+        db: &D,
+        command: &WebSocketMessagesEncodable,
+        signatories: Vec<AuditLogSignatory>,
+        outcome: AuditLogOutcome,
+        result: &WebSocketMessagesEncodable,
+        timestamp: u64,
+    ) -> Result<AuditLogEntry, SentinelError> {
+        let entry = AuditLogEntry::new(
+            self.num_entries,
+            timestamp,
+            command.to_string(),
+            keccak_hash_bytes(&serde_json::to_vec(command)?),
+            signatories,
+            outcome,
+            keccak_hash_bytes(&serde_json::to_vec(result)?),
+            self.head_hash,
+        )?;
+        let db_utils = SentinelDbUtils::new(db);
+        entry.put_in_db(&db_utils)?;
+        self.num_entries += 1;
+        self.head_hash = *entry.hash();
+        self.update_in_db(&db_utils)?;
+        info!("appended entry {} to audit log: {}", entry.index(), entry.to_json());
+        Ok(entry)
+    }
+
+    // NOTE: Records a debug command that ran successfully, along with whichever debug signatories
+    // signed over it, as determined by whose nonces changed whilst it ran.
+    pub fn record<D: DatabaseInterface>(
+        db: &D,
+        command: &WebSocketMessagesEncodable,
+        signatories_before: &DebugSignatories,
+        result: &WebSocketMessagesEncodable,
+    ) -> Result<AuditLogEntry, SentinelError> {
+        let signatories_after = DebugSignatories::get_from_db(db)?;
+        let signatories = AuditLogSignatory::from_nonce_changes(signatories_before, &signatories_after);
+        Self::get(db).append(db, command, signatories, AuditLogOutcome::Succeeded, result)
+    }
+
+    // NOTE: Records a debug command which was rejected or which failed, but only if a debug
+    // signatory is known to have signed over it, else anyone could grow the log, and thus the db,
+    // without limit. That's any command which failed after its signatures were validated, whose
+    // signatories are those whose nonces changed before its db tx was rolled back, or any which
+    // was rejected despite at least one valid signature. Those signatories are recorded with the
+    // nonce they signed over, though the rollback means they may sign over it again. Rejected
+    // debug signatures are those which fail with the json error describing the signatures that
+    // the command required.
+    pub fn record_failure<D: DatabaseInterface>(
+        db: &D,
+        command: &WebSocketMessagesEncodable,
+        signatories: Vec<AuditLogSignatory>,
+        error: &SentinelError,
+    ) -> Result<Option<AuditLogEntry>, SentinelError> {
+        let (outcome, num_valid_signatures) = match error {
+            SentinelError::Json(j) => (
+                AuditLogOutcome::Rejected(j.to_string()),
+                j["numValidSignatures"].as_u64().unwrap_or_default(),
+            ),
+            e => (AuditLogOutcome::Failed(e.to_string()), 0),
+        };
+        if signatories.is_empty() && num_valid_signatures == 0 {
+            warn!("not recording failed debug command '{command}' in audit log since no debug signatory signed it");
+            return Ok(None);
+        };
+        Self::get(db)
+            .append(db, command, signatories, outcome, &WebSocketMessagesEncodable::Null)
+            .map(Some)
+    }
+
+    pub fn get_entry<D: DatabaseInterface>(&self, db: &D, index: u64) -> Result<AuditLogEntry, SentinelError> {
+        if index >= self.num_entries {
+            return Err(AuditLogError::NotFound(index).into());
+        };
+        AuditLogEntry::get_from_db(&SentinelDbUtils::new(db), &AuditLogEntry::db_key(index))
+    }
+
+    pub fn get_entries<D: DatabaseInterface>(
+        &self,
+        db: &D,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<AuditLogEntry>, SentinelError> {
+        let end = std::cmp::min(
+            self.num_entries,
+            offset.saturating_add(std::cmp::min(limit, MAX_AUDIT_LOG_PAGE_SIZE)),
+        );
+        (offset..end).map(|i| self.get_entry(db, i)).collect()
+    }
+
+    pub fn verify<D: DatabaseInterface>(&self, db: &D) -> Result<(), SentinelError> {
+        let mut prev_hash = EthHash::zero();
+        for i in 0..self.num_entries {
+            let entry = self.get_entry(db, i)?;
+            if *entry.prev_hash() != prev_hash || !entry.is_valid()? {
+                return Err(AuditLogError::BrokenChain(i).into());
+            };
+            prev_hash = *entry.hash();
+        }
+        if prev_hash != self.head_hash {
+            return Err(AuditLogError::BrokenChain(self.num_entries).into());
+        };
+        Ok(())
+    }
+
+    fn to_signable_bytes(&self) -> Bytes {
+        [
+            self.num_entries.to_be_bytes().to_vec(),
+            self.head_hash.as_bytes().to_vec(),
+        ]
+        .concat()
+    }
+
+    pub fn sign(&self, pk: &EthPrivateKey) -> Result<EthSignature, SentinelError> {
+        Ok(pk.hash_and_sign_msg_with_eth_prefix(keccak_hash_bytes(&self.to_signable_bytes()).as_bytes())?)
+    }
+
+    pub fn to_signed_json(&self, pk: &EthPrivateKey) -> Result<Json, SentinelError> {
+        Ok(json!({
+            "numEntries": self.num_entries,
+            "headHash": self.head_hash,
+            "signer": pk.to_address(),
+            "signature": self.sign(pk)?.to_0x_string(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::get_test_database;
+    use common_debug_signers::{DebugSignatory, DebugSignature};
+    use ethereum_types::Address as EthAddress;
+
+    use super::*;
+
+    fn get_sample_command() -> WebSocketMessagesEncodable {
+        WebSocketMessagesEncodable::HardReset(DebugSignature::new(None))
+    }
+
+    fn get_sample_result() -> WebSocketMessagesEncodable {
+        WebSocketMessagesEncodable::Success(json!({"hardReset": true}))
+    }
+
+    fn append_n_entries<D: DatabaseInterface>(db: &D, n: u64) {
+        for i in 0..n {
+            AuditLog::get(db)
+                .append_with_timestamp(
+                    db,
+                    &get_sample_command(),
+                    vec![],
+                    AuditLogOutcome::Succeeded,
+                    &get_sample_result(),
+                    1337 + i,
+                )
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn should_get_default_audit_log_if_none_in_db() {
+        let db = get_test_database();
+        let log = AuditLog::get(&db);
+        assert_eq!(log, AuditLog::default());
+        assert!(log.verify(&db).is_ok());
+    }
+
+    #[test]
+    fn should_append_hash_chained_entries() {
+        let db = get_test_database();
+        append_n_entries(&db, 3);
+        let log = AuditLog::get(&db);
+        assert_eq!(*log.num_entries(), 3);
+        let entries = log.get_entries(&db, 0, 10).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(*entries[0].prev_hash(), EthHash::zero());
+        assert_eq!(entries[1].prev_hash(), entries[0].hash());
+        assert_eq!(entries[2].prev_hash(), entries[1].hash());
+        assert_eq!(log.head_hash(), entries[2].hash());
+        assert!(log.verify(&db).is_ok());
+    }
+
+    #[test]
+    fn should_paginate_entries() {
+        let db = get_test_database();
+        append_n_entries(&db, 5);
+        let log = AuditLog::get(&db);
+        let page = log.get_entries(&db, 1, 2).unwrap();
+        assert_eq!(page.iter().map(|e| *e.index()).collect::<Vec<u64>>(), vec![1, 2]);
+        assert!(log.get_entries(&db, 5, 2).unwrap().is_empty());
+        assert_eq!(log.get_entries(&db, 3, 10).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn should_detect_tampered_entry() {
+        let db = get_test_database();
+        append_n_entries(&db, 3);
+        let log = AuditLog::get(&db);
+        let mut tampered = log.get_entry(&db, 1).unwrap().to_json();
+        tampered["timestamp"] = json!(1);
+        db.put(
+            AuditLogEntry::db_key(1).to_vec(),
+            serde_json::to_vec(&tampered).unwrap(),
+            MIN_DATA_SENSITIVITY_LEVEL,
+        )
+        .unwrap();
+        match log.verify(&db) {
+            Err(SentinelError::AuditLog(AuditLogError::BrokenChain(1))) => (),
+            Ok(_) => panic!("should not have succeeded"),
+            Err(e) => panic!("wrong error received: {e}"),
+        };
+    }
+
+    #[test]
+    fn should_get_signatories_from_nonce_changes() {
+        let address_1 = EthAddress::random();
+        let address_2 = EthAddress::random();
+        let before = DebugSignatories::new(vec![
+            DebugSignatory::new("one", &address_1),
+            DebugSignatory::new("two", &address_2),
+        ]);
+        let after = DebugSignatories::new(vec![
+            DebugSignatory::new("one", &address_1).increment_nonce(),
+            DebugSignatory::new("two", &address_2),
+        ]);
+        let result = AuditLogSignatory::from_nonce_changes(&before, &after);
+        assert_eq!(result.len(), 1);
+        assert_eq!(*result[0].eth_address(), address_1);
+        assert_eq!(*result[0].nonce(), 0);
+    }
+
+    #[test]
+    fn should_record_rejected_and_failed_debug_commands_signed_by_debug_signatories() {
+        let db = get_test_database();
+        let signatory = DebugSignatory::new("one", &EthAddress::random());
+        let signatories = AuditLogSignatory::from_nonce_changes(
+            &DebugSignatories::new(vec![signatory.clone()]),
+            &DebugSignatories::new(vec![signatory.increment_nonce()]),
+        );
+        let rejection = SentinelError::Json(json!({"threshold": 2, "numValidSignatures": 1}));
+        let failure = SentinelError::Custom("failed".into());
+        let rejected = AuditLog::record_failure(&db, &get_sample_command(), vec![], &rejection)
+            .unwrap()
+            .unwrap();
+        let failed = AuditLog::record_failure(&db, &get_sample_command(), signatories.clone(), &failure)
+            .unwrap()
+            .unwrap();
+        assert!(matches!(rejected.outcome(), AuditLogOutcome::Rejected(_)));
+        assert!(matches!(failed.outcome(), AuditLogOutcome::Failed(_)));
+        assert!(rejected.signatories().is_empty());
+        assert_eq!(*failed.signatories(), signatories);
+        let log = AuditLog::get(&db);
+        assert_eq!(*log.num_entries(), 2);
+        assert!(log.verify(&db).is_ok());
+    }
+
+    #[test]
+    fn should_not_record_failed_debug_commands_not_signed_by_any_debug_signatory() {
+        let db = get_test_database();
+        let rejection = SentinelError::Json(json!({"threshold": 1, "numValidSignatures": 0}));
+        let failure = SentinelError::Custom("failed".into());
+        assert!(AuditLog::record_failure(&db, &get_sample_command(), vec![], &rejection)
+            .unwrap()
+            .is_none());
+        assert!(AuditLog::record_failure(&db, &get_sample_command(), vec![], &failure)
+            .unwrap()
+            .is_none());
+        assert_eq!(*AuditLog::get(&db).num_entries(), 0);
+    }
+
+    #[test]
+    fn should_sign_audit_log_head() {
+        let db = get_test_database();
+        append_n_entries(&db, 2);
+        let pk = EthPrivateKey::generate_random().unwrap();
+        let result = AuditLog::get(&db).to_signed_json(&pk).unwrap();
+        assert_eq!(result["numEntries"], json!(2));
+    }
+}
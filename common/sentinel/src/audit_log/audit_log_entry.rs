@@ -0,0 +1,133 @@
+use common::{crypto_utils::keccak_hash_bytes, get_prefixed_db_key, Byte, MIN_DATA_SENSITIVITY_LEVEL};
+use common_debug_signers::DebugSignatories;
+use derive_getters::Getters;
+use ethereum_types::{Address as EthAddress, H256 as EthHash};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as Json};
+
+use crate::{
+    db_utils::{DbKey, DbUtilsT},
+    SentinelError,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLogSignatory {
+    name: String,
+    nonce: u64,
+    eth_address: EthAddress,
+}
+
+impl AuditLogSignatory {
+    // NOTE: A debug signatory's nonce is incremented whenever a signature of theirs is used to run a
+    // debug command, and so those who signed over a command are exactly those whose nonces changed
+    // whilst it ran. The nonce recorded is the one they signed over.
+    pub fn from_nonce_changes(before: &DebugSignatories, after: &DebugSignatories) -> Vec<Self> {
+        before
+            .iter()
+            .filter(|b| {
+                after
+                    .iter()
+                    .any(|a| a.eth_address == b.eth_address && a.nonce > b.nonce)
+            })
+            .map(|b| Self {
+                name: b.name.clone(),
+                nonce: b.nonce,
+                eth_address: b.eth_address,
+            })
+            .collect()
+    }
+}
+
+// NOTE: Debug commands whose signatures were rejected, or which failed whilst running, are recorded
+// too, along with their error, so that attempts to run them are as visible as those that succeeded.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AuditLogOutcome {
+    Succeeded,
+    Rejected(String),
+    Failed(String),
+}
+
+// NOTE: Each entry commits to the hash of the one before it, so that no entry may be altered or
+// removed without breaking the chain from that point onwards.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLogEntry {
+    index: u64,
+    timestamp: u64,
+    command: String,
+    command_hash: EthHash,
+    signatories: Vec<AuditLogSignatory>,
+    outcome: AuditLogOutcome,
+    result_hash: EthHash,
+    prev_hash: EthHash,
+    hash: EthHash,
+}
+
+impl DbUtilsT for AuditLogEntry {
+    fn key(&self) -> Result<DbKey, SentinelError> {
+        Ok(Self::db_key(self.index))
+    }
+
+    fn sensitivity() -> Option<Byte> {
+        MIN_DATA_SENSITIVITY_LEVEL
+    }
+
+    fn from_bytes(bytes: &[Byte]) -> Result<Self, SentinelError> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
+
+impl AuditLogEntry {
+    pub(super) fn new(
+        index: u64,
+        timestamp: u64,
+        command: String,
+        command_hash: EthHash,
+        signatories: Vec<AuditLogSignatory>,
+        outcome: AuditLogOutcome,
+        result_hash: EthHash,
+        prev_hash: EthHash,
+    ) -> Result<Self, SentinelError> {
+        let mut entry = Self {
+            index,
+            command,
+            outcome,
+            timestamp,
+            prev_hash,
+            signatories,
+            result_hash,
+            command_hash,
+            hash: EthHash::zero(),
+        };
+        entry.hash = entry.calculate_hash()?;
+        Ok(entry)
+    }
+
+    pub(super) fn db_key(index: u64) -> DbKey {
+        DbKey::from(get_prefixed_db_key(&format!("audit_log_entry_{index}")))
+    }
+
+    fn calculate_hash(&self) -> Result<EthHash, SentinelError> {
+        let bytes = serde_json::to_vec(&(
+            self.index,
+            self.timestamp,
+            &self.command,
+            &self.command_hash,
+            &self.signatories,
+            &self.outcome,
+            &self.result_hash,
+            &self.prev_hash,
+        ))?;
+        Ok(keccak_hash_bytes(&bytes))
+    }
+
+    pub fn is_valid(&self) -> Result<bool, SentinelError> {
+        Ok(self.calculate_hash()? == self.hash)
+    }
+
+    pub fn to_json(&self) -> Json {
+        json!(self)
+    }
+}
@@ -0,0 +1,10 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AuditLogError {
+    #[error("no audit log entry with index {0}")]
+    NotFound(u64),
+
+    #[error("audit log chain is broken at entry {0}")]
+    BrokenChain(u64),
+}
@@ -0,0 +1,9 @@
+mod audit_log;
+mod audit_log_entry;
+mod error;
+
+pub use self::{
+    audit_log::{AuditLog, MAX_AUDIT_LOG_PAGE_SIZE},
+    audit_log_entry::{AuditLogEntry, AuditLogOutcome, AuditLogSignatory},
+    error::AuditLogError,
+};
@@ -1,13 +1,13 @@
 use std::{result::Result, time::SystemTime};
 
-use common_eth::{EthSubmissionMaterial, EthSubmissionMaterials};
+use common_eth::{EthReceipts, EthSubmissionMaterial, EthSubmissionMaterials};
 use common_network_ids::NetworkId;
 use derive_getters::Getters;
 use ethereum_types::{Address as EthAddress, U256};
 use jsonrpsee::ws_client::WsClient;
 use thiserror::Error;
 
-use crate::{endpoints::Endpoints, Bpm, ProcessorOutput, SentinelConfig, SentinelError};
+use crate::{endpoints::Endpoints, Bpm, MerkleError, MerkleTree, ProcessorOutput, SentinelConfig, SentinelError};
 
 #[derive(Debug, Clone, Getters)]
 pub struct Batch {
@@ -160,15 +160,63 @@ impl Batch {
         self.last_submitted_timestamp
     }
 
+    fn pre_filter_receipts_with_proofs(
+        sub_mat: EthSubmissionMaterial,
+        addresses: &[EthAddress],
+    ) -> Result<EthSubmissionMaterial, MerkleError> {
+        let mut merkle_tree = MerkleTree::try_from(&sub_mat)?;
+        let receipts = EthReceipts::new(
+            sub_mat
+                .receipts
+                .iter()
+                .filter(|receipt| addresses.iter().any(|a| receipt.contains_log_from_address(a)))
+                .cloned()
+                .collect(),
+        );
+        let proofs = merkle_tree.get_receipt_proofs(&receipts)?;
+        // NOTE: The raw txs are only present when the sub mat is to be validated, in which case each
+        // pertinent receipt's tx is proven against the transactions root in their stead.
+        let transaction_proofs = if sub_mat.raw_transactions.is_some() {
+            Some(MerkleTree::from_raw_transactions(&sub_mat)?.get_transaction_proofs(&receipts)?)
+        } else {
+            None
+        };
+        Ok(sub_mat.replace_receipts_with_proofs(receipts, proofs, transaction_proofs)?)
+    }
+
     pub fn push(&mut self, sub_mat: EthSubmissionMaterial) {
         if self.pre_filter_receipts {
-            self.batch
-                .push(sub_mat.remove_receipts_if_no_logs_from_addresses(&self.receipt_filtering_addresses));
+            let sub_mat = sub_mat.remove_receipts_if_no_logs_from_addresses(&self.receipt_filtering_addresses);
+            if sub_mat.receipts.is_empty() {
+                self.batch.push(sub_mat)
+            } else {
+                // NOTE: Those receipts with pertinent logs are submitted along with proofs of their
+                // inclusion in the block, so that they can still be validated by the core.
+                match Self::pre_filter_receipts_with_proofs(sub_mat.clone(), &self.receipt_filtering_addresses) {
+                    Ok(s) => self.batch.push(s),
+                    Err(e) => {
+                        warn!("could not get receipt proofs, submitting all receipts instead: {e}");
+                        self.batch.push(sub_mat)
+                    },
+                }
+            }
         } else {
             self.batch.push(sub_mat)
         }
     }
 
+    // NOTE: Whether any of the sub mat's receipts will survive being pushed to the batch, in which
+    // case the core needs its raw txs too in order to validate them.
+    pub fn keeps_receipts(&self, sub_mat: &EthSubmissionMaterial) -> bool {
+        let addresses = &self.receipt_filtering_addresses;
+        !sub_mat.receipts.is_empty()
+            && (!self.pre_filter_receipts
+                || sub_mat
+                    .receipts
+                    .iter()
+                    .any(|receipt| addresses.iter().any(|a| receipt.contains_log_from_address(a))))
+    }
+
     pub fn get_pnetwork_hub(&self) -> &EthAddress {
         &self.pnetwork_hub
     }
@@ -190,6 +238,13 @@ impl Batch {
         self.batch.len() as u64
     }
 
+    // NOTE: Identifies the batch by its network & block range, for the sake of logging. The block
+    // number is that of the most recently pushed block until the batch is submitted.
+    pub fn id(&self) -> String {
+        let from = (self.block_num + 1).saturating_sub(self.size_in_blocks());
+        format!("{}:{from}-{}", self.network_id, self.block_num)
+    }
+
     pub fn get_seconds_since_last_submission(&self) -> u64 {
         match self.last_submitted_timestamp.elapsed() {
             Ok(d) => d.as_secs(),
@@ -289,7 +344,7 @@ mod tests {
     use common_eth::{convert_hex_to_eth_address, EthLog, EthLogs, EthReceipt, EthReceipts};
 
     use super::*;
-    use crate::test_utils::get_sample_batch;
+    use crate::test_utils::{get_sample_batch, get_sample_sub_mat_n};
 
     #[test]
     fn should_enable_batching() {
@@ -488,4 +543,80 @@ mod tests {
         batch.increment_block_num();
         assert_eq!(batch.get_block_num(), 1);
     }
+
+    #[test]
+    fn pushed_block_should_only_have_pertinent_receipts_with_valid_proofs_if_pre_filtering_is_enabled() {
+        let sub_mat = get_sample_sub_mat_n(1);
+        let address = sub_mat
+            .receipts
+            .iter()
+            .find_map(|r| r.logs.first().map(|l| l.address))
+            .unwrap();
+        let mut batch = Batch {
+            pre_filter_receipts: true,
+            receipt_filtering_addresses: vec![address],
+            ..Default::default()
+        };
+        batch.push(sub_mat.clone());
+        let result = &batch.batch[0];
+        assert!(!result.receipts.is_empty());
+        assert!(result.receipts.len() < sub_mat.receipts.len());
+        assert!(result.receipts.iter().all(|r| r.contains_log_from_address(&address)));
+        assert!(result.receipt_proofs_are_valid().unwrap());
+        assert!(result.transaction_proofs.is_none());
+    }
+
+    #[test]
+    fn pushed_block_should_have_tx_proofs_instead_of_raw_txs_if_pre_filtering_is_enabled() {
+        let sub_mat = get_sample_sub_mat_n(1);
+        let raw_txs = (0..sub_mat.receipts.len()).map(|i| vec![0xc0, i as u8]).collect();
+        let sub_mat = sub_mat.add_raw_transactions(raw_txs).unwrap();
+        let address = sub_mat
+            .receipts
+            .iter()
+            .find_map(|r| r.logs.first().map(|l| l.address))
+            .unwrap();
+        let mut batch = Batch {
+            pre_filter_receipts: true,
+            receipt_filtering_addresses: vec![address],
+            ..Default::default()
+        };
+        batch.push(sub_mat);
+        let result = &batch.batch[0];
+        assert!(result.raw_transactions.is_none());
+        assert_eq!(
+            result.transaction_proofs.as_ref().map(|p| p.len()),
+            Some(result.receipts.len())
+        );
+    }
+
+    #[test]
+    fn should_only_keep_receipts_of_sub_mat_if_they_survive_pre_filtering() {
+        let sub_mat = get_sample_sub_mat_n(1);
+        let address = sub_mat
+            .receipts
+            .iter()
+            .find_map(|r| r.logs.first().map(|l| l.address))
+            .unwrap();
+        let other_address = convert_hex_to_eth_address("0x690b9a9e9aa1c9db991c7721a92d351db4fac990").unwrap();
+        let batch = Batch {
+            pre_filter_receipts: true,
+            receipt_filtering_addresses: vec![address],
+            ..Default::default()
+        };
+        assert!(batch.keeps_receipts(&sub_mat));
+        assert!(!batch.keeps_receipts(&EthSubmissionMaterial::default()));
+        let batch = Batch {
+            pre_filter_receipts: true,
+            receipt_filtering_addresses: vec![other_address],
+            ..Default::default()
+        };
+        assert!(!batch.keeps_receipts(&sub_mat));
+        let batch = Batch {
+            pre_filter_receipts: false,
+            receipt_filtering_addresses: vec![other_address],
+            ..Default::default()
+        };
+        assert!(batch.keeps_receipts(&sub_mat));
+    }
 }
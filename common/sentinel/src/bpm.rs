@@ -68,7 +68,7 @@ impl Bpm {
         }
     }
 
-    fn calc_bpm(&self) -> f64 {
+    pub fn calc_bpm(&self) -> f64 {
         if self.1.len() < 2 {
             0.0
         } else {
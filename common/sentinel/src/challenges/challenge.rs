@@ -0,0 +1,182 @@
+use std::fmt;
+
+use common::crypto_utils::keccak_hash_bytes;
+use common_eth::{EthLog, EthSubmissionMaterial};
+use common_network_ids::NetworkId;
+use derive_getters::Getters;
+use ethabi::{decode as eth_abi_decode, encode as eth_abi_encode, ParamType as EthAbiParamType, Token as EthAbiToken};
+use ethereum_types::{Address as EthAddress, H256 as EthHash, U256};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::ChallengesError;
+
+lazy_static! {
+    static ref CHALLENGE_PENDING_TOPIC: EthHash =
+        keccak_hash_bytes(b"ChallengePending((uint256,address,address,uint8,uint64,bytes4))");
+    static ref CHALLENGE_PARAM_TYPE: EthAbiParamType = EthAbiParamType::Tuple(vec![
+        EthAbiParamType::Uint(256),
+        EthAbiParamType::Address,
+        EthAbiParamType::Address,
+        EthAbiParamType::Uint(8),
+        EthAbiParamType::Uint(64),
+        EthAbiParamType::FixedBytes(4),
+    ]);
+}
+
+// NOTE: A challenge made by the hub to an actor, who must answer it before it expires lest they
+// be slashed.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct Challenge {
+    nonce: U256,
+    actor: EthAddress,
+    challenger: EthAddress,
+    actor_type: u8,
+    timestamp: u64,
+    network_id: NetworkId,
+}
+
+impl fmt::Display for Challenge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+fn get_uint(tokens: &[EthAbiToken], i: usize, max: U256) -> Result<U256, ChallengesError> {
+    match tokens.get(i) {
+        Some(EthAbiToken::Uint(u)) if *u <= max => Ok(*u),
+        _ => Err(ChallengesError::CannotDecode(format!("expected a uint at index {i}"))),
+    }
+}
+
+fn get_address(tokens: &[EthAbiToken], i: usize) -> Result<EthAddress, ChallengesError> {
+    match tokens.get(i) {
+        Some(EthAbiToken::Address(a)) => Ok(*a),
+        _ => Err(ChallengesError::CannotDecode(format!(
+            "expected an address at index {i}"
+        ))),
+    }
+}
+
+fn get_network_id(tokens: &[EthAbiToken], i: usize) -> Result<NetworkId, ChallengesError> {
+    match tokens.get(i) {
+        Some(EthAbiToken::FixedBytes(bs)) if bs.len() == 4 => Ok(NetworkId::try_from(&bs[..])?),
+        _ => Err(ChallengesError::CannotDecode(format!(
+            "expected a bytes4 network id at index {i}"
+        ))),
+    }
+}
+
+impl Challenge {
+    pub fn from_log(log: &EthLog) -> Result<Option<Self>, ChallengesError> {
+        if log.topics.first() != Some(&*CHALLENGE_PENDING_TOPIC) {
+            return Ok(None);
+        };
+
+        let tokens = match eth_abi_decode(&[CHALLENGE_PARAM_TYPE.clone()], &log.data)?.pop() {
+            Some(EthAbiToken::Tuple(tokens)) => tokens,
+            _ => {
+                return Err(ChallengesError::CannotDecode(format!(
+                    "no challenge tuple in log from {}",
+                    log.address
+                )))
+            },
+        };
+
+        Ok(Some(Self {
+            nonce: get_uint(&tokens, 0, U256::MAX)?,
+            actor: get_address(&tokens, 1)?,
+            challenger: get_address(&tokens, 2)?,
+            actor_type: get_uint(&tokens, 3, U256::from(u8::MAX))?.as_u32() as u8,
+            timestamp: get_uint(&tokens, 4, U256::from(u64::MAX))?.as_u64(),
+            network_id: get_network_id(&tokens, 5)?,
+        }))
+    }
+
+    // NOTE: Returns those challenges made to the given actor by the hub at the given address. Any
+    // which cannot be decoded, such as those made on networks unknown to this sentinel, are skipped
+    // rather than failing the processing of the block.
+    pub fn from_sub_mat(sub_mat: &EthSubmissionMaterial, hub: &EthAddress, actor: &EthAddress) -> Vec<Self> {
+        let mut challenges = vec![];
+        for receipt in sub_mat.receipts.iter() {
+            for log in receipt.logs.iter().filter(|l| l.is_from_address(hub)) {
+                match Self::from_log(log) {
+                    Ok(Some(challenge)) if &challenge.actor == actor => challenges.push(challenge),
+                    Ok(_) => (),
+                    Err(e) => warn!("could not decode challenge from hub {hub}: {e}"),
+                };
+            }
+        }
+        challenges
+    }
+
+    pub fn to_token(&self) -> Result<EthAbiToken, ChallengesError> {
+        Ok(EthAbiToken::Tuple(vec![
+            EthAbiToken::Uint(self.nonce),
+            EthAbiToken::Address(self.actor),
+            EthAbiToken::Address(self.challenger),
+            EthAbiToken::Uint(U256::from(self.actor_type)),
+            EthAbiToken::Uint(U256::from(self.timestamp)),
+            EthAbiToken::FixedBytes(self.network_id.to_bytes_4()?.to_vec()),
+        ]))
+    }
+
+    // NOTE: The hub identifies a challenge by the hash of its abi encoding.
+    pub fn id(&self) -> Result<EthHash, ChallengesError> {
+        Ok(keccak_hash_bytes(&eth_abi_encode(&[self.to_token()?])))
+    }
+
+    // NOTE: The duration is that configured locally rather than read from the hub, so should the two
+    // differ, challenges will be marked expired too early or too late, and hence either abandoned
+    // whilst they may still be answered, or answered after the hub will accept it.
+    pub fn expires_at(&self, challenge_duration: u64) -> u64 {
+        self.timestamp.saturating_add(challenge_duration)
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+
+    pub fn get_sample_challenge(nonce: u64, actor: EthAddress) -> Challenge {
+        Challenge {
+            actor,
+            actor_type: 1,
+            nonce: U256::from(nonce),
+            timestamp: 1_700_000_000,
+            challenger: EthAddress::from_low_u64_be(42),
+            network_id: NetworkId::try_from("polygon").unwrap(),
+        }
+    }
+
+    fn get_sample_log(challenge: &Challenge) -> EthLog {
+        EthLog::new(
+            EthAddress::from_low_u64_be(1),
+            vec![*CHALLENGE_PENDING_TOPIC],
+            eth_abi_encode(&[challenge.to_token().unwrap()]),
+        )
+    }
+
+    #[test]
+    fn should_decode_challenge_pending_event() {
+        let challenge = get_sample_challenge(1, EthAddress::from_low_u64_be(1337));
+        let result = Challenge::from_log(&get_sample_log(&challenge)).unwrap().unwrap();
+        assert_eq!(result, challenge);
+    }
+
+    #[test]
+    fn should_ignore_other_events() {
+        let log = EthLog::new(EthAddress::from_low_u64_be(1), vec![EthHash::zero()], vec![]);
+        assert!(Challenge::from_log(&log).unwrap().is_none());
+    }
+
+    #[test]
+    fn should_get_different_ids_for_different_challenges() {
+        let actor = EthAddress::from_low_u64_be(1337);
+        let a = get_sample_challenge(1, actor);
+        let b = get_sample_challenge(2, actor);
+        assert_eq!(a.id().unwrap(), a.clone().id().unwrap());
+        assert_ne!(a.id().unwrap(), b.id().unwrap());
+    }
+}
@@ -0,0 +1,133 @@
+use std::fmt;
+
+use common::{crypto_utils::keccak_hash_bytes, strip_hex_prefix, Bytes};
+use common_eth::{encode_fxn_call, EthPrivateKey, EthSigningCapabilities};
+use derive_getters::Getters;
+use ethabi::{encode as eth_abi_encode, Token as EthAbiToken};
+use ethereum_types::H256 as EthHash;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::{Challenge, ChallengesError};
+use crate::{SentinelError, SentinelStatus, WebSocketMessagesEncodable};
+
+const SOLVE_CHALLENGE_ABI_FRAGMENT: &str = "[{\"inputs\":[{\"components\":[{\"internalType\":\"uint256\",\"name\":\"nonce\",\"type\":\"uint256\"},{\"internalType\":\"address\",\"name\":\"actor\",\"type\":\"address\"},{\"internalType\":\"address\",\"name\":\"challenger\",\"type\":\"address\"},{\"internalType\":\"enum IPNetworkHub.ActorTypes\",\"name\":\"actorType\",\"type\":\"uint8\"},{\"internalType\":\"uint64\",\"name\":\"timestamp\",\"type\":\"uint64\"},{\"internalType\":\"bytes4\",\"name\":\"networkId\",\"type\":\"bytes4\"}],\"internalType\":\"struct IPNetworkHub.Challenge\",\"name\":\"challenge\",\"type\":\"tuple\"},{\"internalType\":\"bytes\",\"name\":\"status\",\"type\":\"bytes\"},{\"internalType\":\"bytes\",\"name\":\"signature\",\"type\":\"bytes\"}],\"name\":\"solveChallenge\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"}]";
+
+// NOTE: The answer to a challenge, proving that the core is alive. The signature, made with the
+// core's key, is over the challenge id & the hash of the (itself signed) status json, so that the
+// hub can tie the status to the challenge it answers.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengeResponse {
+    challenge_id: EthHash,
+    status: SentinelStatus,
+    signature: String,
+}
+
+impl fmt::Display for ChallengeResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl TryFrom<WebSocketMessagesEncodable> for ChallengeResponse {
+    type Error = ChallengesError;
+
+    fn try_from(m: WebSocketMessagesEncodable) -> Result<Self, Self::Error> {
+        let err = ChallengesError::CannotConvert {
+            from: m.to_string(),
+            to: "ChallengeResponse".into(),
+        };
+        match m {
+            WebSocketMessagesEncodable::Success(json) => serde_json::from_value(json).map_err(|_| err),
+            _ => Err(err),
+        }
+    }
+}
+
+impl ChallengeResponse {
+    fn get_signable_bytes(challenge_id: &EthHash, status: &SentinelStatus) -> Bytes {
+        let status_hash = keccak_hash_bytes(status.to_string().as_bytes());
+        eth_abi_encode(&[
+            EthAbiToken::FixedBytes(challenge_id.as_bytes().to_vec()),
+            EthAbiToken::FixedBytes(status_hash.as_bytes().to_vec()),
+        ])
+    }
+
+    pub fn new(pk: &EthPrivateKey, challenge: &Challenge, status: SentinelStatus) -> Result<Self, SentinelError> {
+        let challenge_id = challenge.id()?;
+        debug!("signing response to challenge 0x{challenge_id:x}...");
+        let bs = Self::get_signable_bytes(&challenge_id, &status);
+        let sig = pk.hash_and_sign_msg_with_eth_prefix(keccak_hash_bytes(&bs).as_bytes())?;
+        Ok(Self {
+            status,
+            challenge_id,
+            signature: format!("0x{sig}"),
+        })
+    }
+
+    pub fn to_call_data(&self, challenge: &Challenge) -> Result<Bytes, SentinelError> {
+        let challenge_id = challenge.id()?;
+        if challenge_id != self.challenge_id {
+            return Err(SentinelError::Custom(format!(
+                "response to challenge 0x{:x} cannot answer challenge 0x{challenge_id:x}",
+                self.challenge_id
+            )));
+        };
+        Ok(encode_fxn_call(SOLVE_CHALLENGE_ABI_FRAGMENT, "solveChallenge", &[
+            challenge.to_token()?,
+            EthAbiToken::Bytes(self.status.to_string().into_bytes()),
+            EthAbiToken::Bytes(hex::decode(strip_hex_prefix(&self.signature))?),
+        ])?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use common_eth::EthSignature;
+    use ethereum_types::Address as EthAddress;
+
+    use super::*;
+    use crate::challenges::challenge::tests::get_sample_challenge;
+
+    fn get_sample_pk() -> EthPrivateKey {
+        EthPrivateKey::from_str("aacb9c865008b5a8b7666a57f5d609347d3f311fd7b98e1d52603ed9a58876c9").unwrap()
+    }
+
+    #[test]
+    fn should_sign_challenge_response_with_core_key() {
+        let pk = get_sample_pk();
+        let challenge = get_sample_challenge(1, pk.to_address());
+        let status = SentinelStatus::new(&pk, vec![]).unwrap();
+        let response = ChallengeResponse::new(&pk, &challenge, status.clone()).unwrap();
+        assert_eq!(response.challenge_id(), &challenge.id().unwrap());
+        let bs = ChallengeResponse::get_signable_bytes(&challenge.id().unwrap(), &status);
+        let expected_sig = pk
+            .hash_and_sign_msg_with_eth_prefix(keccak_hash_bytes(&bs).as_bytes())
+            .unwrap();
+        assert_eq!(EthSignature::from_str(response.signature()).unwrap(), expected_sig);
+    }
+
+    #[test]
+    fn should_get_solve_challenge_call_data() {
+        let pk = get_sample_pk();
+        let challenge = get_sample_challenge(1, pk.to_address());
+        let status = SentinelStatus::new(&pk, vec![]).unwrap();
+        let response = ChallengeResponse::new(&pk, &challenge, status).unwrap();
+        let data = response.to_call_data(&challenge).unwrap();
+        let selector = keccak_hash_bytes(b"solveChallenge((uint256,address,address,uint8,uint64,bytes4),bytes,bytes)");
+        assert_eq!(data[..4], selector.as_bytes()[..4]);
+    }
+
+    #[test]
+    fn should_not_get_call_data_for_another_challenge() {
+        let pk = get_sample_pk();
+        let challenge = get_sample_challenge(1, pk.to_address());
+        let status = SentinelStatus::new(&pk, vec![]).unwrap();
+        let response = ChallengeResponse::new(&pk, &challenge, status).unwrap();
+        let other = get_sample_challenge(2, EthAddress::from_low_u64_be(1337));
+        assert!(response.to_call_data(&other).is_err());
+    }
+}
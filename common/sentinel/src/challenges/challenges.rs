@@ -0,0 +1,337 @@
+use std::fmt;
+
+use common::{Byte, DatabaseInterface, MIN_DATA_SENSITIVITY_LEVEL};
+use derive_getters::Getters;
+use ethereum_types::H256 as EthHash;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::{Challenge, ChallengesError};
+use crate::{
+    db_utils::{DbKey, DbUtilsT, SentinelDbKeys, SentinelDbUtils},
+    SentinelError,
+    WebSocketMessagesEncodable,
+};
+
+const MAX_NUM_RESOLVED_CHALLENGES: usize = 50;
+
+// NOTE: Whilst a challenge is being answered, its state holds the hashes of the tx answering it,
+// since the tx manager may replace a stuck one with others paying higher fees, any one of which may
+// be the one that's mined.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "camelCase")]
+pub enum ChallengeState {
+    Pending,
+    #[serde(rename_all = "camelCase")]
+    Answering {
+        tx_hashes: Vec<EthHash>,
+        answered_at: u64,
+    },
+    #[serde(rename_all = "camelCase")]
+    Responded {
+        tx_hash: EthHash,
+        responded_at: u64,
+    },
+    Expired,
+}
+
+impl ChallengeState {
+    pub fn is_resolved(&self) -> bool {
+        matches!(self, Self::Responded { .. } | Self::Expired)
+    }
+
+    // NOTE: A challenge is only responded to once the tx answering it has been mined, and whilst it is
+    // being answered, it may go back to pending should that tx fail, in order to be answered again.
+    fn can_become(&self, next: &Self) -> bool {
+        match self {
+            Self::Pending => matches!(next, Self::Answering { .. } | Self::Expired),
+            Self::Answering { .. } => true,
+            Self::Responded { .. } | Self::Expired => false,
+        }
+    }
+}
+
+impl fmt::Display for ChallengeState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengeInfo {
+    id: EthHash,
+    challenge: Challenge,
+    state: ChallengeState,
+}
+
+impl ChallengeInfo {
+    pub fn is_pending(&self) -> bool {
+        self.state == ChallengeState::Pending
+    }
+
+    pub fn is_resolved(&self) -> bool {
+        self.state.is_resolved()
+    }
+}
+
+// NOTE: Those challenges made to this sentinel which the core has seen, & what became of them. The
+// unresolved ones are always kept, but only the most recent of the resolved ones. The ids of those
+// pruned are kept too, so that reprocessing an old block cannot revive one of them as pending.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct Challenges {
+    challenges: Vec<ChallengeInfo>,
+    #[serde(default)]
+    pruned_ids: Vec<EthHash>,
+}
+
+impl DbUtilsT for Challenges {
+    fn key(&self) -> Result<DbKey, SentinelError> {
+        Ok(SentinelDbKeys::get_challenges_db_key())
+    }
+
+    fn sensitivity() -> Option<Byte> {
+        MIN_DATA_SENSITIVITY_LEVEL
+    }
+
+    fn from_bytes(bytes: &[Byte]) -> Result<Self, SentinelError> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
+
+impl TryFrom<WebSocketMessagesEncodable> for Challenges {
+    type Error = ChallengesError;
+
+    fn try_from(m: WebSocketMessagesEncodable) -> Result<Self, Self::Error> {
+        let err = ChallengesError::CannotConvert {
+            from: m.to_string(),
+            to: "Challenges".into(),
+        };
+        match m {
+            WebSocketMessagesEncodable::Success(json) => serde_json::from_value(json).map_err(|_| err),
+            _ => Err(err),
+        }
+    }
+}
+
+impl fmt::Display for Challenges {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl Challenges {
+    pub fn get<D: DatabaseInterface>(db: &D) -> Self {
+        let key = SentinelDbKeys::get_challenges_db_key();
+        match Self::get_from_db(&SentinelDbUtils::new(db), &key) {
+            Ok(c) => c,
+            Err(_) => {
+                debug!("no challenges in db, using default");
+                Self::default()
+            },
+        }
+    }
+
+    pub fn save<D: DatabaseInterface>(&self, db: &D) -> Result<(), SentinelError> {
+        self.update_in_db(&SentinelDbUtils::new(db))
+    }
+
+    // NOTE: Returns false if the challenge is already known, so that reprocessing a block does not
+    // reset the state of any challenge in it, including those resolved long enough ago to have been
+    // pruned.
+    pub fn add(&mut self, challenge: Challenge) -> Result<bool, ChallengesError> {
+        let id = challenge.id()?;
+        if self.challenges.iter().any(|c| c.id == id) {
+            debug!("challenge 0x{id:x} already known");
+            return Ok(false);
+        };
+        if self.pruned_ids.contains(&id) {
+            debug!("challenge 0x{id:x} already resolved and pruned");
+            return Ok(false);
+        };
+        self.challenges.push(ChallengeInfo {
+            id,
+            challenge,
+            state: ChallengeState::Pending,
+        });
+        Ok(true)
+    }
+
+    pub fn pending(&self) -> Vec<&ChallengeInfo> {
+        self.challenges.iter().filter(|c| c.is_pending()).collect()
+    }
+
+    pub fn unresolved(&self) -> Vec<&ChallengeInfo> {
+        self.challenges.iter().filter(|c| !c.is_resolved()).collect()
+    }
+
+    pub fn get_pending_challenge(&self, id: &EthHash) -> Result<&Challenge, ChallengesError> {
+        match self.challenges.iter().find(|c| &c.id == id) {
+            Some(c) if c.is_pending() => Ok(&c.challenge),
+            Some(_) => Err(ChallengesError::NotPending(*id)),
+            None => Err(ChallengesError::NotFound(*id)),
+        }
+    }
+
+    // NOTE: Resolved challenges may not have their state changed, since there's no undoing an answer
+    // to a challenge, nor answering one that's expired.
+    pub fn set_state(&mut self, id: &EthHash, state: ChallengeState) -> Result<(), ChallengesError> {
+        match self.challenges.iter_mut().find(|c| &c.id == id) {
+            Some(c) if c.is_resolved() => Err(ChallengesError::NotPending(*id)),
+            Some(c) if !c.state.can_become(&state) => Err(ChallengesError::InvalidStateChange {
+                id: *id,
+                from: c.state.to_string(),
+                to: state.to_string(),
+            }),
+            Some(c) => {
+                c.state = state;
+                self.prune();
+                Ok(())
+            },
+            None => Err(ChallengesError::NotFound(*id)),
+        }
+    }
+
+    fn prune(&mut self) {
+        let num_resolved = self.challenges.iter().filter(|c| c.is_resolved()).count();
+        let mut num_to_remove = num_resolved.saturating_sub(MAX_NUM_RESOLVED_CHALLENGES);
+        let pruned_ids = &mut self.pruned_ids;
+        self.challenges.retain(|c| {
+            if num_to_remove > 0 && c.is_resolved() {
+                num_to_remove -= 1;
+                pruned_ids.push(c.id);
+                false
+            } else {
+                true
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::get_test_database;
+    use ethereum_types::Address as EthAddress;
+
+    use super::*;
+    use crate::challenges::challenge::tests::get_sample_challenge;
+
+    fn get_sample_answering_state() -> ChallengeState {
+        ChallengeState::Answering {
+            tx_hashes: vec![EthHash::from_low_u64_be(1)],
+            answered_at: 1_700_000_001,
+        }
+    }
+
+    fn get_sample_responded_state() -> ChallengeState {
+        ChallengeState::Responded {
+            tx_hash: EthHash::from_low_u64_be(1),
+            responded_at: 1_700_000_001,
+        }
+    }
+
+    #[test]
+    fn should_not_add_known_challenge() {
+        let challenge = get_sample_challenge(1, EthAddress::from_low_u64_be(1337));
+        let mut challenges = Challenges::default();
+        assert!(challenges.add(challenge.clone()).unwrap());
+        assert!(!challenges.add(challenge).unwrap());
+        assert_eq!(challenges.pending().len(), 1);
+    }
+
+    #[test]
+    fn should_set_state_of_pending_challenge_only() {
+        let challenge = get_sample_challenge(1, EthAddress::from_low_u64_be(1337));
+        let id = challenge.id().unwrap();
+        let mut challenges = Challenges::default();
+        challenges.add(challenge).unwrap();
+        challenges.set_state(&id, get_sample_answering_state()).unwrap();
+        challenges.set_state(&id, get_sample_responded_state()).unwrap();
+        assert!(challenges.unresolved().is_empty());
+        assert!(matches!(
+            challenges.set_state(&id, ChallengeState::Expired),
+            Err(ChallengesError::NotPending(_))
+        ));
+        assert!(matches!(
+            challenges.get_pending_challenge(&EthHash::zero()),
+            Err(ChallengesError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn should_only_respond_to_challenge_once_answering_it() {
+        let challenge = get_sample_challenge(1, EthAddress::from_low_u64_be(1337));
+        let id = challenge.id().unwrap();
+        let mut challenges = Challenges::default();
+        challenges.add(challenge).unwrap();
+        assert!(matches!(
+            challenges.set_state(&id, get_sample_responded_state()),
+            Err(ChallengesError::InvalidStateChange { .. })
+        ));
+        challenges.set_state(&id, get_sample_answering_state()).unwrap();
+        assert!(challenges.pending().is_empty());
+        assert_eq!(challenges.unresolved().len(), 1);
+        challenges.set_state(&id, ChallengeState::Pending).unwrap();
+        assert_eq!(challenges.pending().len(), 1);
+    }
+
+    #[test]
+    fn should_serde_answering_challenge_state() {
+        let state = get_sample_answering_state();
+        let json = serde_json::to_value(&state).unwrap();
+        assert_eq!(json["state"], "answering");
+        assert_eq!(serde_json::from_value::<ChallengeState>(json).unwrap(), state);
+    }
+
+    #[test]
+    fn should_only_keep_max_num_resolved_challenges() {
+        let actor = EthAddress::from_low_u64_be(1337);
+        let mut challenges = Challenges::default();
+        let num_challenges = MAX_NUM_RESOLVED_CHALLENGES as u64 + 2;
+        for i in 0..num_challenges {
+            challenges.add(get_sample_challenge(i, actor)).unwrap();
+        }
+        for i in 0..num_challenges - 1 {
+            let id = get_sample_challenge(i, actor).id().unwrap();
+            challenges.set_state(&id, ChallengeState::Expired).unwrap();
+        }
+        assert_eq!(challenges.challenges().len(), MAX_NUM_RESOLVED_CHALLENGES + 1);
+        assert_eq!(challenges.pending().len(), 1);
+        assert_eq!(challenges.challenges()[0].challenge(), &get_sample_challenge(1, actor));
+    }
+
+    #[test]
+    fn should_not_revive_pruned_resolved_challenge() {
+        let actor = EthAddress::from_low_u64_be(1337);
+        let mut challenges = Challenges::default();
+        let num_challenges = MAX_NUM_RESOLVED_CHALLENGES as u64 + 1;
+        for i in 0..num_challenges {
+            challenges.add(get_sample_challenge(i, actor)).unwrap();
+            let id = get_sample_challenge(i, actor).id().unwrap();
+            challenges.set_state(&id, ChallengeState::Expired).unwrap();
+        }
+        let pruned = get_sample_challenge(0, actor);
+        assert_eq!(challenges.pruned_ids(), &vec![pruned.id().unwrap()]);
+        assert!(!challenges.add(pruned).unwrap());
+        assert!(challenges.pending().is_empty());
+        assert_eq!(challenges.challenges().len(), MAX_NUM_RESOLVED_CHALLENGES);
+    }
+
+    #[test]
+    fn should_get_challenges_saved_before_pruned_ids_were_kept() {
+        let challenges = Challenges::from_bytes(br#"{"challenges":[]}"#).unwrap();
+        assert!(challenges.pruned_ids().is_empty());
+    }
+
+    #[test]
+    fn should_save_and_get_challenges() {
+        let db = get_test_database();
+        let mut challenges = Challenges::default();
+        challenges
+            .add(get_sample_challenge(1, EthAddress::from_low_u64_be(1337)))
+            .unwrap();
+        challenges.save(&db).unwrap();
+        assert_eq!(Challenges::get(&db), challenges);
+    }
+}
@@ -0,0 +1,27 @@
+use common_network_ids::NetworkIdError;
+use ethereum_types::H256 as EthHash;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ChallengesError {
+    #[error("no challenge with id 0x{0:x}")]
+    NotFound(EthHash),
+
+    #[error("challenge with id 0x{0:x} is no longer pending")]
+    NotPending(EthHash),
+
+    #[error("challenge with id 0x{id:x} cannot go from {from} to {to}")]
+    InvalidStateChange { id: EthHash, from: String, to: String },
+
+    #[error("cannot decode challenge: {0}")]
+    CannotDecode(String),
+
+    #[error("cannot convert from: '{from}' to: '{to}'")]
+    CannotConvert { from: String, to: String },
+
+    #[error("challenge network id error: {0}")]
+    NetworkId(#[from] NetworkIdError),
+
+    #[error("challenge eth abi error: {0}")]
+    EthAbi(#[from] ethabi::Error),
+}
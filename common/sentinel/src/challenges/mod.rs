@@ -0,0 +1,11 @@
+mod challenge;
+mod challenge_response;
+mod challenges;
+mod error;
+
+pub use self::{
+    challenge::Challenge,
+    challenge_response::ChallengeResponse,
+    challenges::{ChallengeInfo, ChallengeState, Challenges},
+    error::ChallengesError,
+};
@@ -0,0 +1,111 @@
+use std::result::Result;
+
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+
+use super::SentinelConfigError;
+
+const DEFAULT_POLL_INTERVAL: u64 = 15;
+const DEFAULT_GAS_LIMIT: usize = 500_000;
+const DEFAULT_CHALLENGE_DURATION: u64 = 60 * 60;
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_poll_interval() -> u64 {
+    DEFAULT_POLL_INTERVAL
+}
+
+fn default_challenge_duration() -> u64 {
+    DEFAULT_CHALLENGE_DURATION
+}
+
+fn default_gas_limit() -> usize {
+    DEFAULT_GAS_LIMIT
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChallengeResponderToml {
+    #[serde(default = "default_enabled")]
+    enabled: bool,
+    #[serde(default = "default_poll_interval")]
+    poll_interval: u64,
+    #[serde(default = "default_challenge_duration")]
+    challenge_duration: u64,
+    #[serde(default = "default_gas_limit")]
+    gas_limit: usize,
+}
+
+impl Default for ChallengeResponderToml {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            challenge_duration: DEFAULT_CHALLENGE_DURATION,
+            gas_limit: DEFAULT_GAS_LIMIT,
+        }
+    }
+}
+
+// NOTE: Challenges are answered via txs from the owner, via the tx manager. Since an unanswered
+// challenge costs the sentinel its bond, responding is enabled by default.
+#[derive(Debug, Clone, Getters, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ChallengeResponderConfig {
+    enabled: bool,
+    poll_interval: u64,
+    challenge_duration: u64,
+    gas_limit: usize,
+}
+
+impl Default for ChallengeResponderConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            challenge_duration: DEFAULT_CHALLENGE_DURATION,
+            gas_limit: DEFAULT_GAS_LIMIT,
+        }
+    }
+}
+
+impl TryFrom<&ChallengeResponderToml> for ChallengeResponderConfig {
+    type Error = SentinelConfigError;
+
+    fn try_from(toml: &ChallengeResponderToml) -> Result<Self, Self::Error> {
+        // NOTE: Polling any less often than challenges last risks missing them entirely.
+        if toml.poll_interval >= toml.challenge_duration {
+            return Err(SentinelConfigError::ChallengePollInterval {
+                poll_interval: toml.poll_interval,
+                challenge_duration: toml.challenge_duration,
+            });
+        };
+
+        Ok(Self {
+            enabled: toml.enabled,
+            poll_interval: toml.poll_interval,
+            challenge_duration: toml.challenge_duration,
+            gas_limit: toml.gas_limit,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_default_challenge_responder_config_from_default_toml() {
+        let result = ChallengeResponderConfig::try_from(&ChallengeResponderToml::default()).unwrap();
+        assert_eq!(result, ChallengeResponderConfig::default());
+    }
+
+    #[test]
+    fn should_not_allow_poll_interval_as_long_as_challenge_duration() {
+        let toml = ChallengeResponderToml {
+            poll_interval: DEFAULT_CHALLENGE_DURATION,
+            ..Default::default()
+        };
+        assert!(ChallengeResponderConfig::try_from(&toml).is_err());
+    }
+}
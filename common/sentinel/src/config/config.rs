@@ -1,5 +1,7 @@
-use std::{collections::HashMap, result::Result};
+use std::{collections::HashMap, env, fmt, result::Result, str::FromStr};
 
+use common_chain_ids::{EvmChainConfig, EvmChainRegistry};
+use common_eth::EthPrivateKey;
 use common_network_ids::NetworkId;
 use derive_getters::Getters;
 use ethereum_types::Address as EthAddress;
@@ -8,55 +10,273 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     config::{
+        ChallengeResponderConfig,
+        ChallengeResponderToml,
         GovernanceConfig,
         GovernanceToml,
+        HeartbeatConfig,
+        HeartbeatToml,
         LogConfig,
         LogToml,
         MongoConfig,
         NetworkConfig,
         NetworkToml,
+        RegistrationConfig,
+        RegistrationToml,
+        SentinelConfigDiff,
         SentinelConfigError,
         SentinelCoreConfig,
+        TxManagerConfig,
+        TxManagerToml,
     },
     Endpoints,
+    Env,
+    EnvError,
     SentinelError,
 };
 
+// NOTE: Env vars override values in the config file, eg `SENTINEL__NETWORKS__BSC__BATCH_SIZE=100`,
+// with list values such as endpoints being comma separated.
+const ENV_PREFIX: &str = "SENTINEL";
+const ENV_SEPARATOR: &str = "__";
+const ENV_LIST_SEPARATOR: &str = ",";
+const ENV_LIST_KEYS: [&str; 1] = ["endpoints"];
+
 #[derive(Debug, Clone, Deserialize)]
 struct SentinelConfigToml {
     log: LogToml,
     core: SentinelCoreConfig,
     governance: GovernanceToml,
+    #[serde(default)]
+    heartbeat: HeartbeatToml,
+    #[serde(default)]
+    tx_manager: TxManagerToml,
+    registration: Option<RegistrationToml>,
+    #[serde(default)]
+    challenge_responder: ChallengeResponderToml,
     networks: HashMap<String, NetworkToml>,
     mongo: MongoConfig,
+    // NOTE: Only ever expected to be set via the secrets file.
+    private_key: Option<String>,
 }
 
 impl SentinelConfigToml {
+    // NOTE: Config is layered, with the file overridden by any env vars, which are in turn overridden
+    // by the secrets file, should one be configured via `secrets_path` in either of the former. The
+    // secrets file is a config file too, and so may contain the endpoints for any network, along
+    // with the owner's private key.
     pub fn new(path: &str) -> Result<Self, SentinelError> {
-        Ok(config::Config::builder()
+        let secrets_path = Self::builder(path).build()?.get_string("secrets_path").ok();
+        Self::new_with_secrets(path, secrets_path.as_deref())
+    }
+
+    fn new_with_secrets(path: &str, secrets_path: Option<&str>) -> Result<Self, SentinelError> {
+        let builder = match secrets_path {
+            Some(p) => Self::builder(path).add_source(config::File::with_name(p)),
+            None => Self::builder(path),
+        };
+        Ok(builder.build()?.try_deserialize()?)
+    }
+
+    fn builder(path: &str) -> config::ConfigBuilder<config::builder::DefaultState> {
+        config::Config::builder()
             .add_source(config::File::with_name(path))
-            .build()?
-            .try_deserialize()?)
+            .add_source(Self::env_source())
+    }
+
+    fn env_source() -> config::Environment {
+        let prefix = format!("{ENV_PREFIX}{ENV_SEPARATOR}");
+        let suffixes = ENV_LIST_KEYS.map(|k| format!("{ENV_SEPARATOR}{}", k.to_uppercase()));
+
+        // NOTE: Only those keys which are lists are split, lest other values containing the list
+        // separator be split too.
+        env::vars()
+            .map(|(k, _)| k)
+            .filter(|k| k.starts_with(&prefix) && suffixes.iter().any(|s| k.ends_with(s)))
+            .fold(
+                config::Environment::with_prefix(ENV_PREFIX)
+                    .prefix_separator(ENV_SEPARATOR)
+                    .separator(ENV_SEPARATOR)
+                    .list_separator(ENV_LIST_SEPARATOR)
+                    .try_parsing(true),
+                |env_source, k| {
+                    let key = k[prefix.len()..].to_lowercase().replace(ENV_SEPARATOR, ".");
+                    env_source.with_list_parse_key(&key)
+                },
+            )
     }
 }
 
-#[derive(Debug, Clone, Getters, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Getters, Eq, PartialEq, Serialize, Deserialize)]
 pub struct SentinelConfig {
     log: LogConfig,
     core: SentinelCoreConfig,
     governance: GovernanceConfig,
+    heartbeat: HeartbeatConfig,
+    tx_manager: TxManagerConfig,
+    registration: Option<RegistrationConfig>,
+    challenge_responder: ChallengeResponderConfig,
     networks: HashMap<NetworkId, NetworkConfig>,
     mongo: MongoConfig,
+    // NOTE: Where the config was loaded from, so that it may be reloaded from there later.
+    #[serde(skip)]
+    #[getter(skip)]
+    path: String,
+    #[serde(skip)]
+    #[getter(skip)]
+    private_key: Option<String>,
+}
+
+// NOTE: Secrets are redacted lest they end up in the logs.
+impl fmt::Debug for SentinelConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SentinelConfig")
+            .field("log", &self.log)
+            .field("core", &self.core)
+            .field("governance", &self.governance)
+            .field("heartbeat", &self.heartbeat)
+            .field("tx_manager", &self.tx_manager)
+            .field("registration", &self.registration)
+            .field("challenge_responder", &self.challenge_responder)
+            .field("networks", &self.networks)
+            .field("mongo", &self.mongo)
+            .field("path", &self.path)
+            .field("private_key", &self.private_key.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
 }
 
 impl SentinelConfig {
     pub fn new(path: &str) -> Result<Self, SentinelError> {
-        let res = Self::from_toml(&SentinelConfigToml::new(path)?)?;
+        let mut res = Self::from_toml(&SentinelConfigToml::new(path)?)?;
+        res.path = path.to_string();
         debug!("sentinel config {:?}", res);
         Ok(res)
     }
 
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    // NOTE: The owner's private key, taken from the secrets file if it's in there, else from the
+    // `PRIVATE_KEY` env var as before.
+    pub fn private_key(&self) -> Result<EthPrivateKey, EnvError> {
+        match self.private_key {
+            Some(ref k) => EthPrivateKey::from_str(k).map_err(|e| {
+                error!("{e}");
+                EnvError::MalformedPrivateKey("private_key".into())
+            }),
+            None => {
+                Env::init()?;
+                Env::get_private_key()
+            },
+        }
+    }
+
+    // NOTE: Returns a copy of this config with those changes in the `new` config that can be applied
+    // to a running sentinel, along with a diff describing them & any which cannot be applied.
+    pub fn hot_swap(&self, new: &Self) -> (Self, SentinelConfigDiff) {
+        let mut config = self.clone();
+        let mut diff = SentinelConfigDiff::default();
+
+        let mut network_ids = self.network_ids();
+        network_ids.sort_by_key(|id| id.to_string());
+        for id in network_ids.iter() {
+            match new.networks.get(id) {
+                Some(c) if Some(c) != self.networks.get(id) => {
+                    config.networks.insert(*id, c.clone());
+                    diff.add_changed_network(*id);
+                },
+                Some(_) => (),
+                None => diff.add_requires_restart(&format!("networks.{id} (removed)")),
+            }
+        }
+
+        let mut new_network_ids = new.network_ids();
+        new_network_ids.sort_by_key(|id| id.to_string());
+        for id in new_network_ids.iter().filter(|id| !self.networks.contains_key(id)) {
+            diff.add_requires_restart(&format!("networks.{id} (added)"));
+        }
+
+        if new.core.auth() != self.core.auth() {
+            if new.core.auth().tls() != self.core.auth().tls() {
+                diff.add_requires_restart("core.auth.tls");
+            };
+            let auth = new.core.auth().with_tls(self.core.auth().tls().clone());
+            if &auth != self.core.auth() {
+                config.core = self.core.with_auth(auth);
+                diff.set_rpc_auth_changed();
+            };
+        };
+
+        if new.core.health() != self.core.health() {
+            diff.add_requires_restart("core.health");
+        };
+        if new.core.timeout() != self.core.timeout() {
+            diff.add_requires_restart("core.timeout");
+        };
+        if new.core.rpc_server_address() != self.core.rpc_server_address() {
+            diff.add_requires_restart("core.rpc_server_address");
+        };
+        if new.log != self.log {
+            diff.add_requires_restart("log");
+        };
+        if new.governance != self.governance {
+            diff.add_requires_restart("governance");
+        };
+        if new.heartbeat != self.heartbeat {
+            diff.add_requires_restart("heartbeat");
+        };
+        if new.tx_manager != self.tx_manager {
+            diff.add_requires_restart("tx_manager");
+        };
+        if new.registration != self.registration {
+            diff.add_requires_restart("registration");
+        };
+        if new.challenge_responder != self.challenge_responder {
+            diff.add_requires_restart("challenge_responder");
+        };
+        if new.mongo != self.mongo {
+            diff.add_requires_restart("mongo");
+        };
+        if new.private_key != self.private_key {
+            diff.add_requires_restart("private_key");
+        };
+
+        (config, diff)
+    }
+
+    // NOTE: Returns a copy of this config with the given network's config taken from the `new` one,
+    // for adding a network to a running sentinel.
+    pub fn with_network_from(&self, nid: &NetworkId, new: &Self) -> Result<Self, SentinelConfigError> {
+        if self.networks.contains_key(nid) {
+            return Err(SentinelConfigError::NetworkExists(*nid));
+        };
+        let network_config = new
+            .networks
+            .get(nid)
+            .ok_or_else(|| SentinelConfigError::NoConfig(*nid))?;
+        let mut config = self.clone();
+        config.networks.insert(*nid, network_config.clone());
+        Ok(config)
+    }
+
+    pub fn without_network(&self, nid: &NetworkId) -> Result<Self, SentinelConfigError> {
+        let mut config = self.clone();
+        config
+            .networks
+            .remove(nid)
+            .ok_or_else(|| SentinelConfigError::NoConfig(*nid))?;
+        Ok(config)
+    }
+
     fn from_toml(toml: &SentinelConfigToml) -> Result<Self, SentinelError> {
+        // NOTE: Any EVM chains without built in support must be registered first, since the network
+        // ids of such chains can only be parsed from their names once they are.
+        for c in toml.networks.values().filter_map(|v| v.evm_chain()) {
+            EvmChainRegistry::register(c.clone())?;
+        }
+
         let mut networks: HashMap<NetworkId, NetworkConfig> = HashMap::new();
         for (k, v) in toml.networks.iter() {
             let nid = NetworkId::try_from(k)?;
@@ -64,12 +284,24 @@ impl SentinelConfig {
             networks.insert(nid, config);
         }
 
+        toml.core.auth().validate()?;
+
         Ok(Self {
             networks,
             core: toml.core.clone(),
             log: LogConfig::from_toml(&toml.log)?,
             governance: GovernanceConfig::try_from(&toml.governance)?,
+            heartbeat: HeartbeatConfig::try_from(&toml.heartbeat)?,
+            tx_manager: TxManagerConfig::try_from(&toml.tx_manager)?,
+            registration: toml
+                .registration
+                .as_ref()
+                .map(RegistrationConfig::try_from)
+                .transpose()?,
+            challenge_responder: ChallengeResponderConfig::try_from(&toml.challenge_responder)?,
             mongo: toml.mongo.clone(),
+            path: String::default(),
+            private_key: toml.private_key.clone(),
         })
     }
 
@@ -106,6 +338,13 @@ impl SentinelConfig {
             .ok_or_else(|| SentinelConfigError::NoConfig(*nid))
     }
 
+    pub fn evm_chain(&self, nid: &NetworkId) -> Result<Option<EvmChainConfig>, SentinelConfigError> {
+        self.networks
+            .get(nid)
+            .map(|c| c.evm_chain().clone())
+            .ok_or_else(|| SentinelConfigError::NoConfig(*nid))
+    }
+
     pub fn network_ids(&self) -> Vec<NetworkId> {
         self.networks.clone().into_keys().collect()
     }
@@ -126,6 +365,13 @@ impl SentinelConfig {
             .ok_or_else(|| SentinelConfigError::NoConfig(*nid))
     }
 
+    pub fn eip_1559(&self, nid: &NetworkId) -> Result<bool, SentinelConfigError> {
+        self.networks
+            .get(nid)
+            .map(|c| *c.eip_1559())
+            .ok_or_else(|| SentinelConfigError::NoConfig(*nid))
+    }
+
     pub fn pre_filter_receipts(&self, nid: &NetworkId) -> Result<bool, SentinelConfigError> {
         self.networks
             .get(nid)
@@ -166,4 +412,132 @@ mod tests {
         result.unwrap();
         //assert!(result.is_ok());
     }
+
+    fn get_sample_config() -> SentinelConfig {
+        SentinelConfig::new("src/config/test_utils/sample-config").unwrap()
+    }
+
+    #[test]
+    fn should_get_eip_1559_setting_per_network() {
+        let config = get_sample_config();
+        let bsc = NetworkId::try_from("binance").unwrap();
+        let polygon = NetworkId::try_from("polygon").unwrap();
+        assert!(!config.eip_1559(&bsc).unwrap());
+        assert!(config.eip_1559(&polygon).unwrap());
+    }
+
+    #[test]
+    fn should_get_registration_config() {
+        let config = get_sample_config();
+        let registration = config.registration().as_ref().unwrap();
+        assert_eq!(registration.network_id(), &NetworkId::try_from("polygon").unwrap());
+        assert!(!registration.auto_renew());
+    }
+
+    #[test]
+    fn should_get_challenge_responder_config() {
+        let config = get_sample_config();
+        assert!(config.challenge_responder().enabled());
+        assert_eq!(config.challenge_responder().challenge_duration(), &3600);
+    }
+
+    #[test]
+    fn should_remember_config_path() {
+        assert_eq!(get_sample_config().path(), "src/config/test_utils/sample-config");
+    }
+
+    #[test]
+    fn should_override_config_with_secrets_file() {
+        let toml = SentinelConfigToml::new_with_secrets(
+            "src/config/test_utils/sample-config",
+            Some("src/config/test_utils/sample-secrets"),
+        )
+        .unwrap();
+        let config = SentinelConfig::from_toml(&toml).unwrap();
+        let id = NetworkId::try_from("binance").unwrap();
+        assert_eq!(config.endpoints(&id).unwrap().endpoints(), &vec![
+            "wss://bsc.example.com/some-api-key".to_string()
+        ]);
+        assert!(config.private_key().is_ok());
+    }
+
+    #[test]
+    fn should_redact_secrets_when_debug_printing_config() {
+        let toml = SentinelConfigToml::new_with_secrets(
+            "src/config/test_utils/sample-config",
+            Some("src/config/test_utils/sample-secrets"),
+        )
+        .unwrap();
+        let s = format!("{:?}", SentinelConfig::from_toml(&toml).unwrap());
+        assert!(s.contains("<redacted>"));
+        assert!(!s.contains("some-api-key"));
+        assert!(!s.contains(toml.private_key.as_ref().unwrap().as_str()));
+    }
+
+    #[test]
+    fn hot_swapping_identical_config_should_change_nothing() {
+        let config = get_sample_config();
+        let (result, diff) = config.hot_swap(&config.clone());
+        assert_eq!(result, config);
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn should_hot_swap_changed_network_config() {
+        let config = get_sample_config();
+        let mut new = config.clone();
+        let id = config.network_ids()[0];
+        let mut json = serde_json::to_value(config.networks.get(&id).unwrap()).unwrap();
+        json["batch_size"] = serde_json::json!(1);
+        new.networks.insert(id, serde_json::from_value(json).unwrap());
+        let (result, diff) = config.hot_swap(&new);
+        assert_eq!(diff.changed_networks(), &vec![id]);
+        assert!(diff.requires_restart().is_empty());
+        assert_eq!(result.batch_size(&id).unwrap(), 1);
+    }
+
+    #[test]
+    fn should_not_hot_swap_non_hot_swappable_changes() {
+        let config = get_sample_config();
+        let mut new = config.clone();
+        let id = config.network_ids()[0];
+        new.networks.remove(&id);
+        new.mongo.enabled = !config.mongo.enabled;
+        let (result, diff) = config.hot_swap(&new);
+        assert_eq!(result, config);
+        assert!(diff.changed_networks().is_empty());
+        let expected = vec![format!("networks.{id} (removed)"), "mongo".to_string()];
+        assert_eq!(diff.requires_restart(), &expected);
+    }
+
+    #[test]
+    fn should_add_network_from_other_config() {
+        let config = get_sample_config();
+        let id = config.network_ids()[0];
+        let without = config.without_network(&id).unwrap();
+        assert!(without.endpoints(&id).is_err());
+        let with = without.with_network_from(&id, &config).unwrap();
+        assert_eq!(with, config);
+    }
+
+    #[test]
+    fn should_not_add_network_which_already_exists() {
+        let config = get_sample_config();
+        let id = config.network_ids()[0];
+        match config.with_network_from(&id, &config) {
+            Err(SentinelConfigError::NetworkExists(nid)) => assert_eq!(nid, id),
+            r => panic!("wrong result: {r:?}"),
+        }
+    }
+
+    #[test]
+    fn should_not_remove_network_which_does_not_exist() {
+        let config = get_sample_config();
+        let id = config.network_ids()[0];
+        let without = config.without_network(&id).unwrap();
+        match without.without_network(&id) {
+            Err(SentinelConfigError::NoConfig(nid)) => assert_eq!(nid, id),
+            r => panic!("wrong result: {r:?}"),
+        }
+    }
 }
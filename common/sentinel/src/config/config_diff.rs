@@ -0,0 +1,32 @@
+use common_network_ids::NetworkId;
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+
+// NOTE: The result of hot swapping a newly loaded config into a running sentinel. Changes to the
+// networks or the rpc server's auth can be applied without a restart, whereas anything else which
+// changed is listed in `requires_restart`, and is _not_ applied.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct SentinelConfigDiff {
+    changed_networks: Vec<NetworkId>,
+    rpc_auth_changed: bool,
+    requires_restart: Vec<String>,
+}
+
+impl SentinelConfigDiff {
+    pub(super) fn add_changed_network(&mut self, network_id: NetworkId) {
+        self.changed_networks.push(network_id)
+    }
+
+    pub(super) fn set_rpc_auth_changed(&mut self) {
+        self.rpc_auth_changed = true
+    }
+
+    pub(super) fn add_requires_restart(&mut self, s: &str) {
+        self.requires_restart.push(s.to_string())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changed_networks.is_empty() && !self.rpc_auth_changed && self.requires_restart.is_empty()
+    }
+}
@@ -3,10 +3,22 @@ use std::{net::SocketAddr, str::FromStr};
 use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
 
+use super::{HealthConfig, RpcAuthConfig};
+
 #[derive(Debug, Clone, Getters, Deserialize, Eq, PartialEq, Serialize)]
 pub struct SentinelCoreConfig {
     timeout: u64,
     rpc_server_address: SocketAddr,
+    #[serde(default)]
+    auth: RpcAuthConfig,
+    #[serde(default)]
+    health: HealthConfig,
+}
+
+impl SentinelCoreConfig {
+    pub(super) fn with_auth(&self, auth: RpcAuthConfig) -> Self {
+        Self { auth, ..self.clone() }
+    }
 }
 
 impl Default for SentinelCoreConfig {
@@ -14,6 +26,8 @@ impl Default for SentinelCoreConfig {
         Self {
             timeout: u64::default(),
             rpc_server_address: SocketAddr::from_str("127.0.0.1:3030").expect("this not to fail"),
+            auth: RpcAuthConfig::default(),
+            health: HealthConfig::default(),
         }
     }
 }
@@ -29,4 +29,27 @@ pub enum SentinelConfigError {
 
     #[error("no config for network id {0}")]
     NoConfig(NetworkId),
+
+    #[error("network {network_id} is configured with evm chain id {chain_id}, which is not its own")]
+    EvmChainIdMismatch { network_id: NetworkId, chain_id: u64 },
+
+    #[error("network id {0} is already configured")]
+    NetworkExists(NetworkId),
+
+    #[error("fee bump percent of {percent} is less than the min of {min}")]
+    FeeBumpPercent { percent: u64, min: u64 },
+
+    #[error(
+        "challenge poll interval of {poll_interval}s must be less than the challenge duration of {challenge_duration}s"
+    )]
+    ChallengePollInterval {
+        poll_interval: u64,
+        challenge_duration: u64,
+    },
+
+    #[error("rpc client '{0}' is configured more than once")]
+    DuplicateRpcClient(String),
+
+    #[error("rpc client '{0}' needs a bearer token, an eth address or a cert fingerprint to authenticate with")]
+    NoRpcClientCredentials(String),
 }
@@ -0,0 +1,42 @@
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_MAX_SYNC_DELTA: u64 = 100;
+const DEFAULT_MAX_SECONDS_SINCE_LAST_SUBMISSION: u64 = 600;
+const DEFAULT_NODE_POLL_INTERVAL: u64 = 30;
+
+// NOTE: Thresholds used by the `/healthz` & `/readyz` endpoints. An enabled syncer which hasn't
+// submitted a batch for longer than `max_seconds_since_last_submission` is considered stalled, and
+// one which is more than `max_sync_delta` blocks behind its node is considered not ready. Each
+// network's node is polled for its latest block every `node_poll_interval` seconds.
+#[derive(Debug, Clone, Getters, Eq, PartialEq, Serialize, Deserialize)]
+pub struct HealthConfig {
+    #[serde(default = "default_max_sync_delta")]
+    max_sync_delta: u64,
+    #[serde(default = "default_max_seconds_since_last_submission")]
+    max_seconds_since_last_submission: u64,
+    #[serde(default = "default_node_poll_interval")]
+    node_poll_interval: u64,
+}
+
+fn default_max_sync_delta() -> u64 {
+    DEFAULT_MAX_SYNC_DELTA
+}
+
+fn default_max_seconds_since_last_submission() -> u64 {
+    DEFAULT_MAX_SECONDS_SINCE_LAST_SUBMISSION
+}
+
+fn default_node_poll_interval() -> u64 {
+    DEFAULT_NODE_POLL_INTERVAL
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self {
+            max_sync_delta: DEFAULT_MAX_SYNC_DELTA,
+            max_seconds_since_last_submission: DEFAULT_MAX_SECONDS_SINCE_LAST_SUBMISSION,
+            node_poll_interval: DEFAULT_NODE_POLL_INTERVAL,
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use std::{fmt, result::Result, str::FromStr};
+
+use common_network_ids::NetworkId;
+use derive_getters::Getters;
+use ethereum_types::Address as EthAddress;
+use serde::{Deserialize, Serialize};
+
+use crate::{redact_url, SentinelError};
+
+const DEFAULT_INTERVAL: u64 = 60;
+const DEFAULT_MAX_RETRIES: u64 = 3;
+
+fn default_interval() -> u64 {
+    DEFAULT_INTERVAL
+}
+
+fn default_max_retries() -> u64 {
+    DEFAULT_MAX_RETRIES
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HeartbeatTargetToml {
+    Http {
+        url: String,
+    },
+    File {
+        path: String,
+    },
+    OnChain {
+        network_id: String,
+        address: String,
+        gas_limit: Option<usize>,
+    },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct HeartbeatToml {
+    #[serde(default)]
+    enabled: bool,
+    #[serde(default = "default_interval")]
+    interval: u64,
+    #[serde(default = "default_max_retries")]
+    max_retries: u64,
+    #[serde(default)]
+    targets: Vec<HeartbeatTargetToml>,
+}
+
+impl Default for HeartbeatToml {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            targets: vec![],
+            interval: DEFAULT_INTERVAL,
+            max_retries: DEFAULT_MAX_RETRIES,
+        }
+    }
+}
+
+// NOTE: Where the signed status is published to. An on chain heartbeat is a zero value tx from the
+// owner, whose data is the signed status json, and so it may be sent to any address the monitoring
+// backend watches.
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HeartbeatTarget {
+    Http {
+        url: String,
+    },
+    File {
+        path: String,
+    },
+    OnChain {
+        network_id: NetworkId,
+        address: EthAddress,
+        gas_limit: Option<usize>,
+    },
+}
+
+impl fmt::Display for HeartbeatTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Http { url } => write!(f, "http target {}", redact_url(url)),
+            Self::File { path } => write!(f, "file target {path}"),
+            Self::OnChain {
+                network_id, address, ..
+            } => write!(f, "on chain target {address} on {network_id}"),
+        }
+    }
+}
+
+// NOTE: Http urls may contain api keys, lest they end up in the logs.
+impl fmt::Debug for HeartbeatTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HeartbeatTarget({self})")
+    }
+}
+
+impl TryFrom<&HeartbeatTargetToml> for HeartbeatTarget {
+    type Error = SentinelError;
+
+    fn try_from(toml: &HeartbeatTargetToml) -> Result<Self, Self::Error> {
+        Ok(match toml {
+            HeartbeatTargetToml::Http { url } => Self::Http { url: url.clone() },
+            HeartbeatTargetToml::File { path } => Self::File { path: path.clone() },
+            HeartbeatTargetToml::OnChain {
+                network_id,
+                address,
+                gas_limit,
+            } => Self::OnChain {
+                gas_limit: *gas_limit,
+                address: EthAddress::from_str(address)?,
+                network_id: NetworkId::try_from(network_id)?,
+            },
+        })
+    }
+}
+
+#[derive(Debug, Clone, Getters, Eq, PartialEq, Serialize, Deserialize)]
+pub struct HeartbeatConfig {
+    enabled: bool,
+    interval: u64,
+    max_retries: u64,
+    targets: Vec<HeartbeatTarget>,
+}
+
+impl Default for HeartbeatConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            targets: vec![],
+            interval: DEFAULT_INTERVAL,
+            max_retries: DEFAULT_MAX_RETRIES,
+        }
+    }
+}
+
+impl TryFrom<&HeartbeatToml> for HeartbeatConfig {
+    type Error = SentinelError;
+
+    fn try_from(toml: &HeartbeatToml) -> Result<Self, Self::Error> {
+        Ok(Self {
+            enabled: toml.enabled,
+            interval: toml.interval,
+            max_retries: toml.max_retries,
+            targets: toml
+                .targets
+                .iter()
+                .map(HeartbeatTarget::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_redact_http_target_url_when_debug_printing() {
+        let target = HeartbeatTarget::Http {
+            url: "https://monitoring.example.com/some-api-key".into(),
+        };
+        assert!(!format!("{target:?}").contains("some-api-key"));
+    }
+}
@@ -7,6 +7,16 @@ use serde::{Deserialize, Serialize};
 use super::SentinelConfigError;
 use crate::SentinelError;
 
+// NOTE: The json format includes any `LogContext` fields, such as network id & correlation id, so that
+// logs may be queried by them.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    #[default]
+    Text,
+    Json,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct LogToml {
     pub path: String,
@@ -15,6 +25,8 @@ pub struct LogToml {
     pub max_log_size: u64,
     pub max_num_logs: usize,
     pub use_file_logging: bool,
+    #[serde(default)]
+    pub format: LogFormat,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Getters)]
@@ -26,6 +38,7 @@ pub struct LogConfig {
     pub max_log_size: u64,
     pub max_num_logs: usize,
     pub use_file_logging: bool,
+    format: LogFormat,
 }
 
 impl LogConfig {
@@ -34,6 +47,7 @@ impl LogConfig {
             enabled: toml.enabled,
             path: toml.path.clone(),
             level: toml.level.clone(),
+            format: toml.format,
             use_file_logging: toml.use_file_logging,
             max_num_logs: Self::sanity_check_max_num_logs(toml.max_num_logs)?,
             max_log_size: Self::sanity_check_max_log_size(toml.max_log_size)?,
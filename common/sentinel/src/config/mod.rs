@@ -1,18 +1,40 @@
+mod challenge_responder;
 mod config;
+mod config_diff;
 mod core;
 mod error;
 mod governance;
+mod health;
+mod heartbeat;
 mod log;
 mod mongo;
 mod network;
+mod registration;
+mod rpc_auth;
+mod tx_manager;
 
 pub use self::{
+    challenge_responder::ChallengeResponderConfig,
     config::SentinelConfig,
+    config_diff::SentinelConfigDiff,
     core::SentinelCoreConfig,
     error::SentinelConfigError,
     governance::GovernanceConfig,
-    log::LogConfig,
+    health::HealthConfig,
+    heartbeat::{HeartbeatConfig, HeartbeatTarget},
+    log::{LogConfig, LogFormat},
     mongo::MongoConfig,
     network::{ConfiguredEvent, ConfiguredEvents, NetworkConfig},
+    registration::RegistrationConfig,
+    rpc_auth::{RpcAuthConfig, RpcClientConfig, RpcRole, RpcTlsConfig},
+    tx_manager::TxManagerConfig,
+};
+use self::{
+    challenge_responder::ChallengeResponderToml,
+    governance::GovernanceToml,
+    heartbeat::HeartbeatToml,
+    log::LogToml,
+    network::NetworkToml,
+    registration::RegistrationToml,
+    tx_manager::TxManagerToml,
 };
-use self::{governance::GovernanceToml, log::LogToml, network::NetworkToml};
@@ -1,7 +1,11 @@
+use std::fmt;
+
 use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Getters)]
+use crate::redact_url;
+
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Getters)]
 pub struct MongoConfig {
     pub enabled: bool,
     pub uri_str: String,
@@ -9,3 +13,16 @@ pub struct MongoConfig {
     pub collection: String,
     pub index: String,
 }
+
+// NOTE: The uri may contain credentials, and so is redacted lest they end up in the logs.
+impl fmt::Debug for MongoConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MongoConfig")
+            .field("enabled", &self.enabled)
+            .field("uri_str", &redact_url(&self.uri_str))
+            .field("database", &self.database)
+            .field("collection", &self.collection)
+            .field("index", &self.index)
+            .finish()
+    }
+}
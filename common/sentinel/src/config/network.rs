@@ -1,4 +1,6 @@
-use common_eth::{convert_hex_to_eth_address, convert_hex_to_h256};
+use common_chain_ids::{EthChainId, EvmChainConfig};
+use common_eth::{convert_hex_to_eth_address, convert_hex_to_h256, EthForkSchedule, EthLog};
+use common_metadata::MetadataChainId;
 use common_network_ids::NetworkId;
 use derive_getters::Getters;
 use derive_more::{Constructor, Deref};
@@ -14,6 +16,12 @@ pub struct ConfiguredEvent {
     pub topic: EthHash,
 }
 
+impl ConfiguredEvent {
+    pub fn matches(&self, log: &EthLog) -> bool {
+        log.is_from_address_and_contains_topic(&self.address, &self.topic)
+    }
+}
+
 #[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize, Constructor, Deref)]
 pub struct ConfiguredEvents(Vec<ConfiguredEvent>);
 
@@ -51,6 +59,15 @@ pub struct NetworkToml {
     gas_price: Option<u64>,
     events: Vec<Vec<String>>,
     pre_filter_receipts: bool,
+    #[serde(default)]
+    eip_1559: bool,
+    evm_chain: Option<EvmChainConfig>,
+}
+
+impl NetworkToml {
+    pub(super) fn evm_chain(&self) -> Option<&EvmChainConfig> {
+        self.evm_chain.as_ref()
+    }
 }
 
 #[derive(Debug, Clone, Default, Getters, Eq, PartialEq, Serialize, Deserialize)]
@@ -66,6 +83,8 @@ pub struct NetworkConfig {
     pnetwork_hub: EthAddress,
     events: ConfiguredEvents,
     pre_filter_receipts: bool,
+    eip_1559: bool,
+    evm_chain: Option<EvmChainConfig>,
 }
 
 impl NetworkConfig {
@@ -76,6 +95,9 @@ impl NetworkConfig {
     pub fn from_toml(network_id: NetworkId, toml: &NetworkToml) -> Result<Self, SentinelError> {
         let sleep_duration = toml.sleep_duration;
         let endpoints = Endpoints::new(sleep_duration, network_id, toml.endpoints.clone());
+        if let Some(ref c) = toml.evm_chain {
+            Self::sanity_check_evm_chain(network_id, c)?;
+        };
         Ok(Self {
             endpoints,
             sleep_duration,
@@ -84,6 +106,8 @@ impl NetworkConfig {
             gas_limit: toml.gas_limit,
             events: ConfiguredEvents::try_from(&toml.events)?,
             pre_filter_receipts: toml.pre_filter_receipts,
+            eip_1559: toml.eip_1559,
+            evm_chain: toml.evm_chain.clone(),
             batch_size: Self::sanity_check_batch_size(toml.batch_size)?,
             pnetwork_hub: convert_hex_to_eth_address(&toml.pnetwork_hub)?,
             batch_duration: Self::sanity_check_batch_duration(toml.batch_duration)?,
@@ -98,6 +122,20 @@ impl NetworkConfig {
         self.sleep_duration
     }
 
+    // NOTE: The chain must already be registered, since the network id is parsed from its name.
+    fn sanity_check_evm_chain(network_id: NetworkId, config: &EvmChainConfig) -> Result<(), SentinelError> {
+        info!("sanity checking {network_id} evm chain config...");
+        if EthChainId::try_from(network_id)? != EthChainId::Unknown(config.chain_id) {
+            return Err(SentinelError::SentinelConfig(SentinelConfigError::EvmChainIdMismatch {
+                network_id,
+                chain_id: config.chain_id,
+            }));
+        };
+        EthForkSchedule::try_from(config)?;
+        MetadataChainId::from_registered_evm_chain(config.chain_id)?;
+        Ok(())
+    }
+
     fn sanity_check_batch_size(batch_size: u64) -> Result<u64, SentinelError> {
         info!("sanity checking batch size...");
         const MIN: u64 = 0;
@@ -127,3 +165,64 @@ impl NetworkConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_chain_ids::EvmChainRegistry;
+
+    use super::*;
+
+    fn get_network_toml(chain_id: u64) -> NetworkToml {
+        let s = format!(
+            r#"
+            validate = true
+            batch_size = 10
+            gas_limit = 100000
+            batch_duration = 60
+            sleep_duration = 1
+            pnetwork_hub = "0x0000000000000000000000000000000000000000"
+            endpoints = []
+            events = []
+            pre_filter_receipts = false
+
+            [evm_chain]
+            name = "base"
+            chain_id = {chain_id}
+            forks = [
+              {{ fork = "london", block = 0 }},
+              {{ fork = "shanghai", timestamp = 1704992401 }},
+            ]
+            "#
+        );
+        config::Config::builder()
+            .add_source(config::File::from_str(&s, config::FileFormat::Toml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap()
+    }
+
+    #[test]
+    fn should_get_network_config_for_registered_evm_chain() {
+        let _registry = EvmChainRegistry::lock_for_test();
+        let toml = get_network_toml(8453);
+        EvmChainRegistry::register(toml.evm_chain().unwrap().clone()).unwrap();
+        let network_id = NetworkId::try_from("base").unwrap();
+        let result = NetworkConfig::from_toml(network_id, &toml).unwrap();
+        assert_eq!(result.evm_chain().as_ref().unwrap().gas_multiplier, 1);
+        assert!(!result.eip_1559());
+    }
+
+    #[test]
+    fn should_fail_sanity_check_if_evm_chain_id_is_not_the_networks() {
+        let toml = get_network_toml(8453);
+        let network_id = NetworkId::try_from("polygon").unwrap();
+        let result = NetworkConfig::from_toml(network_id, &toml);
+        assert!(matches!(
+            result,
+            Err(SentinelError::SentinelConfig(
+                SentinelConfigError::EvmChainIdMismatch { .. }
+            ))
+        ));
+    }
+}
@@ -0,0 +1,74 @@
+use std::{result::Result, str::FromStr};
+
+use common_network_ids::NetworkId;
+use derive_getters::Getters;
+use ethereum_types::Address as EthAddress;
+use serde::{Deserialize, Serialize};
+
+use crate::SentinelError;
+
+const DEFAULT_CHECK_INTERVAL: u64 = 60 * 60;
+const DEFAULT_WARN_BEFORE: u64 = 7 * 24 * 60 * 60;
+const DEFAULT_RENEW_BEFORE: u64 = 3 * 24 * 60 * 60;
+const DEFAULT_EXTENSION_DURATION: u64 = 30 * 24 * 60 * 60;
+
+fn default_check_interval() -> u64 {
+    DEFAULT_CHECK_INTERVAL
+}
+
+fn default_warn_before() -> u64 {
+    DEFAULT_WARN_BEFORE
+}
+
+fn default_renew_before() -> u64 {
+    DEFAULT_RENEW_BEFORE
+}
+
+fn default_extension_duration() -> u64 {
+    DEFAULT_EXTENSION_DURATION
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RegistrationToml {
+    network_id: String,
+    registration_manager: String,
+    #[serde(default)]
+    auto_renew: bool,
+    #[serde(default = "default_check_interval")]
+    check_interval: u64,
+    #[serde(default = "default_warn_before")]
+    warn_before: u64,
+    #[serde(default = "default_renew_before")]
+    renew_before: u64,
+    #[serde(default = "default_extension_duration")]
+    extension_duration: u64,
+}
+
+// NOTE: Where the sentinel's registration lives, and how it's to be kept from expiring. Renewals are
+// sent from the owner, via the tx manager.
+#[derive(Debug, Clone, Getters, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RegistrationConfig {
+    network_id: NetworkId,
+    registration_manager: EthAddress,
+    auto_renew: bool,
+    check_interval: u64,
+    warn_before: u64,
+    renew_before: u64,
+    extension_duration: u64,
+}
+
+impl TryFrom<&RegistrationToml> for RegistrationConfig {
+    type Error = SentinelError;
+
+    fn try_from(toml: &RegistrationToml) -> Result<Self, Self::Error> {
+        Ok(Self {
+            auto_renew: toml.auto_renew,
+            warn_before: toml.warn_before,
+            renew_before: toml.renew_before,
+            check_interval: toml.check_interval,
+            extension_duration: toml.extension_duration,
+            network_id: NetworkId::try_from(&toml.network_id)?,
+            registration_manager: EthAddress::from_str(&toml.registration_manager)?,
+        })
+    }
+}
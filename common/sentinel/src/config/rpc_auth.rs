@@ -0,0 +1,207 @@
+use std::{collections::HashSet, fmt};
+
+use derive_getters::Getters;
+use ethereum_types::Address as EthAddress;
+use serde::{Deserialize, Serialize};
+
+use super::SentinelConfigError;
+
+// NOTE: Roles are ordered such that each one may call every method the roles below it may call.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RpcRole {
+    #[default]
+    ReadOnly,
+    Operator,
+    Admin,
+}
+
+impl fmt::Display for RpcRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::ReadOnly => "read_only",
+            Self::Operator => "operator",
+            Self::Admin => "admin",
+        };
+        write!(f, "{s}")
+    }
+}
+
+const DEFAULT_SIGNATURE_MAX_AGE: u64 = 30;
+
+fn default_signature_max_age() -> u64 {
+    DEFAULT_SIGNATURE_MAX_AGE
+}
+
+// NOTE: A client authenticates either via a bearer token, by signing over a timestamp & the request
+// body with the private key for the configured address, as per EIP-191, or via a TLS client cert
+// whose sha256 fingerprint is that configured.
+#[derive(Clone, Getters, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RpcClientConfig {
+    name: String,
+    role: RpcRole,
+    bearer_token: Option<String>,
+    eth_address: Option<EthAddress>,
+    #[serde(default)]
+    cert_fingerprint: Option<String>,
+    rate_limit: Option<u64>,
+}
+
+// NOTE: Fingerprints are often written as colon separated hex, and so those separators, any `0x`
+// prefix, and case are all ignored when comparing them.
+fn normalize_cert_fingerprint(s: &str) -> String {
+    s.trim_start_matches("0x").replace(':', "").to_lowercase()
+}
+
+impl RpcClientConfig {
+    pub fn has_cert_fingerprint(&self, fingerprint: &str) -> bool {
+        self.cert_fingerprint
+            .as_ref()
+            .map(|f| normalize_cert_fingerprint(f) == normalize_cert_fingerprint(fingerprint))
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Debug for RpcClientConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RpcClientConfig")
+            .field("name", &self.name)
+            .field("role", &self.role)
+            .field("bearer_token", &self.bearer_token.as_ref().map(|_| "<redacted>"))
+            .field("eth_address", &self.eth_address)
+            .field("cert_fingerprint", &self.cert_fingerprint)
+            .field("rate_limit", &self.rate_limit)
+            .finish()
+    }
+}
+
+// NOTE: If a client CA is configured, every connection must present a client certificate signed by
+// it, and any request not otherwise authenticated is attributed to the client configured with that
+// certificate's fingerprint.
+#[derive(Debug, Clone, Getters, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RpcTlsConfig {
+    cert_path: String,
+    key_path: String,
+    client_ca_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Getters, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RpcAuthConfig {
+    #[serde(default)]
+    enabled: bool,
+    #[serde(default)]
+    rate_limit: u64, // NOTE: Max requests per minute per client, unless overridden. Zero means unlimited.
+    #[serde(default = "default_signature_max_age")]
+    signature_max_age: u64, // NOTE: Max seconds between a signed request's timestamp and its receipt.
+    #[serde(default)]
+    clients: Vec<RpcClientConfig>,
+    tls: Option<RpcTlsConfig>,
+}
+
+impl Default for RpcAuthConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            rate_limit: 0,
+            signature_max_age: DEFAULT_SIGNATURE_MAX_AGE,
+            clients: vec![],
+            tls: None,
+        }
+    }
+}
+
+impl RpcAuthConfig {
+    pub fn validate(&self) -> Result<(), SentinelConfigError> {
+        let mut names = HashSet::new();
+        for client in self.clients.iter() {
+            if !names.insert(client.name()) {
+                return Err(SentinelConfigError::DuplicateRpcClient(client.name().clone()));
+            };
+            if client.bearer_token.is_none() && client.eth_address.is_none() && client.cert_fingerprint.is_none() {
+                return Err(SentinelConfigError::NoRpcClientCredentials(client.name().clone()));
+            };
+        }
+        Ok(())
+    }
+
+    pub fn rate_limit_for(&self, client: &RpcClientConfig) -> u64 {
+        client.rate_limit.unwrap_or(self.rate_limit)
+    }
+
+    pub(super) fn with_tls(&self, tls: Option<RpcTlsConfig>) -> Self {
+        Self { tls, ..self.clone() }
+    }
+
+    pub fn uses_client_certs(&self) -> bool {
+        self.tls
+            .as_ref()
+            .map(|t| t.client_ca_path.is_some())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_sample_client(name: &str, bearer_token: Option<&str>) -> RpcClientConfig {
+        RpcClientConfig {
+            name: name.to_string(),
+            role: RpcRole::ReadOnly,
+            bearer_token: bearer_token.map(|s| s.to_string()),
+            eth_address: None,
+            cert_fingerprint: None,
+            rate_limit: None,
+        }
+    }
+
+    #[test]
+    fn roles_should_be_ordered_by_privilege() {
+        assert!(RpcRole::Admin > RpcRole::Operator);
+        assert!(RpcRole::Operator > RpcRole::ReadOnly);
+    }
+
+    #[test]
+    fn should_fail_to_validate_client_without_credentials() {
+        let config = RpcAuthConfig {
+            clients: vec![get_sample_client("monitoring", None)],
+            ..Default::default()
+        };
+        match config.validate() {
+            Err(SentinelConfigError::NoRpcClientCredentials(name)) => assert_eq!(name, "monitoring"),
+            Ok(_) => panic!("should not have succeeded"),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_validate_duplicate_clients() {
+        let config = RpcAuthConfig {
+            clients: vec![
+                get_sample_client("monitoring", Some("a")),
+                get_sample_client("monitoring", Some("b")),
+            ],
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(SentinelConfigError::DuplicateRpcClient(_))
+        ));
+    }
+
+    #[test]
+    fn should_match_cert_fingerprints_regardless_of_format() {
+        let client = RpcClientConfig {
+            cert_fingerprint: Some("AB:CD:EF".to_string()),
+            ..get_sample_client("monitoring", None)
+        };
+        assert!(client.has_cert_fingerprint("0xabcdef"));
+        assert!(!client.has_cert_fingerprint("abcdee"));
+    }
+
+    #[test]
+    fn should_not_debug_print_bearer_tokens() {
+        let client = get_sample_client("monitoring", Some("super-secret"));
+        assert!(!format!("{client:?}").contains("super-secret"));
+    }
+}
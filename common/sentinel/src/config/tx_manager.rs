@@ -0,0 +1,112 @@
+use std::result::Result;
+
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+
+use super::SentinelConfigError;
+
+const DEFAULT_POLL_INTERVAL: u64 = 15;
+const DEFAULT_STUCK_AFTER: u64 = 120;
+const DEFAULT_MAX_FEE_BUMPS: u64 = 10;
+const DEFAULT_FEE_BUMP_PERCENT: u64 = 15;
+
+// NOTE: Nodes will not replace a tx in their mempool with one whose fees are less than 10% higher.
+const MIN_FEE_BUMP_PERCENT: u64 = 10;
+
+fn default_poll_interval() -> u64 {
+    DEFAULT_POLL_INTERVAL
+}
+
+fn default_stuck_after() -> u64 {
+    DEFAULT_STUCK_AFTER
+}
+
+fn default_max_fee_bumps() -> u64 {
+    DEFAULT_MAX_FEE_BUMPS
+}
+
+fn default_fee_bump_percent() -> u64 {
+    DEFAULT_FEE_BUMP_PERCENT
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TxManagerToml {
+    #[serde(default = "default_poll_interval")]
+    poll_interval: u64,
+    #[serde(default = "default_stuck_after")]
+    stuck_after: u64,
+    #[serde(default = "default_max_fee_bumps")]
+    max_fee_bumps: u64,
+    #[serde(default = "default_fee_bump_percent")]
+    fee_bump_percent: u64,
+}
+
+impl Default for TxManagerToml {
+    fn default() -> Self {
+        Self {
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            stuck_after: DEFAULT_STUCK_AFTER,
+            max_fee_bumps: DEFAULT_MAX_FEE_BUMPS,
+            fee_bump_percent: DEFAULT_FEE_BUMP_PERCENT,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Getters, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TxManagerConfig {
+    poll_interval: u64,
+    stuck_after: u64,
+    max_fee_bumps: u64,
+    fee_bump_percent: u64,
+}
+
+impl Default for TxManagerConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            stuck_after: DEFAULT_STUCK_AFTER,
+            max_fee_bumps: DEFAULT_MAX_FEE_BUMPS,
+            fee_bump_percent: DEFAULT_FEE_BUMP_PERCENT,
+        }
+    }
+}
+
+impl TryFrom<&TxManagerToml> for TxManagerConfig {
+    type Error = SentinelConfigError;
+
+    fn try_from(toml: &TxManagerToml) -> Result<Self, Self::Error> {
+        if toml.fee_bump_percent < MIN_FEE_BUMP_PERCENT {
+            return Err(SentinelConfigError::FeeBumpPercent {
+                percent: toml.fee_bump_percent,
+                min: MIN_FEE_BUMP_PERCENT,
+            });
+        };
+
+        Ok(Self {
+            poll_interval: toml.poll_interval,
+            stuck_after: toml.stuck_after,
+            max_fee_bumps: toml.max_fee_bumps,
+            fee_bump_percent: toml.fee_bump_percent,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_default_tx_manager_config_from_default_toml() {
+        let result = TxManagerConfig::try_from(&TxManagerToml::default()).unwrap();
+        assert_eq!(result, TxManagerConfig::default());
+    }
+
+    #[test]
+    fn should_not_allow_fee_bump_percent_below_min() {
+        let toml = TxManagerToml {
+            fee_bump_percent: MIN_FEE_BUMP_PERCENT - 1,
+            ..Default::default()
+        };
+        assert!(TxManagerConfig::try_from(&toml).is_err());
+    }
+}
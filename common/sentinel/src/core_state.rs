@@ -1,17 +1,25 @@
 use std::result::Result;
 
 use common::DatabaseInterface;
+use common_debug_signers::DebugSignaturePolicies;
 use common_eth::{ChainDbUtils, ChainError, ChainState};
 use common_metadata::MetadataChainId;
 use common_network_ids::{NetworkId, NetworkIdError};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
-use crate::SentinelError;
+use crate::{AuditLog, Challenges, DebugCommandQueue, GovernanceState, SentinelError};
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct CoreState {
     chain_state: Vec<ChainState>,
+    debug_signature_policies: DebugSignaturePolicies,
+    debug_command_queue: DebugCommandQueue,
+    audit_log: AuditLog,
+    #[serde(default)]
+    governance_state: GovernanceState,
+    #[serde(default)]
+    challenges: Challenges,
 }
 
 impl CoreState {
@@ -27,7 +35,20 @@ impl CoreState {
             .collect::<Result<Vec<ChainState>, ChainError>>()
             .map_err(SentinelError::ChainError)?;
 
-        Ok(Self { chain_state })
+        let debug_signature_policies = DebugSignaturePolicies::get_from_db(db)?;
+        let debug_command_queue = DebugCommandQueue::get(db);
+        let audit_log = AuditLog::get(db);
+        let governance_state = GovernanceState::get(db);
+        let challenges = Challenges::get(db);
+
+        Ok(Self {
+            audit_log,
+            challenges,
+            chain_state,
+            governance_state,
+            debug_command_queue,
+            debug_signature_policies,
+        })
     }
 }
 
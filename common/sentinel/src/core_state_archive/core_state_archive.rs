@@ -0,0 +1,280 @@
+use common::{Bytes, DatabaseInterface, MIN_DATA_SENSITIVITY_LEVEL};
+use common_debug_signers::{DEBUG_SIGNATORIES_DB_KEY, DEBUG_SIGNATURE_POLICIES_DB_KEY};
+use common_eth::{Chain, ChainDbUtils, ChainError};
+use common_metadata::MetadataChainId;
+use common_network_ids::NetworkId;
+use derive_getters::Getters;
+use derive_more::Constructor;
+use serde::{Deserialize, Serialize};
+
+use super::{CoreStateArchiveError, CoreStateArchiveVersion};
+use crate::{
+    db_utils::{DbKey, SentinelDbKeys},
+    get_utc_timestamp,
+    SentinelError,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters, Constructor)]
+pub struct CoreStateArchiveEntry {
+    #[serde(with = "hex")]
+    key: Bytes,
+    #[serde(with = "hex")]
+    value: Bytes,
+}
+
+// NOTE: An archive of everything a core needs in order to carry on from where another left off:
+// the chain structs & the sub mats they reference, the debug signatories & their signature
+// policies, and those sentinel db utils entries listed below. The core's private key is
+// deliberately _not_ included, since that never leaves the TEE.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct CoreStateArchive {
+    version: CoreStateArchiveVersion,
+    timestamp: u64,
+    network_ids: Vec<NetworkId>,
+    entries: Vec<CoreStateArchiveEntry>,
+}
+
+impl CoreStateArchive {
+    // NOTE: Only those sentinel db entries which the importing core cannot rebuild for itself are
+    // archived. Everything else belongs to the core which made it, and so must not overwrite that
+    // of the importing core: its audit log & debug command queue, the txs its app has pending, and
+    // the governance state & challenges concerning its signing address, which differs from that of
+    // the importing core.
+    fn get_sentinel_db_keys() -> Vec<DbKey> {
+        vec![
+            SentinelDbKeys::get_user_op_list_db_key(),
+            SentinelDbKeys::get_actor_inclusion_proof_db_key(),
+        ]
+    }
+
+    fn maybe_get_entry<D: DatabaseInterface>(db: &D, key: Bytes) -> Option<CoreStateArchiveEntry> {
+        match db.get(key.clone(), MIN_DATA_SENSITIVITY_LEVEL) {
+            Ok(value) => Some(CoreStateArchiveEntry::new(key, value)),
+            Err(_) => {
+                // NOTE: Not every key will necessarily exist in any given core's db.
+                debug!("no value in db for key 0x{}, skipping it", hex::encode(&key));
+                None
+            },
+        }
+    }
+
+    pub fn from_db<D: DatabaseInterface>(db: &D, network_ids: Vec<NetworkId>) -> Result<Self, SentinelError> {
+        info!("creating core state archive for network ids: {network_ids:?}...");
+        let chain_db_utils = ChainDbUtils::new(db);
+
+        let mut keys: Vec<Bytes> = vec![];
+        for network_id in network_ids.iter() {
+            let mcid = MetadataChainId::try_from(network_id)?;
+            let mut chain_keys = Chain::get(&chain_db_utils, mcid)?.get_db_keys()?;
+            debug!("archiving {} db entries for {network_id} chain", chain_keys.len());
+            keys.append(&mut chain_keys);
+        }
+        keys.push(DEBUG_SIGNATORIES_DB_KEY.to_vec());
+        keys.push(DEBUG_SIGNATURE_POLICIES_DB_KEY.to_vec());
+        Self::get_sentinel_db_keys().iter().for_each(|k| keys.push(k.into()));
+
+        let entries = keys
+            .into_iter()
+            .filter_map(|k| Self::maybe_get_entry(db, k))
+            .collect::<Vec<CoreStateArchiveEntry>>();
+
+        Ok(Self {
+            entries,
+            network_ids,
+            timestamp: get_utc_timestamp()?,
+            version: CoreStateArchiveVersion::current(),
+        })
+    }
+
+    fn get_entry(&self, key: &[u8]) -> Option<&CoreStateArchiveEntry> {
+        self.entries.iter().find(|e| e.key == key)
+    }
+
+    // NOTE: The keys this archive may write to, which are the same ones `from_db` archives: the
+    // chains of its network ids & the sub mats those chains reference, the debug signatories & their
+    // policies, and the sentinel db keys listed above. The sub mat keys come from the archived chain
+    // itself, which must therefore be for the network it's archived under.
+    fn get_writable_keys(&self) -> Result<Vec<Bytes>, SentinelError> {
+        let mut keys: Vec<Bytes> = vec![];
+        for network_id in self.network_ids.iter() {
+            let mcid = MetadataChainId::try_from(network_id)?;
+            let chain_key = Chain::get_db_key(&mcid)?;
+            match self.get_entry(&chain_key) {
+                Some(entry) => {
+                    let chain: Chain = serde_json::from_slice(&entry.value)?;
+                    if chain.chain_id() != &mcid {
+                        return Err(CoreStateArchiveError::WrongChain {
+                            expected: mcid.to_string(),
+                            found: chain.chain_id().to_string(),
+                        }
+                        .into());
+                    };
+                    keys.append(&mut chain.get_db_keys()?);
+                },
+                None => keys.push(chain_key),
+            }
+        }
+        keys.push(DEBUG_SIGNATORIES_DB_KEY.to_vec());
+        keys.push(DEBUG_SIGNATURE_POLICIES_DB_KEY.to_vec());
+        Self::get_sentinel_db_keys().iter().for_each(|k| keys.push(k.into()));
+        Ok(keys)
+    }
+
+    pub fn write_to_db<D: DatabaseInterface>(&self, db: &D) -> Result<(), SentinelError> {
+        info!("writing core state archive to db...");
+        let chain_db_utils = ChainDbUtils::new(db);
+
+        // NOTE: We check everything we can _before_ writing anything, so that a failure can't leave
+        // the db in a half-imported state.
+        for network_id in self.network_ids.iter() {
+            let mcid = MetadataChainId::try_from(network_id)?;
+            if Chain::is_initialized(&chain_db_utils, mcid) {
+                return Err(ChainError::AlreadyInitialized(mcid).into());
+            }
+        }
+
+        // NOTE: Lest an archive be used to overwrite any other of the core's state, such as its
+        // audit log or debug command queue, the whole archive is rejected if any of its entries is
+        // for a key which `from_db` would not have archived.
+        let writable_keys = self.get_writable_keys()?;
+        if let Some(entry) = self.entries.iter().find(|e| !writable_keys.contains(&e.key)) {
+            return Err(CoreStateArchiveError::UnexpectedKey(hex::encode(&entry.key)).into());
+        };
+
+        for entry in self.entries.iter() {
+            db.put(entry.key.clone(), entry.value.clone(), MIN_DATA_SENSITIVITY_LEVEL)?;
+        }
+
+        info!("wrote {} core state archive entries to db", self.entries.len());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::get_test_database;
+    use common_debug_signers::{DebugSignatories, DebugSignatory};
+    use common_eth::EthPrivateKey;
+    use ethereum_types::Address as EthAddress;
+
+    use super::*;
+    use crate::test_utils::get_sample_sub_mat_n;
+
+    #[test]
+    fn should_archive_core_state_and_write_it_to_a_fresh_db() {
+        let network_id = NetworkId::try_from("ethereum").unwrap();
+        let mcid = MetadataChainId::try_from(&network_id).unwrap();
+        let db_1 = get_test_database();
+        let chain_db_utils_1 = ChainDbUtils::new(&db_1);
+        let validate = false;
+        Chain::init(
+            &chain_db_utils_1,
+            EthAddress::zero(),
+            10,
+            1,
+            get_sample_sub_mat_n(1),
+            mcid,
+            validate,
+        )
+        .unwrap();
+        let debug_signatories = DebugSignatories::new(vec![DebugSignatory::new("signer", &EthAddress::random())]);
+        debug_signatories.put_in_db(&db_1).unwrap();
+
+        let archive = CoreStateArchive::from_db(&db_1, vec![network_id]).unwrap();
+        // NOTE: The chain, its single sub mat & the debug signatories.
+        assert_eq!(archive.entries().len(), 3);
+
+        let db_2 = get_test_database();
+        let chain_db_utils_2 = ChainDbUtils::new(&db_2);
+        chain_db_utils_2
+            .put_pk(&EthPrivateKey::generate_random().unwrap())
+            .unwrap();
+        archive.write_to_db(&db_2).unwrap();
+
+        let chain_1 = Chain::get(&chain_db_utils_1, mcid).unwrap();
+        let chain_2 = Chain::get(&chain_db_utils_2, mcid).unwrap();
+        assert_eq!(chain_1.latest_block_num(), chain_2.latest_block_num());
+        assert_eq!(DebugSignatories::get_from_db(&db_2).unwrap(), debug_signatories);
+        assert!(chain_2.get_canonical_sub_mat(&chain_db_utils_2).unwrap().is_some());
+    }
+
+    #[test]
+    fn should_not_archive_state_belonging_to_the_archiving_core() {
+        let network_id = NetworkId::try_from("ethereum").unwrap();
+        let mcid = MetadataChainId::try_from(&network_id).unwrap();
+        let db = get_test_database();
+        let chain_db_utils = ChainDbUtils::new(&db);
+        let validate = false;
+        Chain::init(
+            &chain_db_utils,
+            EthAddress::zero(),
+            10,
+            1,
+            get_sample_sub_mat_n(1),
+            mcid,
+            validate,
+        )
+        .unwrap();
+        let excluded_keys = vec![
+            SentinelDbKeys::get_pending_txs_db_key(),
+            SentinelDbKeys::get_audit_log_db_key(),
+            SentinelDbKeys::get_governance_state_db_key(),
+            SentinelDbKeys::get_challenges_db_key(),
+            SentinelDbKeys::get_debug_command_queue_db_key(),
+        ];
+        excluded_keys.iter().for_each(|k| {
+            db.put(k.into(), vec![0xc0, 0xff, 0xee], MIN_DATA_SENSITIVITY_LEVEL)
+                .unwrap()
+        });
+        let archive = CoreStateArchive::from_db(&db, vec![network_id]).unwrap();
+        // NOTE: The chain & its single sub mat only.
+        assert_eq!(archive.entries().len(), 2);
+        let excluded_keys: Vec<Bytes> = excluded_keys.iter().map(|k| k.into()).collect();
+        assert!(archive.entries().iter().all(|e| !excluded_keys.contains(e.key())));
+    }
+
+    #[test]
+    fn should_not_write_archive_with_entries_for_other_keys() {
+        let db = get_test_database();
+        let key: Bytes = SentinelDbKeys::get_debug_command_queue_db_key().into();
+        let archive = CoreStateArchive {
+            network_ids: vec![],
+            entries: vec![CoreStateArchiveEntry::new(key.clone(), vec![0xc0, 0xff, 0xee])],
+            ..Default::default()
+        };
+        match archive.write_to_db(&db) {
+            Ok(_) => panic!("should not have succeeded"),
+            Err(SentinelError::CoreStateArchive(CoreStateArchiveError::UnexpectedKey(k))) => {
+                assert_eq!(k, hex::encode(&key))
+            },
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+        assert!(db.get(key, MIN_DATA_SENSITIVITY_LEVEL).is_err());
+    }
+
+    #[test]
+    fn should_not_write_archive_over_initialized_chain() {
+        let network_id = NetworkId::try_from("ethereum").unwrap();
+        let mcid = MetadataChainId::try_from(&network_id).unwrap();
+        let db = get_test_database();
+        let chain_db_utils = ChainDbUtils::new(&db);
+        let validate = false;
+        Chain::init(
+            &chain_db_utils,
+            EthAddress::zero(),
+            10,
+            1,
+            get_sample_sub_mat_n(1),
+            mcid,
+            validate,
+        )
+        .unwrap();
+        let archive = CoreStateArchive::from_db(&db, vec![network_id]).unwrap();
+        match archive.write_to_db(&db) {
+            Ok(_) => panic!("should not have succeeded"),
+            Err(SentinelError::ChainError(ChainError::AlreadyInitialized(id))) => assert_eq!(id, mcid),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+}
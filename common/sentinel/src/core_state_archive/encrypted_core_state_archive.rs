@@ -0,0 +1,192 @@
+use std::str::FromStr;
+
+use aes_gcm::{
+    aead::{Aead, KeyInit},
+    Aes256Gcm,
+    Nonce,
+};
+use common::{
+    crypto_utils::{keccak_hash_bytes, sha256_hash_bytes_into_32},
+    Bytes,
+};
+use common_eth::{EthPrivateKey, EthPublicKey, EthSignature, EthSigningCapabilities};
+use derive_getters::Getters;
+use ethereum_types::{Address as EthAddress, H256 as EthHash};
+use serde::{Deserialize, Serialize};
+
+use super::{CoreStateArchive, CoreStateArchiveError, CoreStateArchiveVersion};
+use crate::SentinelError;
+
+const NONCE_LENGTH: usize = 12;
+const KEY_DERIVATION_SALT: &[u8] = b"CoreStateArchive";
+
+// NOTE: The archive is encrypted via ECIES: An ephemeral key is used to derive a shared secret with
+// the recipient core's public key, from which an AES-256-GCM key is derived. The resulting cipher
+// text is then signed by the source core, so that the recipient can be sure of its provenance
+// _before_ decrypting anything.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptedCoreStateArchive {
+    version: CoreStateArchiveVersion,
+    signer: EthAddress,
+    #[serde(with = "hex")]
+    ephemeral_public_key: Bytes,
+    #[serde(with = "hex")]
+    nonce: Bytes,
+    #[serde(with = "hex")]
+    cipher_text: Bytes,
+    signature: String,
+}
+
+impl EncryptedCoreStateArchive {
+    fn derive_key(shared_secret: &EthHash) -> [u8; 32] {
+        sha256_hash_bytes_into_32(&[shared_secret.as_bytes(), KEY_DERIVATION_SALT].concat())
+    }
+
+    fn signing_preimage(
+        version: &CoreStateArchiveVersion,
+        ephemeral_public_key: &[u8],
+        nonce: &[u8],
+        cipher_text: &[u8],
+    ) -> Bytes {
+        [version.as_bytes(), ephemeral_public_key, nonce, cipher_text].concat()
+    }
+
+    fn eth_prefixed_hash(message: &[u8]) -> EthHash {
+        // NOTE: This mirrors the hashing done in `hash_and_sign_msg_with_eth_prefix`.
+        let eth_msg_prefix = b"\x19Ethereum Signed Message:\n";
+        keccak_hash_bytes(&[eth_msg_prefix, format!("{}", message.len()).as_bytes(), message].concat())
+    }
+
+    pub fn new(
+        archive: &CoreStateArchive,
+        signing_key: &EthPrivateKey,
+        recipient: &EthPublicKey,
+    ) -> Result<Self, SentinelError> {
+        info!("encrypting core state archive...");
+        let version = archive.version().clone();
+        let ephemeral_key = EthPrivateKey::generate_random()?;
+        let ephemeral_public_key = ephemeral_key.to_public_key().to_bytes();
+        let key = Self::derive_key(&ephemeral_key.get_shared_secret(recipient));
+        let nonce = rand::random::<[u8; NONCE_LENGTH]>().to_vec();
+
+        let cipher = Aes256Gcm::new_from_slice(&key).map_err(|e| CoreStateArchiveError::Encryption(e.to_string()))?;
+        let cipher_text = cipher
+            .encrypt(Nonce::from_slice(&nonce), serde_json::to_vec(archive)?.as_ref())
+            .map_err(|e| CoreStateArchiveError::Encryption(e.to_string()))?;
+
+        let preimage = Self::signing_preimage(&version, &ephemeral_public_key, &nonce, &cipher_text);
+        let signature = signing_key.hash_and_sign_msg_with_eth_prefix(&preimage)?.to_0x_string();
+
+        Ok(Self {
+            nonce,
+            version,
+            signature,
+            cipher_text,
+            ephemeral_public_key,
+            signer: signing_key.to_address(),
+        })
+    }
+
+    pub fn verify(&self, expected_signer: &EthAddress) -> Result<(), SentinelError> {
+        debug!("verifying core state archive signature...");
+        if !self.version.is_current() {
+            return Err(CoreStateArchiveError::UnsupportedVersion(self.version.clone()).into());
+        };
+
+        let preimage = Self::signing_preimage(
+            &self.version,
+            &self.ephemeral_public_key,
+            &self.nonce,
+            &self.cipher_text,
+        );
+        let signature = EthSignature::from_str(&self.signature)?;
+        let recovered = signature.recover_signer_address(&Self::eth_prefixed_hash(&preimage))?;
+
+        // NOTE: The `signer` field is merely informational, what matters is who actually signed it.
+        if &recovered != expected_signer {
+            Err(CoreStateArchiveError::InvalidSignature {
+                recovered,
+                expected: *expected_signer,
+            }
+            .into())
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn decrypt(&self, recipient_key: &EthPrivateKey) -> Result<CoreStateArchive, SentinelError> {
+        info!("decrypting core state archive...");
+        let ephemeral_public_key = EthPublicKey::from_slice(&self.ephemeral_public_key)?;
+        let key = Self::derive_key(&recipient_key.get_shared_secret(&ephemeral_public_key));
+        let cipher = Aes256Gcm::new_from_slice(&key).map_err(|_| CoreStateArchiveError::Decryption)?;
+        let plain_text = cipher
+            .decrypt(Nonce::from_slice(&self.nonce), self.cipher_text.as_ref())
+            .map_err(|_| CoreStateArchiveError::Decryption)?;
+        Ok(serde_json::from_slice(&plain_text)?)
+    }
+}
+
+impl TryFrom<&str> for EncryptedCoreStateArchive {
+    type Error = SentinelError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Ok(serde_json::from_str(s)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_sample_archive() -> CoreStateArchive {
+        serde_json::from_value(serde_json::json!({
+            "version": "V1",
+            "timestamp": 1337,
+            "networkIds": [],
+            "entries": [{ "key": "c0ffee", "value": "decaf0" }],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn should_encrypt_verify_and_decrypt_core_state_archive() {
+        let archive = get_sample_archive();
+        let source_key = EthPrivateKey::generate_random().unwrap();
+        let recipient_key = EthPrivateKey::generate_random().unwrap();
+        let encrypted = EncryptedCoreStateArchive::new(&archive, &source_key, &recipient_key.to_public_key()).unwrap();
+        encrypted.verify(&source_key.to_address()).unwrap();
+        let result = encrypted.decrypt(&recipient_key).unwrap();
+        assert_eq!(result, archive);
+    }
+
+    #[test]
+    fn should_fail_to_verify_core_state_archive_from_wrong_signer() {
+        let archive = get_sample_archive();
+        let source_key = EthPrivateKey::generate_random().unwrap();
+        let recipient_key = EthPrivateKey::generate_random().unwrap();
+        let encrypted = EncryptedCoreStateArchive::new(&archive, &source_key, &recipient_key.to_public_key()).unwrap();
+        let expected_signer = EthAddress::random();
+        match encrypted.verify(&expected_signer) {
+            Ok(_) => panic!("should not have succeeded"),
+            Err(SentinelError::CoreStateArchive(CoreStateArchiveError::InvalidSignature { expected, .. })) => {
+                assert_eq!(expected, expected_signer)
+            },
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_decrypt_core_state_archive_with_wrong_key() {
+        let archive = get_sample_archive();
+        let source_key = EthPrivateKey::generate_random().unwrap();
+        let recipient_key = EthPrivateKey::generate_random().unwrap();
+        let encrypted = EncryptedCoreStateArchive::new(&archive, &source_key, &recipient_key.to_public_key()).unwrap();
+        let wrong_key = EthPrivateKey::generate_random().unwrap();
+        match encrypted.decrypt(&wrong_key) {
+            Ok(_) => panic!("should not have succeeded"),
+            Err(SentinelError::CoreStateArchive(CoreStateArchiveError::Decryption)) => (),
+            Err(e) => panic!("wrong error received: {e}"),
+        }
+    }
+}
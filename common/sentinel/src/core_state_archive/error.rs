@@ -0,0 +1,31 @@
+use ethereum_types::Address as EthAddress;
+use thiserror::Error;
+
+use super::CoreStateArchiveVersion;
+
+#[derive(Debug, Error)]
+pub enum CoreStateArchiveError {
+    #[error("invalid core state archive signature - expected signer {expected}, recovered {recovered}")]
+    InvalidSignature {
+        expected: EthAddress,
+        recovered: EthAddress,
+    },
+
+    #[error("no trusted core state archive signer set - set one before importing an archive")]
+    NoTrustedSigner,
+
+    #[error("core state archive may not write to db key 0x{0}")]
+    UnexpectedKey(String),
+
+    #[error("core state archive chain for {expected} is for the wrong chain: {found}")]
+    WrongChain { expected: String, found: String },
+
+    #[error("unsupported core state archive version: {0:?}")]
+    UnsupportedVersion(CoreStateArchiveVersion),
+
+    #[error("could not encrypt core state archive: {0}")]
+    Encryption(String),
+
+    #[error("could not decrypt core state archive - is this core the intended recipient?")]
+    Decryption,
+}
@@ -0,0 +1,13 @@
+mod core_state_archive;
+mod encrypted_core_state_archive;
+mod error;
+mod trusted_archive_signer;
+mod version;
+
+pub use self::{
+    core_state_archive::{CoreStateArchive, CoreStateArchiveEntry},
+    encrypted_core_state_archive::EncryptedCoreStateArchive,
+    error::CoreStateArchiveError,
+    trusted_archive_signer::TrustedArchiveSigner,
+    version::CoreStateArchiveVersion,
+};
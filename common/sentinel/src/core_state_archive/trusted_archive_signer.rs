@@ -0,0 +1,79 @@
+use common::{Byte, DatabaseInterface, MIN_DATA_SENSITIVITY_LEVEL};
+use derive_getters::Getters;
+use derive_more::Constructor;
+use ethereum_types::Address as EthAddress;
+use serde::{Deserialize, Serialize};
+
+use super::CoreStateArchiveError;
+use crate::{
+    db_utils::{DbKey, DbUtilsT, SentinelDbKeys, SentinelDbUtils},
+    SentinelError,
+};
+
+// NOTE: The address of the core whose state archives this core will import. Since an archive
+// replaces the debug signatories & their policies, its signer is set beforehand via its own debug
+// command, rather than being passed in alongside the archive, where whoever signs the import could
+// simply name a key of their own.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Getters, Constructor)]
+pub struct TrustedArchiveSigner {
+    signer: Option<EthAddress>,
+}
+
+impl DbUtilsT for TrustedArchiveSigner {
+    fn key(&self) -> Result<DbKey, SentinelError> {
+        Ok(SentinelDbKeys::get_trusted_archive_signer_db_key())
+    }
+
+    fn sensitivity() -> Option<Byte> {
+        MIN_DATA_SENSITIVITY_LEVEL
+    }
+
+    fn from_bytes(bytes: &[Byte]) -> Result<Self, SentinelError> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
+
+impl TrustedArchiveSigner {
+    pub fn get<D: DatabaseInterface>(db: &D) -> Self {
+        let key = SentinelDbKeys::get_trusted_archive_signer_db_key();
+        match Self::get_from_db(&SentinelDbUtils::new(db), &key) {
+            Ok(s) => s,
+            Err(_) => {
+                debug!("no trusted archive signer in db, using default");
+                Self::default()
+            },
+        }
+    }
+
+    pub fn save<D: DatabaseInterface>(&self, db: &D) -> Result<(), SentinelError> {
+        self.update_in_db(&SentinelDbUtils::new(db))
+    }
+
+    pub fn get_signer(&self) -> Result<EthAddress, CoreStateArchiveError> {
+        self.signer.ok_or(CoreStateArchiveError::NoTrustedSigner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::get_test_database;
+
+    use super::*;
+
+    #[test]
+    fn should_error_if_no_trusted_archive_signer_is_set() {
+        let db = get_test_database();
+        assert!(matches!(
+            TrustedArchiveSigner::get(&db).get_signer(),
+            Err(CoreStateArchiveError::NoTrustedSigner)
+        ));
+    }
+
+    #[test]
+    fn should_save_and_get_trusted_archive_signer() {
+        let db = get_test_database();
+        let signer = EthAddress::random();
+        TrustedArchiveSigner::new(Some(signer)).save(&db).unwrap();
+        assert_eq!(TrustedArchiveSigner::get(&db).get_signer().unwrap(), signer);
+    }
+}
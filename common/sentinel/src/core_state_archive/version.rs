@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum CoreStateArchiveVersion {
+    V1,
+}
+
+impl Default for CoreStateArchiveVersion {
+    fn default() -> Self {
+        Self::current()
+    }
+}
+
+impl CoreStateArchiveVersion {
+    pub fn current() -> Self {
+        Self::V1
+    }
+
+    pub fn is_current(&self) -> bool {
+        self == &Self::current()
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::V1 => &[1],
+        }
+    }
+}
@@ -183,7 +183,17 @@ macro_rules! create_db_keys {
     }
 }
 
-create_db_keys!(USER_OP_LIST, ACTOR_INCLUSION_PROOF);
+create_db_keys!(
+    USER_OP_LIST,
+    ACTOR_INCLUSION_PROOF,
+    DEBUG_COMMAND_QUEUE,
+    AUDIT_LOG,
+    PENDING_TXS,
+    GOVERNANCE_STATE,
+    CHALLENGES,
+    EVM_CHAINS,
+    TRUSTED_ARCHIVE_SIGNER
+);
 
 pub struct SentinelDbUtils<'a, D: DatabaseInterface>(&'a D);
 
@@ -0,0 +1,237 @@
+use common::{Byte, DatabaseInterface, MIN_DATA_SENSITIVITY_LEVEL};
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as Json};
+
+use super::{DebugCommandQueueError, QueuedDebugCommand};
+use crate::{
+    db_utils::{DbKey, DbUtilsT, SentinelDbKeys, SentinelDbUtils},
+    SentinelError,
+    WebSocketMessagesEncodable,
+};
+
+// NOTE: When the delay is non-zero, time lockable debug commands are not run upon receipt, but are
+// instead validated and then queued. They may be cancelled by any debug signatory before they
+// become active, after which they may be executed via a follow up call. A zero delay means the
+// queue is disabled and such commands run immediately, as they always have.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct DebugCommandQueue {
+    delay: u64,
+    next_id: u64,
+    commands: Vec<QueuedDebugCommand>,
+}
+
+impl DbUtilsT for DebugCommandQueue {
+    fn key(&self) -> Result<DbKey, SentinelError> {
+        Ok(SentinelDbKeys::get_debug_command_queue_db_key())
+    }
+
+    fn sensitivity() -> Option<Byte> {
+        MIN_DATA_SENSITIVITY_LEVEL
+    }
+
+    fn from_bytes(bytes: &[Byte]) -> Result<Self, SentinelError> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
+
+impl DebugCommandQueue {
+    pub fn get<D: DatabaseInterface>(db: &D) -> Self {
+        let key = SentinelDbKeys::get_debug_command_queue_db_key();
+        match Self::get_from_db(&SentinelDbUtils::new(db), &key) {
+            Ok(q) => q,
+            Err(_) => {
+                debug!("no debug command queue in db, using default");
+                Self::default()
+            },
+        }
+    }
+
+    fn save<D: DatabaseInterface>(&self, db: &D) -> Result<(), SentinelError> {
+        self.update_in_db(&SentinelDbUtils::new(db))
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.delay > 0
+    }
+
+    pub fn get_command(&self, id: u64) -> Result<&QueuedDebugCommand, DebugCommandQueueError> {
+        self.commands
+            .iter()
+            .find(|c| *c.id() == id)
+            .ok_or(DebugCommandQueueError::NotFound(id))
+    }
+
+    fn remove(&mut self, id: u64) -> Result<QueuedDebugCommand, DebugCommandQueueError> {
+        match self.commands.iter().position(|c| *c.id() == id) {
+            Some(i) => Ok(self.commands.remove(i)),
+            None => Err(DebugCommandQueueError::NotFound(id)),
+        }
+    }
+
+    pub fn set_delay<D: DatabaseInterface>(mut self, db: &D, delay: u64) -> Result<Self, SentinelError> {
+        info!("setting debug command delay to {delay}s");
+        self.delay = delay;
+        self.save(db)?;
+        Ok(self)
+    }
+
+    pub fn enqueue<D: DatabaseInterface>(
+        mut self,
+        db: &D,
+        command: WebSocketMessagesEncodable,
+        now: u64,
+    ) -> Result<QueuedDebugCommand, SentinelError> {
+        if !command.is_time_lockable() {
+            return Err(DebugCommandQueueError::NotTimeLockable(command.to_string()).into());
+        };
+        let queued = QueuedDebugCommand::new(self.next_id, command, now, self.delay);
+        info!("queueing debug command: {}", queued.to_json());
+        self.next_id += 1;
+        self.commands.push(queued.clone());
+        self.save(db)?;
+        Ok(queued)
+    }
+
+    pub fn cancel<D: DatabaseInterface>(mut self, db: &D, id: u64) -> Result<QueuedDebugCommand, SentinelError> {
+        let cancelled = self.remove(id)?;
+        info!("cancelled queued debug command: {}", cancelled.to_json());
+        self.save(db)?;
+        Ok(cancelled)
+    }
+
+    pub fn dequeue<D: DatabaseInterface>(
+        mut self,
+        db: &D,
+        id: u64,
+        now: u64,
+    ) -> Result<QueuedDebugCommand, SentinelError> {
+        let queued = self.get_command(id)?;
+        if !queued.is_active(now) {
+            return Err(DebugCommandQueueError::NotYetActive {
+                id,
+                now,
+                activates_at: *queued.activates_at(),
+            }
+            .into());
+        };
+        let dequeued = self.remove(id)?;
+        self.save(db)?;
+        Ok(dequeued)
+    }
+
+    pub fn to_json(&self) -> Json {
+        json!({
+            "delay": self.delay,
+            "commands": self.commands.iter().map(QueuedDebugCommand::to_json).collect::<Vec<Json>>(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::get_test_database;
+    use common_debug_signers::DebugSignature;
+    use common_eth::EthPrivateKey;
+    use ethereum_types::Address as EthAddress;
+
+    use super::*;
+    use crate::{CoreStateArchive, EncryptedCoreStateArchive};
+
+    fn get_sample_command() -> WebSocketMessagesEncodable {
+        WebSocketMessagesEncodable::HardReset(DebugSignature::new(None))
+    }
+
+    #[test]
+    fn should_get_default_queue_if_none_in_db() {
+        let db = get_test_database();
+        let q = DebugCommandQueue::get(&db);
+        assert_eq!(q, DebugCommandQueue::default());
+        assert!(!q.is_enabled());
+    }
+
+    #[test]
+    fn should_enqueue_and_dequeue_once_active() {
+        let db = get_test_database();
+        let delay = 100;
+        let now = 1337;
+        DebugCommandQueue::get(&db).set_delay(&db, delay).unwrap();
+        let queued = DebugCommandQueue::get(&db)
+            .enqueue(&db, get_sample_command(), now)
+            .unwrap();
+        assert_eq!(*queued.activates_at(), now + delay);
+        assert_eq!(DebugCommandQueue::get(&db).commands().len(), 1);
+
+        let id = *queued.id();
+        match DebugCommandQueue::get(&db).dequeue(&db, id, now + delay - 1) {
+            Err(SentinelError::DebugCommandQueue(DebugCommandQueueError::NotYetActive { .. })) => (),
+            Ok(_) => panic!("should not have succeeded"),
+            Err(e) => panic!("wrong error received: {e}"),
+        };
+
+        let result = DebugCommandQueue::get(&db).dequeue(&db, id, now + delay).unwrap();
+        assert_eq!(result, queued);
+        assert!(DebugCommandQueue::get(&db).commands().is_empty());
+    }
+
+    #[test]
+    fn should_cancel_queued_command() {
+        let db = get_test_database();
+        let q = DebugCommandQueue::get(&db).set_delay(&db, 100).unwrap();
+        let queued = q.enqueue(&db, get_sample_command(), 1337).unwrap();
+        DebugCommandQueue::get(&db).cancel(&db, *queued.id()).unwrap();
+        let q = DebugCommandQueue::get(&db);
+        assert!(q.commands().is_empty());
+        assert_eq!(*q.next_id(), 1);
+        match q.cancel(&db, *queued.id()) {
+            Err(SentinelError::DebugCommandQueue(DebugCommandQueueError::NotFound(_))) => (),
+            Ok(_) => panic!("should not have succeeded"),
+            Err(e) => panic!("wrong error received: {e}"),
+        };
+    }
+
+    #[test]
+    fn should_queue_core_state_imports_and_debug_signer_management_commands() {
+        let db = get_test_database();
+        let now = 1337;
+        let delay = 100;
+        DebugCommandQueue::get(&db).set_delay(&db, delay).unwrap();
+        let archive = serde_json::from_value::<CoreStateArchive>(json!({
+            "version": "V1",
+            "timestamp": now,
+            "networkIds": [],
+            "entries": [],
+        }))
+        .unwrap();
+        let source_key = EthPrivateKey::generate_random().unwrap();
+        let recipient_key = EthPrivateKey::generate_random().unwrap();
+        let encrypted = EncryptedCoreStateArchive::new(&archive, &source_key, &recipient_key.to_public_key()).unwrap();
+        let sig = DebugSignature::new(None);
+        let commands = vec![
+            WebSocketMessagesEncodable::ImportCoreState(Box::new(encrypted), sig.clone()),
+            WebSocketMessagesEncodable::AddDebugSigners(vec![("signer".into(), EthAddress::random())], sig.clone()),
+            WebSocketMessagesEncodable::RemoveDebugSigner(format!("{:?}", EthAddress::random()), sig.clone()),
+            WebSocketMessagesEncodable::SetDebugSignaturePolicy("hard_reset".into(), 2, sig),
+        ];
+        commands.iter().for_each(|c| {
+            let queued = DebugCommandQueue::get(&db).enqueue(&db, c.clone(), now).unwrap();
+            assert_eq!(queued.command(), c);
+            assert!(!queued.is_active(now));
+        });
+        let q = DebugCommandQueue::get(&db);
+        assert_eq!(q.commands().len(), commands.len());
+        assert!(q.commands().iter().all(|c| *c.activates_at() == now + delay));
+    }
+
+    #[test]
+    fn should_not_enqueue_non_time_lockable_command() {
+        let db = get_test_database();
+        let q = DebugCommandQueue::get(&db).set_delay(&db, 100).unwrap();
+        match q.enqueue(&db, WebSocketMessagesEncodable::GetAddress, 1337) {
+            Err(SentinelError::DebugCommandQueue(DebugCommandQueueError::NotTimeLockable(_))) => (),
+            Ok(_) => panic!("should not have succeeded"),
+            Err(e) => panic!("wrong error received: {e}"),
+        };
+    }
+}
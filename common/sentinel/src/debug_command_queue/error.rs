@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DebugCommandQueueError {
+    #[error("no queued debug command with id {0}")]
+    NotFound(u64),
+
+    #[error("queued debug command {id} cannot be executed until {activates_at} (now: {now})")]
+    NotYetActive { id: u64, activates_at: u64, now: u64 },
+
+    #[error("debug command cannot be time locked: {0}")]
+    NotTimeLockable(String),
+}
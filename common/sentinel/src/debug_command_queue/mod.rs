@@ -0,0 +1,9 @@
+mod debug_command_queue;
+mod error;
+mod queued_debug_command;
+
+pub use self::{
+    debug_command_queue::DebugCommandQueue,
+    error::DebugCommandQueueError,
+    queued_debug_command::QueuedDebugCommand,
+};
@@ -0,0 +1,40 @@
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as Json};
+
+use crate::WebSocketMessagesEncodable;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuedDebugCommand {
+    id: u64,
+    queued_at: u64,
+    activates_at: u64,
+    command: WebSocketMessagesEncodable,
+}
+
+impl QueuedDebugCommand {
+    pub(super) fn new(id: u64, command: WebSocketMessagesEncodable, queued_at: u64, delay: u64) -> Self {
+        Self {
+            id,
+            command,
+            queued_at,
+            activates_at: queued_at + delay,
+        }
+    }
+
+    pub fn is_active(&self, now: u64) -> bool {
+        now >= self.activates_at
+    }
+
+    // NOTE: Some commands (eg a `ResetChain` and its submission material) are large, so we only
+    // show which command it is rather than all of its args.
+    pub fn to_json(&self) -> Json {
+        json!({
+            "id": self.id,
+            "queuedAt": self.queued_at,
+            "activatesAt": self.activates_at,
+            "command": self.command.to_string(),
+        })
+    }
+}
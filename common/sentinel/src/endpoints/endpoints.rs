@@ -1,4 +1,4 @@
-use std::result::Result;
+use std::{fmt, result::Result};
 
 use common_network_ids::NetworkId;
 use derive_getters::Getters;
@@ -6,9 +6,9 @@ use jsonrpsee::ws_client::WsClient;
 use serde::{Deserialize, Serialize};
 
 use super::{get_rpc_client, EndpointError};
-use crate::{SentinelConfigError, SentinelError};
+use crate::{redact_url, SentinelConfigError, SentinelError};
 
-#[derive(Debug, Default, Clone, Getters, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Default, Clone, Getters, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Endpoints {
     current: usize,
     sleep_time: u64,
@@ -17,6 +17,22 @@ pub struct Endpoints {
     endpoints: Vec<String>,
 }
 
+// NOTE: Endpoint urls may contain api keys, and so are redacted lest they end up in the logs.
+impl fmt::Debug for Endpoints {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Endpoints")
+            .field("current", &self.current)
+            .field("sleep_time", &self.sleep_time)
+            .field("rotations", &self.rotations)
+            .field("network_id", &self.network_id)
+            .field(
+                "endpoints",
+                &self.endpoints.iter().map(|e| redact_url(e)).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
 impl Endpoints {
     pub fn use_quicknode(&self) -> bool {
         let ce = self.current_endpoint();
@@ -51,6 +67,11 @@ impl Endpoints {
         &self.endpoints[self.current]
     }
 
+    // NOTE: For labelling metrics etc, where the url must not leak any api keys.
+    pub fn current_endpoint_redacted(&self) -> String {
+        redact_url(self.current_endpoint())
+    }
+
     pub async fn rotate(&mut self) -> Result<WsClient, SentinelError> {
         self.increment_current_endpoint_index()?;
         info!("getting next endpoint @ index: {}", self.current);
@@ -15,6 +15,9 @@ pub enum EndpointError {
     #[error("could not push tx to endpoint: {0}")]
     PushTx(jsonrpsee::core::Error),
 
+    #[error("could not estimate gas for tx: {0}")]
+    EstimateGas(jsonrpsee::core::Error),
+
     #[error("ws client has disconnected whilst {0}")]
     WsClientDisconnected(String),
 
@@ -27,3 +30,15 @@ pub enum EndpointError {
         num_rotations: usize,
     },
 }
+
+impl EndpointError {
+    // NOTE: A tx the node itself rejects, eg due to its nonce being too low, or its fees too low to
+    // replace one already in the mempool, would be rejected by any other endpoint too. As would a tx
+    // the node cannot estimate the gas of, eg due to it reverting.
+    pub fn is_tx_rejection(&self) -> bool {
+        matches!(
+            self,
+            Self::PushTx(jsonrpsee::core::Error::Call(_)) | Self::EstimateGas(jsonrpsee::core::Error::Call(_))
+        )
+    }
+}
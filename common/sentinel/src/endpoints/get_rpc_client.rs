@@ -2,12 +2,12 @@ use std::result::Result;
 
 use jsonrpsee::ws_client::{WsClient, WsClientBuilder};
 
-use crate::SentinelError;
+use crate::{redact_url, SentinelError};
 
 const MAX_BODY_SIZE_BYTES: u32 = 50_000_000; // NOTE: some BSC blocks exceed 10mb!
 
 pub async fn get_rpc_client(url: &str) -> Result<WsClient, SentinelError> {
-    debug!("getting RPC client using URL '{url}'...");
+    debug!("getting RPC client using URL '{}'...", redact_url(url));
     Ok(WsClientBuilder::default()
         .max_request_size(MAX_BODY_SIZE_BYTES)
         .max_response_size(MAX_BODY_SIZE_BYTES)
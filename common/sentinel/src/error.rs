@@ -4,7 +4,16 @@ use common_metadata::MetadataChainIdError;
 use common_network_ids::NetworkId;
 use thiserror::Error;
 
-use crate::{BroadcastChannelMessages, DbIntegrity, DbKey, EthRpcMessages, SyncerMessages, WebSocketMessages};
+use crate::{
+    BroadcastChannelMessages,
+    DbIntegrity,
+    DbKey,
+    EthRpcMessages,
+    NetworkManagerMessages,
+    SyncerMessages,
+    TxManagerMessages,
+    WebSocketMessages,
+};
 
 impl From<SentinelError> for CommonError {
     fn from(e: SentinelError) -> CommonError {
@@ -44,6 +53,27 @@ pub enum SentinelError {
     #[error("{0}")]
     SentinelStatusError(#[from] crate::status::SentinelStatusError),
 
+    #[error("core state archive error: {0}")]
+    CoreStateArchive(#[from] crate::CoreStateArchiveError),
+
+    #[error("debug command queue error: {0}")]
+    DebugCommandQueue(#[from] crate::DebugCommandQueueError),
+
+    #[error("audit log error: {0}")]
+    AuditLog(#[from] crate::AuditLogError),
+
+    #[error("tx manager error: {0}")]
+    TxManager(#[from] crate::TxManagerError),
+
+    #[error("registration error: {0}")]
+    Registration(#[from] crate::RegistrationError),
+
+    #[error("governance error: {0}")]
+    Governance(#[from] crate::GovernanceError),
+
+    #[error("challenges error: {0}")]
+    Challenges(#[from] crate::ChallengesError),
+
     #[error("chain error: {0}")]
     ChainError(#[from] common_eth::ChainError),
 
@@ -182,6 +212,12 @@ pub enum SentinelError {
     #[error("websocket channel error: {0}")]
     WebSocketChannel(Box<tokio::sync::mpsc::error::SendError<WebSocketMessages>>),
 
+    #[error("network manager channel error: {0}")]
+    NetworkManagerChannel(Box<tokio::sync::mpsc::error::SendError<NetworkManagerMessages>>),
+
+    #[error("tx manager channel error: {0}")]
+    TxManagerChannel(Box<tokio::sync::mpsc::error::SendError<TxManagerMessages>>),
+
     #[error("syncer channel error: {0}")]
     SyncerChannel(Box<tokio::sync::broadcast::error::SendError<SyncerMessages>>),
 
@@ -190,6 +226,9 @@ pub enum SentinelError {
 
     #[error("mongodb error: {0}")]
     MongoDB(Box<mongodb::error::Error>),
+
+    #[error("http request error: {0}")]
+    Reqwest(reqwest::Error),
 }
 
 impl From<tokio::sync::broadcast::error::SendError<SyncerMessages>> for SentinelError {
@@ -210,6 +249,18 @@ impl From<tokio::sync::mpsc::error::SendError<WebSocketMessages>> for SentinelEr
     }
 }
 
+impl From<tokio::sync::mpsc::error::SendError<NetworkManagerMessages>> for SentinelError {
+    fn from(e: tokio::sync::mpsc::error::SendError<NetworkManagerMessages>) -> Self {
+        Self::NetworkManagerChannel(Box::new(e))
+    }
+}
+
+impl From<tokio::sync::mpsc::error::SendError<TxManagerMessages>> for SentinelError {
+    fn from(e: tokio::sync::mpsc::error::SendError<TxManagerMessages>) -> Self {
+        Self::TxManagerChannel(Box::new(e))
+    }
+}
+
 impl From<tokio::sync::broadcast::error::SendError<BroadcastChannelMessages>> for SentinelError {
     fn from(e: tokio::sync::broadcast::error::SendError<BroadcastChannelMessages>) -> Self {
         Self::BroadcastChannelMessages(Box::new(e))
@@ -232,3 +283,10 @@ impl From<mongodb::error::Error> for SentinelError {
         Self::MongoDB(Box::new(e))
     }
 }
+
+// NOTE: The url is stripped since it may contain an api key.
+impl From<reqwest::Error> for SentinelError {
+    fn from(e: reqwest::Error) -> Self {
+        Self::Reqwest(e.without_url())
+    }
+}
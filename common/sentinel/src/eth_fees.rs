@@ -0,0 +1,133 @@
+use std::{cmp::max, fmt};
+
+use common::Bytes;
+use common_chain_ids::EthChainId;
+use common_eth::EthTransaction;
+use ethereum_types::Address as EthAddress;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum EthFees {
+    Legacy {
+        gas_price: u64,
+    },
+    Eip1559 {
+        max_fee_per_gas: u64,
+        max_priority_fee_per_gas: u64,
+    },
+}
+
+impl fmt::Display for EthFees {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Legacy { gas_price } => write!(f, "legacy gas price: {gas_price}"),
+            Self::Eip1559 {
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+            } => write!(
+                f,
+                "eip1559 max fee: {max_fee_per_gas}, max priority fee: {max_priority_fee_per_gas}"
+            ),
+        }
+    }
+}
+
+impl EthFees {
+    pub fn legacy(gas_price: u64) -> Self {
+        Self::Legacy { gas_price }
+    }
+
+    pub fn eip_1559(max_fee_per_gas: u64, max_priority_fee_per_gas: u64) -> Self {
+        Self::Eip1559 {
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+        }
+    }
+
+    // NOTE: Each fee is raised by at least one wei, so that even the tiniest of fees are bumped.
+    fn bump_fee(fee: u64, percent: u64) -> u64 {
+        fee.saturating_add(max(fee.saturating_mul(percent) / 100, 1))
+    }
+
+    pub fn bump(&self, percent: u64) -> Self {
+        match self {
+            Self::Legacy { gas_price } => Self::legacy(Self::bump_fee(*gas_price, percent)),
+            Self::Eip1559 {
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+            } => Self::eip_1559(
+                Self::bump_fee(*max_fee_per_gas, percent),
+                Self::bump_fee(*max_priority_fee_per_gas, percent),
+            ),
+        }
+    }
+
+    pub fn to_unsigned_tx(
+        &self,
+        data: Bytes,
+        nonce: u64,
+        value: usize,
+        to: EthAddress,
+        chain_id: &EthChainId,
+        gas_limit: usize,
+    ) -> EthTransaction {
+        match self {
+            Self::Legacy { gas_price } => {
+                EthTransaction::new_unsigned(data, nonce, value, to, chain_id, gas_limit, *gas_price)
+            },
+            Self::Eip1559 {
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+            } => EthTransaction::new_unsigned_eip_1559(
+                data,
+                nonce,
+                value,
+                to,
+                chain_id,
+                gas_limit,
+                *max_fee_per_gas,
+                *max_priority_fee_per_gas,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_eth::EthTransactionType;
+
+    use super::*;
+
+    #[test]
+    fn should_get_unsigned_tx_of_correct_type_for_fees() {
+        let chain_id = EthChainId::Mainnet;
+        let legacy_tx = EthFees::legacy(1).to_unsigned_tx(vec![], 0, 0, EthAddress::zero(), &chain_id, 21_000);
+        let eip_1559_tx = EthFees::eip_1559(2, 1).to_unsigned_tx(vec![], 0, 0, EthAddress::zero(), &chain_id, 21_000);
+        assert_eq!(legacy_tx.tx_type, EthTransactionType::Legacy);
+        assert_eq!(eip_1559_tx.tx_type, EthTransactionType::DynamicFee);
+        assert_eq!(eip_1559_tx.max_fee_per_gas, 2.into());
+        assert_eq!(eip_1559_tx.max_priority_fee_per_gas, 1.into());
+    }
+
+    #[test]
+    fn should_serde_eth_fees_json() {
+        let fees = EthFees::eip_1559(2, 1);
+        let json = serde_json::to_value(fees).unwrap();
+        assert_eq!(json["type"], "eip1559");
+        let result: EthFees = serde_json::from_value(json).unwrap();
+        assert_eq!(result, fees);
+    }
+
+    #[test]
+    fn should_bump_eth_fees() {
+        assert_eq!(EthFees::legacy(100).bump(15), EthFees::legacy(115));
+        assert_eq!(EthFees::eip_1559(200, 10).bump(10), EthFees::eip_1559(220, 11));
+    }
+
+    #[test]
+    fn should_bump_tiny_eth_fees_by_at_least_one() {
+        assert_eq!(EthFees::legacy(1).bump(10), EthFees::legacy(2));
+        assert_eq!(EthFees::eip_1559(5, 0).bump(10), EthFees::eip_1559(6, 1));
+    }
+}
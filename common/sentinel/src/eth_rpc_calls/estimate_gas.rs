@@ -0,0 +1,102 @@
+use std::result::Result;
+
+use common::{strip_hex_prefix, Byte};
+use common_network_ids::NetworkId;
+use ethereum_types::Address as EthAddress;
+use jsonrpsee::{core::client::ClientT, rpc_params, ws_client::WsClient};
+use serde_json::json;
+use tokio::time::{sleep, Duration};
+
+use super::{ETH_RPC_CALL_TIME_LIMIT, MAX_RPC_CALL_ATTEMPTS};
+use crate::{run_timer, EndpointError, SentinelError};
+
+const RPC_CMD: &str = "eth_estimateGas";
+
+async fn estimate_gas_inner(
+    from: &EthAddress,
+    to: &EthAddress,
+    data: &[Byte],
+    ws_client: &WsClient,
+) -> Result<u64, SentinelError> {
+    let params = json!({
+        "from": format!("0x{:x}", from),
+        "to": format!("0x{:x}", to),
+        "data": format!("0x{}", hex::encode(data)),
+    });
+    let res: Result<String, jsonrpsee::core::Error> = ws_client.request(RPC_CMD, rpc_params![params]).await;
+    match res {
+        Ok(ref s) => Ok(u64::from_str_radix(&strip_hex_prefix(s), 16)?),
+        Err(e) => Err(EndpointError::EstimateGas(e).into()),
+    }
+}
+
+pub async fn estimate_gas(
+    from: &EthAddress,
+    to: &EthAddress,
+    data: &[Byte],
+    ws_client: &WsClient,
+    sleep_time: u64,
+    network_id: NetworkId,
+) -> Result<u64, SentinelError> {
+    let mut attempt = 1;
+    loop {
+        let m = format!("{network_id} estimating gas attempt #{attempt}");
+        debug!("{m}");
+
+        let r = tokio::select! {
+            res = estimate_gas_inner(from, to, data, ws_client) => res,
+            _ = run_timer(ETH_RPC_CALL_TIME_LIMIT) => Err(EndpointError::TimeOut(m.clone()).into()),
+            _ = ws_client.on_disconnect() => Err(EndpointError::WsClientDisconnected(m.clone()).into()),
+        };
+
+        match r {
+            Ok(r) => break Ok(r),
+            Err(e) => match e {
+                SentinelError::Endpoint(EndpointError::WsClientDisconnected(_)) => {
+                    warn!("{network_id} {RPC_CMD} failed due to web socket dropping");
+                    break Err(e);
+                },
+                SentinelError::Endpoint(ref err) if err.is_tx_rejection() => {
+                    warn!("{network_id} {RPC_CMD} rejected by node: {err}");
+                    break Err(e);
+                },
+                _ => {
+                    if attempt < MAX_RPC_CALL_ATTEMPTS {
+                        attempt += 1;
+                        warn!("{network_id} sleeping for {sleep_time}s before retrying...");
+                        sleep(Duration::from_secs(sleep_time)).await;
+                        continue;
+                    } else {
+                        warn!("{network_id} {RPC_CMD} failed after {attempt} attempts");
+                        break Err(e);
+                    }
+                },
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_eth::convert_hex_to_eth_address;
+
+    use super::*;
+    use crate::{test_utils::get_test_ws_client, DEFAULT_SLEEP_TIME};
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "test-eth-rpc"), ignore)]
+    async fn should_estimate_gas() {
+        let address = convert_hex_to_eth_address("0xedB86cd455ef3ca43f0e227e00469C3bDFA40628").unwrap();
+        let ws_client = get_test_ws_client().await;
+        let result = estimate_gas(
+            &address,
+            &address,
+            &[],
+            &ws_client,
+            DEFAULT_SLEEP_TIME,
+            NetworkId::default(),
+        )
+        .await;
+        assert_eq!(result.unwrap(), 21_000);
+    }
+}
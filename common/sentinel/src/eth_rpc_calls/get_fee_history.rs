@@ -0,0 +1,146 @@
+use std::result::Result;
+
+use common::strip_hex_prefix;
+use common_network_ids::NetworkId;
+use jsonrpsee::{core::client::ClientT, rpc_params, ws_client::WsClient};
+use serde::Deserialize;
+use tokio::time::{sleep, Duration};
+
+use super::{ETH_RPC_CALL_TIME_LIMIT, MAX_RPC_CALL_ATTEMPTS};
+use crate::{run_timer, EndpointError, EthFees, SentinelError};
+
+const RPC_CMD: &str = "eth_feeHistory";
+const NUM_BLOCKS: u64 = 10;
+const REWARD_PERCENTILE: f64 = 50.0;
+
+// NOTE: The max fee allows for the base fee doubling before the tx is mined, which covers six
+// consecutive full blocks' worth of base fee increases.
+const BASE_FEE_MULTIPLIER: u64 = 2;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FeeHistoryJson {
+    base_fee_per_gas: Vec<String>,
+    reward: Option<Vec<Vec<String>>>,
+}
+
+fn parse_hex(s: &str) -> Result<u64, SentinelError> {
+    Ok(u64::from_str_radix(&strip_hex_prefix(s), 16)?)
+}
+
+impl TryFrom<FeeHistoryJson> for EthFees {
+    type Error = SentinelError;
+
+    fn try_from(json: FeeHistoryJson) -> Result<Self, Self::Error> {
+        // NOTE: The final base fee is that of the next, as yet unmined, block.
+        let base_fee = json
+            .base_fee_per_gas
+            .last()
+            .ok_or_else(|| SentinelError::Custom(format!("no base fees in {RPC_CMD} response")))
+            .and_then(|s| parse_hex(s))?;
+
+        let mut rewards = json
+            .reward
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|r| r.first())
+            .map(|s| parse_hex(s))
+            .collect::<Result<Vec<u64>, SentinelError>>()?;
+        rewards.sort_unstable();
+        let max_priority_fee_per_gas = rewards.get(rewards.len() / 2).copied().unwrap_or_default();
+
+        let max_fee_per_gas = base_fee
+            .saturating_mul(BASE_FEE_MULTIPLIER)
+            .saturating_add(max_priority_fee_per_gas);
+
+        Ok(Self::eip_1559(max_fee_per_gas, max_priority_fee_per_gas))
+    }
+}
+
+async fn get_eip_1559_fees_inner(ws_client: &WsClient) -> Result<EthFees, SentinelError> {
+    let res: Result<FeeHistoryJson, jsonrpsee::core::Error> = ws_client
+        .request(RPC_CMD, rpc_params![format!("0x{NUM_BLOCKS:x}"), "latest", vec![
+            REWARD_PERCENTILE
+        ]])
+        .await;
+    match res {
+        Err(e) => Err(SentinelError::JsonRpc(e)),
+        Ok(json) => EthFees::try_from(json),
+    }
+}
+
+pub async fn get_eip_1559_fees(
+    ws_client: &WsClient,
+    sleep_time: u64,
+    network_id: &NetworkId,
+) -> Result<EthFees, SentinelError> {
+    let mut attempt = 1;
+    loop {
+        let m = format!("{network_id} getting eip1559 fees attempt #{attempt}");
+        debug!("{m}");
+
+        let r = tokio::select! {
+            res = get_eip_1559_fees_inner(ws_client) => res,
+            _ = run_timer(ETH_RPC_CALL_TIME_LIMIT) => Err(EndpointError::TimeOut(m.clone()).into()),
+            _ = ws_client.on_disconnect() => Err(EndpointError::WsClientDisconnected(m.clone()).into()),
+        };
+
+        match r {
+            Ok(r) => break Ok(r),
+            Err(e) => match e {
+                SentinelError::Endpoint(EndpointError::WsClientDisconnected(_)) => {
+                    warn!("{network_id} {RPC_CMD} failed due to web socket dropping");
+                    break Err(e);
+                },
+                _ => {
+                    if attempt < MAX_RPC_CALL_ATTEMPTS {
+                        attempt += 1;
+                        warn!("{network_id} sleeping for {sleep_time}s before retrying...");
+                        sleep(Duration::from_secs(sleep_time)).await;
+                        continue;
+                    } else {
+                        warn!("{network_id} {RPC_CMD} failed after {attempt} attempts");
+                        break Err(e);
+                    }
+                },
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::get_test_ws_client, DEFAULT_SLEEP_TIME};
+
+    #[test]
+    fn should_get_eip_1559_fees_from_fee_history_json() {
+        let json: FeeHistoryJson = serde_json::from_str(
+            r#"{
+                "oldestBlock": "0x10",
+                "baseFeePerGas": ["0x64", "0x6e", "0x78"],
+                "gasUsedRatio": [0.5, 0.9],
+                "reward": [["0x3"], ["0x1"], ["0x2"]]
+            }"#,
+        )
+        .unwrap();
+        let result = EthFees::try_from(json).unwrap();
+        let expected_result = EthFees::eip_1559(0x78 * 2 + 2, 2);
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_default_priority_fee_to_zero_if_no_rewards() {
+        let json: FeeHistoryJson = serde_json::from_str(r#"{"baseFeePerGas": ["0x64"], "reward": []}"#).unwrap();
+        let result = EthFees::try_from(json).unwrap();
+        assert_eq!(result, EthFees::eip_1559(0xc8, 0));
+    }
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "test-eth-rpc"), ignore)]
+    async fn should_get_eip_1559_fees() {
+        let ws_client = get_test_ws_client().await;
+        let result = get_eip_1559_fees(&ws_client, DEFAULT_SLEEP_TIME, &NetworkId::default()).await;
+        assert!(result.is_ok());
+    }
+}
@@ -50,6 +50,10 @@ pub struct QuicknodeBlockFromRpc {
     blob_gas_used: Option<String>,
     excess_blob_gas: Option<String>,
     parent_beacon_block_root: Option<String>,
+    requests_hash: Option<String>,
+    send_root: Option<String>,
+    send_count: Option<String>,
+    l1_block_number: Option<String>,
 }
 
 impl TryFrom<QuicknodeBlockFromRpc> for EthBlock {
@@ -102,6 +106,22 @@ impl TryFrom<QuicknodeBlockFromRpc> for EthBlock {
                 None => None,
                 Some(hex) => Some(convert_hex_to_h256(hex)?),
             },
+            requests_hash: match json.requests_hash.as_ref() {
+                None => None,
+                Some(hex) => Some(convert_hex_to_h256(hex)?),
+            },
+            send_root: match json.send_root.as_ref() {
+                None => None,
+                Some(hex) => Some(convert_hex_to_h256(hex)?),
+            },
+            send_count: match json.send_count.as_ref() {
+                None => None,
+                Some(hex) => Some(U256::from_str_radix(&strip_hex_prefix(hex), radix)?),
+            },
+            l1_block_number: match json.l1_block_number.as_ref() {
+                None => None,
+                Some(hex) => Some(U256::from_str_radix(&strip_hex_prefix(hex), radix)?),
+            },
         })
     }
 }
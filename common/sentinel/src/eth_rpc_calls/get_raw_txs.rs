@@ -0,0 +1,171 @@
+use std::result::Result;
+
+use common::{strip_hex_prefix, Bytes};
+use common_eth::EthTransactionFromJsonRpc;
+use common_network_ids::NetworkId;
+use ethereum_types::H256 as EthHash;
+use futures::{stream, Future, Stream, StreamExt};
+use jsonrpsee::{
+    core::{client::ClientT, Error as JsonRpseeError},
+    rpc_params,
+    ws_client::WsClient,
+};
+use serde::Deserialize;
+use tokio::time::{sleep, Duration};
+
+use super::MAX_RPC_CALL_ATTEMPTS;
+use crate::{run_timer, EndpointError, SentinelError};
+
+const GET_FULL_TRANSACTIONS: bool = true;
+const MAX_CONCURRENT_REQUESTS: usize = 250;
+const RPC_CMD: &str = "eth_getBlockByNumber";
+const RAW_TX_RPC_CMD: &str = "eth_getRawTransactionByHash";
+
+#[derive(Clone, Default, Debug, Deserialize)]
+struct BlockWithFullTxsFromRpc {
+    transactions: Vec<EthTransactionFromJsonRpc>,
+}
+
+async fn get_raw_tx_future<'a>(
+    ws_client: &'a WsClient,
+    tx_hash: &'a EthHash,
+) -> impl Future<Output = Result<String, JsonRpseeError>> + 'a {
+    trace!("getting raw tx for tx hash: 0x{tx_hash:x}...");
+    ws_client.request(RAW_TX_RPC_CMD, rpc_params![format!("0x{tx_hash:x}")])
+}
+
+fn get_raw_tx_futures<'a>(
+    ws_client: &'a WsClient,
+    tx_hashes: &'a [EthHash],
+) -> impl Stream<Item = impl Future<Output = Result<String, JsonRpseeError>> + 'a> + 'a {
+    stream::iter(tx_hashes).then(|tx_hash| get_raw_tx_future(ws_client, tx_hash))
+}
+
+async fn get_raw_txs_by_hash(ws_client: &WsClient, tx_hashes: &[EthHash]) -> Result<Vec<Bytes>, SentinelError> {
+    let hexes = get_raw_tx_futures(ws_client, tx_hashes)
+        .buffered(MAX_CONCURRENT_REQUESTS)
+        .collect::<Vec<_>>()
+        .await;
+
+    hexes
+        .into_iter()
+        .map(|h| Ok(hex::decode(strip_hex_prefix(&h?))?))
+        .collect::<Result<Vec<_>, SentinelError>>()
+}
+
+async fn get_raw_txs_inner(ws_client: &WsClient, block_num: u64) -> Result<Vec<Bytes>, SentinelError> {
+    let res: Result<BlockWithFullTxsFromRpc, JsonRpseeError> = ws_client
+        .request(RPC_CMD, rpc_params![format!("0x{block_num:x}"), GET_FULL_TRANSACTIONS])
+        .await;
+    let txs = match res {
+        Ok(json) => json.transactions,
+        Err(JsonRpseeError::ParseError(err)) if err.to_string().contains("null") => {
+            return Err(SentinelError::NoBlock(block_num))
+        },
+        Err(err) => return Err(SentinelError::JsonRpc(err)),
+    };
+
+    let maybe_raw_txs = txs
+        .iter()
+        .map(|tx| tx.to_raw_transaction())
+        .collect::<Result<Vec<Option<Bytes>>, _>>()?;
+
+    // NOTE: Any txs we cannot encode ourselves, eg those of chain specific types, are got by hash.
+    let missing_tx_hashes = txs
+        .iter()
+        .zip(maybe_raw_txs.iter())
+        .filter(|(_, maybe_raw_tx)| maybe_raw_tx.is_none())
+        .map(|(tx, _)| tx.hash())
+        .collect::<Result<Vec<EthHash>, _>>()?;
+
+    if missing_tx_hashes.is_empty() {
+        return Ok(maybe_raw_txs.into_iter().flatten().collect());
+    };
+
+    debug!(
+        "getting {} of {} raw txs in block {block_num} via {RAW_TX_RPC_CMD}",
+        missing_tx_hashes.len(),
+        txs.len()
+    );
+    let mut missing_raw_txs = get_raw_txs_by_hash(ws_client, &missing_tx_hashes).await?.into_iter();
+
+    maybe_raw_txs
+        .into_iter()
+        .map(|maybe_raw_tx| match maybe_raw_tx {
+            Some(raw_tx) => Ok(raw_tx),
+            None => missing_raw_txs
+                .next()
+                .ok_or_else(|| SentinelError::Custom(format!("missing raw tx in block {block_num}"))),
+        })
+        .collect()
+}
+
+pub async fn get_raw_txs(
+    ws_client: &WsClient,
+    block_num: u64,
+    sleep_time: u64,
+    network_id: &NetworkId,
+) -> Result<Vec<Bytes>, SentinelError> {
+    const TIME_LIMIT: u64 = 10 * 1000;
+    let mut attempt = 1;
+    loop {
+        let m = format!("{network_id} getting raw txs for block num {block_num} attempt #{attempt}");
+        debug!("{m}");
+
+        let r = tokio::select! {
+            res = get_raw_txs_inner(ws_client, block_num) => res,
+            _ = run_timer(TIME_LIMIT) => Err(EndpointError::TimeOut(m.clone()).into()),
+            _ = ws_client.on_disconnect() => Err(EndpointError::WsClientDisconnected(m.clone()).into()),
+        };
+
+        match r {
+            Ok(r) => break Ok(r),
+            Err(e) => match e {
+                SentinelError::Endpoint(EndpointError::WsClientDisconnected(_)) => {
+                    warn!("{network_id} {RPC_CMD} failed due to web socket dropping");
+                    break Err(e);
+                },
+                _ => {
+                    if attempt < MAX_RPC_CALL_ATTEMPTS {
+                        attempt += 1;
+                        warn!("{network_id} sleeping for {sleep_time}s before retrying...");
+                        sleep(Duration::from_secs(sleep_time)).await;
+                        continue;
+                    } else {
+                        warn!("{network_id} {RPC_CMD} failed after {attempt} attempts");
+                        break Err(e);
+                    }
+                },
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::crypto_utils::keccak_hash_bytes;
+
+    use super::*;
+    use crate::{get_block, get_latest_block_num, test_utils::get_test_ws_client, DEFAULT_SLEEP_TIME};
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "test-eth-rpc"), ignore)]
+    async fn should_get_raw_txs() {
+        let ws_client = get_test_ws_client().await;
+        let network_id = NetworkId::default();
+        let block_num = get_latest_block_num(&ws_client, DEFAULT_SLEEP_TIME, &network_id)
+            .await
+            .unwrap();
+        let block = get_block(&ws_client, block_num, DEFAULT_SLEEP_TIME, &network_id)
+            .await
+            .unwrap();
+        let result = get_raw_txs(&ws_client, block_num, DEFAULT_SLEEP_TIME, &network_id)
+            .await
+            .unwrap();
+        assert_eq!(result.len(), block.transactions.len());
+        result
+            .iter()
+            .zip(block.transactions.iter())
+            .for_each(|(raw_tx, tx_hash)| assert_eq!(&keccak_hash_bytes(raw_tx), tx_hash));
+    }
+}
@@ -0,0 +1,98 @@
+use std::result::Result;
+
+use common_eth::EthReceipt;
+use common_network_ids::NetworkId;
+use ethereum_types::H256 as EthHash;
+use jsonrpsee::{core::client::ClientT, rpc_params, ws_client::WsClient};
+use serde_json::Value as JsonValue;
+use tokio::time::{sleep, Duration};
+
+use super::{ETH_RPC_CALL_TIME_LIMIT, MAX_RPC_CALL_ATTEMPTS};
+use crate::{run_timer, EndpointError, SentinelError};
+
+const RPC_CMD: &str = "eth_getTransactionReceipt";
+
+// NOTE: Unlike when getting the receipts for a block, a missing receipt here is not an error, since
+// it merely means the tx has yet to be mined.
+async fn get_tx_receipt_inner(ws_client: &WsClient, tx_hash: &EthHash) -> Result<Option<EthReceipt>, SentinelError> {
+    let res: Result<JsonValue, jsonrpsee::core::Error> =
+        ws_client.request(RPC_CMD, rpc_params![format!("0x{tx_hash:x}")]).await;
+    match res {
+        Err(e) => Err(SentinelError::JsonRpc(e)),
+        Ok(JsonValue::Null) => Ok(None),
+        Ok(json) => Ok(Some(EthReceipt::from_json_rpc(&serde_json::from_value(json)?)?)),
+    }
+}
+
+pub async fn get_tx_receipt(
+    ws_client: &WsClient,
+    tx_hash: &EthHash,
+    sleep_time: u64,
+    network_id: &NetworkId,
+) -> Result<Option<EthReceipt>, SentinelError> {
+    let mut attempt = 1;
+    loop {
+        let m = format!("{network_id} getting receipt for tx 0x{tx_hash:x} attempt #{attempt}");
+        debug!("{m}");
+
+        let r = tokio::select! {
+            res = get_tx_receipt_inner(ws_client, tx_hash) => res,
+            _ = run_timer(ETH_RPC_CALL_TIME_LIMIT) => Err(EndpointError::TimeOut(m.clone()).into()),
+            _ = ws_client.on_disconnect() => Err(EndpointError::WsClientDisconnected(m.clone()).into()),
+        };
+
+        match r {
+            Ok(r) => break Ok(r),
+            Err(e) => match e {
+                SentinelError::Endpoint(EndpointError::WsClientDisconnected(_)) => {
+                    warn!("{network_id} {RPC_CMD} failed due to web socket dropping");
+                    break Err(e);
+                },
+                _ => {
+                    if attempt < MAX_RPC_CALL_ATTEMPTS {
+                        attempt += 1;
+                        warn!("{network_id} sleeping for {sleep_time}s before retrying...");
+                        sleep(Duration::from_secs(sleep_time)).await;
+                        continue;
+                    } else {
+                        warn!("{network_id} {RPC_CMD} failed after {attempt} attempts");
+                        break Err(e);
+                    }
+                },
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{get_block, get_latest_block_num, test_utils::get_test_ws_client, DEFAULT_SLEEP_TIME};
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "test-eth-rpc"), ignore)]
+    async fn should_get_tx_receipt() {
+        let ws_client = get_test_ws_client().await;
+        let block_num = get_latest_block_num(&ws_client, DEFAULT_SLEEP_TIME, &NetworkId::default())
+            .await
+            .unwrap();
+        let block = get_block(&ws_client, block_num, DEFAULT_SLEEP_TIME, &NetworkId::default())
+            .await
+            .unwrap();
+        let tx_hash = block.transactions[0];
+        let result = get_tx_receipt(&ws_client, &tx_hash, DEFAULT_SLEEP_TIME, &NetworkId::default())
+            .await
+            .unwrap();
+        assert_eq!(result.unwrap().transaction_hash, tx_hash);
+    }
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "test-eth-rpc"), ignore)]
+    async fn should_get_no_receipt_for_unknown_tx() {
+        let ws_client = get_test_ws_client().await;
+        let result = get_tx_receipt(&ws_client, &EthHash::zero(), DEFAULT_SLEEP_TIME, &NetworkId::default())
+            .await
+            .unwrap();
+        assert!(result.is_none());
+    }
+}
@@ -1,27 +1,37 @@
 mod constants;
+mod estimate_gas;
 mod eth_call;
 mod get_block;
 mod get_chain_id;
+mod get_code;
 mod get_eth_balance;
+mod get_fee_history;
 mod get_gas_price;
 mod get_latest_block_num;
 mod get_nonce;
 mod get_quicknode_sub_mat;
+mod get_raw_txs;
 mod get_receipts;
 mod get_sub_mat;
+mod get_tx_receipt;
 mod push_tx;
 mod test_utils;
 
 use self::constants::{ETH_RPC_CALL_TIME_LIMIT, MAX_RPC_CALL_ATTEMPTS};
 pub use self::{
+    estimate_gas::estimate_gas,
     eth_call::eth_call,
     get_block::get_block,
     get_chain_id::get_chain_id,
+    get_code::get_code,
     get_eth_balance::get_eth_balance,
+    get_fee_history::get_eip_1559_fees,
     get_gas_price::get_gas_price,
     get_latest_block_num::get_latest_block_num,
     get_nonce::get_nonce,
+    get_raw_txs::get_raw_txs,
     get_receipts::get_receipts,
     get_sub_mat::get_sub_mat,
+    get_tx_receipt::get_tx_receipt,
     push_tx::push_tx,
 };
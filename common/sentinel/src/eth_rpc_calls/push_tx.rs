@@ -45,6 +45,10 @@ pub async fn push_tx(
                     warn!("{network_id} {RPC_CMD} failed due to web socket dropping");
                     break Err(e);
                 },
+                SentinelError::Endpoint(ref err) if err.is_tx_rejection() => {
+                    warn!("{network_id} {RPC_CMD} rejected by node: {err}");
+                    break Err(e);
+                },
                 _ => {
                     if attempt < MAX_RPC_CALL_ATTEMPTS {
                         attempt += 1;
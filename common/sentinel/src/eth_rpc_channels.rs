@@ -1,4 +1,7 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
 
 use common_network_ids::NetworkId;
 use derive_more::{Constructor, Deref, DerefMut};
@@ -9,15 +12,48 @@ use tokio::sync::{
 
 use crate::{EthRpcMessages, SentinelConfigError, MAX_CHANNEL_CAPACITY};
 
-#[derive(Debug, Clone, Constructor, Deref)]
-pub struct EthRpcSenders(HashMap<NetworkId, Sender<EthRpcMessages>>);
+// NOTE: Clones of this share the same underlying map, so that networks may be added to or removed
+// from a running sentinel, with every component holding these senders seeing those changes.
+#[derive(Debug, Clone, Default)]
+pub struct EthRpcSenders(Arc<RwLock<HashMap<NetworkId, Sender<EthRpcMessages>>>>);
 
 impl EthRpcSenders {
+    pub fn new(senders: HashMap<NetworkId, Sender<EthRpcMessages>>) -> Self {
+        Self(Arc::new(RwLock::new(senders)))
+    }
+
+    // NOTE: The lock is never held across an await, and no write can leave the map in an
+    // inconsistent state, so we needn't propagate poisoning.
+    fn read(&self) -> RwLockReadGuard<HashMap<NetworkId, Sender<EthRpcMessages>>> {
+        self.0.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<HashMap<NetworkId, Sender<EthRpcMessages>>> {
+        self.0.write().unwrap_or_else(|e| e.into_inner())
+    }
+
     pub fn sender(&self, nid: &NetworkId) -> Result<Sender<EthRpcMessages>, SentinelConfigError> {
-        self.get(nid)
+        self.read()
+            .get(nid)
             .cloned()
             .ok_or_else(|| SentinelConfigError::NoConfig(*nid))
     }
+
+    pub fn insert(&self, nid: NetworkId, sender: Sender<EthRpcMessages>) -> Option<Sender<EthRpcMessages>> {
+        self.write().insert(nid, sender)
+    }
+
+    pub fn remove(&self, nid: &NetworkId) -> Option<Sender<EthRpcMessages>> {
+        self.write().remove(nid)
+    }
+
+    pub fn contains(&self, nid: &NetworkId) -> bool {
+        self.read().contains_key(nid)
+    }
+
+    pub fn network_ids(&self) -> Vec<NetworkId> {
+        self.read().keys().cloned().collect()
+    }
 }
 
 impl From<&EthRpcChannels> for EthRpcSenders {
@@ -52,3 +88,22 @@ impl EthRpcChannels {
         r
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clones_of_eth_rpc_senders_should_see_added_and_removed_networks() {
+        let senders = EthRpcSenders::default();
+        let clone = senders.clone();
+        let nid = NetworkId::default();
+        let (tx, _rx) = mpsc::channel(MAX_CHANNEL_CAPACITY);
+        assert!(clone.sender(&nid).is_err());
+        senders.insert(nid, tx);
+        assert!(clone.sender(&nid).is_ok());
+        assert_eq!(clone.network_ids(), vec![nid]);
+        clone.remove(&nid);
+        assert!(!senders.contains(&nid));
+    }
+}
@@ -0,0 +1,106 @@
+use common::{Byte, DatabaseInterface, MIN_DATA_SENSITIVITY_LEVEL};
+use common_chain_ids::{EvmChainConfig, EvmChainRegistry};
+use common_eth::EthForkSchedule;
+use common_metadata::MetadataChainId;
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    db_utils::{DbKey, DbUtilsT, SentinelDbKeys, SentinelDbUtils},
+    SentinelError,
+};
+
+// NOTE: The EVM chains registered in the core, via the debug signed `register_evm_chain` command.
+// The core doesn't load the config, and so these, rather than any chain described by the app, are
+// what's registered when the core processes a batch.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Getters)]
+pub struct EvmChains {
+    evm_chains: Vec<EvmChainConfig>,
+}
+
+impl DbUtilsT for EvmChains {
+    fn key(&self) -> Result<DbKey, SentinelError> {
+        Ok(SentinelDbKeys::get_evm_chains_db_key())
+    }
+
+    fn sensitivity() -> Option<Byte> {
+        MIN_DATA_SENSITIVITY_LEVEL
+    }
+
+    fn from_bytes(bytes: &[Byte]) -> Result<Self, SentinelError> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
+
+impl EvmChains {
+    pub fn get<D: DatabaseInterface>(db: &D) -> Self {
+        let key = SentinelDbKeys::get_evm_chains_db_key();
+        match Self::get_from_db(&SentinelDbUtils::new(db), &key) {
+            Ok(s) => s,
+            Err(_) => {
+                debug!("no evm chains in db, using default");
+                Self::default()
+            },
+        }
+    }
+
+    pub fn save<D: DatabaseInterface>(&self, db: &D) -> Result<(), SentinelError> {
+        self.update_in_db(&SentinelDbUtils::new(db))
+    }
+
+    pub fn register_all(&self) -> Result<(), SentinelError> {
+        for c in self.evm_chains.iter() {
+            EvmChainRegistry::register(c.clone())?;
+        }
+        Ok(())
+    }
+
+    // NOTE: Replaces any prior config for the same chain id, once the new one has been checked.
+    pub fn add(&mut self, config: EvmChainConfig) -> Result<(), SentinelError> {
+        let chain_id = config.chain_id;
+        EthForkSchedule::try_from(&config)?;
+        EvmChainRegistry::register(config.clone())?;
+        MetadataChainId::from_registered_evm_chain(chain_id)?;
+        self.evm_chains.retain(|c| c.chain_id != chain_id);
+        self.evm_chains.push(config);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::get_test_database;
+
+    use super::*;
+
+    #[test]
+    fn should_add_evm_chain_and_save_it_in_db() {
+        let _registry = EvmChainRegistry::lock_for_test();
+        let db = get_test_database();
+        let config = EvmChainConfig {
+            chain_id: 7_777_777,
+            name: "zora".into(),
+            gas_multiplier: 1,
+            ..Default::default()
+        };
+        let mut evm_chains = EvmChains::get(&db);
+        assert!(evm_chains.evm_chains().is_empty());
+        evm_chains.add(config.clone()).unwrap();
+        evm_chains.add(config.clone()).unwrap();
+        evm_chains.save(&db).unwrap();
+        let result = EvmChains::get(&db);
+        assert_eq!(result.evm_chains(), &vec![config]);
+        assert!(EvmChainRegistry::is_registered(7_777_777));
+    }
+
+    #[test]
+    fn should_not_add_supported_evm_chain() {
+        let config = EvmChainConfig {
+            chain_id: 1,
+            name: "mainnet".into(),
+            gas_multiplier: 1,
+            ..Default::default()
+        };
+        assert!(EvmChains::default().add(config).is_err());
+    }
+}
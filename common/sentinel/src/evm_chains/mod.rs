@@ -0,0 +1,3 @@
+mod evm_chains;
+
+pub use self::evm_chains::EvmChains;
@@ -0,0 +1,10 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum GovernanceError {
+    #[error("cannot decode governance event: {0}")]
+    CannotDecode(String),
+
+    #[error("governance eth abi error: {0}")]
+    EthAbi(#[from] ethabi::Error),
+}
@@ -0,0 +1,246 @@
+use std::fmt;
+
+use common::crypto_utils::keccak_hash_bytes;
+use common_eth::{EthLog, EthSubmissionMaterial};
+use derive_more::{Constructor, Deref};
+use ethabi::{decode as eth_abi_decode, ParamType as EthAbiParamType, Token as EthAbiToken};
+use ethereum_types::{Address as EthAddress, H256 as EthHash, U256};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::GovernanceError;
+
+lazy_static! {
+    static ref SENTINEL_SET_UPDATED_TOPIC: EthHash = keccak_hash_bytes(b"SentinelSetUpdated(uint16,address[])");
+    static ref REGISTRATION_UPDATED_TOPIC: EthHash =
+        keccak_hash_bytes(b"SentinelRegistrationUpdated(address,address,uint16,uint16)");
+    static ref SENTINEL_SLASHED_TOPIC: EthHash = keccak_hash_bytes(b"SentinelSlashed(address,uint16,uint256)");
+    static ref SENTINEL_DEREGISTERED_TOPIC: EthHash = keccak_hash_bytes(b"SentinelDeregistered(address,uint16)");
+}
+
+// NOTE: Those events emitted by the governance contract which concern sentinels. Bar the sentinel
+// set updates, each has the address of the sentinel it concerns as its first indexed topic.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum GovernanceEvent {
+    #[serde(rename_all = "camelCase")]
+    SentinelSetUpdated { epoch: u64, sentinels: Vec<EthAddress> },
+    #[serde(rename_all = "camelCase")]
+    RegistrationUpdated {
+        sentinel: EthAddress,
+        owner: EthAddress,
+        starting_epoch: u64,
+        ending_epoch: u64,
+    },
+    #[serde(rename_all = "camelCase")]
+    SentinelSlashed {
+        sentinel: EthAddress,
+        epoch: u64,
+        amount: U256,
+    },
+    #[serde(rename_all = "camelCase")]
+    SentinelDeregistered { sentinel: EthAddress, epoch: u64 },
+}
+
+impl fmt::Display for GovernanceEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+fn get_indexed_address(log: &EthLog) -> Result<EthAddress, GovernanceError> {
+    match log.topics.get(1) {
+        Some(t) => Ok(EthAddress::from_slice(&t[12..])),
+        None => Err(GovernanceError::CannotDecode(format!(
+            "no indexed sentinel address in log from {}",
+            log.address
+        ))),
+    }
+}
+
+fn get_u64(tokens: &[EthAbiToken], i: usize) -> Result<u64, GovernanceError> {
+    match tokens.get(i) {
+        Some(EthAbiToken::Uint(u)) if *u <= U256::from(u64::MAX) => Ok(u.as_u64()),
+        _ => Err(GovernanceError::CannotDecode(format!("expected a u64 at index {i}"))),
+    }
+}
+
+fn get_u256(tokens: &[EthAbiToken], i: usize) -> Result<U256, GovernanceError> {
+    match tokens.get(i) {
+        Some(EthAbiToken::Uint(u)) => Ok(*u),
+        _ => Err(GovernanceError::CannotDecode(format!("expected a u256 at index {i}"))),
+    }
+}
+
+fn get_address(tokens: &[EthAbiToken], i: usize) -> Result<EthAddress, GovernanceError> {
+    match tokens.get(i) {
+        Some(EthAbiToken::Address(a)) => Ok(*a),
+        _ => Err(GovernanceError::CannotDecode(format!(
+            "expected an address at index {i}"
+        ))),
+    }
+}
+
+fn get_addresses(tokens: &[EthAbiToken], i: usize) -> Result<Vec<EthAddress>, GovernanceError> {
+    match tokens.get(i) {
+        Some(EthAbiToken::Array(xs)) => (0..xs.len()).map(|j| get_address(xs, j)).collect(),
+        _ => Err(GovernanceError::CannotDecode(format!(
+            "expected an address array at index {i}"
+        ))),
+    }
+}
+
+impl GovernanceEvent {
+    // NOTE: Returns `None` for any log that's not one of the governance events, since the governance
+    // contract may emit others which the sentinel has no interest in.
+    pub fn from_log(log: &EthLog) -> Result<Option<Self>, GovernanceError> {
+        let topic = match log.topics.first() {
+            Some(t) => t,
+            None => return Ok(None),
+        };
+
+        let event = if topic == &*SENTINEL_SET_UPDATED_TOPIC {
+            let tokens = eth_abi_decode(
+                &[
+                    EthAbiParamType::Uint(16),
+                    EthAbiParamType::Array(Box::new(EthAbiParamType::Address)),
+                ],
+                &log.data,
+            )?;
+            Self::SentinelSetUpdated {
+                epoch: get_u64(&tokens, 0)?,
+                sentinels: get_addresses(&tokens, 1)?,
+            }
+        } else if topic == &*REGISTRATION_UPDATED_TOPIC {
+            let tokens = eth_abi_decode(
+                &[
+                    EthAbiParamType::Address,
+                    EthAbiParamType::Uint(16),
+                    EthAbiParamType::Uint(16),
+                ],
+                &log.data,
+            )?;
+            Self::RegistrationUpdated {
+                sentinel: get_indexed_address(log)?,
+                owner: get_address(&tokens, 0)?,
+                starting_epoch: get_u64(&tokens, 1)?,
+                ending_epoch: get_u64(&tokens, 2)?,
+            }
+        } else if topic == &*SENTINEL_SLASHED_TOPIC {
+            let tokens = eth_abi_decode(&[EthAbiParamType::Uint(16), EthAbiParamType::Uint(256)], &log.data)?;
+            Self::SentinelSlashed {
+                sentinel: get_indexed_address(log)?,
+                epoch: get_u64(&tokens, 0)?,
+                amount: get_u256(&tokens, 1)?,
+            }
+        } else if topic == &*SENTINEL_DEREGISTERED_TOPIC {
+            let tokens = eth_abi_decode(&[EthAbiParamType::Uint(16)], &log.data)?;
+            Self::SentinelDeregistered {
+                sentinel: get_indexed_address(log)?,
+                epoch: get_u64(&tokens, 0)?,
+            }
+        } else {
+            return Ok(None);
+        };
+
+        Ok(Some(event))
+    }
+
+    pub fn concerns(&self, sentinel: &EthAddress) -> bool {
+        match self {
+            Self::SentinelSetUpdated { .. } => true,
+            Self::RegistrationUpdated { sentinel: s, .. }
+            | Self::SentinelSlashed { sentinel: s, .. }
+            | Self::SentinelDeregistered { sentinel: s, .. } => s == sentinel,
+        }
+    }
+
+    pub fn is_critical(&self) -> bool {
+        matches!(self, Self::SentinelSlashed { .. } | Self::SentinelDeregistered { .. })
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Constructor, Deref)]
+pub struct GovernanceEvents(Vec<GovernanceEvent>);
+
+impl GovernanceEvents {
+    // NOTE: A log which cannot be decoded is skipped rather than erroring, lest a single malformed
+    // (or since changed) event stall the processing of the governance contract's chain.
+    pub fn from_sub_mat(sub_mat: &EthSubmissionMaterial, governance_address: &EthAddress) -> Self {
+        let mut events = vec![];
+        for receipt in sub_mat.receipts.iter() {
+            for log in receipt.logs.iter().filter(|l| l.is_from_address(governance_address)) {
+                match GovernanceEvent::from_log(log) {
+                    Ok(Some(event)) => events.push(event),
+                    Ok(None) => (),
+                    Err(e) => warn!("could not decode governance event from {governance_address}: {e}"),
+                };
+            }
+        }
+        Self(events)
+    }
+}
+
+impl From<Vec<GovernanceEvents>> for GovernanceEvents {
+    fn from(vec_of_governance_events: Vec<GovernanceEvents>) -> Self {
+        Self(vec_of_governance_events.into_iter().flat_map(|e| e.0).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethabi::encode as eth_abi_encode;
+
+    use super::*;
+
+    fn get_sample_log(topics: Vec<EthHash>, data: Vec<EthAbiToken>) -> EthLog {
+        EthLog::new(EthAddress::from_low_u64_be(1), topics, eth_abi_encode(&data))
+    }
+
+    fn address_to_topic(a: &EthAddress) -> EthHash {
+        EthHash::from(*a)
+    }
+
+    #[test]
+    fn should_decode_sentinel_set_updated_event() {
+        let sentinels = vec![EthAddress::from_low_u64_be(2), EthAddress::from_low_u64_be(3)];
+        let log = get_sample_log(vec![*SENTINEL_SET_UPDATED_TOPIC], vec![
+            EthAbiToken::Uint(U256::from(7)),
+            EthAbiToken::Array(sentinels.iter().cloned().map(EthAbiToken::Address).collect()),
+        ]);
+        let result = GovernanceEvent::from_log(&log).unwrap().unwrap();
+        assert_eq!(result, GovernanceEvent::SentinelSetUpdated { epoch: 7, sentinels });
+    }
+
+    #[test]
+    fn should_decode_sentinel_slashed_event() {
+        let sentinel = EthAddress::from_low_u64_be(1337);
+        let log = get_sample_log(vec![*SENTINEL_SLASHED_TOPIC, address_to_topic(&sentinel)], vec![
+            EthAbiToken::Uint(U256::from(3)),
+            EthAbiToken::Uint(U256::from(1000)),
+        ]);
+        let result = GovernanceEvent::from_log(&log).unwrap().unwrap();
+        assert_eq!(result, GovernanceEvent::SentinelSlashed {
+            sentinel,
+            epoch: 3,
+            amount: U256::from(1000),
+        });
+        assert!(result.concerns(&sentinel));
+        assert!(!result.concerns(&EthAddress::zero()));
+        assert!(result.is_critical());
+    }
+
+    #[test]
+    fn should_ignore_other_events() {
+        let log = get_sample_log(vec![EthHash::zero()], vec![]);
+        assert!(GovernanceEvent::from_log(&log).unwrap().is_none());
+    }
+
+    #[test]
+    fn should_error_if_indexed_sentinel_is_missing() {
+        let log = get_sample_log(vec![*SENTINEL_DEREGISTERED_TOPIC], vec![EthAbiToken::Uint(U256::from(
+            3,
+        ))]);
+        assert!(GovernanceEvent::from_log(&log).is_err());
+    }
+}
@@ -0,0 +1,176 @@
+use common::{Byte, DatabaseInterface, MIN_DATA_SENSITIVITY_LEVEL};
+use derive_getters::Getters;
+use ethereum_types::Address as EthAddress;
+use serde::{Deserialize, Serialize};
+
+use super::{GovernanceEvent, GovernanceEvents};
+use crate::{
+    db_utils::{DbKey, DbUtilsT, SentinelDbKeys, SentinelDbUtils},
+    SentinelError,
+};
+
+const MAX_NUM_GOVERNANCE_EVENTS: usize = 10;
+
+// NOTE: What the core has learnt about this sentinel from the events of the governance contract,
+// along with the most recent of those events. Only those canonical blocks beyond the latest one
+// applied are applied, so that reprocessing a block does not apply its events twice.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct GovernanceState {
+    latest_block_num: u64,
+    sentinel_set_epoch: Option<u64>,
+    in_sentinel_set: Option<bool>,
+    registration_ending_epoch: Option<u64>,
+    slashed: bool,
+    deregistered: bool,
+    events: Vec<GovernanceEvent>,
+}
+
+impl DbUtilsT for GovernanceState {
+    fn key(&self) -> Result<DbKey, SentinelError> {
+        Ok(SentinelDbKeys::get_governance_state_db_key())
+    }
+
+    fn sensitivity() -> Option<Byte> {
+        MIN_DATA_SENSITIVITY_LEVEL
+    }
+
+    fn from_bytes(bytes: &[Byte]) -> Result<Self, SentinelError> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
+
+impl GovernanceState {
+    pub fn get<D: DatabaseInterface>(db: &D) -> Self {
+        let key = SentinelDbKeys::get_governance_state_db_key();
+        match Self::get_from_db(&SentinelDbUtils::new(db), &key) {
+            Ok(s) => s,
+            Err(_) => {
+                debug!("no governance state in db, using default");
+                Self::default()
+            },
+        }
+    }
+
+    pub fn save<D: DatabaseInterface>(&self, db: &D) -> Result<(), SentinelError> {
+        self.update_in_db(&SentinelDbUtils::new(db))
+    }
+
+    // NOTE: Returns those events which concern the given sentinel & which were applied.
+    pub fn apply(&mut self, sentinel: &EthAddress, block_num: u64, events: &GovernanceEvents) -> GovernanceEvents {
+        if block_num <= self.latest_block_num {
+            debug!("skipping governance events from block {block_num}, since it has already been applied");
+            return GovernanceEvents::default();
+        };
+        self.latest_block_num = block_num;
+
+        let applied = events
+            .iter()
+            .filter(|e| e.concerns(sentinel))
+            .cloned()
+            .collect::<Vec<GovernanceEvent>>();
+        applied.iter().for_each(|e| self.apply_event(sentinel, e));
+        GovernanceEvents::new(applied)
+    }
+
+    fn apply_event(&mut self, sentinel: &EthAddress, event: &GovernanceEvent) {
+        match event {
+            GovernanceEvent::SentinelSetUpdated { epoch, sentinels } => {
+                self.sentinel_set_epoch = Some(*epoch);
+                self.in_sentinel_set = Some(sentinels.contains(sentinel));
+                // NOTE: The set may be large, and so these events are not kept.
+                return;
+            },
+            GovernanceEvent::RegistrationUpdated {
+                owner, ending_epoch, ..
+            } => {
+                self.deregistered = owner.is_zero();
+                self.registration_ending_epoch = Some(*ending_epoch);
+            },
+            GovernanceEvent::SentinelDeregistered { .. } => {
+                self.deregistered = true;
+                self.registration_ending_epoch = None;
+            },
+            GovernanceEvent::SentinelSlashed { .. } => self.slashed = true,
+        };
+
+        self.events.push(event.clone());
+        if self.events.len() > MAX_NUM_GOVERNANCE_EVENTS {
+            self.events.remove(0);
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::get_test_database;
+    use ethereum_types::U256;
+
+    use super::*;
+
+    fn get_sample_slashing(sentinel: EthAddress, epoch: u64) -> GovernanceEvent {
+        GovernanceEvent::SentinelSlashed {
+            sentinel,
+            epoch,
+            amount: U256::from(1),
+        }
+    }
+
+    #[test]
+    fn should_apply_governance_events_concerning_sentinel() {
+        let sentinel = EthAddress::from_low_u64_be(1337);
+        let other = EthAddress::from_low_u64_be(1338);
+        let events = GovernanceEvents::new(vec![
+            GovernanceEvent::SentinelSetUpdated {
+                epoch: 5,
+                sentinels: vec![other],
+            },
+            get_sample_slashing(other, 5),
+            GovernanceEvent::SentinelDeregistered { sentinel, epoch: 5 },
+        ]);
+        let mut state = GovernanceState::default();
+        let result = state.apply(&sentinel, 1, &events);
+        assert_eq!(result.len(), 2);
+        assert_eq!(state.sentinel_set_epoch(), &Some(5));
+        assert_eq!(state.in_sentinel_set(), &Some(false));
+        assert!(state.deregistered());
+        assert!(!state.slashed());
+        assert_eq!(state.events().len(), 1);
+    }
+
+    #[test]
+    fn should_not_apply_events_from_already_applied_blocks() {
+        let sentinel = EthAddress::from_low_u64_be(1337);
+        let events = GovernanceEvents::new(vec![get_sample_slashing(sentinel, 5)]);
+        let mut state = GovernanceState::default();
+        assert_eq!(state.apply(&sentinel, 10, &events).len(), 1);
+        assert!(state.apply(&sentinel, 10, &events).is_empty());
+        assert_eq!(state.events().len(), 1);
+    }
+
+    #[test]
+    fn should_only_keep_max_num_events() {
+        let sentinel = EthAddress::from_low_u64_be(1337);
+        let mut state = GovernanceState::default();
+        for i in 0..MAX_NUM_GOVERNANCE_EVENTS as u64 + 1 {
+            let events = GovernanceEvents::new(vec![get_sample_slashing(sentinel, i)]);
+            state.apply(&sentinel, i + 1, &events);
+        }
+        assert_eq!(state.events().len(), MAX_NUM_GOVERNANCE_EVENTS);
+        assert_eq!(state.events()[0], get_sample_slashing(sentinel, 1));
+    }
+
+    #[test]
+    fn should_save_and_get_governance_state() {
+        let db = get_test_database();
+        let sentinel = EthAddress::from_low_u64_be(1337);
+        let mut state = GovernanceState::default();
+        state.apply(
+            &sentinel,
+            1,
+            &GovernanceEvents::new(vec![get_sample_slashing(sentinel, 1)]),
+        );
+        state.save(&db).unwrap();
+        assert_eq!(GovernanceState::get(&db), state);
+    }
+}
@@ -0,0 +1,9 @@
+mod error;
+mod governance_event;
+mod governance_state;
+
+pub use self::{
+    error::GovernanceError,
+    governance_event::{GovernanceEvent, GovernanceEvents},
+    governance_state::GovernanceState,
+};
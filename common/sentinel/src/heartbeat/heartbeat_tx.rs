@@ -0,0 +1,70 @@
+use common::Bytes;
+
+use crate::{SentinelError, SentinelStatus};
+
+const BASE_TX_GAS: usize = 21_000;
+const GAS_PER_ZERO_DATA_BYTE: usize = 4;
+const GAS_PER_NON_ZERO_DATA_BYTE: usize = 16;
+const FLOOR_GAS_PER_ZERO_DATA_BYTE: usize = 10;
+const FLOOR_GAS_PER_NON_ZERO_DATA_BYTE: usize = 40;
+
+fn get_data_gas(data: &[u8], gas_per_zero_byte: usize, gas_per_non_zero_byte: usize) -> usize {
+    data.iter()
+        .map(|b| {
+            if *b == 0 {
+                gas_per_zero_byte
+            } else {
+                gas_per_non_zero_byte
+            }
+        })
+        .sum()
+}
+
+// NOTE: Since EIP-7623 (Prague) a tx must pay at least the floor cost of its calldata, regardless
+// of how little gas its execution uses. Using the greater of the two on every chain only costs a
+// higher limit on those which predate it, since unused gas is refunded.
+fn get_intrinsic_gas(data: &[u8]) -> usize {
+    let standard = BASE_TX_GAS + get_data_gas(data, GAS_PER_ZERO_DATA_BYTE, GAS_PER_NON_ZERO_DATA_BYTE);
+    let floor = BASE_TX_GAS + get_data_gas(data, FLOOR_GAS_PER_ZERO_DATA_BYTE, FLOOR_GAS_PER_NON_ZERO_DATA_BYTE);
+    standard.max(floor)
+}
+
+pub fn get_heartbeat_tx_data(status: &SentinelStatus) -> Result<Bytes, SentinelError> {
+    debug!("getting heartbeat tx data...");
+    Ok(serde_json::to_vec(status)?)
+}
+
+// NOTE: Returns the gas limit for an on chain heartbeat without a configured one, which is then
+// submitted via the tx manager, since that handles its nonce & fees. The node's estimate includes any
+// execution gas should the target be a contract, but is never used if less than the intrinsic gas.
+pub fn get_heartbeat_gas_limit(estimated_gas: u64, data: &[u8]) -> usize {
+    (estimated_gas as usize).max(get_intrinsic_gas(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_heartbeat_tx_data() {
+        let status = SentinelStatus::default();
+        let data = get_heartbeat_tx_data(&status).unwrap();
+        assert_eq!(data, serde_json::to_vec(&status).unwrap());
+    }
+
+    #[test]
+    fn should_use_greater_of_estimate_and_intrinsic_gas_for_heartbeat_tx() {
+        let data = [1, 2];
+        assert_eq!(get_heartbeat_gas_limit(50_000, &data), 50_000);
+        assert_eq!(get_heartbeat_gas_limit(21_000, &data), get_intrinsic_gas(&data));
+    }
+
+    #[test]
+    fn should_get_intrinsic_gas_for_data() {
+        assert_eq!(get_intrinsic_gas(&[]), BASE_TX_GAS);
+        assert_eq!(
+            get_intrinsic_gas(&[0, 1, 2]),
+            BASE_TX_GAS + FLOOR_GAS_PER_ZERO_DATA_BYTE + 2 * FLOOR_GAS_PER_NON_ZERO_DATA_BYTE
+        );
+    }
+}
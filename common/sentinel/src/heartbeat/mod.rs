@@ -0,0 +1,3 @@
+mod heartbeat_tx;
+
+pub use self::heartbeat_tx::{get_heartbeat_gas_limit, get_heartbeat_tx_data};
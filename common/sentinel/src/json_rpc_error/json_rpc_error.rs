@@ -0,0 +1,102 @@
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as Json};
+
+use super::JsonRpcErrorCode;
+use crate::{SentinelError, WebSocketMessagesError};
+
+// NOTE: The `error` object of a JSON-RPC response. The `data` field always contains the `kind` of
+// error, a stable name for its code, along with any structured `details` available, so that
+// clients need not pattern match on error messages.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Getters)]
+pub struct JsonRpcError {
+    code: i64,
+    message: String,
+    data: Json,
+}
+
+impl JsonRpcError {
+    pub fn new(code: JsonRpcErrorCode, message: &str, details: Option<Json>) -> Self {
+        Self {
+            code: code.code(),
+            message: message.to_string(),
+            data: json!({ "kind": code, "details": details }),
+        }
+    }
+
+    pub fn method_not_found(method: &str) -> Self {
+        Self::new(
+            JsonRpcErrorCode::MethodNotFound,
+            &format!("unknown method: {method}"),
+            None,
+        )
+    }
+
+    // NOTE: Json errors are put in the `data` field, lest we end up double stringifying them.
+    fn from_json(code: JsonRpcErrorCode, j: &Json) -> Self {
+        let message = match j.get("error").and_then(Json::as_str) {
+            Some(s) => s.to_string(),
+            None => "see error data for details".to_string(),
+        };
+        Self::new(code, &message, Some(j.clone()))
+    }
+}
+
+impl From<&WebSocketMessagesError> for JsonRpcError {
+    fn from(e: &WebSocketMessagesError) -> Self {
+        let code = JsonRpcErrorCode::from(e);
+        let s = e.to_string();
+        match e {
+            WebSocketMessagesError::Json(j) => Self::from_json(code, j),
+            // NOTE: We can't actually _get_ the exceptions from JNI on the core side of things, we
+            // can only ask the JNI env to print them to console for us. So alas we can't really do
+            // much. We can't even get a string to manually parse for common errors etc. As such,
+            // this is literally the best we can do.
+            _ if s.contains("Java exception was thrown") => Self::new(
+                JsonRpcErrorCode::JavaException,
+                "a java exception was thrown - please see core logs for details",
+                None,
+            ),
+            _ => Self::new(code, &s, serde_json::to_value(e).ok()),
+        }
+    }
+}
+
+impl From<&SentinelError> for JsonRpcError {
+    fn from(e: &SentinelError) -> Self {
+        let code = JsonRpcErrorCode::from(e);
+        match e {
+            SentinelError::Json(j) => Self::from_json(code, j),
+            SentinelError::WebSocketMessages(e) => Self::from(e),
+            SentinelError::ChainError(e) => Self::new(code, &e.to_string(), serde_json::to_value(e).ok()),
+            _ => Self::new(code, &e.to_string(), None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_put_json_errors_in_data_field() {
+        let j = json!({ "glossary": {}, "error": "could not validate signature" });
+        let result = JsonRpcError::from(&SentinelError::Json(j.clone()));
+        assert_eq!(*result.code(), JsonRpcErrorCode::InvalidDebugSignature.code());
+        assert_eq!(result.message(), "could not validate signature");
+        assert_eq!(result.data()["kind"], json!("INVALID_DEBUG_SIGNATURE"));
+        assert_eq!(result.data()["details"], j);
+    }
+
+    #[test]
+    fn should_include_structured_details_for_websocket_errors() {
+        let e = WebSocketMessagesError::NotEnoughArgs {
+            got: 1,
+            expected: 2,
+            args: vec!["arg".to_string()],
+        };
+        let result = JsonRpcError::from(&e);
+        assert_eq!(*result.code(), JsonRpcErrorCode::InvalidParams.code());
+        assert_eq!(result.data()["details"]["NotEnoughArgs"]["expected"], json!(2));
+    }
+}
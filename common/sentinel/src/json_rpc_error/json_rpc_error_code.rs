@@ -0,0 +1,328 @@
+use common_eth::ChainError;
+use serde::{Deserialize, Serialize};
+
+use crate::{AuditLogError, DebugCommandQueueError, SentinelError, WebSocketMessagesError};
+
+// NOTE: These codes are part of the RPC server's public interface, and so must never be changed
+// or reused once released. New codes are to be added to the end of their range. The standard
+// JSON-RPC 2.0 codes are used where they fit, with the application defined codes grouped as
+// follows:
+//
+// 1xxx: core connectivity & rpc access
+// 2xxx: chain & block processing
+// 3xxx: debug commands & core state
+// 4xxx: configuration & environment
+// 5xxx: eth rpc endpoints
+// 6xxx: syncers & internal channels
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum JsonRpcErrorCode {
+    /// Invalid JSON was received by the server.
+    ParseError          = -32700,
+    /// The JSON sent is not a valid request object.
+    InvalidRequest      = -32600,
+    /// The method does not exist.
+    MethodNotFound      = -32601,
+    /// Missing, too few or malformed method parameters.
+    InvalidParams       = -32602,
+    /// Any error not covered by a more specific code.
+    Internal            = -32603,
+
+    /// No core is connected to the RPC server.
+    NoCore              = 1000,
+    /// The core, or an endpoint, took too long to respond.
+    Timeout             = 1001,
+    /// A java exception was thrown in the core - see the core's logs for details.
+    JavaException       = 1002,
+    /// The core panicked - see the core's logs for details.
+    CorePanicked        = 1003,
+    /// The core responded with something other than what was expected.
+    UnexpectedResponse  = 1004,
+    /// The core does not handle the message it was sent.
+    UnhandledMessage    = 1005,
+    /// The request could not be authenticated.
+    Unauthorized        = 1006,
+    /// The authenticated client's role does not permit calling the method.
+    Forbidden           = 1007,
+    /// The client has exceeded its rate limit.
+    RateLimited         = 1008,
+
+    /// The chain for the given network is not initialized.
+    NotInitialized      = 2000,
+    /// The chain for the given network is already initialized.
+    AlreadyInitialized  = 2001,
+    /// The submitted block's parent is not in the chain.
+    NoParent            = 2002,
+    /// The submitted block is already in the chain.
+    BlockAlreadyInDb    = 2003,
+    /// No canon block candidate could be found.
+    NoCanonBlockCandidates = 2004,
+    /// More than one canon block candidate was found.
+    TooManyCanonBlockCandidates = 2005,
+    /// The submitted block failed validation.
+    InvalidBlock        = 2006,
+    /// The submitted block's receipts failed validation.
+    InvalidReceipts     = 2007,
+    /// The chain cannot be reset with the given parameters.
+    CannotReset         = 2008,
+    /// The requested block number is not in the chain.
+    BlockNumNotInChain  = 2009,
+    /// The given network is not supported.
+    UnsupportedNetwork  = 2010,
+    /// No block could be found.
+    NoBlock             = 2011,
+    /// A chain database operation failed.
+    ChainDb             = 2012,
+    /// The submitted block's transactions failed validation.
+    InvalidTransactions = 2013,
+    /// A governance event could not be decoded.
+    Governance          = 2014,
+    /// A challenge is unknown to the core, no longer pending, or could not be decoded.
+    Challenge           = 2015,
+    /// Any other chain error.
+    Chain               = 2099,
+
+    /// Not enough valid debug signatures were provided for the debug command.
+    InvalidDebugSignature = 3000,
+    /// No queued debug command exists with the given id.
+    QueuedDebugCommandNotFound = 3001,
+    /// The queued debug command's time lock has not yet expired.
+    QueuedDebugCommandNotYetActive = 3002,
+    /// The debug command cannot be time locked.
+    DebugCommandNotTimeLockable = 3003,
+    /// No audit log entry exists at the given index.
+    AuditLogEntryNotFound = 3004,
+    /// The audit log's hash chain is broken.
+    AuditLogBrokenChain = 3005,
+    /// The core state archive is invalid or could not be imported.
+    CoreStateArchive    = 3006,
+    /// The database failed an integrity check.
+    DbIntegrity         = 3007,
+    /// The database key already exists.
+    KeyExists           = 3008,
+
+    /// The sentinel configuration is invalid.
+    Config              = 4000,
+    /// The environment is missing required values.
+    Env                 = 4001,
+
+    /// An eth rpc endpoint call failed.
+    EthRpc              = 5000,
+    /// No gas price could be obtained for the network.
+    NoGasPrice          = 5001,
+    /// No nonce could be obtained for the network.
+    NoNonce             = 5002,
+    /// A tx submitted by the sentinel could not be managed, eg due to its nonce already being in use.
+    TxManager           = 5003,
+    /// The sentinel's registration could not be read from the registration manager, or it has none.
+    Registration        = 5004,
+
+    /// A syncer needs restarting.
+    SyncerRestart       = 6000,
+    /// An internal channel failed.
+    Channel             = 6001,
+}
+
+impl JsonRpcErrorCode {
+    pub fn code(&self) -> i64 {
+        *self as i64
+    }
+}
+
+impl From<&ChainError> for JsonRpcErrorCode {
+    fn from(e: &ChainError) -> Self {
+        match e {
+            ChainError::NoParent(_) => Self::NoParent,
+            ChainError::NotInitialized(_) => Self::NotInitialized,
+            ChainError::InvalidBlock(..) => Self::InvalidBlock,
+            ChainError::CannotReset { .. } => Self::CannotReset,
+            ChainError::InvalidReceipts(..) => Self::InvalidReceipts,
+            ChainError::InvalidTransactions(..) => Self::InvalidTransactions,
+            ChainError::BlockAlreadyInDb { .. } => Self::BlockAlreadyInDb,
+            ChainError::AlreadyInitialized(_) => Self::AlreadyInitialized,
+            ChainError::BlockNumNotInChain(..) => Self::BlockNumNotInChain,
+            ChainError::NoCanonBlockCandidates => Self::NoCanonBlockCandidates,
+            ChainError::TooManyCanonBlockCandidates(_) => Self::TooManyCanonBlockCandidates,
+            ChainError::DbGet(_) | ChainError::DbInsert(_) | ChainError::DbDelete(_) => Self::ChainDb,
+            ChainError::MetadataChainIdError(_) => Self::UnsupportedNetwork,
+            ChainError::NoHash
+            | ChainError::SerdeJson(_)
+            | ChainError::NoParentHash
+            | ChainError::NoBlockNumber
+            | ChainError::ExpectedABlock
+            | ChainError::FailedToInsert(_)
+            | ChainError::CannotCreatePk(_)
+            | ChainError::NoChainBlockData(_)
+            | ChainError::CouldNotPutPkInDb
+            | ChainError::CouldNotGetChainIdBytes(_)
+            | ChainError::CouldNotGetPrivateKeyDbKey(_)
+            | ChainError::ExpectedChainBlockDataAtIndex(_) => Self::Chain,
+        }
+    }
+}
+
+impl From<&WebSocketMessagesError> for JsonRpcErrorCode {
+    fn from(e: &WebSocketMessagesError) -> Self {
+        match e {
+            WebSocketMessagesError::NoParent(_) => Self::NoParent,
+            WebSocketMessagesError::Panicked => Self::CorePanicked,
+            WebSocketMessagesError::NoBlock { .. } => Self::NoBlock,
+            WebSocketMessagesError::JavaDb(_) => Self::JavaException,
+            WebSocketMessagesError::Timedout(_) => Self::Timeout,
+            WebSocketMessagesError::ChainError(e) => Self::from(e),
+            WebSocketMessagesError::Json(j) => Self::from_json(j),
+            WebSocketMessagesError::NotInitialized(_) => Self::NotInitialized,
+            WebSocketMessagesError::Unhandled(_) => Self::UnhandledMessage,
+            WebSocketMessagesError::Unsupported(_) => Self::UnsupportedNetwork,
+            WebSocketMessagesError::BlockAlreadyInDb { .. } => Self::BlockAlreadyInDb,
+            WebSocketMessagesError::UnexpectedResponse(_) => Self::UnexpectedResponse,
+            WebSocketMessagesError::CodedSentinelError { code, .. } => *code,
+            WebSocketMessagesError::Hex(_)
+            | WebSocketMessagesError::ParseInt(_)
+            | WebSocketMessagesError::CannotCreate(_)
+            | WebSocketMessagesError::ParseNetworkId(_)
+            | WebSocketMessagesError::NeedMoreArgs { .. }
+            | WebSocketMessagesError::NotEnoughArgs { .. }
+            | WebSocketMessagesError::UnrecognizedNetworkId(_) => Self::InvalidParams,
+            WebSocketMessagesError::Custom(_)
+            | WebSocketMessagesError::MaxDelta { .. }
+            | WebSocketMessagesError::NoneError { .. }
+            | WebSocketMessagesError::WrongField { .. }
+            | WebSocketMessagesError::CommonError(_)
+            | WebSocketMessagesError::SentinelError(_)
+            | WebSocketMessagesError::NoInclusionProof
+            | WebSocketMessagesError::CannotConvert { .. }
+            | WebSocketMessagesError::InsufficientMcids { .. } => Self::Internal,
+        }
+    }
+}
+
+impl From<&SentinelError> for JsonRpcErrorCode {
+    fn from(e: &SentinelError) -> Self {
+        match e {
+            SentinelError::NoCore => Self::NoCore,
+            SentinelError::Json(j) => Self::from_json(j),
+            SentinelError::NoParent(_) => Self::NoParent,
+            SentinelError::ChainError(e) => Self::from(e),
+            SentinelError::NoNonce(_) => Self::NoNonce,
+            SentinelError::NoGasPrice(_) => Self::NoGasPrice,
+            SentinelError::KeyExists(_) => Self::KeyExists,
+            SentinelError::SyncerRestart(_) => Self::SyncerRestart,
+            SentinelError::WebSocketMessages(e) => Self::from(e),
+            SentinelError::BlockAlreadyInDb(_) => Self::BlockAlreadyInDb,
+            SentinelError::CoreStateArchive(_) => Self::CoreStateArchive,
+            SentinelError::Timeout(_) | SentinelError::Timedout(_) => Self::Timeout,
+            SentinelError::NoBlock(_) | SentinelError::NoLatestBlockInfo(_) => Self::NoBlock,
+            SentinelError::JniError(_) | SentinelError::JavaExceptionOccurred => Self::JavaException,
+            SentinelError::DbIntegrity(_) | SentinelError::InvalidDbIntegrity(_) => Self::DbIntegrity,
+            SentinelError::DebugCommandQueue(e) => match e {
+                DebugCommandQueueError::NotFound(_) => Self::QueuedDebugCommandNotFound,
+                DebugCommandQueueError::NotYetActive { .. } => Self::QueuedDebugCommandNotYetActive,
+                DebugCommandQueueError::NotTimeLockable(_) => Self::DebugCommandNotTimeLockable,
+            },
+            SentinelError::AuditLog(e) => match e {
+                AuditLogError::NotFound(_) => Self::AuditLogEntryNotFound,
+                AuditLogError::BrokenChain(_) => Self::AuditLogBrokenChain,
+            },
+            SentinelError::Config(_)
+            | SentinelError::SentinelConfig(_)
+            | SentinelError::InvalidFrequency { .. }
+            | SentinelError::ChainIdNotInConfig(_) => Self::Config,
+            SentinelError::Env(_) | SentinelError::DotEnv(_) => Self::Env,
+            SentinelError::JsonRpc(_) | SentinelError::Endpoint(_) | SentinelError::QuicknodeNotAvailable => {
+                Self::EthRpc
+            },
+            SentinelError::TxManager(_) => Self::TxManager,
+            SentinelError::Registration(_) => Self::Registration,
+            SentinelError::Governance(_) => Self::Governance,
+            SentinelError::Challenges(_) => Self::Challenge,
+            SentinelError::Hex(_)
+            | SentinelError::Base64(_)
+            | SentinelError::ParseInt(_)
+            | SentinelError::RustCHex(_)
+            | SentinelError::NetworkId(_)
+            | SentinelError::Utf8Error(_)
+            | SentinelError::FromStrRadix(_)
+            | SentinelError::MetadataChainId(_) => Self::InvalidParams,
+            SentinelError::Receiver(_)
+            | SentinelError::EthRpcChannel(_)
+            | SentinelError::SyncerChannel(_)
+            | SentinelError::OneshotReceiver(_)
+            | SentinelError::WebSocketChannel(_)
+            | SentinelError::TxManagerChannel(_)
+            | SentinelError::NetworkManagerChannel(_)
+            | SentinelError::BroadcastChannelMessages(_) => Self::Channel,
+            SentinelError::IO(_)
+            | SentinelError::Axum(_)
+            | SentinelError::Time(_)
+            | SentinelError::Common(_)
+            | SentinelError::Custom(_)
+            | SentinelError::SigInt(_)
+            | SentinelError::EthAbi(_)
+            | SentinelError::Logger(_)
+            | SentinelError::MongoDB(_)
+            | SentinelError::Reqwest(_)
+            | SentinelError::TryLock(_)
+            | SentinelError::Batching(_)
+            | SentinelError::SerdeJson(_)
+            | SentinelError::TokioJoin(_)
+            | SentinelError::WarpHyper(_)
+            | SentinelError::FileLogger(_)
+            | SentinelError::SignedEvent(_)
+            | SentinelError::PoisonedLock(_)
+            | SentinelError::SentinelStatusError(_) => Self::Internal,
+        }
+    }
+}
+
+impl JsonRpcErrorCode {
+    // NOTE: Json errors come from the debug signers crate, whose signature validation failures
+    // always include a glossary explaining the signature info they contain.
+    fn from_json(j: &serde_json::Value) -> Self {
+        if j.get("glossary").is_some() {
+            Self::InvalidDebugSignature
+        } else {
+            Self::Internal
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_metadata::MetadataChainId;
+
+    use super::*;
+
+    #[test]
+    fn should_map_chain_errors_to_codes() {
+        let e = ChainError::NotInitialized(MetadataChainId::EthereumMainnet);
+        assert_eq!(JsonRpcErrorCode::from(&e), JsonRpcErrorCode::NotInitialized);
+        let e = ChainError::TooManyCanonBlockCandidates(2);
+        assert_eq!(JsonRpcErrorCode::from(&e).code(), 2005);
+    }
+
+    #[test]
+    fn should_preserve_code_of_sentinel_error_sent_over_websocket() {
+        let e = SentinelError::DebugCommandQueue(DebugCommandQueueError::NotFound(1));
+        let expected_code = JsonRpcErrorCode::QueuedDebugCommandNotFound;
+        let ws_error = WebSocketMessagesError::from(e);
+        let s = serde_json::to_string(&ws_error).unwrap();
+        let result = serde_json::from_str::<WebSocketMessagesError>(&s).unwrap();
+        assert_eq!(JsonRpcErrorCode::from(&result), expected_code);
+    }
+
+    #[test]
+    fn should_map_debug_signature_errors_to_code() {
+        let j = serde_json::json!({ "glossary": {}, "error": "could not validate signature" });
+        assert_eq!(
+            JsonRpcErrorCode::from(&SentinelError::Json(j)),
+            JsonRpcErrorCode::InvalidDebugSignature
+        );
+    }
+
+    #[test]
+    fn should_serialize_code_names() {
+        let s = serde_json::to_string(&JsonRpcErrorCode::TooManyCanonBlockCandidates).unwrap();
+        assert_eq!(s, "\"TOO_MANY_CANON_BLOCK_CANDIDATES\"");
+    }
+}
@@ -0,0 +1,4 @@
+mod json_rpc_error;
+mod json_rpc_error_code;
+
+pub use self::{json_rpc_error::JsonRpcError, json_rpc_error_code::JsonRpcErrorCode};
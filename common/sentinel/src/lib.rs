@@ -1,18 +1,27 @@
+mod audit_log;
 mod balances;
 mod batching;
 mod bpm;
 mod call_core;
+mod challenges;
 mod config;
 mod constants;
 mod core_state;
+mod core_state_archive;
 mod db_integrity;
 mod db_utils;
+mod debug_command_queue;
 mod endpoints;
 mod env;
 mod error;
+mod eth_fees;
 mod eth_rpc_calls;
 mod eth_rpc_channels;
+mod evm_chains;
 mod flatten_join_handle;
+mod governance;
+mod heartbeat;
+mod json_rpc_error;
 mod latest_block_info;
 mod logging;
 mod merkle;
@@ -24,21 +33,36 @@ mod signed_events;
 mod status;
 mod sync_state;
 mod test_utils;
+mod tx_manager;
 mod utils;
 
 pub use self::{
+    audit_log::{AuditLog, AuditLogEntry, AuditLogError, AuditLogOutcome, AuditLogSignatory, MAX_AUDIT_LOG_PAGE_SIZE},
     balances::{Balance, Balances},
     batching::Batch,
     bpm::{Bpm, BpmInfo, Bpms},
     call_core::call_core,
+    challenges::{Challenge, ChallengeInfo, ChallengeResponse, ChallengeState, Challenges, ChallengesError},
     config::{
+        ChallengeResponderConfig,
         ConfiguredEvent,
         ConfiguredEvents,
+        HealthConfig,
+        HeartbeatConfig,
+        HeartbeatTarget,
         LogConfig,
+        LogFormat,
         NetworkConfig,
+        RegistrationConfig,
+        RpcAuthConfig,
+        RpcClientConfig,
+        RpcRole,
+        RpcTlsConfig,
         SentinelConfig,
+        SentinelConfigDiff,
         SentinelConfigError,
         SentinelCoreConfig,
+        TxManagerConfig,
     },
     constants::{
         DEFAULT_SLEEP_TIME,
@@ -48,50 +72,87 @@ pub use self::{
         NATIVE_PROTOCOL_ID,
     },
     core_state::CoreState,
+    core_state_archive::{
+        CoreStateArchive,
+        CoreStateArchiveEntry,
+        CoreStateArchiveError,
+        CoreStateArchiveVersion,
+        EncryptedCoreStateArchive,
+        TrustedArchiveSigner,
+    },
     db_integrity::{DbIntegrity, DbIntegrityError},
     db_utils::{DbKey, DbUtilsT, SentinelDbUtils},
+    debug_command_queue::{DebugCommandQueue, DebugCommandQueueError, QueuedDebugCommand},
     endpoints::{EndpointError, Endpoints},
-    env::Env,
+    env::{Env, EnvError},
     error::SentinelError,
+    eth_fees::EthFees,
     eth_rpc_calls::{
+        estimate_gas,
         eth_call,
         get_block,
         get_chain_id,
+        get_code,
+        get_eip_1559_fees,
         get_eth_balance,
         get_gas_price,
         get_latest_block_num,
         get_nonce,
+        get_raw_txs,
         get_receipts,
         get_sub_mat,
+        get_tx_receipt,
         push_tx,
     },
     eth_rpc_channels::{EthRpcChannels, EthRpcSenders},
+    evm_chains::EvmChains,
     flatten_join_handle::flatten_join_handle,
+    governance::{GovernanceError, GovernanceEvent, GovernanceEvents, GovernanceState},
+    heartbeat::{get_heartbeat_gas_limit, get_heartbeat_tx_data},
+    json_rpc_error::{JsonRpcError, JsonRpcErrorCode},
     latest_block_info::{LatestBlockInfo, LatestBlockInfos},
-    logging::{init_logger, LogLevel},
+    logging::{init_logger, LogContext, LogLevel},
     merkle::{MerkleError, MerkleProof, MerkleTree},
     messages::{
         BroadcastChannelMessages,
+        CorrelationId,
+        EthRpcBroadcastChannelMessages,
         EthRpcMessages,
+        NetworkManagerMessages,
         Responder,
         RpcServerBroadcastChannelMessages,
         SyncerBroadcastChannelMessages,
         SyncerMessages,
+        TxManagerMessages,
         WebSocketMessages,
         WebSocketMessagesEncodable,
         WebSocketMessagesEncodableDbOps,
+        WebSocketMessagesEnvelope,
         WebSocketMessagesError,
         WebSocketMessagesInitArgs,
         WebSocketMessagesProcessBatchArgs,
         WebSocketMessagesResetChainArgs,
     },
     processor::{process_batch, ProcessorOutput},
-    registration::{get_registration_extension_tx, get_registration_signature},
+    registration::{
+        decode_address_output,
+        decode_u64_output,
+        get_epoch_duration_call_data,
+        get_epochs_manager_call_data,
+        get_registration_extension_tx,
+        get_registration_extension_tx_data,
+        get_registration_signature,
+        get_sentinel_registration_call_data,
+        get_start_first_epoch_timestamp_call_data,
+        RegistrationError,
+        SentinelRegistration,
+    },
     sanity_check_frequency::sanity_check_frequency,
     signed_events::{SignedEvent, SignedEventError, SignedEvents},
     status::{SentinelStatus, SentinelStatusError},
     sync_state::SyncState,
-    utils::{get_utc_timestamp, run_timer},
+    tx_manager::{ManagedTx, PendingTxs, TxManagerError},
+    utils::{get_utc_timestamp, redact_url, run_timer},
 };
 
 #[macro_use]
@@ -3,6 +3,7 @@ use std::result::Result;
 use flexi_logger::{Cleanup, Criterion, FileSpec, Logger, Naming};
 use log::Level;
 
+use super::get_format_function;
 use crate::{config::LogConfig, SentinelError};
 
 pub fn initialize_file_logger(config: &LogConfig, cli_log_level: Option<Level>) -> Result<(), SentinelError> {
@@ -15,7 +16,7 @@ pub fn initialize_file_logger(config: &LogConfig, cli_log_level: Option<Level>)
 
     Logger::try_with_str(log_str).and_then(|logger| {
         logger
-            .format(flexi_logger::colored_opt_format) // NOTE: This adds more detail to log entries, timestamp, file-path etc.
+            .format(get_format_function(config.format()))
             .log_to_file(FileSpec::default().directory(&config.path))
             .rotate(
                 Criterion::Size(config.max_log_size),
@@ -0,0 +1,82 @@
+use std::{
+    io::{Error as IoError, Write},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use flexi_logger::{DeferredNow, FormatFunction};
+use log::Record;
+use serde::Serialize;
+
+use super::LogContext;
+use crate::LogFormat;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonLogEntry<'a> {
+    timestamp: u128,
+    level: &'a str,
+    target: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<u32>,
+    msg: String,
+    #[serde(flatten)]
+    context: Option<LogContext>,
+}
+
+// NOTE: One json object per line, with the timestamp in milliseconds since the unix epoch.
+fn json_format(w: &mut dyn Write, _now: &mut DeferredNow, record: &Record) -> Result<(), IoError> {
+    let entry = JsonLogEntry {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or_default(),
+        level: record.level().as_str(),
+        target: record.target(),
+        file: record.file(),
+        line: record.line(),
+        msg: record.args().to_string(),
+        context: LogContext::current(),
+    };
+    serde_json::to_writer(w, &entry).map_err(IoError::from)
+}
+
+pub(super) fn get_format_function(format: &LogFormat) -> FormatFunction {
+    match format {
+        LogFormat::Json => json_format,
+        // NOTE: This adds more detail to log entries, timestamp, file-path etc.
+        LogFormat::Text => flexi_logger::colored_opt_format,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value as Json;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_format_log_entry_as_json_with_context() {
+        let mut buf = vec![];
+        LogContext::for_rpc_call(Some(1))
+            .scope(async {
+                json_format(
+                    &mut buf,
+                    &mut DeferredNow::new(),
+                    &Record::builder()
+                        .args(format_args!("some msg"))
+                        .level(log::Level::Info)
+                        .target("some_target")
+                        .build(),
+                )
+            })
+            .await
+            .unwrap();
+        let json: Json = serde_json::from_slice(&buf).unwrap();
+        assert_eq!(json["msg"], "some msg");
+        assert_eq!(json["level"], "INFO");
+        assert_eq!(json["rpcId"], 1);
+        assert!(json["correlationId"].is_string());
+    }
+}
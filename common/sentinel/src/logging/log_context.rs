@@ -0,0 +1,114 @@
+use std::{cell::RefCell, future::Future};
+
+use common_network_ids::NetworkId;
+use serde::Serialize;
+
+use crate::CorrelationId;
+
+tokio::task_local! {
+    static LOG_CONTEXT: RefCell<LogContext>;
+}
+
+// NOTE: Context which is attached to every log entry made from within the task it is scoped to,
+// when using the json log format. Outside of any such scope there is simply no context.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogContext {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    network_id: Option<NetworkId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    block_num: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    batch_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rpc_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    correlation_id: Option<CorrelationId>,
+}
+
+impl LogContext {
+    pub fn for_network(network_id: NetworkId) -> Self {
+        Self {
+            network_id: Some(network_id),
+            ..Default::default()
+        }
+    }
+
+    pub fn for_rpc_call(rpc_id: Option<u64>) -> Self {
+        Self {
+            rpc_id,
+            correlation_id: Some(CorrelationId::new()),
+            ..Default::default()
+        }
+    }
+
+    pub async fn scope<F: Future>(self, f: F) -> F::Output {
+        LOG_CONTEXT.scope(RefCell::new(self), f).await
+    }
+
+    pub fn current() -> Option<Self> {
+        LOG_CONTEXT.try_with(|c| c.borrow().clone()).ok()
+    }
+
+    pub fn correlation_id() -> Option<CorrelationId> {
+        LOG_CONTEXT
+            .try_with(|c| c.borrow().correlation_id.clone())
+            .ok()
+            .flatten()
+    }
+
+    fn update<F: FnOnce(&mut Self)>(f: F) {
+        // NOTE: There's nothing to update when called from outside of a scoped task.
+        let _ = LOG_CONTEXT.try_with(|c| f(&mut c.borrow_mut()));
+    }
+
+    pub fn set_block_num(n: u64) {
+        Self::update(|c| c.block_num = Some(n))
+    }
+
+    pub fn set_batch_id(id: Option<String>) {
+        Self::update(|c| c.batch_id = id)
+    }
+
+    // NOTE: For tagging logs with the correlation id of whichever msg is currently being handled, such
+    // as in the websocket server, which handles msgs from many different tasks.
+    pub fn set_correlation_id(id: Option<CorrelationId>) {
+        Self::update(|c| c.correlation_id = id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn should_have_no_context_outside_of_scope() {
+        LogContext::set_block_num(1);
+        assert!(LogContext::current().is_none());
+        assert!(LogContext::correlation_id().is_none());
+    }
+
+    #[tokio::test]
+    async fn should_update_context_within_scope() {
+        let network_id = NetworkId::try_from("ethereum").unwrap();
+        let result = LogContext::for_network(network_id)
+            .scope(async {
+                LogContext::set_block_num(1337);
+                LogContext::set_batch_id(Some("some-batch".into()));
+                LogContext::current()
+            })
+            .await
+            .unwrap();
+        assert_eq!(result.network_id, Some(network_id));
+        assert_eq!(result.block_num, Some(1337));
+        assert_eq!(result.batch_id, Some("some-batch".into()));
+    }
+
+    #[tokio::test]
+    async fn rpc_call_context_should_have_correlation_id() {
+        let result = LogContext::for_rpc_call(Some(1))
+            .scope(async { LogContext::correlation_id() })
+            .await;
+        assert!(result.is_some());
+    }
+}
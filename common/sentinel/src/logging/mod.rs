@@ -1,7 +1,13 @@
 mod file_logger;
 mod init_logger;
+mod json_format;
+mod log_context;
 mod log_level;
 mod stdout_logger;
 
-use self::{file_logger::initialize_file_logger, stdout_logger::initialize_stdout_logger};
-pub use self::{init_logger::init_logger, log_level::LogLevel};
+use self::{
+    file_logger::initialize_file_logger,
+    json_format::get_format_function,
+    stdout_logger::initialize_stdout_logger,
+};
+pub use self::{init_logger::init_logger, log_context::LogContext, log_level::LogLevel};
@@ -3,6 +3,7 @@ use std::result::Result;
 use flexi_logger::Logger;
 use log::Level;
 
+use super::get_format_function;
 use crate::{config::LogConfig, SentinelError};
 
 pub fn initialize_stdout_logger(config: &LogConfig, cli_args_log_level: Option<Level>) -> Result<(), SentinelError> {
@@ -14,7 +15,7 @@ pub fn initialize_stdout_logger(config: &LogConfig, cli_args_log_level: Option<L
 
     Logger::try_with_str(log_str).and_then(|logger| {
         logger
-            .format(flexi_logger::colored_opt_format) // NOTE: This adds more detail to log entries, timestamp, file-path etc.
+            .format(get_format_function(config.format()))
             .log_to_stdout()
             .append()
             .start()
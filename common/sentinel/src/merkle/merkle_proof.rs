@@ -1,3 +1,4 @@
+use common_eth::{EthReceiptProof, EthTransactionProof};
 use derive_more::Constructor;
 use eth_trie::Trie;
 use serde::{Deserialize, Serialize};
@@ -20,6 +21,18 @@ impl TryFrom<(&mut MerkleTree, &[u8])> for MerkleProof {
     }
 }
 
+impl From<MerkleProof> for EthReceiptProof {
+    fn from(proof: MerkleProof) -> Self {
+        Self::new(proof.0)
+    }
+}
+
+impl From<MerkleProof> for EthTransactionProof {
+    fn from(proof: MerkleProof) -> Self {
+        Self::new(proof.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
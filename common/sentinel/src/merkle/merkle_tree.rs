@@ -1,10 +1,10 @@
 use std::sync::Arc;
 
-use common_eth::EthSubmissionMaterial;
+use common_eth::{EthReceiptProof, EthReceipts, EthSubmissionMaterial, EthTransactionProof};
 use derive_more::{Constructor, Deref, DerefMut};
 use eth_trie::{EthTrie, MemoryDB, Trie};
 
-use super::MerkleError;
+use super::{MerkleError, MerkleProof};
 
 #[derive(Debug, Constructor, Deref, DerefMut)]
 pub struct MerkleTree(EthTrie<MemoryDB>);
@@ -26,8 +26,48 @@ impl TryFrom<&EthSubmissionMaterial> for MerkleTree {
     }
 }
 
+impl MerkleTree {
+    // NOTE: The sub mat's transactions trie, as opposed to its receipts trie built via `TryFrom`.
+    pub fn from_raw_transactions(sub_mat: &EthSubmissionMaterial) -> Result<Self, MerkleError> {
+        let db = Arc::new(MemoryDB::new(true));
+        let mut trie = EthTrie::new(db);
+
+        for (k, v) in sub_mat.get_rlp_encoded_indices_and_raw_transactions()? {
+            trie.insert(&k, &v)?;
+        }
+
+        Ok(Self::new(trie))
+    }
+
+    pub fn get_transaction_proofs(&mut self, receipts: &EthReceipts) -> Result<Vec<EthTransactionProof>, MerkleError> {
+        self.root_hash()?;
+        receipts
+            .iter()
+            .map(|receipt| {
+                let tx_index = receipt.rlp_encode_transaction_index();
+                Ok(MerkleProof::try_from((&mut *self, tx_index.as_ref()))?.into())
+            })
+            .collect()
+    }
+
+    pub fn get_receipt_proofs(&mut self, receipts: &EthReceipts) -> Result<Vec<EthReceiptProof>, MerkleError> {
+        // NOTE: We commit the trie first so that the proofs consist of the nodes hashed into its root.
+        self.root_hash()?;
+        receipts
+            .iter()
+            .map(|receipt| {
+                let (tx_index, _) = receipt.get_rlp_encoded_index_and_rlp_encoded_receipt_tuple()?;
+                Ok(MerkleProof::try_from((&mut *self, tx_index.as_ref()))?.into())
+            })
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use common::crypto_utils::keccak_hash_bytes;
+    use ethereum_types::H256 as EthHash;
+
     use super::*;
     use crate::test_utils::get_sample_sub_mat_n;
 
@@ -39,4 +79,40 @@ mod tests {
         let expected_root_hash = sub_mat.receipts_root.unwrap();
         assert_eq!(root_hash.as_bytes(), expected_root_hash.as_bytes());
     }
+
+    #[test]
+    fn should_get_receipt_proofs_that_verify_against_receipts_root() {
+        let sub_mat = get_sample_sub_mat_n(1);
+        let mut merkle_tree = MerkleTree::try_from(&sub_mat).unwrap();
+        let receipts = EthReceipts::new(sub_mat.receipts[..3].to_vec());
+        let proofs = merkle_tree.get_receipt_proofs(&receipts).unwrap();
+        let receipts_root = sub_mat.receipts_root.unwrap();
+        assert_eq!(proofs.len(), receipts.len());
+        receipts
+            .iter()
+            .zip(proofs.iter())
+            .for_each(|(receipt, proof)| assert!(proof.verify(&receipts_root, receipt).unwrap()));
+    }
+
+    #[test]
+    fn should_get_transaction_proofs_that_verify_against_transactions_root() {
+        let raw_txs = vec![vec![0xc0], hex::decode("02c0").unwrap(), vec![0x01, 0xc1, 0x80]];
+        let mut sub_mat = get_sample_sub_mat_n(1).add_raw_transactions(raw_txs.clone()).unwrap();
+        let mut receipts = EthReceipts::new(sub_mat.receipts[..3].to_vec());
+        receipts
+            .0
+            .iter_mut()
+            .zip(raw_txs.iter())
+            .for_each(|(receipt, raw_tx)| receipt.transaction_hash = keccak_hash_bytes(raw_tx));
+        let mut merkle_tree = MerkleTree::from_raw_transactions(&sub_mat).unwrap();
+        let transactions_root = EthHash::from_slice(merkle_tree.root_hash().unwrap().as_bytes());
+        let proofs = merkle_tree.get_transaction_proofs(&receipts).unwrap();
+        assert_eq!(proofs.len(), receipts.len());
+        receipts
+            .iter()
+            .zip(proofs.iter())
+            .for_each(|(receipt, proof)| assert!(proof.verify(&transactions_root, receipt).unwrap()));
+        sub_mat.raw_transactions = None;
+        assert!(MerkleTree::from_raw_transactions(&sub_mat).is_err());
+    }
 }
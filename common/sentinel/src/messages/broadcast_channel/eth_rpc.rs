@@ -0,0 +1,18 @@
+use std::fmt;
+
+use crate::SentinelConfig;
+
+#[derive(Debug, Clone)]
+pub enum EthRpcBroadcastChannelMessages {
+    UpdateConfig(Box<SentinelConfig>),
+}
+
+impl fmt::Display for EthRpcBroadcastChannelMessages {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let prefix = "eth rpc broadcast channel message:";
+        let s = match self {
+            Self::UpdateConfig(_) => "update config",
+        };
+        write!(f, "{prefix} {s}")
+    }
+}
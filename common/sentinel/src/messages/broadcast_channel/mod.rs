@@ -1,3 +1,4 @@
+mod eth_rpc;
 mod rpc_server;
 mod syncer;
 
@@ -6,7 +7,12 @@ use common_network_ids::NetworkId;
 #[derive(Debug, Clone)]
 pub enum BroadcastChannelMessages {
     RpcServer(RpcServerBroadcastChannelMessages),
+    EthRpc(NetworkId, EthRpcBroadcastChannelMessages),
     Syncer(NetworkId, SyncerBroadcastChannelMessages),
 }
 
-pub use self::{rpc_server::RpcServerBroadcastChannelMessages, syncer::SyncerBroadcastChannelMessages};
+pub use self::{
+    eth_rpc::EthRpcBroadcastChannelMessages,
+    rpc_server::RpcServerBroadcastChannelMessages,
+    syncer::SyncerBroadcastChannelMessages,
+};
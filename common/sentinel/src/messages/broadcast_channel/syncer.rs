@@ -1,11 +1,14 @@
 use std::fmt;
 
+use crate::SentinelConfig;
+
 #[derive(Debug, Clone)]
 pub enum SyncerBroadcastChannelMessages {
     Stop,
     Start,
     CoreConnected,
     CoreDisconnected,
+    UpdateConfig(Box<SentinelConfig>),
 }
 
 impl fmt::Display for SyncerBroadcastChannelMessages {
@@ -16,6 +19,7 @@ impl fmt::Display for SyncerBroadcastChannelMessages {
             Self::Start => "start",
             Self::CoreConnected => "core connected",
             Self::CoreDisconnected => "core disconnected",
+            Self::UpdateConfig(_) => "update config",
         };
         write!(f, "{prefix} {s}")
     }
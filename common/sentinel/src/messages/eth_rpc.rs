@@ -1,10 +1,10 @@
 use common::Bytes;
-use common_eth::{DefaultBlockParameter, EthSubmissionMaterial, EthTransaction};
+use common_eth::{DefaultBlockParameter, EthReceipt, EthSubmissionMaterial, EthTransaction};
 use common_network_ids::NetworkId;
 use ethereum_types::{Address as EthAddress, H256 as EthHash, U256};
 use tokio::sync::{oneshot, oneshot::Receiver};
 
-use crate::{Responder, SentinelError};
+use crate::{EthFees, Responder, SentinelError};
 
 #[derive(Debug)]
 pub enum EthRpcMessages {
@@ -12,9 +12,13 @@ pub enum EthRpcMessages {
     GetLatestBlockNum((NetworkId, Responder<u64>)),
     GetNonce((NetworkId, EthAddress, Responder<u64>)),
     EthCall((Bytes, NetworkId, EthAddress, DefaultBlockParameter, Responder<Bytes>)),
+    EstimateGas((NetworkId, EthAddress, EthAddress, Bytes, Responder<u64>)),
     GetGasPrice((NetworkId, Responder<u64>)),
+    GetFees((NetworkId, Responder<EthFees>)),
     GetSubMat((NetworkId, u64, Responder<EthSubmissionMaterial>)),
+    GetRawTxs((NetworkId, u64, Responder<Vec<Bytes>>)),
     GetEthBalance((NetworkId, EthAddress, Responder<U256>)),
+    GetTxReceipt((NetworkId, EthHash, Responder<Option<EthReceipt>>)),
 }
 
 impl EthRpcMessages {
@@ -28,6 +32,19 @@ impl EthRpcMessages {
         (Self::GetSubMat((nid, n, tx)), rx)
     }
 
+    pub fn get_raw_txs_msg(nid: NetworkId, n: u64) -> (Self, Receiver<Result<Vec<Bytes>, SentinelError>>) {
+        let (tx, rx) = oneshot::channel();
+        (Self::GetRawTxs((nid, n, tx)), rx)
+    }
+
+    pub fn get_tx_receipt_msg(
+        nid: NetworkId,
+        h: EthHash,
+    ) -> (Self, Receiver<Result<Option<EthReceipt>, SentinelError>>) {
+        let (tx, rx) = oneshot::channel();
+        (Self::GetTxReceipt((nid, h, tx)), rx)
+    }
+
     pub fn get_nonce_msg(nid: NetworkId, a: EthAddress) -> (Self, Receiver<Result<u64, SentinelError>>) {
         let (tx, rx) = oneshot::channel();
         (Self::GetNonce((nid, a, tx)), rx)
@@ -40,7 +57,12 @@ impl EthRpcMessages {
 
     pub fn get_gas_price_msg(nid: NetworkId) -> (Self, Receiver<Result<u64, SentinelError>>) {
         let (tx, rx) = oneshot::channel();
-        (Self::GetLatestBlockNum((nid, tx)), rx)
+        (Self::GetGasPrice((nid, tx)), rx)
+    }
+
+    pub fn get_fees_msg(nid: NetworkId) -> (Self, Receiver<Result<EthFees, SentinelError>>) {
+        let (tx, rx) = oneshot::channel();
+        (Self::GetFees((nid, tx)), rx)
     }
 
     pub fn get_push_tx_msg(t: EthTransaction, nid: NetworkId) -> (Self, Receiver<Result<EthHash, SentinelError>>) {
@@ -57,4 +79,14 @@ impl EthRpcMessages {
         let (tx, rx) = oneshot::channel();
         (Self::EthCall((d, b, a, p, tx)), rx)
     }
+
+    pub fn get_estimate_gas_msg(
+        nid: NetworkId,
+        from: EthAddress,
+        to: EthAddress,
+        d: Bytes,
+    ) -> (Self, Receiver<Result<u64, SentinelError>>) {
+        let (tx, rx) = oneshot::channel();
+        (Self::EstimateGas((nid, from, to, d, tx)), rx)
+    }
 }
@@ -1,18 +1,29 @@
 mod broadcast_channel;
 mod eth_rpc;
+mod network_manager;
 mod responder;
 mod syncer;
+mod tx_manager;
 mod websocket;
 
 pub use self::{
-    broadcast_channel::{BroadcastChannelMessages, RpcServerBroadcastChannelMessages, SyncerBroadcastChannelMessages},
+    broadcast_channel::{
+        BroadcastChannelMessages,
+        EthRpcBroadcastChannelMessages,
+        RpcServerBroadcastChannelMessages,
+        SyncerBroadcastChannelMessages,
+    },
     eth_rpc::EthRpcMessages,
+    network_manager::NetworkManagerMessages,
     responder::Responder,
     syncer::SyncerMessages,
+    tx_manager::TxManagerMessages,
     websocket::{
+        CorrelationId,
         WebSocketMessages,
         WebSocketMessagesEncodable,
         WebSocketMessagesEncodableDbOps,
+        WebSocketMessagesEnvelope,
         WebSocketMessagesError,
         WebSocketMessagesInitArgs,
         WebSocketMessagesProcessBatchArgs,
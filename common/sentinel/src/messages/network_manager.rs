@@ -0,0 +1,28 @@
+use common_network_ids::NetworkId;
+use tokio::sync::{oneshot, oneshot::Receiver};
+
+use crate::{Responder, SentinelConfig, SentinelError};
+
+#[derive(Debug)]
+pub enum NetworkManagerMessages {
+    AddNetwork((NetworkId, Box<SentinelConfig>, bool, Responder<()>)),
+    RemoveNetwork((NetworkId, Responder<()>)),
+}
+
+impl NetworkManagerMessages {
+    // NOTE: Whether or not the core is connected is passed in so that the newly spawned syncer needn't
+    // wait for the core to reconnect before it can start syncing.
+    pub fn add_network_msg(
+        nid: NetworkId,
+        config: SentinelConfig,
+        core_cxn: bool,
+    ) -> (Self, Receiver<Result<(), SentinelError>>) {
+        let (tx, rx) = oneshot::channel();
+        (Self::AddNetwork((nid, Box::new(config), core_cxn, tx)), rx)
+    }
+
+    pub fn remove_network_msg(nid: NetworkId) -> (Self, Receiver<Result<(), SentinelError>>) {
+        let (tx, rx) = oneshot::channel();
+        (Self::RemoveNetwork((nid, tx)), rx)
+    }
+}
@@ -0,0 +1,25 @@
+use common::Bytes;
+use common_network_ids::NetworkId;
+use ethereum_types::{Address as EthAddress, H256 as EthHash};
+use tokio::sync::{oneshot, oneshot::Receiver};
+
+use crate::{Responder, SentinelError};
+
+#[derive(Debug)]
+pub enum TxManagerMessages {
+    SubmitTx((NetworkId, EthAddress, Bytes, usize, Responder<EthHash>)),
+}
+
+impl TxManagerMessages {
+    // NOTE: The tx manager chooses the tx's nonce & fees, and signs it with the owner's private key.
+    // The returned hash is that of the tx as first submitted, which may later be replaced.
+    pub fn submit_tx_msg(
+        nid: NetworkId,
+        to: EthAddress,
+        data: Bytes,
+        gas_limit: usize,
+    ) -> (Self, Receiver<Result<EthHash, SentinelError>>) {
+        let (tx, rx) = oneshot::channel();
+        (Self::SubmitTx((nid, to, data, gas_limit, tx)), rx)
+    }
+}
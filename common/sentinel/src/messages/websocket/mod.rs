@@ -1,11 +1,14 @@
+mod correlation_id;
 mod websocket_messages;
 mod websocket_messages_args;
 mod websocket_messages_db_ops;
 mod websocket_messages_encodable;
+mod websocket_messages_envelope;
 mod websocket_messages_error;
 mod websocket_messages_utils;
 
 pub use self::{
+    correlation_id::CorrelationId,
     websocket_messages::WebSocketMessages,
     websocket_messages_args::{
         WebSocketMessagesInitArgs,
@@ -14,5 +17,6 @@ pub use self::{
     },
     websocket_messages_db_ops::WebSocketMessagesEncodableDbOps,
     websocket_messages_encodable::WebSocketMessagesEncodable,
+    websocket_messages_envelope::WebSocketMessagesEnvelope,
     websocket_messages_error::WebSocketMessagesError,
 };
@@ -1,17 +1,21 @@
 use tokio::sync::{oneshot, oneshot::Receiver};
 
-use super::WebSocketMessagesEncodable;
-use crate::{Responder, SentinelError};
+use super::{CorrelationId, WebSocketMessagesEncodable};
+use crate::{LogContext, Responder, SentinelError};
 
 #[derive(Debug)]
 pub struct WebSocketMessages(
     pub WebSocketMessagesEncodable,
     pub Responder<WebSocketMessagesEncodable>,
+    pub CorrelationId,
 );
 
 impl WebSocketMessages {
+    // NOTE: Messages created from within a `LogContext` carrying a correlation id (such as that of an
+    // rpc call) share that id, else they're given one of their own.
     pub fn new(msg: WebSocketMessagesEncodable) -> (Self, Receiver<Result<WebSocketMessagesEncodable, SentinelError>>) {
         let (tx, rx) = oneshot::channel();
-        (Self(msg, tx), rx)
+        let correlation_id = LogContext::correlation_id().unwrap_or_default();
+        (Self(msg, tx, correlation_id), rx)
     }
 }
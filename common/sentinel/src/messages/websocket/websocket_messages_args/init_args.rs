@@ -18,6 +18,17 @@ pub struct WebSocketMessagesInitArgs {
 }
 
 impl WebSocketMessagesInitArgs {
+    pub fn new(validate: bool, hub: EthAddress, tail_length: u64, confirmations: u64, network_id: NetworkId) -> Self {
+        Self {
+            validate,
+            hub,
+            tail_length,
+            confirmations,
+            network_id,
+            sub_mat: None,
+        }
+    }
+
     fn name(&self) -> String {
         "WebSocketMessagesInitArgs".into()
     }
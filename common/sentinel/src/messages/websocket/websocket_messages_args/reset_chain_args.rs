@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use common_debug_signers::DebugSignature;
 use common_eth::EthSubmissionMaterial;
 use common_network_ids::NetworkId;
 use derive_getters::{Dissolve, Getters};
@@ -17,9 +18,31 @@ pub struct WebSocketMessagesResetChainArgs {
     block_num: Option<u64>,
     hub: Option<EthAddress>,
     block: Option<EthSubmissionMaterial>,
+    sig: DebugSignature,
 }
 
 impl WebSocketMessagesResetChainArgs {
+    // NOTE: A `block_num` of `None` means the latest block is to be used.
+    pub fn new(
+        network_id: NetworkId,
+        block_num: Option<u64>,
+        confs: u64,
+        validate: bool,
+        hub: Option<EthAddress>,
+        sig: DebugSignature,
+    ) -> Self {
+        Self {
+            sig,
+            hub,
+            confs,
+            validate,
+            block_num,
+            network_id,
+            block: None,
+            use_latest_block: block_num.is_none(),
+        }
+    }
+
     pub fn add_sub_mat(&mut self, m: EthSubmissionMaterial) {
         self.block = Some(m)
     }
@@ -31,6 +54,8 @@ impl TryFrom<Vec<String>> for WebSocketMessagesResetChainArgs {
 
     fn try_from(args: Vec<String>) -> Result<Self, WebSocketMessagesError> {
         // NOTE: Example: ["EthereumMainnet", "latest", "10", "false"]
+        // NOTE: Optionally followed by a hub address, then any debug signatures. Pass an empty hub
+        // in order to supply signatures without one.
 
         if args.is_empty() {
             return Err(WebSocketMessagesError::CannotCreate(args));
@@ -76,7 +101,14 @@ impl TryFrom<Vec<String>> for WebSocketMessagesResetChainArgs {
             None
         };
 
+        let sig = if args.len() > expected_num_args + 1 {
+            DebugSignature::from(&args[expected_num_args + 1..])
+        } else {
+            DebugSignature::new(None)
+        };
+
         Ok(Self {
+            sig,
             hub,
             block,
             confs,
@@ -1,14 +1,18 @@
 use std::fmt;
 
 use base64::{engine::general_purpose, Engine};
+use common_chain_ids::EvmChainConfig;
 use common_debug_signers::DebugSignature;
 use common_network_ids::NetworkId;
-use ethereum_types::Address as EthAddress;
+use ethereum_types::{Address as EthAddress, H256 as EthHash};
 use serde::{Deserialize, Serialize};
 use serde_json::Value as Json;
 
 use super::WebSocketMessagesEncodableDbOps;
 use crate::{
+    ChallengeState,
+    EncryptedCoreStateArchive,
+    PendingTxs,
     SentinelError,
     WebSocketMessagesError,
     WebSocketMessagesInitArgs,
@@ -21,22 +25,37 @@ pub enum WebSocketMessagesEncodable {
     Null,
     GetAddress,
     GetPublicKey,
+    CreateKey(DebugSignature),
     Success(Json),
     CheckInit(NetworkId),
+    ExecuteQueued(u64),
+    GetAuditLog(u64, u64),
     HardReset(DebugSignature),
+    CancelQueued(u64, DebugSignature),
+    SetDebugCommandDelay(u64, DebugSignature),
     GetStatus(Vec<NetworkId>),
+    GetPendingTxs,
+    GetChallenges,
     GetAttestationCertificate,
     GetCoreState(Vec<NetworkId>),
     Error(WebSocketMessagesError),
     GetAttestationSignature(Vec<u8>),
     GetLatestBlockInfos(Vec<NetworkId>),
     DbOps(WebSocketMessagesEncodableDbOps),
+    UpdatePendingTxs(Box<PendingTxs>),
+    SetChallengeState(EthHash, ChallengeState),
+    GetChallengeResponse(EthHash, Vec<NetworkId>),
     RemoveDebugSigner(String, DebugSignature),
+    RegisterEvmChain(Box<EvmChainConfig>, DebugSignature),
     Initialize(Box<WebSocketMessagesInitArgs>),
     ResetChain(Box<WebSocketMessagesResetChainArgs>),
     ProcessBatch(Box<WebSocketMessagesProcessBatchArgs>),
     GetRegistrationSignature(EthAddress, u64, DebugSignature),
     AddDebugSigners(Vec<(String, EthAddress)>, DebugSignature),
+    SetDebugSignaturePolicy(String, usize, DebugSignature),
+    ExportCoreState(Vec<NetworkId>, Vec<u8>, DebugSignature),
+    SetTrustedArchiveSigner(EthAddress, DebugSignature),
+    ImportCoreState(Box<EncryptedCoreStateArchive>, DebugSignature),
 }
 
 impl TryFrom<WebSocketMessagesEncodable> for Json {
@@ -58,6 +77,47 @@ impl WebSocketMessagesEncodable {
         matches!(self, Self::HardReset(_))
     }
 
+    // NOTE: These are the destructive debug commands which may be queued behind a delay, rather than
+    // being run immediately. See `DebugCommandQueue` for more info.
+    pub fn is_time_lockable(&self) -> bool {
+        matches!(
+            self,
+            Self::HardReset(_)
+                | Self::ResetChain(_)
+                | Self::ImportCoreState(..)
+                | Self::AddDebugSigners(..)
+                | Self::RemoveDebugSigner(..)
+                | Self::RegisterEvmChain(..)
+                | Self::SetDebugCommandDelay(..)
+                | Self::SetDebugSignaturePolicy(..)
+                | Self::SetTrustedArchiveSigner(..)
+                | Self::DbOps(WebSocketMessagesEncodableDbOps::Put(..))
+                | Self::DbOps(WebSocketMessagesEncodableDbOps::Delete(..))
+        )
+    }
+
+    // NOTE: These are the commands requiring debug signatures, and thus those which are recorded
+    // in the core's `AuditLog`.
+    pub fn is_debug_command(&self) -> bool {
+        matches!(
+            self,
+            Self::DbOps(_)
+                | Self::HardReset(_)
+                | Self::CreateKey(_)
+                | Self::ResetChain(_)
+                | Self::CancelQueued(..)
+                | Self::ExportCoreState(..)
+                | Self::ImportCoreState(..)
+                | Self::AddDebugSigners(..)
+                | Self::RemoveDebugSigner(..)
+                | Self::RegisterEvmChain(..)
+                | Self::SetDebugCommandDelay(..)
+                | Self::SetDebugSignaturePolicy(..)
+                | Self::SetTrustedArchiveSigner(..)
+                | Self::GetRegistrationSignature(..)
+        )
+    }
+
     pub fn is_success(&self) -> bool {
         matches!(self, Self::Success(_))
     }
@@ -79,13 +139,28 @@ impl fmt::Display for WebSocketMessagesEncodable {
             Self::CheckInit(..) => "CheckIni".to_string(),
             Self::GetStatus(..) => "GetStatus".to_string(),
             Self::HardReset(..) => "HardReset".to_string(),
+            Self::CancelQueued(..) => "CancelQueued".to_string(),
+            Self::ExecuteQueued(..) => "ExecuteQueued".to_string(),
+            Self::GetAuditLog(..) => "GetAuditLog".to_string(),
             Self::Initialize(_) => "Initialize".to_string(),
             Self::ResetChain(_) => "ResetChain".to_string(),
             Self::GetPublicKey => "GetPublicKey".to_string(),
+            Self::CreateKey(..) => "CreateKey".to_string(),
+            Self::GetPendingTxs => "GetPendingTxs".to_string(),
+            Self::UpdatePendingTxs(_) => "UpdatePendingTxs".to_string(),
+            Self::GetChallenges => "GetChallenges".to_string(),
+            Self::SetChallengeState(..) => "SetChallengeState".to_string(),
+            Self::GetChallengeResponse(..) => "GetChallengeResponse".to_string(),
             Self::GetCoreState(..) => "GetCoreState".to_string(),
             Self::ProcessBatch(..) => "ProcessBatch".to_string(),
+            Self::ExportCoreState(..) => "ExportCoreState".to_string(),
+            Self::ImportCoreState(..) => "ImportCoreState".to_string(),
             Self::AddDebugSigners(..) => "AddDebugSigners".to_string(),
             Self::RemoveDebugSigner(..) => "RemoveDebugSigner".to_string(),
+            Self::RegisterEvmChain(..) => "RegisterEvmChain".to_string(),
+            Self::SetDebugCommandDelay(..) => "SetDebugCommandDelay".to_string(),
+            Self::SetDebugSignaturePolicy(..) => "SetDebugSignaturePolicy".to_string(),
+            Self::SetTrustedArchiveSigner(..) => "SetTrustedArchiveSigner".to_string(),
             Self::GetLatestBlockInfos(..) => "GetLatestBlockInfos".to_string(),
             Self::GetAttestationSignature(..) => "GetAttestationSignature".to_string(),
             Self::GetAttestationCertificate => "GetAttestationCertificate".to_string(),
@@ -0,0 +1,122 @@
+use base64::{engine::general_purpose, Engine};
+use derive_getters::{Dissolve, Getters};
+use serde::{Deserialize, Serialize};
+
+use super::CorrelationId;
+use crate::{SentinelError, WebSocketMessagesEncodable};
+
+// NOTE: Bumped whenever the envelope's format changes. Version 0 is the legacy format which predates
+// envelopes, wherein a bare `WebSocketMessagesEncodable` is sent.
+const WEBSOCKET_MESSAGES_ENVELOPE_VERSION: u16 = 1;
+const LEGACY_ENVELOPE_VERSION: u16 = 0;
+
+// NOTE: This is what actually goes over the wire between the app and the core. The correlation id is
+// optional since the core may fail before it has parsed the envelope it was sent, in which case it
+// responds with a bare `WebSocketMessagesEncodable`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Getters, Dissolve)]
+#[serde(rename_all = "camelCase")]
+pub struct WebSocketMessagesEnvelope {
+    version: u16,
+    correlation_id: Option<CorrelationId>,
+    msg: WebSocketMessagesEncodable,
+}
+
+impl WebSocketMessagesEnvelope {
+    pub fn new(correlation_id: Option<CorrelationId>, msg: WebSocketMessagesEncodable) -> Self {
+        Self {
+            msg,
+            correlation_id,
+            version: WEBSOCKET_MESSAGES_ENVELOPE_VERSION,
+        }
+    }
+
+    // NOTE: For talking to an app or core which predates envelopes. Such an envelope is sent as the
+    // bare msg, and so carries no correlation id.
+    pub fn legacy(msg: WebSocketMessagesEncodable) -> Self {
+        Self {
+            msg,
+            correlation_id: None,
+            version: LEGACY_ENVELOPE_VERSION,
+        }
+    }
+
+    pub fn is_legacy(&self) -> bool {
+        self.version == LEGACY_ENVELOPE_VERSION
+    }
+}
+
+impl TryFrom<String> for WebSocketMessagesEnvelope {
+    type Error = SentinelError;
+
+    fn try_from(s: String) -> Result<Self, SentinelError> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl TryFrom<&str> for WebSocketMessagesEnvelope {
+    type Error = SentinelError;
+
+    fn try_from(s: &str) -> Result<Self, SentinelError> {
+        let bytes = general_purpose::STANDARD_NO_PAD.decode(s)?;
+        match serde_json::from_slice::<Self>(&bytes) {
+            Ok(envelope) if envelope.version > WEBSOCKET_MESSAGES_ENVELOPE_VERSION => Err(SentinelError::Custom(
+                format!("unsupported websocket messages envelope version {}", envelope.version),
+            )),
+            Ok(envelope) => Ok(envelope),
+            Err(_) => Ok(Self::legacy(serde_json::from_slice(&bytes)?)),
+        }
+    }
+}
+
+impl TryInto<String> for WebSocketMessagesEnvelope {
+    type Error = SentinelError;
+
+    fn try_into(self) -> Result<String, SentinelError> {
+        let bytes = if self.is_legacy() {
+            serde_json::to_vec(&self.msg)?
+        } else {
+            serde_json::to_vec(&self)?
+        };
+        Ok(general_purpose::STANDARD_NO_PAD.encode(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_encode_and_decode_envelope() {
+        let envelope =
+            WebSocketMessagesEnvelope::new(Some(CorrelationId::new()), WebSocketMessagesEncodable::GetAddress);
+        let s: String = envelope.clone().try_into().unwrap();
+        let result = WebSocketMessagesEnvelope::try_from(s).unwrap();
+        assert_eq!(result, envelope);
+    }
+
+    #[test]
+    fn should_decode_bare_encodable_msg_into_legacy_envelope() {
+        let s: String = WebSocketMessagesEncodable::Null.try_into().unwrap();
+        let result = WebSocketMessagesEnvelope::try_from(s).unwrap();
+        assert!(result.is_legacy());
+        assert!(result.correlation_id().is_none());
+        assert_eq!(result.msg(), &WebSocketMessagesEncodable::Null);
+    }
+
+    #[test]
+    fn should_encode_legacy_envelope_as_bare_encodable_msg() {
+        let expected: String = WebSocketMessagesEncodable::GetAddress.try_into().unwrap();
+        let result: String = WebSocketMessagesEnvelope::legacy(WebSocketMessagesEncodable::GetAddress)
+            .try_into()
+            .unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn should_fail_to_decode_envelope_of_unsupported_version() {
+        let mut envelope = WebSocketMessagesEnvelope::new(None, WebSocketMessagesEncodable::GetAddress);
+        envelope.version = WEBSOCKET_MESSAGES_ENVELOPE_VERSION + 1;
+        let s: String = envelope.try_into().unwrap();
+        assert!(WebSocketMessagesEnvelope::try_from(s).is_err());
+    }
+}
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value as Json;
 use thiserror::Error;
 
-use crate::SentinelError;
+use crate::{JsonRpcErrorCode, SentinelError};
 
 #[derive(Clone, Error, Debug, PartialEq, Serialize, Deserialize)]
 pub enum WebSocketMessagesError {
@@ -68,6 +68,9 @@ pub enum WebSocketMessagesError {
     #[error("sentinel error: {0}")]
     SentinelError(String),
 
+    #[error("sentinel error: {message}")]
+    CodedSentinelError { code: JsonRpcErrorCode, message: String },
+
     #[error("{0}")]
     Json(Json),
 
@@ -116,7 +119,13 @@ impl From<SentinelError> for WebSocketMessagesError {
     fn from(e: SentinelError) -> Self {
         match e {
             SentinelError::Json(j) => Self::Json(j),
-            other => Self::SentinelError(format!("{other}")),
+            SentinelError::ChainError(e) => Self::from(e),
+            SentinelError::WebSocketMessages(e) => e,
+            // NOTE: So that the RPC server can still give the correct error code for it.
+            other => Self::CodedSentinelError {
+                code: JsonRpcErrorCode::from(&other),
+                message: format!("{other}"),
+            },
         }
     }
 }
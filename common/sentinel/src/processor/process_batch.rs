@@ -2,9 +2,19 @@ use std::result::Result;
 
 use common::DatabaseInterface;
 use common_eth::{Chain, ChainDbUtils, EthSubmissionMaterials};
+use ethereum_types::Address as EthAddress;
 
 use super::process_single;
-use crate::{NetworkConfig, ProcessorOutput, SentinelError, SignedEvents};
+use crate::{
+    Challenges,
+    EvmChains,
+    GovernanceEvents,
+    GovernanceState,
+    NetworkConfig,
+    ProcessorOutput,
+    SentinelError,
+    SignedEvents,
+};
 
 pub fn process_batch<D: DatabaseInterface>(
     db: &D,
@@ -13,32 +23,75 @@ pub fn process_batch<D: DatabaseInterface>(
     network_config: &NetworkConfig,
     reprocess: bool,
     dry_run: bool,
+    governance_address: Option<EthAddress>,
 ) -> Result<ProcessorOutput, SentinelError> {
+    // NOTE: The core doesn't load the config, so the EVM chains registered in the core are registered
+    // here in order for their fork schedules to be used when validating. Any EVM chain described by
+    // the app supplied network config is ignored, since the app isn't trusted to describe one.
+    EvmChains::get(db).register_all()?;
+
     let network_id = network_config.network_id();
     info!("processing {network_id} batch of submission material...");
 
     let c_db_utils = ChainDbUtils::new(db);
 
     let mut chain = Chain::get(&c_db_utils, network_id.try_into()?)?;
+    let mut governance_state = GovernanceState::get(db);
+    let mut challenges = Challenges::get(db);
+
+    let mut signed_events = vec![];
+    let mut governance_events = vec![];
+    let mut new_challenges = vec![];
+    for sub_mat in batch.iter() {
+        let (s, g, c) = process_single(
+            db,
+            sub_mat.clone(),
+            validate,
+            dry_run,
+            network_config,
+            reprocess,
+            &mut chain,
+            governance_address,
+            &mut governance_state,
+            &mut challenges,
+        )?;
+        signed_events.push(s);
+        governance_events.push(g);
+        new_challenges.extend(c);
+    }
+    let signed_events = SignedEvents::from(signed_events);
+    let governance_events = GovernanceEvents::from(governance_events);
 
-    let signed_events = SignedEvents::from(
-        batch
-            .iter()
-            .map(|sub_mat| {
-                process_single(
-                    db,
-                    sub_mat.clone(),
-                    validate,
-                    dry_run,
-                    network_config,
-                    reprocess,
-                    &mut chain,
-                )
-            })
-            .collect::<Result<Vec<SignedEvents>, SentinelError>>()?,
-    );
+    if !governance_events.is_empty() {
+        info!(
+            "found {} {network_id} governance events concerning this sentinel",
+            governance_events.len()
+        );
+        if dry_run {
+            warn!("dry running so not saving governance state");
+        } else {
+            governance_state.save(db)?;
+        };
+    };
+    if !new_challenges.is_empty() {
+        warn!(
+            "found {} new {network_id} challenges to this sentinel",
+            new_challenges.len()
+        );
+        if dry_run {
+            warn!("dry running so not saving challenges");
+        } else {
+            challenges.save(db)?;
+        };
+    };
     info!("finished processing {network_id} submission material");
 
-    let r = ProcessorOutput::new(network_id, batch.get_last_block_num()?, signed_events)?;
+    let r = ProcessorOutput::new(
+        network_id,
+        batch.get_last_block_num()?,
+        signed_events,
+        governance_events,
+        new_challenges,
+    )?;
     Ok(r)
 }
@@ -2,9 +2,11 @@ use std::result::Result;
 
 use common::DatabaseInterface;
 use common_eth::{Chain, ChainDbUtils, EthSubmissionMaterial};
+use ethereum_types::Address as EthAddress;
 
-use crate::{NetworkConfig, SentinelError, SignedEvents};
+use crate::{Challenge, Challenges, GovernanceEvents, GovernanceState, NetworkConfig, SentinelError, SignedEvents};
 
+#[allow(clippy::too_many_arguments)]
 pub(super) fn process_single<D: DatabaseInterface>(
     db: &D,
     sub_mat: EthSubmissionMaterial,
@@ -13,7 +15,10 @@ pub(super) fn process_single<D: DatabaseInterface>(
     network_config: &NetworkConfig,
     reprocess: bool,
     chain: &mut Chain,
-) -> Result<SignedEvents, SentinelError> {
+    governance_address: Option<EthAddress>,
+    governance_state: &mut GovernanceState,
+    challenges: &mut Challenges,
+) -> Result<(SignedEvents, GovernanceEvents, Vec<Challenge>), SentinelError> {
     let mcid = *chain.chain_id();
     // NOTE: All db transaction stuff is handled via strongbox
 
@@ -39,13 +44,34 @@ pub(super) fn process_single<D: DatabaseInterface>(
 
     if maybe_canon_block.is_none() {
         warn!("there is no canonical block on chain {mcid} yet");
-        return Ok(SignedEvents::empty());
+        return Ok((SignedEvents::empty(), GovernanceEvents::default(), vec![]));
     }
 
     let canonical_sub_mat = maybe_canon_block.expect("this not to fail due to above check");
     if canonical_sub_mat.receipts.is_empty() {
         debug!("{mcid} canon block had no receipts to process");
-        return Ok(SignedEvents::empty());
+        return Ok((SignedEvents::empty(), GovernanceEvents::default(), vec![]));
+    }
+
+    let signing_address = chain_db_utils.get_signing_address()?;
+
+    // NOTE: The governance address is only passed in for the chain the governance contract lives on.
+    let governance_events = match governance_address {
+        Some(ref a) => {
+            let events = GovernanceEvents::from_sub_mat(&canonical_sub_mat, a);
+            let canon_block_num = canonical_sub_mat.get_block_number()?.as_u64();
+            governance_state.apply(&signing_address, canon_block_num, &events)
+        },
+        None => GovernanceEvents::default(),
+    };
+
+    // NOTE: Only those challenges not seen before are returned, lest a reprocessed block's challenges
+    // be reported, & answered, twice.
+    let mut new_challenges = vec![];
+    for challenge in Challenge::from_sub_mat(&canonical_sub_mat, network_config.pnetwork_hub(), &signing_address) {
+        if challenges.add(challenge.clone())? {
+            new_challenges.push(challenge);
+        };
     }
 
     let signed_events = SignedEvents::try_from((
@@ -58,5 +84,5 @@ pub(super) fn process_single<D: DatabaseInterface>(
     debug!("found signed events: {signed_events:?}");
     debug!("finished processing {mcid} block {n}");
 
-    Ok(signed_events)
+    Ok((signed_events, governance_events, new_challenges))
 }
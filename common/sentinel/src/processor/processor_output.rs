@@ -5,7 +5,7 @@ use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as Json;
 
-use crate::{get_utc_timestamp, SentinelError, SignedEvents};
+use crate::{get_utc_timestamp, Challenge, GovernanceEvents, SentinelError, SignedEvents};
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize, Getters)]
 pub struct ProcessorOutput {
@@ -13,6 +13,10 @@ pub struct ProcessorOutput {
     network_id: NetworkId,
     latest_block_num: u64,
     signed_events: SignedEvents,
+    #[serde(default)]
+    governance_events: GovernanceEvents,
+    #[serde(default)]
+    challenges: Vec<Challenge>,
 }
 
 impl ProcessorOutput {
@@ -20,10 +24,14 @@ impl ProcessorOutput {
         network_id: NetworkId,
         latest_block_num: u64,
         signed_events: SignedEvents,
+        governance_events: GovernanceEvents,
+        challenges: Vec<Challenge>,
     ) -> Result<Self, SentinelError> {
         Ok(Self {
             network_id,
+            challenges,
             signed_events,
+            governance_events,
             latest_block_num,
             timestamp: get_utc_timestamp()?,
         })
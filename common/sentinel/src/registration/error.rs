@@ -0,0 +1,11 @@
+use ethereum_types::Address as EthAddress;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RegistrationError {
+    #[error("sentinel {0} is not registered with the registration manager")]
+    NotRegistered(EthAddress),
+
+    #[error("cannot decode registration manager output: {0}")]
+    CannotDecode(String),
+}
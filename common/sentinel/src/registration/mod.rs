@@ -1,7 +1,19 @@
+mod error;
 mod registration_extension;
 mod registration_signature;
+mod sentinel_registration;
 
 pub use self::{
-    registration_extension::get_registration_extension_tx,
+    error::RegistrationError,
+    registration_extension::{get_registration_extension_tx, get_registration_extension_tx_data},
     registration_signature::get_registration_signature,
+    sentinel_registration::{
+        decode_address_output,
+        decode_u64_output,
+        get_epoch_duration_call_data,
+        get_epochs_manager_call_data,
+        get_sentinel_registration_call_data,
+        get_start_first_epoch_timestamp_call_data,
+        SentinelRegistration,
+    },
 };
@@ -1,19 +1,28 @@
+use common::Bytes;
 use common_chain_ids::EthChainId;
 use common_eth::{encode_fxn_call, EthPrivateKey, EthTransaction};
 use common_network_ids::NetworkId;
 use ethabi::Token as EthAbiToken;
 use ethereum_types::{Address as EthAddress, U256};
 
-use crate::SentinelError;
+use crate::{EthFees, SentinelError};
 
 const REGISTRATION_EXTENSION_GAS_LIMIT: usize = 100_000;
 
 const REGISTRATION_ABI_FRAGMENT: &str = "[{\"inputs\":[{\"internalType\":\"uint64\",\"name\":\"duration\",\"type\":\"uint64\"}],\"name\":\"increaseSentinelRegistrationDuration\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"}]";
 
+// NOTE: Returns the data for a tx extending the sender's registration, along with its gas limit.
+pub fn get_registration_extension_tx_data(duration: u64) -> Result<(Bytes, usize), SentinelError> {
+    let data = encode_fxn_call(REGISTRATION_ABI_FRAGMENT, "increaseSentinelRegistrationDuration", &[
+        EthAbiToken::Uint(U256::from(duration)),
+    ])?;
+    Ok((data, REGISTRATION_EXTENSION_GAS_LIMIT))
+}
+
 pub fn get_registration_extension_tx(
     nonce: u64,
     duration: u64,
-    gas_price: u64,
+    fees: EthFees,
     network_id: NetworkId,
     owner_pk: &EthPrivateKey,
     registration_manager: EthAddress,
@@ -21,17 +30,8 @@ pub fn get_registration_extension_tx(
     debug!("getting registation extension tx...");
     let value = 0;
     let ecid = EthChainId::try_from(network_id)?;
-    let data = encode_fxn_call(REGISTRATION_ABI_FRAGMENT, "increaseSentinelRegistrationDuration", &[
-        EthAbiToken::Uint(U256::from(duration)),
-    ])?;
-    Ok(EthTransaction::new_unsigned(
-        data,
-        nonce,
-        value,
-        registration_manager,
-        &ecid,
-        REGISTRATION_EXTENSION_GAS_LIMIT,
-        gas_price,
-    )
-    .sign(owner_pk)?)
+    let (data, gas_limit) = get_registration_extension_tx_data(duration)?;
+    Ok(fees
+        .to_unsigned_tx(data, nonce, value, registration_manager, &ecid, gas_limit)
+        .sign(owner_pk)?)
 }
@@ -0,0 +1,169 @@
+use common::Bytes;
+use common_eth::encode_fxn_call;
+use derive_getters::Getters;
+use ethabi::{decode as eth_abi_decode, ParamType as EthAbiParamType, Token as EthAbiToken};
+use ethereum_types::{Address as EthAddress, U256};
+use serde::{Deserialize, Serialize};
+
+use super::RegistrationError;
+use crate::SentinelError;
+
+const REGISTRATION_MANAGER_ABI_FRAGMENT: &str = "[{\"inputs\":[{\"internalType\":\"address\",\"name\":\"sentinel\",\"type\":\"address\"}],\"name\":\"sentinelRegistration\",\"outputs\":[{\"components\":[{\"internalType\":\"address\",\"name\":\"owner\",\"type\":\"address\"},{\"internalType\":\"uint16\",\"name\":\"startingEpoch\",\"type\":\"uint16\"},{\"internalType\":\"uint16\",\"name\":\"endingEpoch\",\"type\":\"uint16\"},{\"internalType\":\"bytes1\",\"name\":\"kind\",\"type\":\"bytes1\"}],\"internalType\":\"struct IRegistrationManager.Registration\",\"name\":\"\",\"type\":\"tuple\"}],\"stateMutability\":\"view\",\"type\":\"function\"},{\"inputs\":[],\"name\":\"epochsManager\",\"outputs\":[{\"internalType\":\"address\",\"name\":\"\",\"type\":\"address\"}],\"stateMutability\":\"view\",\"type\":\"function\"}]";
+
+const EPOCHS_MANAGER_ABI_FRAGMENT: &str = "[{\"inputs\":[],\"name\":\"epochDuration\",\"outputs\":[{\"internalType\":\"uint256\",\"name\":\"\",\"type\":\"uint256\"}],\"stateMutability\":\"view\",\"type\":\"function\"},{\"inputs\":[],\"name\":\"startFirstEpochTimestamp\",\"outputs\":[{\"internalType\":\"uint256\",\"name\":\"\",\"type\":\"uint256\"}],\"stateMutability\":\"view\",\"type\":\"function\"}]";
+
+pub fn get_sentinel_registration_call_data(sentinel: &EthAddress) -> Result<Bytes, SentinelError> {
+    Ok(encode_fxn_call(
+        REGISTRATION_MANAGER_ABI_FRAGMENT,
+        "sentinelRegistration",
+        &[EthAbiToken::Address(*sentinel)],
+    )?)
+}
+
+pub fn get_epochs_manager_call_data() -> Result<Bytes, SentinelError> {
+    Ok(encode_fxn_call(REGISTRATION_MANAGER_ABI_FRAGMENT, "epochsManager", &[])?)
+}
+
+pub fn get_epoch_duration_call_data() -> Result<Bytes, SentinelError> {
+    Ok(encode_fxn_call(EPOCHS_MANAGER_ABI_FRAGMENT, "epochDuration", &[])?)
+}
+
+pub fn get_start_first_epoch_timestamp_call_data() -> Result<Bytes, SentinelError> {
+    Ok(encode_fxn_call(
+        EPOCHS_MANAGER_ABI_FRAGMENT,
+        "startFirstEpochTimestamp",
+        &[],
+    )?)
+}
+
+fn get_u64_from_tokens(tokens: &[EthAbiToken], i: usize) -> Result<u64, RegistrationError> {
+    match tokens.get(i) {
+        Some(EthAbiToken::Uint(u)) if *u <= U256::from(u64::MAX) => Ok(u.as_u64()),
+        _ => Err(RegistrationError::CannotDecode(format!(
+            "expected a u64 at index {i} of {tokens:?}"
+        ))),
+    }
+}
+
+pub fn decode_address_output(bytes: &[u8]) -> Result<EthAddress, SentinelError> {
+    match eth_abi_decode(&[EthAbiParamType::Address], bytes)?.first() {
+        Some(EthAbiToken::Address(a)) => Ok(*a),
+        _ => Err(RegistrationError::CannotDecode("expected an address".into()).into()),
+    }
+}
+
+pub fn decode_u64_output(bytes: &[u8]) -> Result<u64, SentinelError> {
+    let tokens = eth_abi_decode(&[EthAbiParamType::Uint(256)], bytes)?;
+    Ok(get_u64_from_tokens(&tokens, 0)?)
+}
+
+// NOTE: A sentinel's registration covers each epoch from its starting epoch to its ending epoch
+// inclusive, and so it expires at the end of its ending epoch.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct SentinelRegistration {
+    owner: EthAddress,
+    starting_epoch: u64,
+    ending_epoch: u64,
+}
+
+impl SentinelRegistration {
+    pub fn from_eth_call_output(sentinel: &EthAddress, bytes: &[u8]) -> Result<Self, SentinelError> {
+        // NOTE: The registration struct is static, and so it's abi encoded as its fields would be.
+        let tokens = eth_abi_decode(
+            &[
+                EthAbiParamType::Address,
+                EthAbiParamType::Uint(16),
+                EthAbiParamType::Uint(16),
+                EthAbiParamType::FixedBytes(1),
+            ],
+            bytes,
+        )?;
+        let owner = match tokens.first() {
+            Some(EthAbiToken::Address(a)) => Ok(*a),
+            _ => Err(RegistrationError::CannotDecode(format!(
+                "expected an owner address in {tokens:?}"
+            ))),
+        }?;
+        if owner.is_zero() {
+            return Err(RegistrationError::NotRegistered(*sentinel).into());
+        };
+        Ok(Self {
+            owner,
+            starting_epoch: get_u64_from_tokens(&tokens, 1)?,
+            ending_epoch: get_u64_from_tokens(&tokens, 2)?,
+        })
+    }
+
+    pub fn expiry(&self, start_first_epoch_timestamp: u64, epoch_duration: u64) -> u64 {
+        start_first_epoch_timestamp.saturating_add((self.ending_epoch + 1).saturating_mul(epoch_duration))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethabi::encode as eth_abi_encode;
+
+    use super::*;
+
+    fn get_sample_eth_call_output(owner: EthAddress, starting_epoch: u64, ending_epoch: u64) -> Bytes {
+        eth_abi_encode(&[
+            EthAbiToken::Address(owner),
+            EthAbiToken::Uint(U256::from(starting_epoch)),
+            EthAbiToken::Uint(U256::from(ending_epoch)),
+            EthAbiToken::FixedBytes(vec![1]),
+        ])
+    }
+
+    #[test]
+    fn should_get_sentinel_registration_from_eth_call_output() {
+        let owner = EthAddress::from_low_u64_be(1337);
+        let bytes = get_sample_eth_call_output(owner, 2, 5);
+        let result = SentinelRegistration::from_eth_call_output(&EthAddress::zero(), &bytes).unwrap();
+        assert_eq!(result.owner(), &owner);
+        assert_eq!(result.starting_epoch(), &2);
+        assert_eq!(result.ending_epoch(), &5);
+    }
+
+    #[test]
+    fn should_error_if_sentinel_is_not_registered() {
+        let bytes = get_sample_eth_call_output(EthAddress::zero(), 0, 0);
+        let result = SentinelRegistration::from_eth_call_output(&EthAddress::zero(), &bytes);
+        assert!(matches!(
+            result,
+            Err(SentinelError::Registration(RegistrationError::NotRegistered(_)))
+        ));
+    }
+
+    #[test]
+    fn should_get_registration_expiry_at_end_of_ending_epoch() {
+        let owner = EthAddress::from_low_u64_be(1337);
+        let bytes = get_sample_eth_call_output(owner, 2, 5);
+        let registration = SentinelRegistration::from_eth_call_output(&EthAddress::zero(), &bytes).unwrap();
+        assert_eq!(registration.expiry(1_000, 100), 1_600);
+    }
+
+    #[test]
+    fn should_decode_u64_output() {
+        let bytes = eth_abi_encode(&[EthAbiToken::Uint(U256::from(1337))]);
+        assert_eq!(decode_u64_output(&bytes).unwrap(), 1337);
+    }
+
+    #[test]
+    fn should_decode_address_output() {
+        let address = EthAddress::from_low_u64_be(1337);
+        let bytes = eth_abi_encode(&[EthAbiToken::Address(address)]);
+        assert_eq!(decode_address_output(&bytes).unwrap(), address);
+    }
+
+    #[test]
+    fn should_encode_call_data() {
+        assert_eq!(
+            get_sentinel_registration_call_data(&EthAddress::zero()).unwrap().len(),
+            36
+        );
+        assert_eq!(get_epochs_manager_call_data().unwrap().len(), 4);
+        assert_eq!(get_epoch_duration_call_data().unwrap().len(), 4);
+        assert_eq!(get_start_first_epoch_timestamp_call_data().unwrap().len(), 4);
+    }
+}
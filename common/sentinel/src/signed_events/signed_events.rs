@@ -5,7 +5,7 @@ use ethereum_types::H256 as EthHash;
 use serde::{Deserialize, Serialize};
 
 use super::{SignedEvent, SignedEventError};
-use crate::{ConfiguredEvent, MerkleProof, MerkleTree, NetworkConfig};
+use crate::{MerkleProof, MerkleTree, NetworkConfig};
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, Constructor, Deref, DerefMut)]
 pub struct SignedEvents(Vec<SignedEvent>);
@@ -44,12 +44,12 @@ impl TryFrom<(&MetadataChainId, &EthPrivateKey, &EthSubmissionMaterial, &Network
         let mut relevant_infos: Vec<(EthHash, MerkleProof, Vec<EthLog>)> = vec![];
 
         // NOTE: These are the events that the sentinel is configured to watch out for (via the config file)
-        for ConfiguredEvent { address, topic } in network_config.events().iter() {
-            for receipt in eth_submission_material.receipts.iter() {
+        for event in network_config.events().iter() {
+            for (i, receipt) in eth_submission_material.receipts.iter().enumerate() {
                 let mut relevant_logs = vec![];
 
                 for log in receipt.logs.iter() {
-                    if log.is_from_address_and_contains_topic(address, topic) {
+                    if event.matches(log) {
                         relevant_logs.push(log.clone())
                     };
                 }
@@ -58,9 +58,17 @@ impl TryFrom<(&MetadataChainId, &EthPrivateKey, &EthSubmissionMaterial, &Network
                     continue;
                 } else {
                     debug!("found {} relevant logs", relevant_logs.len());
-                    let (transaction_index, _) = receipt.get_rlp_encoded_index_and_rlp_encoded_receipt_tuple()?;
+                    // NOTE: Pre-filtered sub mats already carry the proofs for their receipts, and a
+                    // trie built from a subset of receipts would not match the block's receipts root.
                     let receipt_inclusion_proof =
-                        MerkleProof::try_from((&mut merkle_tree, transaction_index.as_ref()))?;
+                        match eth_submission_material.receipt_proofs.as_ref().and_then(|p| p.get(i)) {
+                            Some(proof) => MerkleProof::new(proof.to_vec()),
+                            None => {
+                                let (transaction_index, _) =
+                                    receipt.get_rlp_encoded_index_and_rlp_encoded_receipt_tuple()?;
+                                MerkleProof::try_from((&mut merkle_tree, transaction_index.as_ref()))?
+                            },
+                        };
                     relevant_infos.push((receipt.transaction_hash, receipt_inclusion_proof, relevant_logs.clone()));
                     relevant_logs.clear();
                 }
@@ -1,4 +1,5 @@
 use common_network_ids::NetworkId;
+use derive_getters::Getters;
 use derive_more::{Constructor, Deref};
 use serde::{Deserialize, Serialize};
 
@@ -23,7 +24,7 @@ impl From<(Vec<NetworkId>, Vec<u64>, Vec<u64>)> for SyncState {
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, Getters)]
 #[serde(rename_all = "camelCase")]
 pub struct SyncStatus {
     network_id: NetworkId,
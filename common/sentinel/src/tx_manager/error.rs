@@ -0,0 +1,14 @@
+use common_network_ids::NetworkId;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum TxManagerError {
+    #[error("a tx with nonce {nonce} is already pending on network {network_id}")]
+    NonceInUse { network_id: NetworkId, nonce: u64 },
+
+    #[error("no pending tx with nonce {nonce} on network {network_id}")]
+    NotFound { network_id: NetworkId, nonce: u64 },
+
+    #[error("cannot convert from: '{from}' to: 'PendingTxs'")]
+    CannotConvert { from: String },
+}
@@ -0,0 +1,146 @@
+use common::Bytes;
+use common_chain_ids::EthChainId;
+use common_eth::{EthPrivateKey, EthTransaction};
+use common_network_ids::NetworkId;
+use derive_getters::Getters;
+use ethereum_types::{Address as EthAddress, H256 as EthHash};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as Json};
+
+use crate::{EthFees, SentinelError};
+
+// NOTE: A tx submitted by the sentinel, which remains pending until it is mined. Each time it's
+// replaced with one paying higher fees, the replacement's hash is appended to the hashes, since any
+// one of them may end up being the one that's mined.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedTx {
+    network_id: NetworkId,
+    nonce: u64,
+    to: EthAddress,
+    #[serde(with = "hex")]
+    data: Bytes,
+    gas_limit: usize,
+    fees: EthFees,
+    tx_hashes: Vec<EthHash>,
+    created_at: u64,
+    submitted_at: u64,
+    num_bumps: u64,
+}
+
+impl ManagedTx {
+    pub fn new(
+        network_id: NetworkId,
+        nonce: u64,
+        to: EthAddress,
+        data: Bytes,
+        gas_limit: usize,
+        fees: EthFees,
+        now: u64,
+    ) -> Self {
+        Self {
+            to,
+            data,
+            fees,
+            nonce,
+            gas_limit,
+            network_id,
+            num_bumps: 0,
+            created_at: now,
+            submitted_at: now,
+            tx_hashes: vec![],
+        }
+    }
+
+    pub fn sign(&self, pk: &EthPrivateKey) -> Result<EthTransaction, SentinelError> {
+        let value = 0;
+        let ecid = EthChainId::try_from(self.network_id)?;
+        Ok(self
+            .fees
+            .to_unsigned_tx(self.data.clone(), self.nonce, value, self.to, &ecid, self.gas_limit)
+            .sign(pk)?)
+    }
+
+    pub fn add_tx_hash(&mut self, tx_hash: EthHash, now: u64) {
+        self.tx_hashes.push(tx_hash);
+        self.submitted_at = now;
+    }
+
+    pub fn is_stuck(&self, now: u64, stuck_after: u64) -> bool {
+        now.saturating_sub(self.submitted_at) >= stuck_after
+    }
+
+    pub fn bump_fees(&mut self, percent: u64) {
+        self.fees = self.fees.bump(percent);
+        self.num_bumps += 1;
+    }
+
+    pub fn to_json(&self) -> Json {
+        json!({
+            "networkId": self.network_id.to_string(),
+            "nonce": self.nonce,
+            "to": format!("0x{}", hex::encode(self.to)),
+            "gasLimit": self.gas_limit,
+            "fees": self.fees,
+            "txHashes": self.tx_hashes.iter().map(|h| format!("0x{h:x}")).collect::<Vec<_>>(),
+            "createdAt": self.created_at,
+            "submittedAt": self.submitted_at,
+            "numBumps": self.num_bumps,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn get_sample_managed_tx() -> ManagedTx {
+        let network_id = NetworkId::try_from("polygon").unwrap();
+        ManagedTx::new(
+            network_id,
+            1,
+            EthAddress::zero(),
+            vec![1, 2, 3],
+            21_000,
+            EthFees::legacy(100),
+            1337,
+        )
+    }
+
+    #[test]
+    fn should_sign_managed_tx() {
+        let pk = EthPrivateKey::from_str("dfcc79a57e91c42d7eea05f82a08bd1b7e77f30236bb7c56fe98d3366a1929c4").unwrap();
+        let tx = get_sample_managed_tx();
+        let result = tx.sign(&pk).unwrap();
+        assert_eq!(result.nonce, 1.into());
+    }
+
+    #[test]
+    fn should_only_be_stuck_after_given_time_since_submission() {
+        let mut tx = get_sample_managed_tx();
+        let stuck_after = 120;
+        assert!(!tx.is_stuck(1337 + stuck_after - 1, stuck_after));
+        assert!(tx.is_stuck(1337 + stuck_after, stuck_after));
+        tx.add_tx_hash(EthHash::zero(), 1337 + stuck_after);
+        assert!(!tx.is_stuck(1337 + stuck_after, stuck_after));
+    }
+
+    #[test]
+    fn should_bump_managed_tx_fees() {
+        let mut tx = get_sample_managed_tx();
+        tx.bump_fees(15);
+        assert_eq!(tx.fees(), &EthFees::legacy(115));
+        assert_eq!(tx.num_bumps(), &1);
+    }
+
+    #[test]
+    fn should_serde_managed_tx() {
+        let mut tx = get_sample_managed_tx();
+        tx.add_tx_hash(EthHash::zero(), 1338);
+        let bytes = serde_json::to_vec(&tx).unwrap();
+        let result: ManagedTx = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(result, tx);
+    }
+}
@@ -0,0 +1,5 @@
+mod error;
+mod managed_tx;
+mod pending_txs;
+
+pub use self::{error::TxManagerError, managed_tx::ManagedTx, pending_txs::PendingTxs};
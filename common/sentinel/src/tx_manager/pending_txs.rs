@@ -0,0 +1,189 @@
+use common::{Byte, DatabaseInterface, MIN_DATA_SENSITIVITY_LEVEL};
+use common_network_ids::NetworkId;
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as Json};
+
+use super::{ManagedTx, TxManagerError};
+use crate::{
+    db_utils::{DbKey, DbUtilsT, SentinelDbKeys, SentinelDbUtils},
+    SentinelError,
+    WebSocketMessagesEncodable,
+};
+
+// NOTE: The txs the sentinel has submitted but which have yet to be mined, across all networks. These
+// are persisted in the core so that they survive restarts, and so that the nonces of any subsequent
+// txs account for them, since a node's nonce for an address only includes mined txs.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Getters)]
+pub struct PendingTxs {
+    txs: Vec<ManagedTx>,
+}
+
+impl DbUtilsT for PendingTxs {
+    fn key(&self) -> Result<DbKey, SentinelError> {
+        Ok(SentinelDbKeys::get_pending_txs_db_key())
+    }
+
+    fn sensitivity() -> Option<Byte> {
+        MIN_DATA_SENSITIVITY_LEVEL
+    }
+
+    fn from_bytes(bytes: &[Byte]) -> Result<Self, SentinelError> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
+
+impl TryFrom<WebSocketMessagesEncodable> for PendingTxs {
+    type Error = SentinelError;
+
+    fn try_from(m: WebSocketMessagesEncodable) -> Result<Self, Self::Error> {
+        match m {
+            WebSocketMessagesEncodable::Success(json) => Ok(serde_json::from_value(json)?),
+            _ => Err(TxManagerError::CannotConvert { from: m.to_string() }.into()),
+        }
+    }
+}
+
+impl PendingTxs {
+    pub fn get<D: DatabaseInterface>(db: &D) -> Self {
+        let key = SentinelDbKeys::get_pending_txs_db_key();
+        match Self::get_from_db(&SentinelDbUtils::new(db), &key) {
+            Ok(p) => p,
+            Err(_) => {
+                debug!("no pending txs in db, using default");
+                Self::default()
+            },
+        }
+    }
+
+    pub fn save<D: DatabaseInterface>(&self, db: &D) -> Result<(), SentinelError> {
+        self.update_in_db(&SentinelDbUtils::new(db))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.txs.is_empty()
+    }
+
+    fn position(&self, network_id: &NetworkId, nonce: u64) -> Option<usize> {
+        self.txs
+            .iter()
+            .position(|tx| tx.network_id() == network_id && *tx.nonce() == nonce)
+    }
+
+    // NOTE: The chain's nonce for an address only accounts for its mined txs, and so any txs still
+    // pending for that network must be skipped over.
+    pub fn next_nonce(&self, network_id: &NetworkId, chain_nonce: u64) -> u64 {
+        self.txs
+            .iter()
+            .filter(|tx| tx.network_id() == network_id)
+            .map(|tx| tx.nonce() + 1)
+            .fold(chain_nonce, std::cmp::max)
+    }
+
+    pub fn add(&mut self, tx: ManagedTx) -> Result<(), TxManagerError> {
+        if self.position(tx.network_id(), *tx.nonce()).is_some() {
+            return Err(TxManagerError::NonceInUse {
+                network_id: *tx.network_id(),
+                nonce: *tx.nonce(),
+            });
+        };
+        self.txs.push(tx);
+        Ok(())
+    }
+
+    pub fn update(&mut self, tx: ManagedTx) -> Result<(), TxManagerError> {
+        match self.position(tx.network_id(), *tx.nonce()) {
+            Some(i) => {
+                self.txs[i] = tx;
+                Ok(())
+            },
+            None => Err(TxManagerError::NotFound {
+                network_id: *tx.network_id(),
+                nonce: *tx.nonce(),
+            }),
+        }
+    }
+
+    pub fn remove(&mut self, network_id: &NetworkId, nonce: u64) -> Result<ManagedTx, TxManagerError> {
+        match self.position(network_id, nonce) {
+            Some(i) => Ok(self.txs.remove(i)),
+            None => Err(TxManagerError::NotFound {
+                network_id: *network_id,
+                nonce,
+            }),
+        }
+    }
+
+    pub fn to_json(&self) -> Json {
+        json!({
+            "numPendingTxs": self.txs.len(),
+            "pendingTxs": self.txs.iter().map(ManagedTx::to_json).collect::<Vec<Json>>(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::get_test_database;
+    use ethereum_types::Address as EthAddress;
+
+    use super::*;
+    use crate::EthFees;
+
+    fn get_sample_tx(network_id: &str, nonce: u64) -> ManagedTx {
+        let nid = NetworkId::try_from(network_id).unwrap();
+        ManagedTx::new(nid, nonce, EthAddress::zero(), vec![], 21_000, EthFees::legacy(1), 0)
+    }
+
+    #[test]
+    fn should_get_default_pending_txs_if_none_in_db() {
+        let db = get_test_database();
+        let result = PendingTxs::get(&db);
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn should_save_and_get_pending_txs() {
+        let db = get_test_database();
+        let mut pending = PendingTxs::default();
+        pending.add(get_sample_tx("polygon", 1)).unwrap();
+        pending.save(&db).unwrap();
+        let result = PendingTxs::get(&db);
+        assert_eq!(result, pending);
+    }
+
+    #[test]
+    fn should_get_next_nonce_accounting_for_pending_txs() {
+        let mut pending = PendingTxs::default();
+        let polygon = NetworkId::try_from("polygon").unwrap();
+        let bsc = NetworkId::try_from("bsc").unwrap();
+        assert_eq!(pending.next_nonce(&polygon, 5), 5);
+        pending.add(get_sample_tx("polygon", 5)).unwrap();
+        pending.add(get_sample_tx("polygon", 6)).unwrap();
+        assert_eq!(pending.next_nonce(&polygon, 5), 7);
+        assert_eq!(pending.next_nonce(&polygon, 10), 10);
+        assert_eq!(pending.next_nonce(&bsc, 0), 0);
+    }
+
+    #[test]
+    fn should_not_add_pending_tx_with_nonce_in_use() {
+        let mut pending = PendingTxs::default();
+        pending.add(get_sample_tx("polygon", 1)).unwrap();
+        assert!(pending.add(get_sample_tx("polygon", 1)).is_err());
+        assert!(pending.add(get_sample_tx("bsc", 1)).is_ok());
+    }
+
+    #[test]
+    fn should_update_and_remove_pending_tx() {
+        let mut pending = PendingTxs::default();
+        let polygon = NetworkId::try_from("polygon").unwrap();
+        let mut tx = get_sample_tx("polygon", 1);
+        pending.add(tx.clone()).unwrap();
+        tx.bump_fees(10);
+        pending.update(tx.clone()).unwrap();
+        assert_eq!(pending.txs(), &vec![tx.clone()]);
+        assert_eq!(pending.remove(&polygon, 1).unwrap(), tx);
+        assert!(pending.remove(&polygon, 1).is_err());
+        assert!(pending.is_empty());
+    }
+}